@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS "public"."channels" (
+    "id" varchar(255) NOT NULL,
+    "name" varchar(255) NOT NULL,
+    "owner_id" uuid NOT NULL,
+    "topic" text NOT NULL DEFAULT '',
+    "topic_updated_by" varchar(255) NOT NULL DEFAULT '',
+    "topic_updated_at" timestamptz DEFAULT NOW(),
+    "created_at" timestamptz DEFAULT NOW(),
+    "updated_at" timestamptz DEFAULT NOW(),
+    PRIMARY KEY ("id")
+);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS "public"."channels_access_controls" (
+    "channel_id" varchar(255) NOT NULL
+        REFERENCES "public"."channels" ("id") ON DELETE CASCADE,
+    "letter" char(1) NOT NULL,
+    "mask" varchar(255) NOT NULL,
+    "updated_by" varchar(255) NOT NULL,
+    "updated_at" timestamptz DEFAULT NOW(),
+    "expires_at" timestamptz NULL,
+    "reason" varchar(255) NULL,
+    PRIMARY KEY ("channel_id", "letter", "mask")
+);
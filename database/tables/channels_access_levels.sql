@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS "public"."channels_access_levels" (
+    "channel_id" varchar(255) NOT NULL
+        REFERENCES "public"."channels" ("id") ON DELETE CASCADE,
+    "user_id" uuid NOT NULL,
+    "letter" char(1) NOT NULL,
+    PRIMARY KEY ("channel_id", "user_id", "letter")
+);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS "public"."channels_settings" (
+    "channel_id" varchar(255) NOT NULL
+        REFERENCES "public"."channels" ("id") ON DELETE CASCADE,
+    "letter" char(1) NOT NULL,
+    "flag" text NOT NULL,
+    "updated_by" varchar(255) NOT NULL,
+    "updated_at" timestamptz DEFAULT NOW(),
+    PRIMARY KEY ("channel_id", "letter")
+);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS "public"."chat_history" (
+    "id" uuid NOT NULL,
+    "target" varchar(255) NOT NULL,
+    "sender" varchar(255) NOT NULL,
+    "sender_id" varchar(255) NOT NULL DEFAULT '',
+    "recipient" varchar(255) NOT NULL,
+    "recipient_id" varchar(255) NOT NULL DEFAULT '',
+    "command" varchar(16) NOT NULL,
+    "payload" text NOT NULL,
+    "created_at" timestamptz DEFAULT NOW(),
+    PRIMARY KEY ("id")
+);
+
+CREATE INDEX IF NOT EXISTS "chat_history_target_created_at_idx"
+    ON "public"."chat_history" ("target", "created_at");
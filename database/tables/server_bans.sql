@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS "public"."server_bans" (
+    "kind" varchar(8) NOT NULL,
+    "mask" varchar(255) NOT NULL,
+    "reason" varchar(255) NOT NULL,
+    "updated_by" varchar(255) NOT NULL,
+    "updated_at" timestamptz DEFAULT NOW(),
+    "expires_at" timestamptz NULL,
+    PRIMARY KEY ("kind", "mask")
+);
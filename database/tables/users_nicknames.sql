@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS "public"."users_nicknames" (
+    "nickname" varchar(255) NOT NULL,
+    "user_id" uuid NOT NULL,
+    "created_at" timestamptz DEFAULT NOW(),
+    PRIMARY KEY ("nickname")
+);
+
+CREATE INDEX IF NOT EXISTS "users_nicknames_user_id_idx"
+    ON "public"."users_nicknames" ("user_id");
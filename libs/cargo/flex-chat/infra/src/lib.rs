@@ -13,4 +13,4 @@ pub(crate) mod socket;
 pub mod channel;
 pub mod client;
 
-pub use flex_chat::{macros, mode, user};
+pub use flex_chat::{irc, macros, mode, user};
@@ -10,7 +10,13 @@
 
 use std::collections::HashSet;
 
-use flex_chat::channel::{Channel, ChannelInterface, ChannelMemberInterface};
+use flex_chat::channel::{
+	Channel,
+	ChannelAccessLevel,
+	ChannelInterface,
+	ChannelMemberInterface,
+	MemberInterface,
+};
 use flex_chat::client::channel::errors::{
 	ErrChanoprivsneededError,
 	ErrNosuchchannelError,
@@ -26,7 +32,12 @@ use flex_chat::client::channel::responses::{
 };
 use flex_chat::client::nick::errors::ErrNosuchnickError;
 use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
-use flex_chat::client::{ClientInterface, ClientSocketInterface, Origin};
+use flex_chat::client::{
+	Capability,
+	ClientInterface,
+	ClientSocketInterface,
+	Origin,
+};
 use flex_chat::user::UserInterface;
 use tracing::instrument;
 
@@ -36,6 +47,17 @@ use crate::client::Client;
 // Énumération //
 // ----------- //
 
+/// Destinataires de la liste des membres d'un salon.
+enum NamesRecipients
+{
+	/// Uniquement le client courant.
+	Itself,
+	/// Les clients d'une chambre, y compris le client courant.
+	Room(String),
+	/// Les clients d'une chambre, à l'exception de ceux d'une autre chambre.
+	RoomExcept(String, String),
+}
+
 pub enum Socket<'a>
 {
 	Owned
@@ -88,6 +110,20 @@ impl<'a> Socket<'a>
 		channels
 	}
 
+	/// Quitte la chambre d'un salon, ainsi que les chambres des niveaux
+	/// d'accès de ce salon.
+	pub fn leave_channel_rooms(&self, channel_id: &str)
+	{
+		let channel_id = channel_id.to_lowercase();
+		_ = self.socket().leave(format!("channel:{channel_id}"));
+		for access_level in ChannelAccessLevel::ALL {
+			_ = self.socket().leave(format!(
+				"channel:{}{channel_id}",
+				access_level.symbol()
+			));
+		}
+	}
+
 	/// Vérifie si le pseudonyme donné est le même que celui sauvegardé dans
 	/// l'instance du client courant.
 	pub fn has_same_nickname(&self, nickname: &str) -> bool
@@ -98,6 +134,36 @@ impl<'a> Socket<'a>
 
 impl<'a> Socket<'a>
 {
+	/// Émet un événement aux clients des salons donnés (à l'exception du
+	/// client courant) ayant activé une capacité serveur.
+	pub fn emit_to_capable<E, S>(
+		&self,
+		rooms: impl IntoIterator<Item = String>,
+		capability: Capability,
+		event: E,
+		data: S,
+	) where
+		E: ToString,
+		S: serde::Serialize,
+	{
+		let rooms: Vec<_> = rooms.into_iter().collect();
+		let sockets =
+			self.socket().broadcast().to(rooms).sockets().unwrap_or_default();
+
+		let event = event.to_string();
+
+		for socket in sockets {
+			let is_capable = socket
+				.extensions
+				.get::<Client>()
+				.filter(|client| client.has_capability(capability))
+				.is_some();
+			if is_capable {
+				_ = socket.emit(event.clone(), &data);
+			}
+		}
+	}
+
 	pub fn send_err(&self, comment: impl ToString)
 	{
 		self.emit("ERROR", comment.to_string());
@@ -231,11 +297,97 @@ impl<'a> ChannelClientSocketCommandResponse for Socket<'a>
 	fn send_rpl_namreply<DTO>(
 		&self,
 		channel: &Self::Channel,
-		mut map_member: impl FnMut(
+		map_member: impl FnMut(
 			&<Self::Channel as ChannelMemberInterface>::Member,
 		) -> Option<DTO>,
 	) where
 		DTO: serde::Serialize + std::fmt::Debug,
+	{
+		self.emit_rpl_namreply(channel, map_member, false);
+	}
+
+	fn send_rpl_namreply_to_self<DTO>(
+		&self,
+		channel: &Self::Channel,
+		map_member: impl FnMut(
+			&<Self::Channel as ChannelMemberInterface>::Member,
+		) -> Option<DTO>,
+	) where
+		DTO: serde::Serialize + std::fmt::Debug,
+	{
+		self.emit_rpl_namreply(channel, map_member, true);
+	}
+}
+
+impl Socket<'_>
+{
+	fn emit_rpl_namreply<DTO>(
+		&self,
+		channel: &Channel<uuid::Uuid>,
+		mut map_member: impl FnMut(
+			&<Channel<uuid::Uuid> as ChannelMemberInterface>::Member,
+		) -> Option<DTO>,
+		only_self: bool,
+	) where
+		DTO: serde::Serialize + std::fmt::Debug,
+	{
+		if only_self {
+			// NOTE: sur un salon +u, le client ne voit que lui-même et les
+			//       membres visibles, à moins d'être opérateur du salon.
+			let sees_everyone = channel.can_member_see_everyone(self.cid());
+			self.emit_rpl_namreply_members(
+				channel,
+				|member| {
+					(sees_everyone
+						|| member.id() == self.cid()
+						|| channel.is_member_visible(member.id()))
+					.then(|| map_member(member))
+					.flatten()
+				},
+				&NamesRecipients::Itself,
+			);
+			return;
+		}
+
+		if !channel.is_auditorium() {
+			self.emit_rpl_namreply_members(
+				channel,
+				map_member,
+				&NamesRecipients::Room(channel.room()),
+			);
+			return;
+		}
+
+		// NOTE: sur un salon +u, seuls les opérateurs du salon reçoivent la
+		//       liste complète des membres, les autres membres ne reçoivent
+		//       que les membres visibles.
+		let operators_room = channel.operators_room();
+		self.emit_rpl_namreply_members(
+			channel,
+			&mut map_member,
+			&NamesRecipients::Room(operators_room.clone()),
+		);
+		self.emit_rpl_namreply_members(
+			channel,
+			|member| {
+				channel
+					.is_member_visible(member.id())
+					.then(|| map_member(member))
+					.flatten()
+			},
+			&NamesRecipients::RoomExcept(channel.room(), operators_room),
+		);
+	}
+
+	fn emit_rpl_namreply_members<DTO>(
+		&self,
+		channel: &Channel<uuid::Uuid>,
+		mut map_member: impl FnMut(
+			&<Channel<uuid::Uuid> as ChannelMemberInterface>::Member,
+		) -> Option<DTO>,
+		recipients: &NamesRecipients,
+	) where
+		DTO: serde::Serialize + std::fmt::Debug,
 	{
 		let origin = Origin::from(self.client());
 		let rpl_names = Vec::from_iter(channel.members());
@@ -253,7 +405,7 @@ impl<'a> ChannelClientSocketCommandResponse for Socket<'a>
 		});
 
 		for rpl_name in rpl_names {
-			self.emit_within(channel.room(), rpl_name.name(), rpl_name);
+			self.emit_names(recipients, rpl_name.name(), rpl_name);
 		}
 
 		let rpl_endofnames = RplEndofnamesReply {
@@ -261,7 +413,26 @@ impl<'a> ChannelClientSocketCommandResponse for Socket<'a>
 			channel: &channel.name,
 			tags: RplEndofnamesReply::default_tags(),
 		};
-		self.emit_within(channel.room(), rpl_endofnames.name(), rpl_endofnames);
+		self.emit_names(recipients, rpl_endofnames.name(), rpl_endofnames);
+	}
+
+	fn emit_names<S>(&self, recipients: &NamesRecipients, event: &str, data: S)
+	where
+		S: serde::Serialize + std::fmt::Debug,
+	{
+		match recipients {
+			| NamesRecipients::Itself => self.emit(event, data),
+			| NamesRecipients::Room(room) => {
+				self.emit_within(room, event, data);
+			}
+			| NamesRecipients::RoomExcept(room, except) => {
+				_ = self
+					.socket()
+					.within(room.to_owned())
+					.except(except.to_owned())
+					.emit(event.to_owned(), data);
+			}
+		}
 	}
 }
 
@@ -51,9 +51,12 @@ $crate::paste::paste! { $(
 	{
 		pub fn default_tags() -> std::collections::HashMap<String, $crate::serde_json::Value>
 		{
-			return [("msgid", $crate::Uuid::new_v4())]
+			return [
+				("msgid", $crate::Uuid::new_v4().to_string()),
+				("time", $crate::server_time()),
+			]
 				.into_iter()
-				.map(|(k, v)| (k.to_string(), v.to_string().into()))
+				.map(|(k, v)| (k.to_string(), v.into()))
 				.collect()
 		}
 	}
@@ -15,3 +15,12 @@ mod reserved_numerics;
 
 pub use flex_web_framework::types::uuid::Uuid;
 pub use {paste, serde, serde_json};
+
+/// Date à laquelle le serveur traite un message, au format attendu par
+/// l'étiquette `time` de la capacité serveur `server-time`.
+pub fn server_time() -> String
+{
+	flex_web_framework::types::time::Utc::now()
+		.format("%Y-%m-%dT%H:%M:%S%.3fZ")
+		.to_string()
+}
@@ -53,9 +53,12 @@ $crate::paste::paste! { $(
 		#[allow(dead_code)]
 		pub fn default_tags() -> std::collections::HashMap<String, String>
 		{
-			return [("msgid", $crate::Uuid::new_v4())]
+			return [
+				("msgid", $crate::Uuid::new_v4().to_string()),
+				("time", $crate::server_time()),
+			]
 				.into_iter()
-				.map(|(k, v)| (k.to_string(), v.to_string()))
+				.map(|(k, v)| (k.to_string(), v))
 				.collect()
 		}
 
@@ -12,20 +12,20 @@ mod interface;
 mod member;
 mod mode;
 mod permission;
+mod registration;
 mod topic;
 mod validation;
 
-use std::collections::HashMap;
-
-use flex_wildcard_matching::WildcardMatching;
+use std::collections::{HashMap, HashSet};
 
 pub use self::interface::*;
 pub use self::member::*;
 pub use self::mode::*;
 pub use self::permission::*;
+pub use self::registration::*;
 pub use self::topic::*;
 pub use self::validation::*;
-use crate::mode::{ApplyMode, Mask};
+use crate::mode::{ApplyMode, EXTBAN_QUIET, Mask};
 use crate::user::{User, UserAddressInterface, UserInterface};
 
 // ---- //
@@ -61,6 +61,10 @@ where
 	>,
 	/// Topic du salon.
 	pub(crate) topic: <Self as ChannelTopicInterface>::Topic,
+	/// Enregistrement du salon, s'il a été enregistré par son propriétaire.
+	pub registration: Option<ChannelRegistration<MemberID>>,
+	/// Les événements récents du salon, pour le mode +f.
+	pub(crate) flood: FloodTracker<MemberID>,
 }
 
 // -------------- //
@@ -83,6 +87,88 @@ where
 			access_control: Default::default(),
 			modes_settings: Default::default(),
 			topic: Default::default(),
+			registration: Default::default(),
+			flood: Default::default(),
+		}
+	}
+
+	/// Enregistre un événement du salon, lorsque le salon a le drapeau
+	/// +f <rules> et une règle pour ce type d'événement. Retourne la règle
+	/// dont le seuil vient d'être dépassé.
+	pub fn record_flood(&mut self, kind: FloodKind, id: ID)
+	-> Option<FloodRule>
+	{
+		let protection = self.modes_settings.flood_protection()?;
+		self.flood.record(protection, kind, id, std::time::Instant::now())
+	}
+
+	/// Est-ce que le salon est enregistré?
+	pub fn is_registered(&self) -> bool
+	{
+		self.registration.is_some()
+	}
+
+	/// Est-ce que le salon est permanent (drapeau +P)?
+	pub fn is_permanent(&self) -> bool
+	{
+		self.modes_settings.has_permanent_flag()
+	}
+
+	/// Est-ce que le salon est en mode auditorium (drapeau +u)?
+	pub fn is_auditorium(&self) -> bool
+	{
+		self.modes_settings.has_auditorium_flag()
+	}
+
+	/// Est-ce que le membre est visible de tous les membres du salon? Sur un
+	/// salon +u, seuls les membres ayant un niveau d'accès minimal à VIP le
+	/// sont.
+	pub fn is_member_visible(&self, member_id: &ID) -> bool
+	{
+		if !self.is_auditorium() {
+			return true;
+		}
+		self.members
+			.get(member_id)
+			.and_then(|member| member.highest_access_level())
+			.is_some()
+	}
+
+	/// Est-ce que le membre voit tous les membres du salon? Sur un salon +u,
+	/// seuls les opérateurs du salon (HalfOperator ou plus) les voient.
+	pub fn can_member_see_everyone(&self, member_id: &ID) -> bool
+	{
+		if !self.is_auditorium() {
+			return true;
+		}
+		self.members
+			.get(member_id)
+			.and_then(|member| member.highest_access_level())
+			.is_some_and(|access_level| {
+				access_level.flag() >= ChannelAccessLevel::HalfOperator.flag()
+			})
+	}
+
+	/// Chambre Socket des opérateurs du salon (HalfOperator ou plus).
+	pub fn operators_room(&self) -> String
+	{
+		format!(
+			"channel:{}{}",
+			ChannelAccessLevel::HalfOperator.symbol(),
+			self.id()
+		)
+	}
+
+	/// Chambre Socket dans laquelle sont diffusés les événements d'un membre
+	/// (JOIN, PART, QUIT, messages). Sur un salon +u, les événements des
+	/// membres sans niveau d'accès ne sont diffusés qu'aux opérateurs du
+	/// salon.
+	pub fn member_room(&self, member_id: &ID) -> String
+	{
+		if self.is_member_visible(member_id) {
+			self.room()
+		} else {
+			self.operators_room()
 		}
 	}
 }
@@ -146,9 +232,16 @@ where
 				(mode::CHANNEL_MODE_LIST_INVITE_EXCEPT, mode.clone())
 			});
 
+		let quietlist = self
+			.access_control
+			.quietlist
+			.values()
+			.map(|mode| (mode::CHANNEL_MODE_LIST_QUIET, mode.clone()));
+
 		list.extend(banlist);
 		list.extend(banlist_except);
 		list.extend(invitelist_except);
+		list.extend(quietlist);
 
 		list
 	}
@@ -163,12 +256,12 @@ where
 	fn add_ban(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<mode::AccessControlMask>>
 	{
-		let mask = mask.into();
-		let mask_s = mask.to_string();
-		let mode = ApplyMode::new(mode::AccessControlMask::new(mask))
+		let entry = mask.into();
+		let mask_s = entry.mask.to_string();
+		let mode = ApplyMode::new(entry)
 			.with_update_by(apply_by.nickname())
 			.with_args([mask_s.clone()]);
 		self.access_control.add_ban(mask_s, mode)
@@ -183,19 +276,29 @@ where
 	fn is_banned(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
 	) -> bool
 	{
-		if self.isin_banlist_exception(user) {
+		if self.isin_banlist_exception(user, user_channels) {
 			return false;
 		}
 
-		let check = |addr| self.access_control.banlist.contains_key(&addr);
+		let now = chrono::Utc::now();
+
+		let check = |addr| {
+			self.access_control
+				.banlist
+				.get(&addr)
+				.is_some_and(|mode| !mode.flag.is_expired(now))
+		};
 
 		let check2 = || {
 			self.access_control
 				.banlist
-				.keys()
-				.any(|mask| user.full_address().iswm(mask))
+				.values()
+				.filter(|mode| !mode.flag.is_expired(now))
+				.filter(|mode| !mode.flag.mask.is_action())
+				.any(|mode| mode.flag.mask.matches(user, user_channels))
 		};
 
 		check(user.address("*!*@*"))
@@ -215,6 +318,32 @@ where
 			|| check2()
 	}
 
+	fn is_banned_from_action(
+		&self,
+		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
+		extban: char,
+	) -> bool
+	{
+		if self.isin_banlist_exception(user, user_channels) {
+			return false;
+		}
+
+		let now = chrono::Utc::now();
+
+		self.access_control
+			.banlist
+			.values()
+			.filter(|mode| !mode.flag.is_expired(now))
+			.filter(|mode| {
+				mode.flag
+					.mask
+					.extended()
+					.is_some_and(|extended| extended.letter() == extban)
+			})
+			.any(|mode| mode.flag.mask.matches(user, user_channels))
+	}
+
 	fn remove_ban(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
@@ -240,12 +369,12 @@ where
 	fn add_ban_except(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<mode::AccessControlMask>>
 	{
-		let mask = mask.into();
-		let mask_s = mask.to_string();
-		let mode = ApplyMode::new(mode::AccessControlMask::new(mask))
+		let entry = mask.into();
+		let mask_s = entry.mask.to_string();
+		let mode = ApplyMode::new(entry)
 			.with_update_by(apply_by.nickname())
 			.with_args([mask_s.clone()]);
 		self.access_control.add_ban_except(mask_s, mode)
@@ -260,18 +389,25 @@ where
 	fn isin_banlist_exception(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
 	) -> bool
 	{
-		#[rustfmt::skip]
+		let now = chrono::Utc::now();
+
 		let check = |addr| {
-			self.access_control.banlist_except.contains_key(&addr)
+			self.access_control
+				.banlist_except
+				.get(&addr)
+				.is_some_and(|mode| !mode.flag.is_expired(now))
 		};
 
 		let check2 = || {
 			self.access_control
 				.banlist_except
-				.keys()
-				.any(|mask| user.full_address().iswm(mask))
+				.values()
+				.filter(|mode| !mode.flag.is_expired(now))
+				.filter(|mode| !mode.flag.mask.is_action())
+				.any(|mode| mode.flag.mask.matches(user, user_channels))
 		};
 
 		check(user.address("*!*@*"))
@@ -316,12 +452,12 @@ where
 	fn add_invite_except(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<mode::AccessControlMask>>
 	{
-		let mask = mask.into();
-		let mask_s = mask.to_string();
-		let mode = ApplyMode::new(mode::AccessControlMask::new(mask))
+		let entry = mask.into();
+		let mask_s = entry.mask.to_string();
+		let mode = ApplyMode::new(entry)
 			.with_update_by(apply_by.nickname())
 			.with_args([mask_s.clone()]);
 		self.access_control.add_invite_except(mask_s, mode)
@@ -336,18 +472,25 @@ where
 	fn isin_invitelist_exception(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
 	) -> bool
 	{
-		#[rustfmt::skip]
+		let now = chrono::Utc::now();
+
 		let check = |addr| {
-			self.access_control.invitelist_except.contains_key(&addr)
+			self.access_control
+				.invitelist_except
+				.get(&addr)
+				.is_some_and(|mode| !mode.flag.is_expired(now))
 		};
 
 		let check2 = || {
 			self.access_control
 				.invitelist_except
-				.keys()
-				.any(|mask| user.full_address().iswm(mask))
+				.values()
+				.filter(|mode| !mode.flag.is_expired(now))
+				.filter(|mode| !mode.flag.mask.is_action())
+				.any(|mode| mode.flag.mask.matches(user, user_channels))
 		};
 
 		check(user.address("*!*@*"))
@@ -383,6 +526,68 @@ where
 	}
 }
 
+impl<ID> ChannelAccessControlQuietInterface for Channel<ID>
+where
+	ID: Clone,
+	ID: ToString,
+	ID: PartialEq + Eq + std::hash::Hash,
+{
+	fn add_quiet(
+		&mut self,
+		apply_by: &<Self as ChannelAccessControlInterface>::User,
+		mask: impl Into<AccessControlMask>,
+	) -> Option<ApplyMode<mode::AccessControlMask>>
+	{
+		let entry = mask.into();
+		let mask_s = entry.mask.to_string();
+		let mode = ApplyMode::new(entry)
+			.with_update_by(apply_by.nickname())
+			.with_args([mask_s.clone()]);
+		self.access_control.add_quiet(mask_s, mode)
+	}
+
+	fn has_quietmask(&self, mask: &Mask) -> bool
+	{
+		let mask_s = mask.to_string();
+		self.access_control.quietlist.contains_key(&mask_s)
+	}
+
+	fn is_quieted(
+		&self,
+		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
+	) -> bool
+	{
+		if self.isin_banlist_exception(user, user_channels) {
+			return false;
+		}
+
+		let now = chrono::Utc::now();
+
+		self.access_control
+			.quietlist
+			.values()
+			.filter(|mode| !mode.flag.is_expired(now))
+			.any(|mode| mode.flag.mask.matches(user, user_channels))
+			|| self.is_banned_from_action(user, user_channels, EXTBAN_QUIET)
+	}
+
+	fn remove_quiet(
+		&mut self,
+		apply_by: &<Self as ChannelAccessControlInterface>::User,
+		mask: impl Into<Mask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	{
+		let mask = mask.into();
+		let mask_s = mask.to_string();
+		let mode = ApplyMode::new(mode::AccessControlMask::new(mask))
+			.with_update_by(apply_by.nickname())
+			.with_args([mask_s.clone()]);
+		self.access_control.remove_quiet(mask_s)?;
+		Some(mode)
+	}
+}
+
 impl<ID> ChannelAccessControlInviteInterface for Channel<ID>
 where
 	ID: Clone,
@@ -392,9 +597,28 @@ where
 	fn add_invite(
 		&mut self,
 		client_id: <Self as ChannelAccessControlInterface>::ClientID,
+		invite: ChannelInvite,
+	) -> bool
+	{
+		// NOTE: les invitations expirées sont retirées au fil de l'eau.
+		let now = chrono::Utc::now();
+		self.access_control
+			.invite_list
+			.retain(|_, invite| !invite.is_expired(now));
+
+		self.access_control.invite_list.insert(client_id, invite).is_none()
+	}
+
+	fn is_invited(
+		&self,
+		client_id: &<Self as ChannelAccessControlInterface>::ClientID,
 	) -> bool
 	{
-		self.access_control.invite_list.insert(client_id)
+		let now = chrono::Utc::now();
+		self.access_control
+			.invite_list
+			.get(client_id)
+			.is_some_and(|invite| !invite.is_expired(now))
 	}
 
 	fn remove_invite(
@@ -402,7 +626,7 @@ where
 		client_id: &<Self as ChannelAccessControlInterface>::ClientID,
 	) -> bool
 	{
-		self.access_control.invite_list.remove(client_id)
+		self.access_control.invite_list.remove(client_id).is_some()
 	}
 }
 
@@ -518,3 +742,40 @@ where
 		&mut self.topic
 	}
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_auditorium_member_room()
+	{
+		let mut channel = Channel::<u8>::new("#Flex");
+		channel.members_mut().insert(1, ChannelMember::new(1));
+		channel.members_mut().insert(
+			2,
+			ChannelMember::new(2).with_modes([ChannelAccessLevel::Vip]),
+		);
+		channel.members_mut().insert(
+			3,
+			ChannelMember::new(3).with_modes([ChannelAccessLevel::Operator]),
+		);
+
+		assert_eq!(channel.member_room(&1), "channel:#flex");
+		assert!(channel.can_member_see_everyone(&1));
+
+		channel.modes_settings.set(ApplyMode::new(SettingsFlag::Auditorium));
+
+		assert_eq!(channel.member_room(&1), "channel:%#flex");
+		assert_eq!(channel.member_room(&2), "channel:#flex");
+		assert_eq!(channel.member_room(&3), "channel:#flex");
+		assert!(!channel.can_member_see_everyone(&1));
+		assert!(!channel.can_member_see_everyone(&2));
+		assert!(channel.can_member_see_everyone(&3));
+	}
+}
@@ -8,7 +8,9 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::channel::AccessControlMask;
+use std::collections::HashSet;
+
+use crate::channel::{AccessControlMask, ChannelInvite};
 use crate::mode::{ApplyMode, Mask};
 use crate::user::UserInterface;
 
@@ -20,6 +22,7 @@ pub trait ChannelAccessControlInterface:
 	ChannelAccessControlBanInterface
 	+ ChannelAccessControlBanExceptInterface
 	+ ChannelAccessControlInviteInterface
+	+ ChannelAccessControlQuietInterface
 {
 	/// Type représentant un ID d'un client.
 	type ClientID;
@@ -37,7 +40,7 @@ pub trait ChannelAccessControlBanInterface
 	fn add_ban(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	where
 		Self: ChannelAccessControlInterface;
@@ -49,6 +52,18 @@ pub trait ChannelAccessControlBanInterface
 	fn is_banned(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
+	) -> bool
+	where
+		Self: ChannelAccessControlInterface;
+
+	/// Est-ce qu'un membre donné est concerné par un masque étendu d'action
+	/// (`~q`, `~n`) de la liste des bans.
+	fn is_banned_from_action(
+		&self,
+		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
+		extban: char,
 	) -> bool
 	where
 		Self: ChannelAccessControlInterface;
@@ -69,7 +84,7 @@ pub trait ChannelAccessControlBanExceptInterface
 	fn add_ban_except(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	where
 		Self: ChannelAccessControlInterface;
@@ -82,6 +97,7 @@ pub trait ChannelAccessControlBanExceptInterface
 	fn isin_banlist_exception(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
 	) -> bool
 	where
 		Self: ChannelAccessControlInterface;
@@ -102,7 +118,7 @@ pub trait ChannelAccessControlInviteExceptInterface
 	fn add_invite_except(
 		&mut self,
 		apply_by: &<Self as ChannelAccessControlInterface>::User,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	where
 		Self: ChannelAccessControlInterface;
@@ -115,6 +131,7 @@ pub trait ChannelAccessControlInviteExceptInterface
 	fn isin_invitelist_exception(
 		&self,
 		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
 	) -> bool
 	where
 		Self: ChannelAccessControlInterface;
@@ -129,12 +146,57 @@ pub trait ChannelAccessControlInviteExceptInterface
 		Self: ChannelAccessControlInterface;
 }
 
+pub trait ChannelAccessControlQuietInterface
+{
+	/// Ajoute un masque dans la liste des utilisateurs muets du salon.
+	fn add_quiet(
+		&mut self,
+		apply_by: &<Self as ChannelAccessControlInterface>::User,
+		mask: impl Into<AccessControlMask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	where
+		Self: ChannelAccessControlInterface;
+
+	/// Est-ce qu'une mask existe dans la liste des utilisateurs muets.
+	fn has_quietmask(&self, mask: &Mask) -> bool;
+
+	/// Est-ce qu'un membre donné est rendu muet sur le salon, par la liste
+	/// des utilisateurs muets ou par un masque `~q` de la liste des bans.
+	fn is_quieted(
+		&self,
+		user: &<Self as ChannelAccessControlInterface>::User,
+		user_channels: &HashSet<String>,
+	) -> bool
+	where
+		Self: ChannelAccessControlInterface;
+
+	/// Retire un masque de la liste des utilisateurs muets du salon.
+	fn remove_quiet(
+		&mut self,
+		apply_by: &<Self as ChannelAccessControlInterface>::User,
+		mask: impl Into<Mask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	where
+		Self: ChannelAccessControlInterface;
+}
+
 pub trait ChannelAccessControlInviteInterface
 {
-	/// Ajoute un utilisateur dans la liste des invitations du salon.
+	/// Ajoute un utilisateur dans la liste des invitations du salon. Une
+	/// invitation existante est remplacée.
 	fn add_invite(
 		&mut self,
 		id: <Self as ChannelAccessControlInterface>::ClientID,
+		invite: ChannelInvite,
+	) -> bool
+	where
+		Self: ChannelAccessControlInterface;
+
+	/// Est-ce qu'un utilisateur a une invitation en attente, non expirée,
+	/// sur le salon.
+	fn is_invited(
+		&self,
+		id: &<Self as ChannelAccessControlInterface>::ClientID,
 	) -> bool
 	where
 		Self: ChannelAccessControlInterface;
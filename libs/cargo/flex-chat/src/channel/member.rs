@@ -25,6 +25,8 @@ pub struct ChannelMember<ID>
 	member_id: ID,
 	/// Les niveaux d'accès liés au membre du salon.
 	access_level: HashSet<ChannelAccessLevel>,
+	/// Le membre est masqué aux autres membres du salon (drapeau +D).
+	hidden: bool,
 }
 
 // -------------- //
@@ -41,9 +43,22 @@ impl<ID> ChannelMember<ID>
 		Self {
 			member_id,
 			access_level: Default::default(),
+			hidden: false,
 		}
 	}
 
+	/// Est-ce que le membre est masqué aux autres membres du salon.
+	pub fn is_hidden(&self) -> bool
+	{
+		self.hidden
+	}
+
+	/// Masque ou révèle le membre aux autres membres du salon.
+	pub fn set_hidden(&mut self, hidden: bool)
+	{
+		self.hidden = hidden;
+	}
+
 	/// Crée la structure [ChannelMember] avec ses modes.
 	pub fn with_modes(
 		mut self,
@@ -10,6 +10,7 @@
 
 mod access_control;
 mod access_level;
+mod flood;
 mod settings;
 
 use std::collections::HashMap;
@@ -17,6 +18,7 @@ use std::ops;
 
 pub use self::access_control::*;
 pub use self::access_level::*;
+pub use self::flood::*;
 pub use self::settings::*;
 use crate::mode::ApplyMode;
 
@@ -8,7 +8,9 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
 
 use crate::mode::{ApplyMode, Mask};
 
@@ -19,6 +21,8 @@ use crate::mode::{ApplyMode, Mask};
 pub const CHANNEL_MODE_LIST_BAN: char = 'b';
 pub const CHANNEL_MODE_LIST_BAN_EXCEPT: char = 'e';
 pub const CHANNEL_MODE_LIST_INVITE_EXCEPT: char = 'I';
+// NOTE: la lettre `q` est déjà utilisée par le niveau d'accès propriétaire.
+pub const CHANNEL_MODE_LIST_QUIET: char = 'Q';
 
 // --------- //
 // Structure //
@@ -35,8 +39,10 @@ pub struct AccessControl<ID>
 	pub banlist_except: HashMap<String, ApplyMode<AccessControlMask>>,
 	/// Les exceptions du mode d'invitation uniquement du salon.
 	pub invitelist_except: HashMap<String, ApplyMode<AccessControlMask>>,
+	/// Les utilisateurs rendus muets sur le salon.
+	pub quietlist: HashMap<String, ApplyMode<AccessControlMask>>,
 	/// Les utilisateurs en attente dans la liste des invitations.
-	pub invite_list: HashSet<ID>,
+	pub invite_list: HashMap<ID, ChannelInvite>,
 }
 
 #[derive(Debug)]
@@ -46,6 +52,30 @@ pub struct AccessControl<ID>
 pub struct AccessControlMask
 {
 	pub mask: Mask,
+	/// Date à laquelle le masque expire. Aucune date signifie que le masque
+	/// est permanent.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<DateTime<Utc>>,
+	/// La raison de l'application du masque.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reason: Option<String>,
+}
+
+/// Invitation d'un utilisateur sur un salon.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ChannelInvite
+{
+	/// Le pseudonyme de l'utilisateur ayant invité.
+	pub invited_by: String,
+	/// Date de l'invitation.
+	pub invited_at: DateTime<Utc>,
+	/// Date à laquelle l'invitation expire. Aucune date signifie que
+	/// l'invitation n'expire pas.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<DateTime<Utc>>,
 }
 
 // -------------- //
@@ -56,7 +86,57 @@ impl AccessControlMask
 {
 	pub fn new(mask: Mask) -> Self
 	{
-		Self { mask }
+		Self {
+			mask,
+			expires_at: Default::default(),
+			reason: Default::default(),
+		}
+	}
+
+	/// Le masque expire après la durée donnée.
+	pub fn with_duration(mut self, duration: TimeDelta) -> Self
+	{
+		self.expires_at.replace(Utc::now() + duration);
+		self
+	}
+
+	/// La raison de l'application du masque.
+	pub fn with_reason(mut self, reason: impl ToString) -> Self
+	{
+		let reason = reason.to_string();
+		self.reason = (!reason.is_empty()).then_some(reason);
+		self
+	}
+
+	/// Est-ce que le masque a expiré à la date donnée.
+	pub fn is_expired(&self, now: DateTime<Utc>) -> bool
+	{
+		self.expires_at.is_some_and(|expires_at| expires_at <= now)
+	}
+}
+
+impl ChannelInvite
+{
+	pub fn new(invited_by: impl ToString) -> Self
+	{
+		Self {
+			invited_by: invited_by.to_string(),
+			invited_at: Utc::now(),
+			expires_at: Default::default(),
+		}
+	}
+
+	/// L'invitation expire après la durée donnée.
+	pub fn with_duration(mut self, duration: TimeDelta) -> Self
+	{
+		self.expires_at.replace(self.invited_at + duration);
+		self
+	}
+
+	/// Est-ce que l'invitation a expiré à la date donnée.
+	pub fn is_expired(&self, now: DateTime<Utc>) -> bool
+	{
+		self.expires_at.is_some_and(|expires_at| expires_at <= now)
 	}
 }
 
@@ -116,6 +196,24 @@ impl<ID> AccessControl<ID>
 		Some(mode)
 	}
 
+	pub fn add_quiet(
+		&mut self,
+		mask: impl Into<Mask>,
+		mode: ApplyMode<AccessControlMask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	{
+		let mask = mask.into();
+		let mask_key = mask.to_string();
+
+		if self.quietlist.contains_key(&mask_key) {
+			return None;
+		}
+
+		self.quietlist.insert(mask_key, mode.clone());
+
+		Some(mode)
+	}
+
 	pub fn remove_ban(
 		&mut self,
 		mask: impl Into<Mask>,
@@ -145,4 +243,206 @@ impl<ID> AccessControl<ID>
 		let mask_key = mask.to_string();
 		self.invitelist_except.remove(&mask_key)
 	}
+
+	pub fn remove_quiet(
+		&mut self,
+		mask: impl Into<Mask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	{
+		let mask = mask.into();
+		let mask_key = mask.to_string();
+		self.quietlist.remove(&mask_key)
+	}
+
+	/// Retire les masques expirés des listes de contrôle d'accès. Retourne les
+	/// masques retirés avec la lettre de leur liste.
+	pub fn remove_expired(
+		&mut self,
+		now: DateTime<Utc>,
+	) -> Vec<(char, ApplyMode<AccessControlMask>)>
+	{
+		let mut removed = Vec::default();
+
+		for (letter, list) in [
+			(CHANNEL_MODE_LIST_BAN, &mut self.banlist),
+			(CHANNEL_MODE_LIST_BAN_EXCEPT, &mut self.banlist_except),
+			(CHANNEL_MODE_LIST_INVITE_EXCEPT, &mut self.invitelist_except),
+			(CHANNEL_MODE_LIST_QUIET, &mut self.quietlist),
+		] {
+			list.retain(|_, mode| {
+				if !mode.flag.is_expired(now) {
+					return true;
+				}
+				removed.push((letter, mode.clone()));
+				false
+			});
+		}
+
+		removed
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<Mask> for AccessControlMask
+{
+	fn from(mask: Mask) -> Self
+	{
+		Self::new(mask)
+	}
+}
+
+/// Analyse d'une entrée d'une liste de contrôle d'accès.
+//
+// ```bnf
+// input :: [ duration ':' ] mask
+// ```
+//
+// EXAMPLE(1): "*!*@host"		-> "*!*@host" (permanent)
+// EXAMPLE(2): "1h:*!*@host"	-> "*!*@host" (expire dans une heure)
+// EXAMPLE(3): "1d12h:nick"		-> "nick!*@*" (expire dans un jour et demi)
+impl<S> From<S> for AccessControlMask
+where
+	S: AsRef<str>,
+{
+	fn from(s: S) -> Self
+	{
+		let entry = s.as_ref();
+
+		// NOTE: un hôte peut contenir le caractère `:` (IPv6), le préfixe
+		//       n'est considéré comme une durée que s'il est valide.
+		let Some((duration, mask)) =
+			entry.split_once(':').and_then(|(duration, mask)| {
+				Some((parse_duration(duration)?, mask))
+			})
+		else {
+			return Self::new(Mask::from(entry));
+		};
+
+		Self::new(Mask::from(mask)).with_duration(duration)
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Analyse d'une durée composée d'unités: `s` (secondes), `m` (minutes), `h`
+/// (heures), `d` (jours) et `w` (semaines).
+///
+/// Exemple: `45s`, `30m`, `1h`, `1d12h`, `2w`.
+pub fn parse_duration(input: &str) -> Option<TimeDelta>
+{
+	if input.is_empty() {
+		return None;
+	}
+
+	let mut total = TimeDelta::zero();
+	let mut digits = String::new();
+
+	for ch in input.chars() {
+		if ch.is_ascii_digit() {
+			digits.push(ch);
+			continue;
+		}
+
+		let value: i64 = digits.parse().ok()?;
+		digits.clear();
+
+		let delta = match ch {
+			| 's' => TimeDelta::try_seconds(value)?,
+			| 'm' => TimeDelta::try_minutes(value)?,
+			| 'h' => TimeDelta::try_hours(value)?,
+			| 'd' => TimeDelta::try_days(value)?,
+			| 'w' => TimeDelta::try_weeks(value)?,
+			| _ => return None,
+		};
+
+		total = total.checked_add(&delta)?;
+	}
+
+	// NOTE: chaque nombre DOIT être suivi d'une unité.
+	if !digits.is_empty() || total.is_zero() {
+		return None;
+	}
+
+	Some(total)
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_duration()
+	{
+		assert_eq!(parse_duration("45s"), TimeDelta::try_seconds(45));
+		assert_eq!(parse_duration("30m"), TimeDelta::try_minutes(30));
+		assert_eq!(parse_duration("1d12h"), TimeDelta::try_hours(36));
+		assert_eq!(parse_duration("2w"), TimeDelta::try_days(14));
+		assert_eq!(parse_duration(""), None);
+		assert_eq!(parse_duration("0h"), None);
+		assert_eq!(parse_duration("2001"), None);
+		assert_eq!(parse_duration("1x"), None);
+	}
+
+	#[test]
+	fn test_access_control_mask_from_entry()
+	{
+		let entry = AccessControlMask::from("1h:*!*@host");
+		assert_eq!(entry.mask.to_string(), "*!*@host");
+		assert!(entry.expires_at.is_some());
+
+		let entry = AccessControlMask::from("*!*@2001:db8::1");
+		assert_eq!(entry.mask.to_string(), "*!*@2001:db8::1");
+		assert!(entry.expires_at.is_none());
+	}
+
+	#[test]
+	fn test_remove_expired()
+	{
+		let mut access_control = AccessControl::<String>::default();
+
+		let timed = AccessControlMask::from("1h:*!*@timed");
+		let permanent = AccessControlMask::from("*!*@permanent");
+		let quiet = AccessControlMask::from("30m:*!*@quiet");
+		access_control.add_ban("*!*@timed", ApplyMode::new(timed));
+		access_control
+			.add_ban_except("*!*@permanent", ApplyMode::new(permanent));
+		access_control.add_quiet("*!*@quiet", ApplyMode::new(quiet));
+
+		let now = Utc::now();
+		assert!(access_control.remove_expired(now).is_empty());
+
+		let later = now + TimeDelta::try_hours(2).unwrap();
+		let mut removed = access_control.remove_expired(later);
+		removed.sort_by_key(|(letter, _)| *letter);
+		assert_eq!(removed.len(), 2);
+		assert_eq!(removed[0].0, CHANNEL_MODE_LIST_QUIET);
+		assert_eq!(removed[1].0, CHANNEL_MODE_LIST_BAN);
+		assert!(access_control.banlist.is_empty());
+		assert!(access_control.quietlist.is_empty());
+		assert_eq!(access_control.banlist_except.len(), 1);
+	}
+
+	#[test]
+	fn test_channel_invite_expiry()
+	{
+		let invite = ChannelInvite::new("PhiSyX");
+		assert!(!invite.is_expired(Utc::now() + TimeDelta::days(365)));
+
+		let invite = ChannelInvite::new("PhiSyX")
+			.with_duration(TimeDelta::try_minutes(5).unwrap());
+		assert!(!invite.is_expired(invite.invited_at));
+		assert!(invite.is_expired(
+			invite.invited_at + TimeDelta::try_minutes(5).unwrap()
+		));
+	}
 }
@@ -79,6 +79,15 @@ pub enum ChannelAccessLevel
 
 impl ChannelAccessLevel
 {
+	/// Tous les niveaux d'accès, du plus élevé au plus bas.
+	pub const ALL: [Self; 5] = [
+		Self::Owner,
+		Self::AdminOperator,
+		Self::Operator,
+		Self::HalfOperator,
+		Self::Vip,
+	];
+
 	/// Lettre lié à un mode de salon pour utilisateur.
 	pub fn letter(&self) -> char
 	{
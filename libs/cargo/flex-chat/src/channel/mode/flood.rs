@@ -0,0 +1,524 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use std::{fmt, str};
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Période maximale, en secondes, du mode +f.
+pub const FLOOD_PERIOD_MAX: u64 = 3600;
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Ce que retourne [FloodProtection::from_str] en cas d'erreur.
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum FloodProtectionError
+{
+	/// La règle n'a pas le format `[<règle>,...]:<période>`.
+	InvalidFormat,
+	/// Le seuil d'une règle est invalide (ou nul).
+	InvalidLimit,
+	/// Le type d'une règle est inconnu.
+	InvalidKind(String),
+	/// L'action d'une règle est inconnue.
+	InvalidAction(String),
+	/// La période est invalide, nulle ou supérieure à [FLOOD_PERIOD_MAX].
+	InvalidPeriod,
+}
+
+/// Les types d'événements surveillés par le mode +f.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum FloodKind
+{
+	/// Les JOIN sur le salon (`j`).
+	Join,
+	/// Les messages envoyés sur le salon, par l'ensemble des membres (`m`).
+	Message,
+	/// Les changements de pseudonymes des membres du salon (`n`).
+	Nick,
+	/// Les messages envoyés sur le salon, par membre (`t`).
+	Text,
+}
+
+/// Les actions appliquées automatiquement lorsqu'un seuil est dépassé.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum FloodAction
+{
+	/// Passe le salon en sur invitation uniquement (`i`).
+	InviteOnly,
+	/// Sanctionne et bannit le membre fautif (`kb`).
+	KickBan,
+	/// Passe le salon en modéré (`m`).
+	Moderate,
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Paramètre du mode +f, par exemple `[5j,10m,3n]:15`: plus de 5 JOIN, 10
+/// messages ou 3 changements de pseudonymes en 15 secondes déclenchent une
+/// action. L'action par défaut d'une règle peut être remplacée avec
+/// `#<action>`, par exemple `[5j#i,3t#kb]:15`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FloodProtection
+{
+	/// Les règles de la protection.
+	pub rules: Vec<FloodRule>,
+	/// Période, en secondes, de la fenêtre glissante. C'est aussi la durée
+	/// des actions appliquées automatiquement.
+	pub period: u64,
+}
+
+/// Une règle du mode +f.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct FloodRule
+{
+	/// Nombre d'événements autorisés sur la période.
+	pub limit: u16,
+	/// Type d'événement surveillé.
+	pub kind: FloodKind,
+	/// Action appliquée lorsque le seuil est dépassé.
+	pub action: FloodAction,
+}
+
+/// Les événements récents d'un salon, pour le mode +f.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct FloodTracker<ID>
+{
+	queues: Vec<FloodQueue<ID>>,
+}
+
+/// Les événements récents d'un type, pour l'ensemble des membres ou pour un
+/// membre (`t`).
+#[derive(Debug)]
+#[derive(Clone)]
+struct FloodQueue<ID>
+{
+	kind: FloodKind,
+	id: Option<ID>,
+	events: VecDeque<Instant>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl FloodKind
+{
+	/// Lettre associée au type d'événement.
+	pub fn letter(&self) -> char
+	{
+		match self {
+			| Self::Join => 'j',
+			| Self::Message => 'm',
+			| Self::Nick => 'n',
+			| Self::Text => 't',
+		}
+	}
+
+	/// Action appliquée par défaut pour ce type d'événement.
+	pub fn default_action(&self) -> FloodAction
+	{
+		match self {
+			| Self::Join => FloodAction::InviteOnly,
+			| Self::Message | Self::Nick => FloodAction::Moderate,
+			| Self::Text => FloodAction::KickBan,
+		}
+	}
+}
+
+impl FloodProtection
+{
+	/// Période de la fenêtre glissante.
+	pub fn duration(&self) -> Duration
+	{
+		Duration::from_secs(self.period)
+	}
+}
+
+impl<ID> FloodTracker<ID>
+where
+	ID: PartialEq,
+{
+	/// Enregistre un événement. Retourne la règle dont le seuil vient d'être
+	/// dépassé. Les événements ayant déclenché la règle sont oubliés, afin de
+	/// ne pas la déclencher à nouveau pour chacun des événements suivants.
+	///
+	/// Seuls les types d'événements ayant une règle sont enregistrés, et au
+	/// plus autant d'événements que le plus grand seuil de leurs règles.
+	pub fn record(
+		&mut self,
+		protection: &FloodProtection,
+		kind: FloodKind,
+		id: ID,
+		now: Instant,
+	) -> Option<FloodRule>
+	{
+		let max_limit = protection
+			.rules
+			.iter()
+			.filter(|rule| rule.kind == kind)
+			.map(|rule| rule.limit as usize)
+			.max()?;
+
+		self.queues.retain_mut(|queue| {
+			while queue.events.front().is_some_and(|at| {
+				now.saturating_duration_since(*at) >= protection.duration()
+			}) {
+				queue.events.pop_front();
+			}
+			!queue.events.is_empty()
+		});
+
+		// NOTE: les messages (`t`) sont comptés par membre.
+		let id = (kind == FloodKind::Text).then_some(id);
+
+		let position = self
+			.queues
+			.iter()
+			.position(|queue| queue.kind == kind && queue.id == id)
+			.unwrap_or_else(|| {
+				self.queues.push(FloodQueue {
+					kind,
+					id,
+					events: Default::default(),
+				});
+				self.queues.len() - 1
+			});
+
+		let queue = &mut self.queues[position];
+		queue.events.push_back(now);
+		if queue.events.len() > max_limit + 1 {
+			queue.events.pop_front();
+		}
+
+		let count = queue.events.len();
+		let rule = protection
+			.rules
+			.iter()
+			.find(|rule| rule.kind == kind && count > rule.limit as usize)?;
+
+		self.queues.swap_remove(position);
+
+		Some(*rule)
+	}
+
+	/// Oublie tous les événements.
+	pub fn clear(&mut self)
+	{
+		self.queues.clear();
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<ID> Default for FloodTracker<ID>
+{
+	fn default() -> Self
+	{
+		Self {
+			queues: Default::default(),
+		}
+	}
+}
+
+impl str::FromStr for FloodKind
+{
+	type Err = FloodProtectionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Ok(match s {
+			| "j" => Self::Join,
+			| "m" => Self::Message,
+			| "n" => Self::Nick,
+			| "t" => Self::Text,
+			| _ => return Err(FloodProtectionError::InvalidKind(s.to_owned())),
+		})
+	}
+}
+
+impl str::FromStr for FloodAction
+{
+	type Err = FloodProtectionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Ok(match s {
+			| "i" => Self::InviteOnly,
+			| "kb" => Self::KickBan,
+			| "m" => Self::Moderate,
+			| _ => {
+				return Err(FloodProtectionError::InvalidAction(s.to_owned()));
+			}
+		})
+	}
+}
+
+impl str::FromStr for FloodRule
+{
+	type Err = FloodProtectionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let (rule, maybe_action) = match s.split_once('#') {
+			| Some((rule, action)) => (rule, Some(action)),
+			| None => (s, None),
+		};
+
+		let kind_at = rule
+			.find(|ch: char| !ch.is_ascii_digit())
+			.ok_or(FloodProtectionError::InvalidFormat)?;
+		let (limit, kind) = rule.split_at(kind_at);
+
+		let limit = limit
+			.parse::<u16>()
+			.ok()
+			.filter(|limit| *limit > 0)
+			.ok_or(FloodProtectionError::InvalidLimit)?;
+		let kind = kind.parse::<FloodKind>()?;
+		let action = match maybe_action {
+			| Some(action) => action.parse()?,
+			| None => kind.default_action(),
+		};
+
+		Ok(Self {
+			limit,
+			kind,
+			action,
+		})
+	}
+}
+
+impl str::FromStr for FloodProtection
+{
+	type Err = FloodProtectionError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let (rules, period) = s
+			.strip_prefix('[')
+			.and_then(|s| s.split_once("]:"))
+			.ok_or(FloodProtectionError::InvalidFormat)?;
+
+		let rules = rules
+			.split(',')
+			.map(str::parse)
+			.collect::<Result<Vec<FloodRule>, _>>()?;
+
+		let period = period
+			.parse::<u64>()
+			.ok()
+			.filter(|period| (1..=FLOOD_PERIOD_MAX).contains(period))
+			.ok_or(FloodProtectionError::InvalidPeriod)?;
+
+		Ok(Self { rules, period })
+	}
+}
+
+impl TryFrom<String> for FloodProtection
+{
+	type Error = FloodProtectionError;
+
+	fn try_from(s: String) -> Result<Self, Self::Error>
+	{
+		s.parse()
+	}
+}
+
+impl From<FloodProtection> for String
+{
+	fn from(protection: FloodProtection) -> Self
+	{
+		protection.to_string()
+	}
+}
+
+impl fmt::Display for FloodAction
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self {
+			| Self::InviteOnly => write!(f, "i"),
+			| Self::KickBan => write!(f, "kb"),
+			| Self::Moderate => write!(f, "m"),
+		}
+	}
+}
+
+impl fmt::Display for FloodRule
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}{}", self.limit, self.kind.letter())?;
+		if self.action != self.kind.default_action() {
+			write!(f, "#{}", self.action)?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Display for FloodProtection
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		let rules: Vec<_> =
+			self.rules.iter().map(|rule| rule.to_string()).collect();
+		write!(f, "[{}]:{}", rules.join(","), self.period)
+	}
+}
+
+impl fmt::Display for FloodProtectionError
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self {
+			| Self::InvalidFormat => {
+				write!(f, "Le format attendu est [<règle>,...]:<période>")
+			}
+			| Self::InvalidLimit => {
+				write!(f, "Le seuil d'une règle est invalide")
+			}
+			| Self::InvalidKind(kind) => {
+				write!(f, "Le type de règle « {kind} » est inconnu")
+			}
+			| Self::InvalidAction(action) => {
+				write!(f, "L'action « {action} » est inconnue")
+			}
+			| Self::InvalidPeriod => write!(f, "La période est invalide"),
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_flood_protection()
+	{
+		let protection: FloodProtection = "[5j,10m,3n]:15".parse().unwrap();
+		assert_eq!(protection.period, 15);
+		assert_eq!(protection.rules.len(), 3);
+		assert_eq!(protection.rules[0].action, FloodAction::InviteOnly);
+		assert_eq!(protection.to_string(), "[5j,10m,3n]:15");
+
+		let protection: FloodProtection = "[3t,5j#kb]:10".parse().unwrap();
+		assert_eq!(protection.rules[1].action, FloodAction::KickBan);
+		assert_eq!(protection.to_string(), "[3t,5j#kb]:10");
+
+		assert_eq!(
+			"5j:15".parse::<FloodProtection>(),
+			Err(FloodProtectionError::InvalidFormat)
+		);
+		assert_eq!(
+			"[0j]:15".parse::<FloodProtection>(),
+			Err(FloodProtectionError::InvalidLimit)
+		);
+		assert_eq!(
+			"[5x]:15".parse::<FloodProtection>(),
+			Err(FloodProtectionError::InvalidKind(String::from("x")))
+		);
+		assert_eq!(
+			"[5j]:0".parse::<FloodProtection>(),
+			Err(FloodProtectionError::InvalidPeriod)
+		);
+		assert_eq!(
+			"[5j]:86400".parse::<FloodProtection>(),
+			Err(FloodProtectionError::InvalidPeriod)
+		);
+	}
+
+	#[test]
+	fn test_flood_tracker()
+	{
+		let protection: FloodProtection = "[2m,1t]:10".parse().unwrap();
+		let mut tracker = FloodTracker::default();
+		let now = Instant::now();
+
+		assert_eq!(
+			tracker.record(&protection, FloodKind::Message, 1, now),
+			None
+		);
+		assert_eq!(tracker.record(&protection, FloodKind::Text, 1, now), None);
+		assert_eq!(tracker.record(&protection, FloodKind::Text, 2, now), None);
+
+		let rule = tracker.record(&protection, FloodKind::Text, 1, now);
+		assert_eq!(rule.map(|rule| rule.action), Some(FloodAction::KickBan));
+
+		assert_eq!(
+			tracker.record(&protection, FloodKind::Message, 1, now),
+			None
+		);
+		let rule = tracker.record(&protection, FloodKind::Message, 2, now);
+		assert_eq!(rule.map(|rule| rule.action), Some(FloodAction::Moderate));
+
+		// NOTE: les événements en dehors de la fenêtre sont oubliés.
+		let later = now + Duration::from_secs(10);
+		assert_eq!(
+			tracker.record(&protection, FloodKind::Message, 1, later),
+			None
+		);
+		assert_eq!(
+			tracker.record(&protection, FloodKind::Message, 1, later),
+			None
+		);
+	}
+
+	#[test]
+	fn test_flood_tracker_records_only_ruled_kinds()
+	{
+		let protection: FloodProtection = "[3j]:10".parse().unwrap();
+		let mut tracker = FloodTracker::default();
+		let now = Instant::now();
+
+		for id in 0..100 {
+			assert_eq!(
+				tracker.record(&protection, FloodKind::Text, id, now),
+				None
+			);
+		}
+		assert!(tracker.queues.is_empty());
+
+		for id in 0..3 {
+			assert_eq!(
+				tracker.record(&protection, FloodKind::Join, id, now),
+				None
+			);
+		}
+		assert_eq!(tracker.queues[0].events.len(), 3);
+	}
+}
@@ -11,7 +11,7 @@
 use std::collections::HashMap;
 use std::fmt;
 
-use super::ChannelModes;
+use super::{ChannelModes, FloodProtection};
 use crate::mode::ApplyMode;
 
 // --------- //
@@ -28,14 +28,27 @@ pub trait SettingsFlagInterface: Clone + fmt::Debug + serde::Serialize
 // Constant //
 // -------- //
 
+pub const CHANNEL_MODE_SETTINGS_AUDITORIUM: char = 'u';
+pub const CHANNEL_MODE_SETTINGS_DELAYED_JOIN: char = 'D';
+pub const CHANNEL_MODE_SETTINGS_FLOOD: char = 'f';
 pub const CHANNEL_MODE_SETTINGS_KEY: char = 'k';
 pub const CHANNEL_MODE_SETTINGS_INVITE_ONLY: char = 'i';
 pub const CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS: char = 'l';
 pub const CHANNEL_MODE_SETTINGS_MODERATE: char = 'm';
+pub const CHANNEL_MODE_SETTINGS_NO_COLORS: char = 'c';
+pub const CHANNEL_MODE_SETTINGS_NO_CTCP: char = 'C';
 pub const CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES: char = 'n';
+pub const CHANNEL_MODE_SETTINGS_NO_KNOCK: char = 'K';
+pub const CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE: char = 'N';
+pub const CHANNEL_MODE_SETTINGS_NO_NOTICE: char = 'T';
 pub const CHANNEL_MODE_SETTINGS_NOTOPIC: char = 't';
 pub const CHANNEL_MODE_SETTINGS_OPERONLY: char = 'O';
+pub const CHANNEL_MODE_SETTINGS_PERMANENT: char = 'P';
+pub const CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED: char = 'M';
+pub const CHANNEL_MODE_SETTINGS_REGISTERED_ONLY: char = 'R';
 pub const CHANNEL_MODE_SETTINGS_SECRET: char = 's';
+pub const CHANNEL_MODE_SETTINGS_SECURE_ONLY: char = 'z';
+pub const CHANNEL_MODE_SETTINGS_TOPIC_LOCK: char = 'L';
 
 // ----------- //
 // Énumération //
@@ -48,6 +61,15 @@ pub const CHANNEL_MODE_SETTINGS_SECRET: char = 's';
 #[serde(rename_all = "snake_case")]
 pub enum SettingsFlag
 {
+	/// Salon en mode auditorium. Les membres sans niveau d'accès ne voient
+	/// que les membres ayant un niveau d'accès minimal à VIP, et leurs
+	/// messages ne sont transmis qu'aux opérateurs du salon.
+	Auditorium,
+	/// Les membres qui rejoignent le salon sont masqués jusqu'à ce qu'ils
+	/// parlent.
+	DelayedJoin,
+	/// Protection contre le flood, par exemple `[5j,10m,3n]:15`.
+	Flood(FloodProtection),
 	/// Salon accessible sur invitation uniquement.
 	InviteOnly,
 	/// Clé du salon, pour le rejoindre.
@@ -56,17 +78,39 @@ pub enum SettingsFlag
 	Limit(u16),
 	/// Salon en modéré.
 	Moderate,
+	/// Interdire les couleurs et les formats de texte dans les messages.
+	NoColors,
+	/// Interdire les messages CTCP (hors ACTION) sur le salon.
+	NoCtcp,
 	/// Interdire les messages provenant des utilisateurs externes au salon.
 	NoExternalMessages,
+	/// Interdire la commande KNOCK sur le salon.
+	NoKnock,
+	/// Interdire aux membres du salon de changer de pseudonyme.
+	NoNickChange,
+	/// Interdire les NOTICE à destination du salon.
+	NoNotice,
 	/// Interdire le changement du sujet (topic) par les utilisateurs non
 	/// opérateurs. Le niveau requis pour le changement: HalfOperator
 	/// [AccessLevelFlag::HalfOperator].
 	NoTopic,
 	/// Salon réservé aux opérateurs globaux uniquement.
 	OperOnly,
+	/// Salon permanent, qui n'est pas supprimé lorsqu'il est vide.
+	Permanent,
+	/// Seuls les utilisateurs connectés à un compte peuvent parler sur le
+	/// salon.
+	RegisteredModerated,
+	/// Salon réservé aux utilisateurs connectés à un compte.
+	RegisteredOnly,
 	/// Salon secret. Ces salons ne seront pas affiché dans la liste des
 	/// salons.
 	Secret,
+	/// Salon réservé aux utilisateurs utilisant une connexion sécurisée.
+	SecureOnly,
+	/// Verrouiller le sujet (topic) du salon. Le niveau requis pour le
+	/// changement: AdminOperator [AccessLevelFlag::AdminOperator].
+	TopicLock,
 }
 
 // -------------- //
@@ -97,6 +141,25 @@ impl ChannelModes<SettingsFlag>
 		})
 	}
 
+	/// Vérifie que le drapeau +f <rules> contienne les bonnes règles.
+	pub fn contains_flood_flag(&self, protection: &FloodProtection) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(&mode.flag, SettingsFlag::Flood(current) if current == protection)
+		})
+	}
+
+	/// Règles du drapeau +f <rules>.
+	pub fn flood_protection(&self) -> Option<&FloodProtection>
+	{
+		self.modes.values().find_map(|mode| {
+			match &mode.flag {
+				| SettingsFlag::Flood(protection) => Some(protection),
+				| _ => None,
+			}
+		})
+	}
+
 	/// Vérifie que le drapeau +k <key> contienne la bonne clé.
 	pub fn contains_key_flag(&self, key: &str) -> bool
 	{
@@ -105,6 +168,40 @@ impl ChannelModes<SettingsFlag>
 			.any(|mode| mode.flag == SettingsFlag::Key(key.to_owned()))
 	}
 
+	/// Est-ce que les paramètres du salon contiennent le drapeau +u
+	pub fn has_auditorium_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::Auditorium,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +D
+	pub fn has_delayed_join_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::DelayedJoin,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +f <rules>
+	pub fn has_flood_flag(&self) -> bool
+	{
+		self.flood_protection().is_some()
+	}
+
 	/// Est-ce que les paramètres du salon contiennent le drapeau +i
 	pub fn has_invite_only_flag(&self) -> bool
 	{
@@ -161,6 +258,34 @@ impl ChannelModes<SettingsFlag>
 		})
 	}
 
+	/// Est-ce que les paramètres du salon contiennent le drapeau +c
+	pub fn has_no_colors_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::NoColors,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +C
+	pub fn has_no_ctcp_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::NoCtcp,
+					..
+				}
+			)
+		})
+	}
+
 	/// Est-ce que les paramètres du salon contiennent le drapeau +n
 	pub fn has_no_external_messages_flag(&self) -> bool
 	{
@@ -175,6 +300,48 @@ impl ChannelModes<SettingsFlag>
 		})
 	}
 
+	/// Est-ce que les paramètres du salon contiennent le drapeau +K
+	pub fn has_no_knock_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::NoKnock,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +N
+	pub fn has_no_nick_change_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::NoNickChange,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +T
+	pub fn has_no_notice_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::NoNotice,
+					..
+				}
+			)
+		})
+	}
+
 	/// Est-ce que les paramètres du salon contiennent le drapeau +O
 	pub fn has_operonly_flag(&self) -> bool
 	{
@@ -189,6 +356,48 @@ impl ChannelModes<SettingsFlag>
 		})
 	}
 
+	/// Est-ce que les paramètres du salon contiennent le drapeau +P
+	pub fn has_permanent_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::Permanent,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +M
+	pub fn has_registered_moderated_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::RegisteredModerated,
+					..
+				}
+			)
+		})
+	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +R
+	pub fn has_registered_only_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::RegisteredOnly,
+					..
+				}
+			)
+		})
+	}
+
 	/// Est-ce que les paramètres du salon contiennent le drapeau +s
 	pub fn has_secret_flag(&self) -> bool
 	{
@@ -203,6 +412,20 @@ impl ChannelModes<SettingsFlag>
 		})
 	}
 
+	/// Est-ce que les paramètres du salon contiennent le drapeau +z
+	pub fn has_secure_only_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::SecureOnly,
+					..
+				}
+			)
+		})
+	}
+
 	/// Est-ce que les paramètres du salon contiennent le drapeau +t
 	pub fn has_topic_flag(&self) -> bool
 	{
@@ -216,6 +439,20 @@ impl ChannelModes<SettingsFlag>
 			)
 		})
 	}
+
+	/// Est-ce que les paramètres du salon contiennent le drapeau +L
+	pub fn has_topic_lock_flag(&self) -> bool
+	{
+		self.modes.values().any(|mode| {
+			matches!(
+				mode,
+				ApplyMode {
+					flag: SettingsFlag::TopicLock,
+					..
+				}
+			)
+		})
+	}
 }
 
 impl ChannelModes<SettingsFlag>
@@ -229,6 +466,13 @@ impl ChannelModes<SettingsFlag>
 		let letter = mode.letter().to_string();
 
 		match &mode.flag {
+			| SettingsFlag::Flood(protection) => {
+				if self.contains_flood_flag(protection) {
+					return None;
+				} else if self.has_flood_flag() {
+					self.modes.remove(&letter);
+				}
+			}
 			| SettingsFlag::Key(key) => {
 				if self.contains_key_flag(key) {
 					return None;
@@ -318,16 +562,31 @@ impl SettingsFlagInterface for SettingsFlag
 	fn letter(&self) -> char
 	{
 		match self {
+			| Self::Auditorium => CHANNEL_MODE_SETTINGS_AUDITORIUM,
+			| Self::DelayedJoin => CHANNEL_MODE_SETTINGS_DELAYED_JOIN,
+			| Self::Flood(_) => CHANNEL_MODE_SETTINGS_FLOOD,
 			| Self::Key(_) => CHANNEL_MODE_SETTINGS_KEY,
 			| Self::Limit(_) => CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS,
 			| Self::InviteOnly => CHANNEL_MODE_SETTINGS_INVITE_ONLY,
 			| Self::Moderate => CHANNEL_MODE_SETTINGS_MODERATE,
+			| Self::NoColors => CHANNEL_MODE_SETTINGS_NO_COLORS,
+			| Self::NoCtcp => CHANNEL_MODE_SETTINGS_NO_CTCP,
 			| Self::NoExternalMessages => {
 				CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES
 			}
+			| Self::NoKnock => CHANNEL_MODE_SETTINGS_NO_KNOCK,
+			| Self::NoNickChange => CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE,
+			| Self::NoNotice => CHANNEL_MODE_SETTINGS_NO_NOTICE,
 			| Self::NoTopic => CHANNEL_MODE_SETTINGS_NOTOPIC,
 			| Self::OperOnly => CHANNEL_MODE_SETTINGS_OPERONLY,
+			| Self::Permanent => CHANNEL_MODE_SETTINGS_PERMANENT,
+			| Self::RegisteredModerated => {
+				CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED
+			}
+			| Self::RegisteredOnly => CHANNEL_MODE_SETTINGS_REGISTERED_ONLY,
 			| Self::Secret => CHANNEL_MODE_SETTINGS_SECRET,
+			| Self::SecureOnly => CHANNEL_MODE_SETTINGS_SECURE_ONLY,
+			| Self::TopicLock => CHANNEL_MODE_SETTINGS_TOPIC_LOCK,
 		}
 	}
 }
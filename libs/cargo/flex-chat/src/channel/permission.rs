@@ -27,4 +27,9 @@ pub enum ChannelNoPermissionCause
 	ERR_BANNEDFROMCHAN,
 	ERR_CHANISINMODERATED,
 	ERR_NOTMEMBEROFCHAN,
+	ERR_NEEDREGGEDNICK,
+	ERR_NOCOLORSONCHAN,
+	ERR_NOCTCP,
+	ERR_NONOTICE,
+	ERR_QUIETED,
 }
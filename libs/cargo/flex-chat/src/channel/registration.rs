@@ -0,0 +1,116 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::{HashMap, HashSet};
+
+use crate::channel::ChannelAccessLevel;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Enregistrement d'un salon par son propriétaire. Les niveaux d'accès des
+/// membres enregistrés sont conservés, même lorsqu'ils quittent le salon.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct ChannelRegistration<ID>
+where
+	ID: PartialEq + Eq + std::hash::Hash,
+{
+	/// Propriétaire du salon.
+	pub owner: ID,
+	/// Les niveaux d'accès des membres enregistrés du salon.
+	pub access_levels: HashMap<ID, HashSet<ChannelAccessLevel>>,
+	/// Date d'enregistrement du salon.
+	pub registered_at: chrono::DateTime<chrono::Utc>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<ID> ChannelRegistration<ID>
+where
+	ID: Clone,
+	ID: PartialEq + Eq + std::hash::Hash,
+{
+	/// Crée un nouvel enregistrement de salon. Le propriétaire reçoit le
+	/// niveau d'accès [ChannelAccessLevel::Owner].
+	pub fn new(owner: ID) -> Self
+	{
+		Self {
+			access_levels: HashMap::from_iter([(
+				owner.clone(),
+				HashSet::from_iter([ChannelAccessLevel::Owner]),
+			)]),
+			owner,
+			registered_at: chrono::Utc::now(),
+		}
+	}
+
+	/// Les niveaux d'accès enregistrés d'un membre.
+	pub fn access_level(&self, id: &ID)
+	-> Option<&HashSet<ChannelAccessLevel>>
+	{
+		self.access_levels.get(id)
+	}
+
+	/// Met à jour les niveaux d'accès enregistrés d'un membre. Un membre sans
+	/// niveau d'accès est retiré de l'enregistrement.
+	pub fn update_access_level(
+		&mut self,
+		id: ID,
+		access_level: impl IntoIterator<Item = ChannelAccessLevel>,
+	)
+	{
+		let access_level = HashSet::from_iter(access_level);
+		if access_level.is_empty() {
+			self.access_levels.remove(&id);
+		} else {
+			self.access_levels.insert(id, access_level);
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_owner_access_level()
+	{
+		let registration = ChannelRegistration::new(1);
+		assert_eq!(
+			registration.access_level(&1),
+			Some(&HashSet::from_iter([ChannelAccessLevel::Owner]))
+		);
+		assert_eq!(registration.access_level(&2), None);
+	}
+
+	#[test]
+	fn test_update_access_level()
+	{
+		let mut registration = ChannelRegistration::new(1);
+
+		registration.update_access_level(2, [ChannelAccessLevel::Operator]);
+		assert_eq!(
+			registration.access_level(&2),
+			Some(&HashSet::from_iter([ChannelAccessLevel::Operator]))
+		);
+
+		registration.update_access_level(2, []);
+		assert_eq!(registration.access_level(&2), None);
+	}
+}
@@ -10,6 +10,13 @@
 
 use crate::channel::TopicInterface;
 
+// -------- //
+// Constant //
+// -------- //
+
+/// Nombre maximal de sujets précédents conservés dans l'historique d'un salon.
+pub const CHANNEL_TOPIC_HISTORY_LIMIT: usize = 10;
+
 // --------- //
 // Structure //
 // --------- //
@@ -26,6 +33,103 @@ pub struct ChannelTopic
 	updated_by: String,
 	/// Date de mis à jour du sujet.
 	updated_at: chrono::DateTime<chrono::Utc>,
+	/// Historique des sujets précédents, du plus récent au plus ancien.
+	#[serde(skip)]
+	history: Vec<ChannelTopicHistoryEntry>,
+}
+
+/// Sujet précédent d'un salon.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct ChannelTopicHistoryEntry
+{
+	/// Le sujet.
+	topic: String,
+	/// Origine de la mis à jour du sujet.
+	updated_by: String,
+	/// Date de mis à jour du sujet.
+	updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChannelTopic
+{
+	/// Crée un sujet de salon, par exemple à partir d'un salon enregistré.
+	pub fn new(
+		topic: impl ToString,
+		updated_by: impl ToString,
+		updated_at: chrono::DateTime<chrono::Utc>,
+	) -> Self
+	{
+		Self {
+			topic: topic.to_string(),
+			updated_by: updated_by.to_string(),
+			updated_at,
+			history: Default::default(),
+		}
+	}
+
+	/// Historique des sujets précédents du salon, du plus récent au plus
+	/// ancien.
+	pub fn history(&self) -> &[ChannelTopicHistoryEntry]
+	{
+		&self.history
+	}
+
+	/// Restaure un sujet de l'historique, à partir de sa position (la plus
+	/// récente étant à 0). Le sujet actuel est conservé dans l'historique.
+	pub fn restore(&mut self, index: usize, updated_by: impl ToString) -> bool
+	{
+		let Some(entry) = self.history.get(index) else {
+			return false;
+		};
+		let topic = entry.topic.clone();
+		self.set(topic, updated_by);
+		true
+	}
+
+	/// Conserve le sujet actuel dans l'historique avant qu'il ne soit
+	/// remplacé. Un sujet vide n'est pas conservé.
+	fn archive(&mut self)
+	{
+		if self.topic.is_empty() {
+			return;
+		}
+
+		self.history.insert(
+			0,
+			ChannelTopicHistoryEntry {
+				topic: std::mem::take(&mut self.topic),
+				updated_by: std::mem::take(&mut self.updated_by),
+				updated_at: self.updated_at,
+			},
+		);
+		self.history.truncate(CHANNEL_TOPIC_HISTORY_LIMIT);
+	}
+}
+
+impl ChannelTopicHistoryEntry
+{
+	/// Le sujet.
+	pub fn topic(&self) -> &str
+	{
+		&self.topic
+	}
+
+	/// Date de mis à jour du sujet.
+	pub fn updated_at(&self) -> &chrono::DateTime<chrono::Utc>
+	{
+		&self.updated_at
+	}
+
+	/// Origine de la mis à jour du sujet.
+	pub fn updated_by(&self) -> &str
+	{
+		&self.updated_by
+	}
 }
 
 // -------------- //
@@ -46,6 +150,7 @@ impl TopicInterface for ChannelTopic
 
 	fn set(&mut self, topic: impl ToString, updated_by: impl ToString)
 	{
+		self.archive();
 		self.updated_at = chrono::Utc::now();
 		self.updated_by = updated_by.to_string();
 		self.topic = topic.to_string();
@@ -53,6 +158,7 @@ impl TopicInterface for ChannelTopic
 
 	fn unset(&mut self, updated_by: impl ToString)
 	{
+		self.archive();
 		self.updated_at = chrono::Utc::now();
 		self.updated_by = updated_by.to_string();
 		self.topic = String::default();
@@ -68,3 +174,50 @@ impl TopicInterface for ChannelTopic
 		&self.updated_by
 	}
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_topic_history()
+	{
+		let mut topic = ChannelTopic::default();
+		topic.set("Premier sujet", "PhiSyX");
+		assert!(topic.history().is_empty());
+
+		topic.set("Deuxième sujet", "Mike");
+		assert_eq!(topic.history().len(), 1);
+		assert_eq!(topic.history()[0].topic(), "Premier sujet");
+		assert_eq!(topic.history()[0].updated_by(), "PhiSyX");
+
+		topic.unset("Mike");
+		topic.set("Troisième sujet", "PhiSyX");
+		assert_eq!(topic.history().len(), 2);
+		assert_eq!(topic.history()[0].topic(), "Deuxième sujet");
+
+		for n in 0..CHANNEL_TOPIC_HISTORY_LIMIT * 2 {
+			topic.set(format!("Sujet {n}"), "PhiSyX");
+		}
+		assert_eq!(topic.history().len(), CHANNEL_TOPIC_HISTORY_LIMIT);
+	}
+
+	#[test]
+	fn test_topic_restore()
+	{
+		let mut topic = ChannelTopic::default();
+		topic.set("Premier sujet", "PhiSyX");
+		topic.set("Deuxième sujet", "PhiSyX");
+
+		assert!(!topic.restore(1, "Mike"));
+		assert!(topic.restore(0, "Mike"));
+		assert_eq!(topic.get(), "Premier sujet");
+		assert_eq!(topic.updated_by(), "Mike");
+		assert_eq!(topic.history()[0].topic(), "Deuxième sujet");
+	}
+}
@@ -0,0 +1,133 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use core::{fmt, str};
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Capacités serveur (IRCv3) qu'un client peut négocier à l'aide de la
+/// commande `/CAP`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum Capability
+{
+	/// Notifie les membres des salons communs lorsqu'un utilisateur s'identifie
+	/// ou se déconnecte de son compte.
+	AccountNotify,
+	/// Notifie les membres des salons communs lorsqu'un utilisateur se marque
+	/// (ou ne se marque plus) comme absent.
+	AwayNotify,
+	/// Renvoie au client les messages qu'il a lui-même envoyés.
+	EchoMessage,
+	/// Le message JOIN contient le compte et le nom réel de l'utilisateur.
+	ExtendedJoin,
+	/// Les messages peuvent contenir des étiquettes (tags).
+	MessageTags,
+	/// Les réponses NAMES/WHO contiennent tous les préfixes des niveaux d'accès
+	/// des membres d'un salon, et non seulement le plus élevé.
+	MultiPrefix,
+	/// Authentification SASL lors de l'enregistrement de la connexion
+	/// (commande `/AUTHENTICATE`).
+	Sasl,
+	/// Les messages contiennent l'étiquette `time`, représentant la date à
+	/// laquelle le serveur a traité le message.
+	ServerTime,
+	/// La réponse NAMES contient le masque complet des membres d'un salon
+	/// (`nick!ident@host`).
+	UserhostInNames,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Capability
+{
+	/// Toutes les capacités supportées par le serveur.
+	pub const ALL: [Self; 9] = [
+		Self::AccountNotify,
+		Self::AwayNotify,
+		Self::EchoMessage,
+		Self::ExtendedJoin,
+		Self::MessageTags,
+		Self::MultiPrefix,
+		Self::Sasl,
+		Self::ServerTime,
+		Self::UserhostInNames,
+	];
+
+	/// Nom de la capacité.
+	pub fn as_str(&self) -> &'static str
+	{
+		match self {
+			| Self::AccountNotify => "account-notify",
+			| Self::AwayNotify => "away-notify",
+			| Self::EchoMessage => "echo-message",
+			| Self::ExtendedJoin => "extended-join",
+			| Self::MessageTags => "message-tags",
+			| Self::MultiPrefix => "multi-prefix",
+			| Self::Sasl => "sasl",
+			| Self::ServerTime => "server-time",
+			| Self::UserhostInNames => "userhost-in-names",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for Capability
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl str::FromStr for Capability
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Self::ALL
+			.into_iter()
+			.find(|capability| capability.as_str().eq_ignore_ascii_case(s))
+			.ok_or_else(|| s.to_owned())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_capability_from_str()
+	{
+		assert_eq!("server-time".parse(), Ok(Capability::ServerTime));
+		assert_eq!("Echo-Message".parse(), Ok(Capability::EchoMessage));
+		assert_eq!("batch".parse::<Capability>(), Err(String::from("batch")));
+	}
+
+	#[test]
+	fn test_capability_as_str()
+	{
+		for capability in Capability::ALL {
+			assert_eq!(capability.as_str().parse(), Ok(capability));
+		}
+	}
+}
@@ -28,6 +28,17 @@ pub trait ChannelClientSocketCommandResponse: ClientSocketInterface
 		) -> Option<DTO>,
 	) where
 		DTO: serde::Serialize + std::fmt::Debug;
+
+	/// Émet uniquement au client courant les membres d'un salon par chunk de
+	/// 300.
+	fn send_rpl_namreply_to_self<DTO>(
+		&self,
+		channel: &Self::Channel,
+		map_member: impl FnMut(
+			&<Self::Channel as ChannelMemberInterface>::Member,
+		) -> Option<DTO>,
+	) where
+		DTO: serde::Serialize + std::fmt::Debug;
 }
 
 pub trait ChannelClientSocketErrorReplies: ClientSocketInterface
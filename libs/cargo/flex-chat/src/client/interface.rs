@@ -114,6 +114,9 @@ pub trait ClientInterface: fmt::Debug
 	/// Définit un nouveau jeton de connexion.
 	fn new_token(&mut self);
 
+	/// Retire au client son statut d'opérateur.
+	fn unmarks_client_as_operator(&mut self);
+
 	/// Utilisateur du client.
 	fn user(&self) -> &Self::User;
 
@@ -8,6 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod capability;
 pub mod channel;
 mod interface;
 pub mod nick;
@@ -19,6 +20,7 @@ use std::net;
 
 use flex_crypto::SHA256;
 
+pub use self::capability::*;
 pub use self::interface::*;
 pub use self::origin::*;
 use crate::user::{Flag, Mode, User, UserFlagInterface, UserInterface};
@@ -29,6 +31,8 @@ pub struct Client<cID, sID>
 {
 	/// ID du client.
 	id: cID,
+	/// ID du compte auquel le client est identifié.
+	account_id: Option<cID>,
 	/// ID de la Socket.
 	socket_id: Option<sID>,
 	/// Enregistré au serveur de Chat.
@@ -41,6 +45,15 @@ pub struct Client<cID, sID>
 	user: User,
 	/// Les salons qu'à rejoint le client.
 	pub channels: HashSet<String>,
+	/// Les capacités serveur activées par le client. Vaut [None] tant que le
+	/// client n'a pas négocié de capacités (client web).
+	capabilities: Option<HashSet<Capability>>,
+	/// Négociation des capacités serveur en cours.
+	negotiating_capabilities: bool,
+	/// Date de connexion du client au serveur de Chat.
+	signon_at: chrono::DateTime<chrono::Utc>,
+	/// Date de la dernière activité du client (envoi de messages).
+	last_activity_at: chrono::DateTime<chrono::Utc>,
 }
 
 // -------------- //
@@ -60,12 +73,103 @@ impl<cID, sID> Client<cID, sID>
 			socket_id: Some(socket_id),
 			connected: Default::default(),
 			id: client_id,
+			account_id: Default::default(),
 			token,
 			registered: Default::default(),
 			user: User::new(ip),
 			channels: Default::default(),
+			capabilities: Default::default(),
+			negotiating_capabilities: Default::default(),
+			signon_at: chrono::Utc::now(),
+			last_activity_at: chrono::Utc::now(),
 		}
 	}
+
+	/// ID du compte auquel le client est identifié.
+	pub fn account_id(&self) -> Option<&cID>
+	{
+		self.account_id.as_ref()
+	}
+
+	/// Commence la négociation des capacités serveur. L'enregistrement du
+	/// client est suspendu tant que la négociation n'est pas terminée.
+	pub fn begin_capabilities_negotiation(&mut self)
+	{
+		self.capabilities.get_or_insert_with(Default::default);
+		if !self.registered {
+			self.negotiating_capabilities = true;
+		}
+	}
+
+	/// Les capacités serveur activées par le client.
+	pub fn capabilities(&self) -> impl Iterator<Item = &Capability>
+	{
+		self.capabilities.iter().flatten()
+	}
+
+	/// Désactive une capacité serveur.
+	pub fn disable_capability(&mut self, capability: Capability)
+	{
+		self.capabilities
+			.get_or_insert_with(Default::default)
+			.remove(&capability);
+	}
+
+	/// Active une capacité serveur.
+	pub fn enable_capability(&mut self, capability: Capability)
+	{
+		self.capabilities
+			.get_or_insert_with(Default::default)
+			.insert(capability);
+	}
+
+	/// Termine la négociation des capacités serveur.
+	pub fn end_capabilities_negotiation(&mut self)
+	{
+		self.negotiating_capabilities = false;
+	}
+
+	/// Est-ce que le client a activé une capacité serveur donnée?
+	///
+	/// NOTE(phisyx): un client n'ayant jamais négocié de capacités (client
+	/// web) reçoit toutes les données supplémentaires.
+	pub fn has_capability(&self, capability: Capability) -> bool
+	{
+		self.capabilities
+			.as_ref()
+			.is_none_or(|capabilities| capabilities.contains(&capability))
+	}
+
+	/// Durée d'inactivité du client, en secondes.
+	pub fn idle_time(&self) -> i64
+	{
+		(chrono::Utc::now() - self.last_activity_at).num_seconds()
+	}
+
+	/// Est-ce que le client est en cours de négociation des capacités
+	/// serveur?
+	pub fn is_negotiating_capabilities(&self) -> bool
+	{
+		self.negotiating_capabilities
+	}
+
+	/// Date de connexion du client au serveur de Chat.
+	pub fn signon_at(&self) -> &chrono::DateTime<chrono::Utc>
+	{
+		&self.signon_at
+	}
+
+	/// Définit l'ID du compte auquel le client est identifié.
+	pub fn set_account_id(&mut self, account_id: Option<cID>)
+	{
+		self.account_id = account_id;
+	}
+
+	/// Met à jour la date de la dernière activité du client.
+	pub fn update_last_activity(&mut self)
+	{
+		self.last_activity_at = chrono::Utc::now();
+	}
 }
 
 // -------------- //
@@ -178,6 +282,8 @@ where
 	fn set_registered(&mut self)
 	{
 		self.registered = true;
+		self.signon_at = chrono::Utc::now();
+		self.last_activity_at = self.signon_at;
 	}
 
 	fn sid(&self) -> &Self::SocketID
@@ -197,6 +303,13 @@ where
 		self.token = token;
 	}
 
+	fn unmarks_client_as_operator(&mut self)
+	{
+		self.user.unset_flag(|flag| {
+			matches!(flag, Flag::GlobalOperator | Flag::LocalOperator)
+		});
+	}
+
 	fn user(&self) -> &Self::User
 	{
 		&self.user
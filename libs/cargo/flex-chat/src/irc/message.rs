@@ -0,0 +1,423 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Taille maximale d'une ligne IRC (hors tags), CRLF compris.
+pub const IRC_LINE_MAX_SIZE: usize = 512;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Message IRC au format texte, tel qu'il transite sur une connexion TCP.
+///
+/// ```bnf
+/// message ::= [ '@' tags SPACE ] [ ':' prefix SPACE ] command [ params ] crlf
+/// params  ::= *( SPACE middle ) [ SPACE ':' trailing ]
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq, Eq)]
+pub struct IrcMessage
+{
+	/// Les tags IRCv3 du message.
+	pub tags: Vec<(String, Option<String>)>,
+	/// Le préfixe du message (`nick!ident@host` ou nom du serveur).
+	pub prefix: Option<String>,
+	/// La commande (ou la numérique) du message, en majuscule.
+	pub command: String,
+	/// Les paramètres du message.
+	pub params: Vec<String>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IrcMessage
+{
+	/// Crée un nouveau message IRC à partir d'une commande.
+	pub fn new(command: impl ToString) -> Self
+	{
+		Self {
+			command: command.to_string(),
+			..Default::default()
+		}
+	}
+
+	/// Définit le préfixe du message.
+	pub fn with_prefix(mut self, prefix: impl ToString) -> Self
+	{
+		self.prefix.replace(prefix.to_string());
+		self
+	}
+
+	/// Ajoute un paramètre au message.
+	pub fn with_param(mut self, param: impl ToString) -> Self
+	{
+		self.params.push(param.to_string());
+		self
+	}
+
+	/// Ajoute des paramètres au message.
+	pub fn with_params<S>(mut self, params: impl IntoIterator<Item = S>) -> Self
+	where
+		S: ToString,
+	{
+		self.params.extend(params.into_iter().map(|p| p.to_string()));
+		self
+	}
+
+	/// Ajoute un tag au message.
+	pub fn with_tag(mut self, key: impl ToString, value: Option<String>)
+	-> Self
+	{
+		self.tags.push((key.to_string(), value));
+		self
+	}
+
+	/// Le message peut-il être envoyé tel quel ? Seul le dernier paramètre
+	/// peut être vide, contenir des espaces ou commencer par ':'.
+	pub fn is_valid(&self) -> bool
+	{
+		if self.command.is_empty() {
+			return false;
+		}
+
+		let Some((_, middles)) = self.params.split_last() else {
+			return true;
+		};
+
+		middles.iter().map(|param| Self::sanitize(param)).all(|param| {
+			!param.is_empty() && !param.contains(' ') && !param.starts_with(':')
+		})
+	}
+}
+
+impl IrcMessage
+{
+	/// Analyse d'une ligne IRC. Les caractères de fin de ligne (`\r\n`)
+	/// sont ignorés.
+	///
+	/// Retourne [None] lorsque la ligne ne contient aucune commande.
+	pub fn parse(line: &str) -> Option<Self>
+	{
+		let mut rest = line.trim_end_matches(['\r', '\n']).trim_start();
+
+		let mut message = Self::default();
+
+		if let Some(tags) = rest.strip_prefix('@') {
+			let (raw_tags, after) = tags.split_once(' ')?;
+			message.tags = raw_tags
+				.split(';')
+				.filter(|tag| !tag.is_empty())
+				.map(|tag| {
+					match tag.split_once('=') {
+						| Some((key, value)) => {
+							(key.to_owned(), Some(Self::unescape_tag(value)))
+						}
+						| None => (tag.to_owned(), None),
+					}
+				})
+				.collect();
+			rest = after.trim_start();
+		}
+
+		if let Some(prefix) = rest.strip_prefix(':') {
+			let (prefix, after) = prefix.split_once(' ')?;
+			message.prefix.replace(prefix.to_owned());
+			rest = after.trim_start();
+		}
+
+		let (command, mut params) =
+			rest.split_once(' ').unwrap_or((rest, Default::default()));
+
+		if command.is_empty() {
+			return None;
+		}
+
+		message.command = command.to_uppercase();
+
+		loop {
+			params = params.trim_start_matches(' ');
+
+			if params.is_empty() {
+				break;
+			}
+
+			if let Some(trailing) = params.strip_prefix(':') {
+				message.params.push(trailing.to_owned());
+				break;
+			}
+
+			let (middle, after) =
+				params.split_once(' ').unwrap_or((params, Default::default()));
+			message.params.push(middle.to_owned());
+			params = after;
+		}
+
+		Some(message)
+	}
+
+	/// Récupère un paramètre du message par sa position.
+	pub fn param(&self, index: usize) -> Option<&str>
+	{
+		self.params.get(index).map(String::as_str)
+	}
+
+	/// Récupère un tag du message par sa clé.
+	pub fn tag(&self, key: &str) -> Option<Option<&str>>
+	{
+		self.tags
+			.iter()
+			.find(|(k, _)| k == key)
+			.map(|(_, value)| value.as_deref())
+	}
+
+	/// Ligne IRC à envoyer, CRLF compris. Au-delà de [IRC_LINE_MAX_SIZE]
+	/// octets (hors tags), la ligne est tronquée sans couper de caractère.
+	pub fn to_line(&self) -> String
+	{
+		let mut line = self.to_string();
+
+		// NOTE: les espaces des valeurs des tags sont échappés, le premier
+		//       espace termine donc les tags.
+		let tags_len = if self.tags.is_empty() {
+			0
+		} else {
+			line.find(' ').map_or(line.len(), |idx| idx + 1)
+		};
+
+		let mut max_len = tags_len + IRC_LINE_MAX_SIZE - 2;
+		if line.len() > max_len {
+			while !line.is_char_boundary(max_len) {
+				max_len -= 1;
+			}
+			line.truncate(max_len);
+		}
+
+		line.push_str("\r\n");
+		line
+	}
+
+	fn escape_tag(value: &str) -> String
+	{
+		let mut output = String::with_capacity(value.len());
+		for ch in value.chars() {
+			match ch {
+				| ';' => output.push_str("\\:"),
+				| ' ' => output.push_str("\\s"),
+				| '\\' => output.push_str("\\\\"),
+				| '\r' => output.push_str("\\r"),
+				| '\n' => output.push_str("\\n"),
+				| _ => output.push(ch),
+			}
+		}
+		output
+	}
+
+	/// Les caractères de fin de ligne (`\r`, `\n`) et le caractère nul ne
+	/// doivent jamais être envoyés dans un message, sans quoi le texte d'un
+	/// client pourrait former de nouvelles lignes du protocole.
+	fn sanitize(value: &str) -> String
+	{
+		value.replace(['\r', '\n', '\0'], " ")
+	}
+
+	fn unescape_tag(value: &str) -> String
+	{
+		let mut output = String::with_capacity(value.len());
+		let mut chars = value.chars();
+		while let Some(ch) = chars.next() {
+			if ch != '\\' {
+				output.push(ch);
+				continue;
+			}
+			match chars.next() {
+				| Some(':') => output.push(';'),
+				| Some('s') => output.push(' '),
+				| Some('r') => output.push('\r'),
+				| Some('n') => output.push('\n'),
+				| Some(other) => output.push(other),
+				| None => break,
+			}
+		}
+		output
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for IrcMessage
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		if !self.tags.is_empty() {
+			let tags = self
+				.tags
+				.iter()
+				.map(|(key, value)| {
+					match value {
+						| Some(value) => {
+							format!("{key}={}", Self::escape_tag(value))
+						}
+						| None => key.to_owned(),
+					}
+				})
+				.collect::<Vec<_>>()
+				.join(";");
+			write!(f, "@{tags} ")?;
+		}
+
+		if let Some(prefix) = self.prefix.as_deref() {
+			write!(f, ":{} ", Self::sanitize(prefix))?;
+		}
+
+		write!(f, "{}", Self::sanitize(&self.command))?;
+
+		let total = self.params.len();
+		for (idx, param) in self.params.iter().enumerate() {
+			let param = Self::sanitize(param);
+			// NOTE(phisyx): seul le dernier paramètre peut contenir des
+			//               espaces ou commencer par ':'.
+			let is_last = idx + 1 == total;
+			if is_last
+				&& (param.is_empty()
+					|| param.contains(' ')
+					|| param.starts_with(':'))
+			{
+				write!(f, " :{param}")?;
+			} else {
+				write!(f, " {param}")?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_command()
+	{
+		let message = IrcMessage::parse("nick PhiSyX\r\n").unwrap();
+		assert_eq!(message.command, "NICK");
+		assert_eq!(message.params, vec!["PhiSyX"]);
+		assert!(IrcMessage::parse("\r\n").is_none());
+	}
+
+	#[test]
+	fn test_parse_trailing()
+	{
+		let message =
+			IrcMessage::parse("PRIVMSG #flex :Hello :world ").unwrap();
+		assert_eq!(message.params, vec!["#flex", "Hello :world "]);
+
+		let message = IrcMessage::parse("USER ident 0 * :Real name").unwrap();
+		assert_eq!(message.params, vec!["ident", "0", "*", "Real name"]);
+	}
+
+	#[test]
+	fn test_parse_prefix_and_tags()
+	{
+		let message = IrcMessage::parse(
+			"@msgid=abc;+draft/reply=a\\sb;flag :nick!ident@host JOIN #flex",
+		)
+		.unwrap();
+		assert_eq!(message.prefix.as_deref(), Some("nick!ident@host"));
+		assert_eq!(message.tag("msgid"), Some(Some("abc")));
+		assert_eq!(message.tag("+draft/reply"), Some(Some("a b")));
+		assert_eq!(message.tag("flag"), Some(None));
+		assert_eq!(message.params, vec!["#flex"]);
+	}
+
+	#[test]
+	fn test_display()
+	{
+		let message = IrcMessage::new("001")
+			.with_prefix("irc.flex")
+			.with_params(["PhiSyX", "Bienvenue sur le réseau"]);
+		assert_eq!(
+			message.to_string(),
+			":irc.flex 001 PhiSyX :Bienvenue sur le réseau"
+		);
+
+		let message = IrcMessage::new("PRIVMSG")
+			.with_tag("msgid", Some(String::from("a;b")))
+			.with_params(["#flex", ":)"]);
+		assert_eq!(message.to_string(), "@msgid=a\\:b PRIVMSG #flex ::)");
+
+		let message = IrcMessage::new("JOIN").with_param("#flex");
+		assert_eq!(IrcMessage::parse(&message.to_string()), Some(message));
+	}
+
+	#[test]
+	fn test_display_line_injection()
+	{
+		let message = IrcMessage::new("PRIVMSG")
+			.with_prefix("nick!ident@host")
+			.with_params(["#flex", "Hello\r\nQUIT :bye\0"]);
+		assert_eq!(
+			message.to_string(),
+			":nick!ident@host PRIVMSG #flex :Hello  QUIT :bye "
+		);
+	}
+
+	#[test]
+	fn test_to_line_truncated()
+	{
+		let message = IrcMessage::new("PRIVMSG")
+			.with_prefix("nick!ident@host")
+			.with_params(["#flex", &"é".repeat(IRC_LINE_MAX_SIZE)]);
+		let line = message.to_line();
+		assert!(line.len() <= IRC_LINE_MAX_SIZE);
+		assert!(line.ends_with("é\r\n"));
+
+		let message = IrcMessage::new("PRIVMSG")
+			.with_tag("msgid", Some("a b".repeat(IRC_LINE_MAX_SIZE)))
+			.with_params(["#flex", "Hello"]);
+		assert!(message.to_line().ends_with(" PRIVMSG #flex Hello\r\n"));
+	}
+
+	#[test]
+	fn test_is_valid()
+	{
+		let message = IrcMessage::new("PRIVMSG").with_params(["#flex", ""]);
+		assert!(message.is_valid());
+
+		let message = IrcMessage::new("MODE").with_params(["a b", "+i"]);
+		assert!(!message.is_valid());
+
+		let message = IrcMessage::new("MODE").with_params([":nick", "+i"]);
+		assert!(!message.is_valid());
+
+		let message = IrcMessage::new("MODE").with_params(["#a\n", "+i"]);
+		assert!(!message.is_valid());
+
+		let message = IrcMessage::new("KICK").with_params(["#flex", "", "x"]);
+		assert!(!message.is_valid());
+	}
+}
@@ -10,6 +10,7 @@
 
 pub mod channel;
 pub mod client;
+pub mod irc;
 pub mod mode;
 pub mod user;
 
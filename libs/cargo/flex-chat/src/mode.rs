@@ -9,7 +9,9 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 mod apply;
+mod cidr;
 mod mask;
 
 pub use self::apply::*;
+pub use self::cidr::*;
 pub use self::mask::*;
@@ -0,0 +1,167 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fmt, net, str};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Plage d'adresses IP au format CIDR: `192.168.0.0/16`, `2001:db8::/48`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct Cidr
+{
+	/// Adresse réseau (les bits de l'hôte sont à zéro).
+	network: net::IpAddr,
+	/// Longueur du préfixe.
+	prefix_len: u8,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum CidrError
+{
+	/// Le séparateur `/` est manquant.
+	MissingPrefix,
+	/// L'adresse IP est invalide.
+	InvalidAddress,
+	/// La longueur du préfixe est invalide pour la famille de l'adresse.
+	InvalidPrefix,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Cidr
+{
+	/// Est-ce que l'adresse IP donnée fait partie de la plage.
+	pub fn contains(&self, ip_addr: &net::IpAddr) -> bool
+	{
+		// NOTE: une adresse IPv4 peut être représentée sous forme d'adresse
+		//       IPv6 (`::ffff:192.168.0.1`).
+		match (self.network, ip_addr.to_canonical()) {
+			| (net::IpAddr::V4(network), net::IpAddr::V4(ip)) => {
+				let mask = Self::mask_v4(self.prefix_len);
+				u32::from(ip) & mask == u32::from(network)
+			}
+			| (net::IpAddr::V6(network), net::IpAddr::V6(ip)) => {
+				let mask = Self::mask_v6(self.prefix_len);
+				u128::from(ip) & mask == u128::from(network)
+			}
+			| _ => false,
+		}
+	}
+
+	fn mask_v4(prefix_len: u8) -> u32
+	{
+		u32::MAX.checked_shl(32 - u32::from(prefix_len)).unwrap_or(0)
+	}
+
+	fn mask_v6(prefix_len: u8) -> u128
+	{
+		u128::MAX.checked_shl(128 - u32::from(prefix_len)).unwrap_or(0)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl str::FromStr for Cidr
+{
+	type Err = CidrError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let (addr, prefix_len) =
+			s.split_once('/').ok_or(CidrError::MissingPrefix)?;
+
+		let addr: net::IpAddr =
+			addr.parse().map_err(|_| CidrError::InvalidAddress)?;
+		let prefix_len: u8 =
+			prefix_len.parse().map_err(|_| CidrError::InvalidPrefix)?;
+
+		let network = match addr {
+			| net::IpAddr::V4(ip) if prefix_len <= 32 => {
+				let mask = Self::mask_v4(prefix_len);
+				net::IpAddr::V4(net::Ipv4Addr::from(u32::from(ip) & mask))
+			}
+			| net::IpAddr::V6(ip) if prefix_len <= 128 => {
+				let mask = Self::mask_v6(prefix_len);
+				net::IpAddr::V6(net::Ipv6Addr::from(u128::from(ip) & mask))
+			}
+			| _ => return Err(CidrError::InvalidPrefix),
+		};
+
+		Ok(Self {
+			network,
+			prefix_len,
+		})
+	}
+}
+
+impl fmt::Display for Cidr
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}/{}", self.network, self.prefix_len)
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_cidr()
+	{
+		let cidr: Cidr = "192.168.1.5/16".parse().unwrap();
+		assert_eq!(cidr.to_string(), "192.168.0.0/16");
+
+		let cidr: Cidr = "2001:DB8:1:2::1/48".parse().unwrap();
+		assert_eq!(cidr.to_string(), "2001:db8:1::/48");
+
+		assert_eq!("10.0.0.0".parse::<Cidr>(), Err(CidrError::MissingPrefix));
+		assert_eq!(
+			"10.0.0.0/33".parse::<Cidr>(),
+			Err(CidrError::InvalidPrefix)
+		);
+		assert_eq!("host/8".parse::<Cidr>(), Err(CidrError::InvalidAddress));
+	}
+
+	#[test]
+	fn test_cidr_contains()
+	{
+		let cidr: Cidr = "192.168.0.0/16".parse().unwrap();
+		assert!(cidr.contains(&"192.168.42.1".parse().unwrap()));
+		assert!(cidr.contains(&"::ffff:192.168.42.1".parse().unwrap()));
+		assert!(!cidr.contains(&"192.169.0.1".parse().unwrap()));
+
+		let cidr: Cidr = "2001:db8::/48".parse().unwrap();
+		assert!(cidr.contains(&"2001:db8:0:ffff::1".parse().unwrap()));
+		assert!(!cidr.contains(&"2001:db8:1::1".parse().unwrap()));
+		assert!(!cidr.contains(&"192.168.42.1".parse().unwrap()));
+
+		let cidr: Cidr = "0.0.0.0/0".parse().unwrap();
+		assert!(cidr.contains(&"8.8.8.8".parse().unwrap()));
+	}
+}
@@ -8,21 +8,76 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::HashSet;
 use std::fmt;
 
-// --------- //
-// Structure //
-// --------- //
+use flex_wildcard_matching::WildcardMatching;
+
+use super::Cidr;
+use crate::user::{User, UserAddressInterface, UserInterface};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Préfixe des masques étendus (extbans).
+pub const EXTBAN_PREFIX: char = '~';
+/// Masque étendu: correspond au compte de l'utilisateur.
+pub const EXTBAN_ACCOUNT: char = 'a';
+/// Masque étendu: correspond aux membres d'un autre salon.
+pub const EXTBAN_CHANNEL: char = 'j';
+/// Masque étendu: empêche les changements de pseudonyme.
+pub const EXTBAN_NICK: char = 'n';
+/// Masque étendu: l'utilisateur peut rejoindre le salon mais ne peut pas
+/// parler.
+pub const EXTBAN_QUIET: char = 'q';
+/// Masque étendu: correspond au nom réel de l'utilisateur.
+pub const EXTBAN_REALNAME: char = 'r';
+
+// ----------- //
+// Énumération //
+// ----------- //
 
 #[derive(Debug)]
 #[derive(Clone)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(serde::Serialize, serde::Deserialize)]
-pub struct Mask
+#[serde(untagged)]
+pub enum Mask
 {
-	nick: String,
-	ident: String,
-	host: String,
+	/// Masque d'adresse: `nick!ident@host`.
+	Address
+	{
+		nick: String,
+		ident: String,
+		host: String,
+	},
+	/// Masque étendu: `~<lettre>:<valeur>`.
+	Extended(ExtendedMask),
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "extban", content = "value")]
+pub enum ExtendedMask
+{
+	/// `~a:<compte>`
+	#[serde(rename = "a")]
+	Account(String),
+	/// `~j:<salon>`
+	#[serde(rename = "j")]
+	Channel(String),
+	/// `~n:<masque>`
+	#[serde(rename = "n")]
+	Nick(Box<Mask>),
+	/// `~q:<masque>`
+	#[serde(rename = "q")]
+	Quiet(Box<Mask>),
+	/// `~r:<nom réel>`
+	#[serde(rename = "r")]
+	Realname(String),
 }
 
 // -------------- //
@@ -33,7 +88,7 @@ impl Mask
 {
 	pub fn generic() -> Self
 	{
-		Self {
+		Self::Address {
 			nick: String::from("*"),
 			ident: String::from("*"),
 			host: String::from("*"),
@@ -72,10 +127,10 @@ impl Mask
 			maybe_host = "*";
 		}
 
-		Self {
+		Self::Address {
 			nick: String::from("*"),
 			ident: String::from(maybe_ident),
-			host: String::from(maybe_host),
+			host: Self::parse_cidr_or_host(maybe_host),
 		}
 	}
 
@@ -96,10 +151,10 @@ impl Mask
 			maybe_host = "*";
 		}
 
-		Self {
+		Self::Address {
 			nick: String::from("*"),
 			ident: String::from("*"),
-			host: String::from(maybe_host),
+			host: Self::parse_cidr_or_host(maybe_host),
 		}
 	}
 
@@ -121,7 +176,7 @@ impl Mask
 			maybe_nick = "*";
 		}
 
-		Self {
+		Self::Address {
 			nick: String::from(maybe_nick),
 			ident: String::from("*"),
 			host: String::from("*"),
@@ -155,7 +210,7 @@ impl Mask
 			maybe_ident = "*";
 		}
 
-		Self {
+		Self::Address {
 			nick: String::from(maybe_nick),
 			ident: String::from(maybe_ident),
 			host: String::from("*"),
@@ -196,13 +251,25 @@ impl Mask
 			maybe_host = "*";
 		}
 
-		Self {
+		Self::Address {
 			nick: String::from(maybe_nick),
 			ident: String::from(maybe_ident),
-			host: String::from(maybe_host),
+			host: Self::parse_cidr_or_host(maybe_host),
 		}
 	}
 
+	/// Analyse d'un hôte pouvant être une plage d'adresses IP au format CIDR,
+	/// auquel cas elle est normalisée.
+	//
+	// EXAMPLE(1): "192.168.1.5/16"	-> "192.168.0.0/16"
+	// EXAMPLE(2): "2001:DB8::/32"	-> "2001:db8::/32"
+	// EXAMPLE(3): "host"			-> "host"
+	fn parse_cidr_or_host(host: &str) -> String
+	{
+		host.parse::<Cidr>()
+			.map_or_else(|_| String::from(host), |cidr| cidr.to_string())
+	}
+
 	/// Analyse d'un mask entier.
 	fn parse_mask(input: &str) -> Self
 	{
@@ -222,10 +289,116 @@ impl Mask
 			return Self::parse_nick_and_ident_and_host(input);
 		}
 
-		Self {
-			nick: String::from("*"),
-			ident: String::from("*"),
-			host: String::from("*"),
+		Self::generic()
+	}
+
+	/// Analyse d'un masque étendu.
+	//
+	// ```bnf
+	// input :: < '~' letter ':' value >
+	// ```
+	//
+	// EXAMPLE(1): "~a:account"		-> ~a:account
+	// EXAMPLE(2): "~q:*!*@host"	-> ~q:*!*@host
+	// EXAMPLE(3): "~n:nick"		-> ~n:nick!*@*
+	fn parse_extended(input: &str) -> Option<Self>
+	{
+		let (letter, value) =
+			input.strip_prefix(EXTBAN_PREFIX)?.split_once(':')?;
+
+		let mut letters = letter.chars();
+		let letter = letters.next().filter(|_| letters.next().is_none())?;
+
+		let value = if value.is_empty() { "*" } else { value };
+
+		let extended = match letter {
+			| EXTBAN_ACCOUNT => ExtendedMask::Account(value.to_owned()),
+			| EXTBAN_CHANNEL => ExtendedMask::Channel(value.to_lowercase()),
+			| EXTBAN_NICK => ExtendedMask::Nick(Box::new(Self::from(value))),
+			| EXTBAN_QUIET => ExtendedMask::Quiet(Box::new(Self::from(value))),
+			| EXTBAN_REALNAME => ExtendedMask::Realname(value.to_owned()),
+			| _ => return None,
+		};
+
+		Some(Self::Extended(extended))
+	}
+
+	/// Le masque étendu.
+	pub fn extended(&self) -> Option<&ExtendedMask>
+	{
+		match self {
+			| Self::Address { .. } => None,
+			| Self::Extended(extended) => Some(extended),
+		}
+	}
+
+	/// La plage d'adresses IP du masque, lorsque l'hôte du masque est au
+	/// format CIDR.
+	pub fn cidr(&self) -> Option<Cidr>
+	{
+		match self {
+			| Self::Address { host, .. } => host.parse().ok(),
+			| Self::Extended(_) => None,
+		}
+	}
+
+	/// Est-ce que le masque est un masque étendu d'action (`~q`, `~n`), qui
+	/// ne bannit pas l'utilisateur du salon.
+	pub fn is_action(&self) -> bool
+	{
+		matches!(
+			self.extended(),
+			Some(ExtendedMask::Nick(_) | ExtendedMask::Quiet(_))
+		)
+	}
+
+	/// Est-ce que le masque correspond à un utilisateur, membre des salons
+	/// donnés.
+	pub fn matches(&self, user: &User, user_channels: &HashSet<String>)
+	-> bool
+	{
+		match self {
+			// NOTE: une plage CIDR est comparée à l'adresse IP réelle de
+			//       l'utilisateur, et non à son hôte masqué. Cette adresse
+			//       DOIT provenir de `Host::client_ip`, qui ignore les
+			//       en-têtes de proxy falsifiables par le client.
+			| Self::Address { nick, ident, host } => {
+				match host.parse::<Cidr>() {
+					| Ok(cidr) => {
+						user.nickname().iswm(nick)
+							&& user.ident().iswm(ident)
+							&& cidr.contains(user.host().ip_addr.expose())
+					}
+					| Err(_) => user.full_address().iswm(&self.to_string()),
+				}
+			}
+			| Self::Extended(ExtendedMask::Account(account)) => {
+				user.account().is_some_and(|name| name.iswm(account))
+			}
+			| Self::Extended(ExtendedMask::Channel(channel)) => {
+				user_channels.iter().any(|name| name.iswm(channel))
+			}
+			| Self::Extended(ExtendedMask::Realname(realname)) => {
+				user.realname.iswm(realname)
+			}
+			| Self::Extended(
+				ExtendedMask::Nick(mask) | ExtendedMask::Quiet(mask),
+			) => mask.matches(user, user_channels),
+		}
+	}
+}
+
+impl ExtendedMask
+{
+	/// Lettre du masque étendu.
+	pub fn letter(&self) -> char
+	{
+		match self {
+			| Self::Account(_) => EXTBAN_ACCOUNT,
+			| Self::Channel(_) => EXTBAN_CHANNEL,
+			| Self::Nick(_) => EXTBAN_NICK,
+			| Self::Quiet(_) => EXTBAN_QUIET,
+			| Self::Realname(_) => EXTBAN_REALNAME,
 		}
 	}
 }
@@ -242,6 +415,10 @@ where
 	{
 		let mask = s.as_ref();
 
+		if let Some(extended) = Self::parse_extended(mask) {
+			return extended;
+		}
+
 		if mask.eq("*")
 			|| mask.eq("!")
 			|| mask.eq("@")
@@ -275,7 +452,27 @@ impl fmt::Display for Mask
 {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
 	{
-		write!(f, "{}!{}@{}", self.nick, self.ident, self.host)
+		match self {
+			| Self::Address { nick, ident, host } => {
+				write!(f, "{nick}!{ident}@{host}")
+			}
+			| Self::Extended(extended) => extended.fmt(f),
+		}
+	}
+}
+
+impl fmt::Display for ExtendedMask
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{EXTBAN_PREFIX}{}:", self.letter())?;
+
+		match self {
+			| Self::Account(value)
+			| Self::Channel(value)
+			| Self::Realname(value) => value.fmt(f),
+			| Self::Nick(mask) | Self::Quiet(mask) => mask.fmt(f),
+		}
 	}
 }
 
@@ -380,4 +577,70 @@ mod tests
 		assert!(Mask::from("nick!ident") == Mask::from("nick!ident@*"));
 		assert!(Mask::from("nick!ident@host") == Mask::from("nick!ident@host"));
 	}
+
+	#[test]
+	fn test_parse_extended_mask()
+	{
+		let mask = Mask::from("~a:account");
+		assert_eq!(
+			mask.extended(),
+			Some(&ExtendedMask::Account(String::from("account")))
+		);
+		assert_eq!(mask.to_string(), "~a:account");
+
+		let mask = Mask::from("~j:#Flex");
+		assert_eq!(mask.to_string(), "~j:#flex");
+
+		let mask = Mask::from("~q:nick");
+		assert!(mask.is_action());
+		assert_eq!(mask.to_string(), "~q:nick!*@*");
+
+		let mask = Mask::from("~n:*!*@host");
+		assert!(mask.is_action());
+		assert_eq!(mask.to_string(), "~n:*!*@host");
+
+		assert_eq!(Mask::from("~x:value").to_string(), "~x:value!*@*");
+		assert!(!Mask::from("~r:real name").is_action());
+	}
+
+	#[test]
+	fn test_parse_cidr_mask()
+	{
+		let mask = Mask::from("*!*@192.168.1.5/16");
+		assert_eq!(mask.to_string(), "*!*@192.168.0.0/16");
+		assert_eq!(mask.cidr(), "192.168.0.0/16".parse().ok());
+
+		let mask = Mask::from("@2001:DB8::/48");
+		assert_eq!(mask.to_string(), "*!*@2001:db8::/48");
+		assert!(mask.cidr().is_some());
+
+		assert_eq!(Mask::from("*!*@host").cidr(), None);
+		assert_eq!(Mask::from("~a:10.0.0.0/8").cidr(), None);
+	}
+
+	#[test]
+	fn test_cidr_mask_ignores_spoofed_forwarded_for()
+	{
+		use std::net::IpAddr;
+
+		use crate::user::Host;
+
+		let peer_ip: IpAddr = "198.51.100.7".parse().unwrap();
+		let channels = HashSet::default();
+
+		// NOTE: un client qui n'est pas un proxy de confiance ne peut pas
+		//       usurper une adresse IP par l'en-tête `X-Forwarded-For`.
+		let ip = Host::client_ip(peer_ip, false, Some("10.0.0.1"), None);
+		let user = User::new(ip);
+		assert!(!Mask::from("*!*@10.0.0.0/8").matches(&user, &channels));
+		assert!(Mask::from("*!*@198.51.100.0/24").matches(&user, &channels));
+
+		// NOTE: seule l'adresse ajoutée par le proxy de confiance est
+		//       retenue, celle envoyée par le client est ignorée.
+		let ip =
+			Host::client_ip(peer_ip, true, Some("10.0.0.1, 203.0.113.9"), None);
+		let user = User::new(ip);
+		assert!(!Mask::from("*!*@10.0.0.0/8").matches(&user, &channels));
+		assert!(Mask::from("*!*@203.0.113.0/24").matches(&user, &channels));
+	}
 }
@@ -12,6 +12,8 @@ mod flag;
 mod host;
 mod interface;
 mod nick;
+mod server_ban;
+mod snomask;
 mod validation;
 
 use std::collections::HashSet;
@@ -24,6 +26,8 @@ pub use self::flag::*;
 pub use self::host::*;
 pub use self::interface::*;
 pub use self::nick::*;
+pub use self::server_ban::*;
+pub use self::snomask::*;
 pub use self::validation::*;
 use crate::mode::ApplyMode;
 
@@ -57,6 +61,9 @@ pub struct User
 	/// Le nom réel de l'utilisateur.
 	#[serde(skip_serializing)]
 	pub realname: String,
+	/// Nom du compte auquel l'utilisateur est identifié (SASL).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	account: Option<String>,
 	/// Les drapeaux utilisateurs
 	#[serde(skip_serializing)]
 	flags: HashSet<Mode>,
@@ -87,6 +94,7 @@ impl User
 			old_nickname: Default::default(),
 			server_password: Default::default(),
 			realname: Default::default(),
+			account: Default::default(),
 			flags: Default::default(),
 		}
 	}
@@ -109,6 +117,11 @@ impl UserInterface for User
 {
 	type Host = Host;
 
+	fn account(&self) -> Option<&str>
+	{
+		self.account.as_deref()
+	}
+
 	fn host(&self) -> &Self::Host
 	{
 		&self.host
@@ -144,6 +157,12 @@ impl UserInterface for User
 		self.server_password.as_deref()
 	}
 
+	/// Définit le nom du compte auquel l'[utilisateur](Self) est identifié.
+	fn set_account(&mut self, account: Option<String>)
+	{
+		self.account = account;
+	}
+
 	/// Définit l'ident de l'[utilisateur](Self).
 	fn set_ident(&mut self, ident: impl ToString) -> Result<String, Error>
 	{
@@ -292,6 +311,19 @@ impl UserFlagInterface for User
 		self.flags.iter().map(|flag| (flag.letter(), flag.clone()))
 	}
 
+	fn has_invisible_flag(&self) -> bool
+	{
+		self.flags.iter().any(|flag| {
+			matches!(
+				flag,
+				Mode {
+					flag: Self::Flag::Invisible,
+					..
+				}
+			)
+		})
+	}
+
 	fn has_nokick_flag(&self) -> bool
 	{
 		self.flags.iter().any(|flag| {
@@ -305,6 +337,46 @@ impl UserFlagInterface for User
 		})
 	}
 
+	fn has_secure_flag(&self) -> bool
+	{
+		self.flags.iter().any(|flag| {
+			matches!(
+				flag,
+				Mode {
+					flag: Self::Flag::Secure,
+					..
+				}
+			)
+		})
+	}
+
+	fn has_wallops_flag(&self) -> bool
+	{
+		self.flags.iter().any(|flag| {
+			matches!(
+				flag,
+				Mode {
+					flag: Self::Flag::Wallops,
+					..
+				}
+			)
+		})
+	}
+
+	fn server_notice_masks(&self) -> Option<&str>
+	{
+		self.flags.iter().find_map(|flag| {
+			let Mode {
+				flag: Flag::ServerNotices(masks),
+				..
+			} = flag
+			else {
+				return None;
+			};
+			Some(masks.as_str())
+		})
+	}
+
 	fn set_flag(&mut self, flag: impl Into<Mode>)
 	{
 		self.flags.insert(flag.into());
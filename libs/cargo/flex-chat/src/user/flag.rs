@@ -18,6 +18,12 @@
 /// commande `/MODE`, mais à l'aide de la commande `/AWAY`.
 pub const USER_FLAG_AWAY: char = 'a';
 
+/// Drapeau '`i`': utilisateur marqué comme [invisible](Flag::Invisible).
+///
+/// Un utilisateur invisible n'apparaît pas dans les réponses de la commande
+/// `/WHO` des clients ne partageant aucun salon avec lui.
+pub const USER_FLAG_INVISIBLE: char = 'i';
+
 /// Drapeau '`o`': utilisateur marqué comme opérateur ([Flag::GlobalOperator]).
 ///
 /// Si un utilisateur tente de se faire passer pour un opérateur en utilisant le
@@ -46,6 +52,28 @@ pub const USER_FLAG_LOCAL_OPERATOR: char = 'O';
 /// autres utilisateurs.
 pub const USER_FLAG_NOKICK: char = 'q';
 
+/// Drapeau '`s`': utilisateur abonné aux notices du serveur
+/// ([Flag::ServerNotices]).
+///
+/// Ce drapeau prend en argument les catégories de notices (snomasks), par
+/// exemple `+ckn`. Seuls les opérateurs peuvent appliquer ce drapeau.
+pub const USER_FLAG_SERVER_NOTICES: char = 's';
+
+/// Drapeau '`w`': utilisateur recevant les messages WALLOPS et GLOBOPS
+/// ([Flag::Wallops]).
+///
+/// Les messages GLOBOPS ne sont reçus que par les opérateurs ayant ce
+/// drapeau.
+pub const USER_FLAG_WALLOPS: char = 'w';
+
+/// Drapeau '`z`': utilisateur connecté à l'aide d'une connexion sécurisée
+/// ([Flag::Secure]).
+///
+/// Ce drapeau est appliqué par le serveur lors de la connexion. Si un
+/// utilisateur tente d'appliquer ou de retirer ce drapeau, la tentative
+/// devrait être ignorée.
+pub const USER_FLAG_SECURE: char = 'z';
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -64,10 +92,21 @@ pub enum Flag
 	),
 	/// Opérateur global.
 	GlobalOperator,
+	/// Utilisateur invisible.
+	Invisible,
 	/// Opérateur local.
 	LocalOperator,
 	/// Opérateur non kickable.
 	NoKick,
+	/// Utilisateur connecté à l'aide d'une connexion sécurisée.
+	Secure,
+	/// Opérateur abonné aux notices du serveur.
+	ServerNotices(
+		/// Catégories, voir [ServerNoticeMask](super::ServerNoticeMask).
+		String,
+	),
+	/// Utilisateur recevant les messages WALLOPS (et GLOBOPS).
+	Wallops,
 }
 
 // -------------- //
@@ -81,8 +120,12 @@ impl Flag
 		match self {
 			| Self::Away(_) => USER_FLAG_AWAY,
 			| Self::GlobalOperator => USER_FLAG_GLOBAL_OPERATOR,
+			| Self::Invisible => USER_FLAG_INVISIBLE,
 			| Self::LocalOperator => USER_FLAG_LOCAL_OPERATOR,
 			| Self::NoKick => USER_FLAG_NOKICK,
+			| Self::Secure => USER_FLAG_SECURE,
+			| Self::ServerNotices(_) => USER_FLAG_SERVER_NOTICES,
+			| Self::Wallops => USER_FLAG_WALLOPS,
 		}
 	}
 }
@@ -13,6 +13,9 @@ use std::{fmt, net};
 
 use flex_crypto::SHA256;
 use flex_secret::Secret;
+use flex_wildcard_matching::WildcardMatching;
+
+use crate::mode::Cidr;
 
 // --------- //
 // Structure //
@@ -62,6 +65,34 @@ impl Host
 	}
 }
 
+impl Host
+{
+	/// Adresse IP réelle d'un client connecté depuis l'adresse IP `peer_ip`.
+	///
+	/// Les en-têtes `X-Forwarded-For` et `X-Real-IP` ne sont pris en compte
+	/// que lorsque le pair est un proxy de confiance : n'importe quel client
+	/// peut les envoyer. Seule la dernière adresse de `X-Forwarded-For`, celle
+	/// ajoutée par le proxy de confiance, est retenue.
+	pub fn client_ip(
+		peer_ip: net::IpAddr,
+		is_trusted_proxy: bool,
+		forwarded_for: Option<&str>,
+		real_ip: Option<&str>,
+	) -> net::IpAddr
+	{
+		if !is_trusted_proxy {
+			return peer_ip;
+		}
+
+		let forwarded_ip = forwarded_for
+			.and_then(|forwarded_for| forwarded_for.rsplit(',').next())
+			.or(real_ip)
+			.and_then(|ip| ip.trim().parse().ok());
+
+		forwarded_ip.unwrap_or(peer_ip)
+	}
+}
+
 impl Host
 {
 	/// Définit un nouvel hôte virtuel.
@@ -71,6 +102,31 @@ impl Host
 	}
 }
 
+impl Host
+{
+	/// Est-ce que l'hôte correspond à un masque d'hôte.
+	pub fn matches_mask(&self, mask_host: &str) -> bool
+	{
+		Self::matches_address(mask_host, &self.cloaked, self.ip_addr.expose())
+	}
+
+	// NOTE: l'hôte est comparé à l'hôte masqué de l'utilisateur et à son
+	//       adresse IP réelle, une plage CIDR uniquement à l'adresse IP.
+	pub(crate) fn matches_address(
+		mask_host: &str,
+		hostname: &str,
+		ip_addr: &net::IpAddr,
+	) -> bool
+	{
+		if let Ok(cidr) = mask_host.parse::<Cidr>() {
+			return cidr.contains(ip_addr);
+		}
+
+		(!hostname.is_empty() && hostname.iswm(mask_host))
+			|| ip_addr.to_canonical().to_string().iswm(mask_host)
+	}
+}
+
 impl Host
 {
 	/// Génère un nom d'hôte à partir de l'adresse IP.
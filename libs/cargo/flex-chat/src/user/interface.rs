@@ -32,6 +32,9 @@ pub trait UserInterface:
 {
 	type Host: ToString;
 
+	/// Nom du compte auquel l'utilisateur est identifié.
+	fn account(&self) -> Option<&str>;
+
 	/// Hôte de l'utilisateur.
 	fn host(&self) -> &Self::Host;
 
@@ -54,6 +57,9 @@ pub trait UserInterface:
 	/// Mot de passe utilisateur utilisé pour la connexion au serveur (exposed).
 	fn server_password_exposed(&self) -> Option<&Arc<str>>;
 
+	/// Définit le nom du compte auquel l'[utilisateur](Self) est identifié.
+	fn set_account(&mut self, account: Option<String>);
+
 	/// Définit l'ident de l'[utilisateur](Self).
 	fn set_ident(&mut self, ident: impl ToString) -> Result<String, Error>;
 
@@ -116,9 +122,22 @@ pub trait UserFlagInterface
 	#[rustfmt::skip]
 	fn flags(&self) -> impl Iterator<Item = (char, ApplyMode<Self::Flag>)> + '_;
 
+	/// Vérifie que l'utilisateur a comme drapeau, le drapeau i (invisible).
+	fn has_invisible_flag(&self) -> bool;
+
 	/// Vérifie que l'utilisateur a comme drapeau, le drapeau q (nokick).
 	fn has_nokick_flag(&self) -> bool;
 
+	/// Vérifie que l'utilisateur a comme drapeau, le drapeau z (connexion
+	/// sécurisée).
+	fn has_secure_flag(&self) -> bool;
+
+	/// Vérifie que l'utilisateur a comme drapeau, le drapeau w (wallops).
+	fn has_wallops_flag(&self) -> bool;
+
+	/// Les catégories de notices du serveur du drapeau s de l'utilisateur.
+	fn server_notice_masks(&self) -> Option<&str>;
+
 	/// Applique un drapeau à l'[utilisateur](Self).
 	fn set_flag(&mut self, flag: impl Into<ApplyMode<Self::Flag>>);
 }
@@ -0,0 +1,347 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fmt, net, str};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use flex_wildcard_matching::WildcardMatching;
+
+use super::{Host, User, UserInterface};
+use crate::mode::Cidr;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Bannissement du serveur d'un masque `ident@hôte`.
+pub const SERVER_BAN_KLINE: char = 'K';
+/// Bannissement du réseau d'un masque `ident@hôte`.
+pub const SERVER_BAN_GLINE: char = 'G';
+/// Bannissement d'une adresse IP (ou d'une plage CIDR), avant même
+/// l'enregistrement de la connexion.
+pub const SERVER_BAN_ZLINE: char = 'Z';
+/// L'utilisateur reste connecté, mais ses commandes sont ignorées.
+pub const SERVER_BAN_SHUN: char = 'S';
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Bannissement appliqué par un opérateur sur le serveur.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ServerBan
+{
+	/// Type du bannissement.
+	pub kind: ServerBanKind,
+	/// Le masque normalisé (`ident@hôte`, adresse IP ou plage CIDR).
+	pub mask: String,
+	/// La raison du bannissement.
+	pub reason: String,
+	/// Le pseudonyme de l'opérateur ayant appliqué le bannissement.
+	pub updated_by: String,
+	/// Date d'application du bannissement.
+	pub updated_at: DateTime<Utc>,
+	/// Date à laquelle le bannissement expire. Aucune date signifie que le
+	/// bannissement est permanent.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<DateTime<Utc>>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ServerBanKind
+{
+	Kline,
+	Gline,
+	Zline,
+	Shun,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerBan
+{
+	pub fn new(
+		kind: ServerBanKind,
+		mask: impl ToString,
+		updated_by: impl ToString,
+	) -> Self
+	{
+		Self {
+			kind,
+			mask: mask.to_string(),
+			reason: Default::default(),
+			updated_by: updated_by.to_string(),
+			updated_at: Utc::now(),
+			expires_at: Default::default(),
+		}
+	}
+
+	/// Le bannissement expire après la durée donnée.
+	pub fn with_duration(mut self, duration: TimeDelta) -> Self
+	{
+		self.expires_at.replace(self.updated_at + duration);
+		self
+	}
+
+	/// La raison du bannissement.
+	pub fn with_reason(mut self, reason: impl ToString) -> Self
+	{
+		self.reason = reason.to_string();
+		self
+	}
+
+	/// Est-ce que le bannissement a expiré à la date donnée.
+	pub fn is_expired(&self, now: DateTime<Utc>) -> bool
+	{
+		self.expires_at.is_some_and(|expires_at| expires_at <= now)
+	}
+
+	/// Est-ce que le bannissement correspond à un utilisateur.
+	pub fn matches(&self, user: &User) -> bool
+	{
+		let host = user.host();
+		self.matches_address(user.ident(), &host.cloaked, host.ip_addr.expose())
+	}
+
+	/// Est-ce que le bannissement correspond à une adresse IP. Seul le
+	/// masque d'un ZLINE est comparé, les autres masques ont besoin de
+	/// l'ident de l'utilisateur.
+	pub fn matches_ip(&self, ip_addr: &net::IpAddr) -> bool
+	{
+		self.kind == ServerBanKind::Zline
+			&& Host::matches_address(&self.mask, "", ip_addr)
+	}
+
+	fn matches_address(
+		&self,
+		ident: &str,
+		hostname: &str,
+		ip_addr: &net::IpAddr,
+	) -> bool
+	{
+		if self.kind == ServerBanKind::Zline {
+			return self.matches_ip(ip_addr);
+		}
+
+		let Some((mask_ident, mask_host)) = self.mask.split_once('@') else {
+			return false;
+		};
+
+		ident.iswm(mask_ident)
+			&& Host::matches_address(mask_host, hostname, ip_addr)
+	}
+}
+
+impl ServerBanKind
+{
+	/// Lettre du type de bannissement, utilisée par la commande STATS.
+	pub fn letter(&self) -> char
+	{
+		match self {
+			| Self::Kline => SERVER_BAN_KLINE,
+			| Self::Gline => SERVER_BAN_GLINE,
+			| Self::Zline => SERVER_BAN_ZLINE,
+			| Self::Shun => SERVER_BAN_SHUN,
+		}
+	}
+
+	/// Type de bannissement à partir de la lettre d'une requête STATS (la
+	/// casse est ignorée).
+	pub fn from_letter(letter: char) -> Option<Self>
+	{
+		let kind = match letter.to_ascii_uppercase() {
+			| SERVER_BAN_KLINE => Self::Kline,
+			| SERVER_BAN_GLINE => Self::Gline,
+			| SERVER_BAN_ZLINE => Self::Zline,
+			| SERVER_BAN_SHUN => Self::Shun,
+			| _ => return None,
+		};
+		Some(kind)
+	}
+
+	/// Nom de la commande appliquant ce type de bannissement.
+	pub fn command(&self) -> &'static str
+	{
+		match self {
+			| Self::Kline => "KLINE",
+			| Self::Gline => "GLINE",
+			| Self::Zline => "ZLINE",
+			| Self::Shun => "SHUN",
+		}
+	}
+
+	/// Analyse et normalise le masque d'un bannissement.
+	//
+	// EXAMPLE(1): ZLINE "192.168.1.5/16"	-> "192.168.0.0/16"
+	// EXAMPLE(2): ZLINE "host"				-> None
+	// EXAMPLE(3): KLINE "ident@Host"		-> "ident@host"
+	// EXAMPLE(4): KLINE "host"				-> "*@host"
+	// EXAMPLE(5): KLINE "*@*"				-> None
+	pub fn parse_mask(&self, input: &str) -> Option<String>
+	{
+		if *self == Self::Zline {
+			let input = input.trim_start_matches("*@");
+			if let Ok(cidr) = input.parse::<Cidr>() {
+				return Some(cidr.to_string());
+			}
+			return input
+				.parse::<net::IpAddr>()
+				.ok()
+				.map(|ip_addr| ip_addr.to_string());
+		}
+
+		let (mut ident, host) = input.split_once('@').unwrap_or(("*", input));
+
+		if ident.is_empty() {
+			ident = "*";
+		}
+
+		// NOTE: un masque correspondant à tous les utilisateurs est refusé.
+		if host.is_empty()
+			|| host.contains(['@', '!'])
+			|| host.chars().all(|ch| ch == '*' || ch == '?')
+		{
+			return None;
+		}
+
+		let host = host
+			.parse::<Cidr>()
+			.map_or_else(|_| host.to_lowercase(), |cidr| cidr.to_string());
+
+		Some(format!("{ident}@{host}"))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for ServerBanKind
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.command())
+	}
+}
+
+impl str::FromStr for ServerBanKind
+{
+	type Err = &'static str;
+
+	fn from_str(command: &str) -> Result<Self, Self::Err>
+	{
+		let kind = match command.to_ascii_uppercase().as_str() {
+			| "KLINE" => Self::Kline,
+			| "GLINE" => Self::Gline,
+			| "ZLINE" => Self::Zline,
+			| "SHUN" => Self::Shun,
+			| _ => return Err("Type de bannissement inconnu"),
+		};
+		Ok(kind)
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_mask()
+	{
+		let kline = ServerBanKind::Kline;
+		assert_eq!(kline.parse_mask("ident@Host"), Some("ident@host".into()));
+		assert_eq!(kline.parse_mask("host"), Some("*@host".into()));
+		assert_eq!(
+			kline.parse_mask("@10.0.0.1/8"),
+			Some("*@10.0.0.0/8".into())
+		);
+		assert_eq!(kline.parse_mask("*@*"), None);
+		assert_eq!(kline.parse_mask("ident@"), None);
+
+		let zline = ServerBanKind::Zline;
+		assert_eq!(zline.parse_mask("10.1.2.3/16"), Some("10.1.0.0/16".into()));
+		assert_eq!(
+			zline.parse_mask("*@2001:db8::1"),
+			Some("2001:db8::1".into())
+		);
+		assert_eq!(zline.parse_mask("host"), None);
+	}
+
+	#[test]
+	fn test_matches_address()
+	{
+		let ip_addr = net::IpAddr::from([192, 168, 1, 5]);
+
+		let kline =
+			ServerBan::new(ServerBanKind::Kline, "*@*.example.org", "oper");
+		assert!(kline.matches_address("ident", "abc.example.org", &ip_addr));
+		assert!(!kline.matches_address("ident", "example.com", &ip_addr));
+
+		let gline =
+			ServerBan::new(ServerBanKind::Gline, "bot*@192.168.0.0/16", "oper");
+		assert!(gline.matches_address("bot1", "example.org", &ip_addr));
+		assert!(!gline.matches_address("user", "example.org", &ip_addr));
+
+		let shun = ServerBan::new(ServerBanKind::Shun, "*@192.168.1.*", "oper");
+		assert!(shun.matches_address("user", "example.org", &ip_addr));
+		assert!(!shun.matches_ip(&ip_addr));
+
+		let zline =
+			ServerBan::new(ServerBanKind::Zline, "192.168.0.0/16", "oper");
+		assert!(zline.matches_ip(&ip_addr));
+		assert!(!zline.matches_ip(&net::IpAddr::from([10, 0, 0, 1])));
+	}
+
+	#[test]
+	fn test_server_ban_expiry()
+	{
+		let permanent = ServerBan::new(ServerBanKind::Kline, "*@host", "oper");
+		assert!(!permanent.is_expired(Utc::now()));
+
+		let timed = ServerBan::new(ServerBanKind::Kline, "*@host", "oper")
+			.with_duration(TimeDelta::minutes(30));
+		assert!(!timed.is_expired(Utc::now()));
+		assert!(timed.is_expired(Utc::now() + TimeDelta::hours(1)));
+	}
+
+	#[test]
+	fn test_server_ban_kind_letter()
+	{
+		for kind in [
+			ServerBanKind::Kline,
+			ServerBanKind::Gline,
+			ServerBanKind::Zline,
+			ServerBanKind::Shun,
+		] {
+			assert_eq!(ServerBanKind::from_letter(kind.letter()), Some(kind));
+		}
+		assert_eq!(ServerBanKind::from_letter('k'), Some(ServerBanKind::Kline));
+		assert_eq!(ServerBanKind::from_letter('x'), None);
+		assert_eq!("shun".parse(), Ok(ServerBanKind::Shun));
+	}
+}
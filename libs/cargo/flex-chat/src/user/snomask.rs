@@ -0,0 +1,128 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Les catégories de notices du serveur (snomasks) auxquelles un opérateur
+/// peut s'abonner à l'aide du drapeau utilisateur '`s`'.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum ServerNoticeMask
+{
+	/// Les connexions et déconnexions des clients (`c`).
+	Connects,
+	/// Les KILL (`k`).
+	Kills,
+	/// Les clients devenus opérateurs (`o`).
+	Opers,
+	/// Les changements de pseudonymes (`n`).
+	Nicks,
+	/// Les protections contre le flood déclenchées sur les salons (`f`).
+	Floods,
+	/// Les bannissements du serveur appliqués, retirés ou déclenchés (`b`).
+	Bans,
+	/// Les tentatives de connexion OPER échouées (`O`).
+	FailedOpers,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerNoticeMask
+{
+	/// Toutes les catégories de notices, dans l'ordre d'affichage.
+	pub const ALL: [Self; 7] = [
+		Self::Connects,
+		Self::Kills,
+		Self::Opers,
+		Self::Nicks,
+		Self::Floods,
+		Self::Bans,
+		Self::FailedOpers,
+	];
+
+	/// Lettre associée à la catégorie.
+	pub fn letter(&self) -> char
+	{
+		match self {
+			| Self::Connects => 'c',
+			| Self::Kills => 'k',
+			| Self::Opers => 'o',
+			| Self::Nicks => 'n',
+			| Self::Floods => 'f',
+			| Self::Bans => 'b',
+			| Self::FailedOpers => 'O',
+		}
+	}
+
+	/// Catégorie associée à une lettre.
+	pub fn from_letter(letter: char) -> Option<Self>
+	{
+		Self::ALL.into_iter().find(|mask| mask.letter() == letter)
+	}
+
+	/// Applique des changements (`+ckn-o`, `*` pour toutes les catégories)
+	/// au masque actuel. Les lettres inconnues sont ignorées et le masque
+	/// retourné suit l'ordre de [ServerNoticeMask::ALL].
+	pub fn apply(current: &str, changes: &str) -> String
+	{
+		let mut masks: Vec<Self> =
+			current.chars().filter_map(Self::from_letter).collect();
+		let mut adding = true;
+
+		for letter in changes.chars() {
+			let updated: Vec<Self> = match letter {
+				| '+' => {
+					adding = true;
+					continue;
+				}
+				| '-' => {
+					adding = false;
+					continue;
+				}
+				| '*' => Self::ALL.to_vec(),
+				| letter => Self::from_letter(letter).into_iter().collect(),
+			};
+
+			if adding {
+				masks.extend(updated);
+			} else {
+				masks.retain(|mask| !updated.contains(mask));
+			}
+		}
+
+		Self::ALL
+			.into_iter()
+			.filter(|mask| masks.contains(mask))
+			.map(|mask| mask.letter())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_apply_server_notice_mask()
+	{
+		assert_eq!(ServerNoticeMask::apply("", "*"), "ckonfbO");
+		assert_eq!(ServerNoticeMask::apply("", "+nc"), "cn");
+		assert_eq!(ServerNoticeMask::apply("cn", "kx"), "ckn");
+		assert_eq!(ServerNoticeMask::apply("ckn", "-k+o"), "con");
+		assert_eq!(ServerNoticeMask::apply("ckonfbO", "-*+b"), "b");
+		assert_eq!(ServerNoticeMask::apply("cn", "-cn"), "");
+	}
+}
@@ -153,6 +153,7 @@ impl<S, E, C> ApplicationExtensionInterface for AxumApplication<S, E, C>
 	{
 		let payload = payload.into();
 		let instance = Ext::new(payload);
+		self.application_adapter.extensions.insert(instance.clone());
 		self.application_adapter.router.global = self
 			.application_adapter
 			.router
@@ -184,6 +185,7 @@ impl<S, E, C> AsyncApplicationExtensionInterface for AxumApplication<S, E, C>
 	{
 		let payload = payload.into();
 		let instance = Ex::new(payload).await;
+		self.application_adapter.extensions.insert(instance.clone());
 		self.application_adapter.router.global = self
 			.application_adapter
 			.router
@@ -370,14 +372,21 @@ where
 			scoped_router,
 		);
 
+		let user_state = <F as WebSocketFeature<S>>::State::default();
+
+		// NOTE: les clones de l'état de la feature partagent leurs données,
+		//       la tâche de démarrage y accède en dehors des gestionnaires de
+		//       Socket.IO.
+		let startup_state: &'static <F as WebSocketFeature<S>>::State =
+			Box::leak(Box::new(user_state.clone()));
+
 		let (layer, io) = SocketIo::builder()
 			.with_state(self.application_adapter.state.clone())
-			.with_state(<F as WebSocketFeature<S>>::State::default())
+			.with_state(user_state)
 			.req_path(F::ENDPOINT)
 			.build_layer();
 
-		io.ns(
-			"/",
+		let on_connect =
 			|socket: extract::SocketRef,
 			 server_state: extract::State<AxumState<S>>,
 			 user_state: extract::State<<F as WebSocketFeature<S>>::State>,
@@ -385,8 +394,15 @@ where
 				F::Handlers::listen(&socket);
 				F::Handlers2::listen(&socket);
 				F::on_connect(socket, server_state, user_state, auth_data);
+			};
+		io.ns("/", on_connect);
+
+		let startup_io = io.clone();
+		self.application_adapter.startup_tasks.push(Box::new(
+			move |extensions| {
+				Box::pin(F::on_start(startup_io, startup_state, extensions))
 			},
-		);
+		));
 
 		self.application_adapter.state.set_ws(io);
 
@@ -410,6 +426,7 @@ where
 				&config.user
 			);
 
+			self.application_adapter.extensions.insert(config.user.clone());
 			scoped_router = scoped_router.layer(axum::Extension(config.user));
 		}
 
@@ -23,6 +23,20 @@ pub struct PostgreSQLDatabase
 	pub connection: PostgresSGBD,
 }
 
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl PostgreSQLDatabase
+{
+	/// Pool de connexion de la base de données PostgreSQL, pour les requêtes
+	/// que le constructeur de requêtes ne sait pas (encore) construire.
+	pub fn pool(&self) -> &sqlx::PgPool
+	{
+		self.connection.pool()
+	}
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -38,6 +38,11 @@ macro_rules! impl_ws_handler_interface {
 					socket.on($generic::EVENT_NAME, $generic::handle);
 				)*
 			}
+
+			fn events() -> Vec<&'static str>
+			{
+				vec![$( $generic::EVENT_NAME ),*]
+			}
 		}
 	)*};
 
@@ -65,6 +70,11 @@ macro_rules! impl_ws_handler_interface {
 					socket.on($generic::UNSET_EVENT_NAME, $generic::handle_unset);
 				)*
 			}
+
+			fn events() -> Vec<&'static str>
+			{
+				vec![$( $generic::SET_EVENT_NAME, $generic::UNSET_EVENT_NAME ),*]
+			}
 		}
 	)*};
 }
@@ -80,12 +90,24 @@ pub trait WebSocketFeature<UserState>: Feature<State = UserState>
 	type Handlers: 'static + Send + Sync + WebSocketHandlersInterface;
 	type Handlers2: 'static + Send + Sync + WebSocketHandlers2Interface;
 
-	/// L'état de la feature WebSocket.
-	type State: 'static + Send + Sync + Default;
+	/// L'état de la feature WebSocket. Les clones de l'état DOIVENT partager
+	/// leurs données.
+	type State: 'static + Send + Sync + Default + Clone;
 
 	/// Point d'entrée racine de la WebSocket.
 	const ENDPOINT: &'static str;
 
+	/// Appelé au démarrage du serveur, avant l'acceptation de la moindre
+	/// connexion. Le démarrage du serveur est interrompu en cas d'erreur.
+	fn on_start(
+		_io: socketioxide::SocketIo,
+		_user_state: &'static <Self as WebSocketFeature<UserState>>::State,
+		_extensions: axum::http::Extensions,
+	) -> impl Future<Output = Result<(), String>> + Send + 'static
+	{
+		async { Ok(()) }
+	}
+
 	fn on_connect(
 		socket: socketioxide::extract::SocketRef,
 		server_state: socketioxide::extract::State<AxumState<UserState>>,
@@ -121,11 +143,17 @@ pub trait WebSocketAsyncFeature<UserState>:
 pub trait WebSocketHandlersInterface
 {
 	fn listen(socket: &socketioxide::extract::SocketRef);
+
+	/// Les noms des événements écoutés par les gestionnaires.
+	fn events() -> Vec<&'static str>;
 }
 
 pub trait WebSocketHandlers2Interface
 {
 	fn listen(socket: &socketioxide::extract::SocketRef);
+
+	/// Les noms des événements (SET / UNSET) écoutés par les gestionnaires.
+	fn events() -> Vec<&'static str>;
 }
 
 pub trait WebSocketHandler
@@ -17,6 +17,8 @@ mod net
 	pub use tokio::net::*;
 }
 
+use std::pin::Pin;
+
 use axum_server::tls_rustls::RustlsConfig;
 use console::style;
 use flex_kernel::{
@@ -35,6 +37,19 @@ use crate::http::routing::HttpRouterCollection;
 use crate::settings::ServerSettings;
 use crate::{AxumState, server, settings};
 
+// ---- //
+// Type //
+// ---- //
+
+/// Tâche de démarrage d'une feature, exécutée avant l'ouverture du serveur
+/// HTTP à partir des extensions du serveur.
+pub type StartupTask = Box<
+	dyn FnOnce(
+			axum::http::Extensions,
+		) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>
+		+ Send,
+>;
+
 // --------- //
 // Structure //
 // --------- //
@@ -52,6 +67,10 @@ pub struct Server<UserState, UserEnv, UserCLI>
 	pub router: HttpRouterCollection<UserState>,
 	/// État global du serveur.
 	pub state: AxumState<UserState>,
+	/// Extensions du serveur, accessibles aux tâches de démarrage.
+	pub extensions: axum::http::Extensions,
+	/// Tâches de démarrage des features.
+	pub startup_tasks: Vec<StartupTask>,
 }
 
 // -------------- //
@@ -182,6 +201,8 @@ impl<S, E, C> ApplicationAdapterInterface for server::Server<S, E, C>
 			env_vars: Default::default(),
 			cli_args: Default::default(),
 			state: Default::default(),
+			extensions: Default::default(),
+			startup_tasks: Default::default(),
 		}
 	}
 }
@@ -192,6 +213,11 @@ impl<S, E, C> AsyncApplicationStartupExtension for server::Server<S, E, C>
 	{
 		self.display_all_routes();
 		self = self.define_static_resources();
+		for startup_task in std::mem::take(&mut self.startup_tasks) {
+			startup_task(self.extensions.clone())
+				.await
+				.expect("Démarrage des features");
+		}
 		self.launch().await.expect("Ouverture du serveur HTTP");
 	}
 }
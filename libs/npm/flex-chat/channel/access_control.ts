@@ -29,4 +29,9 @@ export class ChannelAccessControl {
 	 */
 	invitelist_exception: Map<MaskAddr, ModeApplyFlag<AccessControlMode>> =
 		new Map();
+
+	/**
+	 * Liste des utilisateurs muets du salon.
+	 */
+	quietlist: Map<MaskAddr, ModeApplyFlag<AccessControlMode>> = new Map();
 }
@@ -22,3 +22,19 @@ export class InviteCommand implements CommandInterface<"INVITE"> {
 		this.store.emit("INVITE", payload);
 	}
 }
+
+export class InviteListCommand implements CommandInterface<"INVITE LIST"> {
+	constructor(private store: ChatStoreInterface) {}
+
+	send(payload: Command<"INVITE LIST">) {
+		this.store.emit("INVITE LIST", payload);
+	}
+}
+
+export class UninviteCommand implements CommandInterface<"UNINVITE"> {
+	constructor(private store: ChatStoreInterface) {}
+
+	send(payload: Command<"UNINVITE">) {
+		this.store.emit("UNINVITE", payload);
+	}
+}
@@ -68,3 +68,27 @@ export class InviteHandler implements SocketEventInterface<"INVITE"> {
 		this.store.play_audio("invite");
 	}
 }
+
+export class UninviteHandler implements SocketEventInterface<"UNINVITE"> {
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("UNINVITE", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"UNINVITE">) {
+		this.store
+			.network()
+			.add_connect_event(
+				data,
+				`* ${data.origin.nickname} a retiré ton invitation sur ${data.channel}`,
+			);
+	}
+}
@@ -12,8 +12,8 @@ import type { ChatStoreInterface } from "../../store";
 import type { Module } from "../interface";
 
 import { is_channel } from "../../asserts/room";
-import { InviteCommand } from "./command";
-import { InviteHandler } from "./handler";
+import { InviteCommand, InviteListCommand, UninviteCommand } from "./command";
+import { InviteHandler, UninviteHandler } from "./handler";
 
 // -------------- //
 // Implémentation //
@@ -29,6 +29,7 @@ export class InviteModule implements Module<InviteModule> {
 	static create(store: ChatStoreInterface): InviteModule {
 		return new InviteModule(
 			new InviteCommand(store),
+			new InviteListCommand(store),
 			new InviteHandler(store),
 		);
 	}
@@ -38,6 +39,7 @@ export class InviteModule implements Module<InviteModule> {
 	// ----------- //
 	constructor(
 		private command: InviteCommand,
+		private list_command: InviteListCommand,
 		private handler: InviteHandler,
 	) {}
 
@@ -46,7 +48,9 @@ export class InviteModule implements Module<InviteModule> {
 	// ------- //
 
 	input(room_id: RoomID, nickname?: string, channel_raw?: ChannelID) {
+		// NOTE: sans paramètre, liste les invitations en attente.
 		if (!nickname) {
+			this.list_command.send({});
 			return;
 		}
 
@@ -70,3 +74,55 @@ export class InviteModule implements Module<InviteModule> {
 		this.handler.listen();
 	}
 }
+
+export class UninviteModule implements Module<UninviteModule> {
+	// ------ //
+	// STATIC //
+	// ------ //
+
+	static NAME = "UNINVITE";
+
+	static create(store: ChatStoreInterface): UninviteModule {
+		return new UninviteModule(
+			new UninviteCommand(store),
+			new UninviteHandler(store),
+		);
+	}
+
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(
+		private command: UninviteCommand,
+		private handler: UninviteHandler,
+	) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	input(room_id: RoomID, nickname?: string, channel_raw?: ChannelID) {
+		if (!nickname) {
+			return;
+		}
+
+		let channel = room_id;
+		if (channel_raw) {
+			channel = channel_raw;
+		}
+
+		if (!is_channel(channel)) {
+			return;
+		}
+
+		this.send({ nickname, channel });
+	}
+
+	send(payload: Command<"UNINVITE">) {
+		this.command.send(payload);
+	}
+
+	listen() {
+		this.handler.listen();
+	}
+}
@@ -40,6 +40,81 @@ export class ReplyInvitingHandler
 	}
 }
 
+export class ReplyInvitelistHandler
+	implements SocketEventInterface<"RPL_INVITELIST">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("RPL_INVITELIST", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"RPL_INVITELIST">) {
+		this.store
+			.network()
+			.add_connect_event(
+				data,
+				`* Invitation en attente sur ${data.channel}`,
+			);
+	}
+}
+
+export class ReplyEndofinvitelistHandler
+	implements SocketEventInterface<"RPL_ENDOFINVITELIST">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("RPL_ENDOFINVITELIST", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"RPL_ENDOFINVITELIST">) {
+		this.store
+			.network()
+			.add_connect_event(data, "* Fin de la liste des invitations");
+	}
+}
+
+export class ReplyUninvitedHandler
+	implements SocketEventInterface<"RPL_UNINVITED">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("RPL_UNINVITED", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"RPL_UNINVITED">) {
+		this.store
+			.network()
+			.add_connect_event(
+				data,
+				`* L'invitation de ${data.nick} sur ${data.channel} a été retirée`,
+			);
+	}
+}
+
 export class ErrorInviteonlychanHandler
 	implements SocketEventInterface<"ERR_INVITEONLYCHAN">
 {
@@ -65,3 +140,29 @@ export class ErrorInviteonlychanHandler
 		);
 	}
 }
+
+export class ErrorNotinvitedHandler
+	implements SocketEventInterface<"ERR_NOTINVITED">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("ERR_NOTINVITED", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"ERR_NOTINVITED">) {
+		let room = this.store.room_manager().active();
+		room.add_event(
+			"error:err_notinvited",
+			room.create_event(data),
+			data.reason,
+		);
+	}
+}
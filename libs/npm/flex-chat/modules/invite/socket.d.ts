@@ -10,4 +10,5 @@
 
 declare interface ErrorReplies {
 	ERR_INVITEONLYCHAN: { channel: ChannelID };
+	ERR_NOTINVITED: { nick: string; channel: ChannelID };
 }
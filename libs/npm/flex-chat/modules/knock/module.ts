@@ -0,0 +1,61 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import type { ChatStoreInterface } from "../../store";
+import type { Module } from "../interface";
+
+import { is_channel } from "../../asserts/room";
+import { KnockCommand } from "./command";
+import { KnockHandler } from "./handler";
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+export class KnockModule implements Module<KnockModule> {
+	// ------ //
+	// STATIC //
+	// ------ //
+
+	static NAME = "KNOCK";
+
+	static create(store: ChatStoreInterface): KnockModule {
+		return new KnockModule(new KnockCommand(store), new KnockHandler(store));
+	}
+
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(
+		private command: KnockCommand,
+		private handler: KnockHandler,
+	) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	input(_: RoomID, channel?: ChannelID, ...words: Array<string>) {
+		if (!is_channel(channel)) {
+			return;
+		}
+
+		let reason = words.join(" ") || undefined;
+		this.send({ channel, reason });
+	}
+
+	send(payload: Command<"KNOCK">) {
+		this.command.send(payload);
+	}
+
+	listen() {
+		this.handler.listen();
+	}
+}
@@ -0,0 +1,145 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+import type { ChatStoreInterface } from "../../store";
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+export class ReplyKnockdlvrHandler
+	implements SocketEventInterface<"RPL_KNOCKDLVR">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("RPL_KNOCKDLVR", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"RPL_KNOCKDLVR">) {
+		this.store
+			.network()
+			.add_connect_event(
+				data,
+				`* Ta demande d'invitation sur ${data.channel} a été transmise`,
+			);
+	}
+}
+
+export class ErrorCannotknockHandler
+	implements SocketEventInterface<"ERR_CANNOTKNOCK">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("ERR_CANNOTKNOCK", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"ERR_CANNOTKNOCK">) {
+		let room = this.store.room_manager().active();
+		room.add_event(
+			"error:err_cannotknock",
+			room.create_event(data),
+			data.reason,
+		);
+	}
+}
+
+export class ErrorChanopenHandler
+	implements SocketEventInterface<"ERR_CHANOPEN">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("ERR_CHANOPEN", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"ERR_CHANOPEN">) {
+		let room = this.store.room_manager().active();
+		room.add_event(
+			"error:err_chanopen",
+			room.create_event(data),
+			data.reason,
+		);
+	}
+}
+
+export class ErrorKnockonchanHandler
+	implements SocketEventInterface<"ERR_KNOCKONCHAN">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("ERR_KNOCKONCHAN", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"ERR_KNOCKONCHAN">) {
+		let room = this.store.room_manager().active();
+		room.add_event(
+			"error:err_knockonchan",
+			room.create_event(data),
+			data.reason,
+		);
+	}
+}
+
+export class ErrorToomanyknockHandler
+	implements SocketEventInterface<"ERR_TOOMANYKNOCK">
+{
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private store: ChatStoreInterface) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	listen() {
+		this.store.on("ERR_TOOMANYKNOCK", (data) => this.handle(data));
+	}
+
+	handle(data: GenericReply<"ERR_TOOMANYKNOCK">) {
+		let room = this.store.room_manager().active();
+		room.add_event(
+			"error:err_toomanyknock",
+			room.create_event(data),
+			data.reason,
+		);
+	}
+}
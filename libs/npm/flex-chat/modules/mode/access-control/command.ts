@@ -62,3 +62,19 @@ export class UninviteExCommand implements CommandInterface<"UNINVITEX"> {
 		this.store.emit("UNINVITEX", payload);
 	}
 }
+
+export class QuietCommand implements CommandInterface<"QUIET"> {
+	constructor(private store: ChatStoreInterface) {}
+
+	send(payload: Command<"QUIET">) {
+		this.store.emit("QUIET", payload);
+	}
+}
+
+export class UnquietCommand implements CommandInterface<"UNQUIET"> {
+	constructor(private store: ChatStoreInterface) {}
+
+	send(payload: Command<"UNQUIET">) {
+		this.store.emit("UNQUIET", payload);
+	}
+}
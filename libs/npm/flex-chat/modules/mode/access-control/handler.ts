@@ -52,7 +52,7 @@ export class ModeAccessControlHandler implements SocketEventInterface<"MODE"> {
 			mode: ModeApplyFlag<any>,
 		): mode is ModeApplyFlag<AccessControlMode> {
 			return (
-				["b", "e", "I"].includes(letter) &&
+				["b", "e", "I", "Q"].includes(letter) &&
 				Object.hasOwn(mode.flag, "mask")
 			);
 		}
@@ -63,13 +63,7 @@ export class ModeAccessControlHandler implements SocketEventInterface<"MODE"> {
 					continue;
 				}
 
-				let mask_addr = [
-					mode.flag.mask.nick,
-					"!",
-					mode.flag.mask.ident,
-					"@",
-					mode.flag.mask.host,
-				].join("") as MaskAddr;
+				let mask_addr = mask_to_string(mode.flag.mask) as MaskAddr;
 
 				if (letter === "b") {
 					channel.access_control.banlist.set(mask_addr, mode);
@@ -83,6 +77,8 @@ export class ModeAccessControlHandler implements SocketEventInterface<"MODE"> {
 						mask_addr,
 						mode,
 					);
+				} else if (letter === "Q") {
+					channel.access_control.quietlist.set(mask_addr, mode);
 				}
 			}
 		}
@@ -93,13 +89,7 @@ export class ModeAccessControlHandler implements SocketEventInterface<"MODE"> {
 					continue;
 				}
 
-				let mask_addr = [
-					mode.flag.mask.nick,
-					"!",
-					mode.flag.mask.ident,
-					"@",
-					mode.flag.mask.host,
-				].join("") as MaskAddr;
+				let mask_addr = mask_to_string(mode.flag.mask) as MaskAddr;
 
 				if (letter === "b") {
 					channel.access_control.banlist.delete(mask_addr);
@@ -109,8 +99,29 @@ export class ModeAccessControlHandler implements SocketEventInterface<"MODE"> {
 					channel.access_control.invitelist_exception.delete(
 						mask_addr,
 					);
+				} else if (letter === "Q") {
+					channel.access_control.quietlist.delete(mask_addr);
 				}
 			}
 		}
 	}
 }
+
+// -------- //
+// Fonction //
+// -------- //
+
+/**
+ * Représentation textuelle d'un masque: `nick!ident@host` ou, pour un masque
+ * étendu, `~<lettre>:<valeur>`.
+ */
+function mask_to_string(mask: AccessControlMask): string {
+	if ("extban" in mask) {
+		let value =
+			typeof mask.value === "string"
+				? mask.value
+				: mask_to_string(mask.value);
+		return `~${mask.extban}:${value}`;
+	}
+	return `${mask.nick}!${mask.ident}@${mask.host}`;
+}
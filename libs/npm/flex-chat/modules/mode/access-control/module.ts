@@ -14,9 +14,11 @@ import {
 	BanCommand,
 	BanExCommand,
 	InviteExCommand,
+	QuietCommand,
 	UnbanCommand,
 	UnbanExCommand,
 	UninviteExCommand,
+	UnquietCommand,
 } from "./command";
 import { ModeAccessControlHandler } from "./handler";
 
@@ -410,3 +412,123 @@ export class UninviteExModule implements Module<UninviteExModule> {
 
 	listen() {}
 }
+
+export class QuietModule implements Module<QuietModule> {
+	// ------ //
+	// STATIC //
+	// ------ //
+
+	static NAME = "QUIET";
+
+	static create(store: ChatStoreInterface): QuietModule {
+		return new QuietModule(new QuietCommand(store));
+	}
+
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private command: QuietCommand) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	input(
+		room_id?: RoomID,
+		channels_raw?: ChannelID,
+		...masks_raw: Array<string>
+	) {
+		if (!room_id?.startsWith("#")) {
+			let channels = channels_raw?.split(",");
+			if (!channels) {
+				return;
+			}
+			let masks = masks_raw as Array<MaskAddr>;
+			this.send({ channels, masks });
+			return;
+		}
+
+		if (channels_raw?.startsWith("#")) {
+			let channels = channels_raw?.split(",");
+			if (!channels) {
+				return;
+			}
+			let masks = masks_raw as Array<MaskAddr>;
+			this.send({ channels, masks });
+			return;
+		}
+
+		let channels = [room_id];
+		if (channels_raw) {
+			masks_raw.unshift(channels_raw);
+		}
+		let masks = masks_raw as Array<MaskAddr>;
+		this.send({ channels, masks });
+	}
+
+	send(payload: Command<"QUIET">) {
+		this.command.send(payload);
+	}
+
+	listen() {}
+}
+
+export class UnquietModule implements Module<UnquietModule> {
+	// ------ //
+	// STATIC //
+	// ------ //
+
+	static NAME = "UNQUIET";
+
+	static create(store: ChatStoreInterface): UnquietModule {
+		return new UnquietModule(new UnquietCommand(store));
+	}
+
+	// ----------- //
+	// Constructor //
+	// ----------- //
+	constructor(private command: UnquietCommand) {}
+
+	// ------- //
+	// Méthode //
+	// ------- //
+
+	input(
+		room_id?: RoomID,
+		channels_raw?: ChannelID,
+		...masks_raw: Array<string>
+	) {
+		if (!room_id?.startsWith("#")) {
+			let channels = channels_raw?.split(",");
+			if (!channels) {
+				return;
+			}
+			let masks = masks_raw as Array<MaskAddr>;
+			this.send({ channels, masks });
+			return;
+		}
+
+		if (channels_raw?.startsWith("#")) {
+			let channels = channels_raw?.split(",");
+			if (!channels) {
+				return;
+			}
+			let masks = masks_raw as Array<MaskAddr>;
+			this.send({ channels, masks });
+			return;
+		}
+
+		let channels = [room_id];
+		if (channels_raw) {
+			masks_raw.unshift(channels_raw);
+		}
+		let masks = masks_raw as Array<MaskAddr>;
+		this.send({ channels, masks });
+	}
+
+	send(payload: Command<"UNQUIET">) {
+		this.command.send(payload);
+	}
+
+	listen() {}
+}
@@ -32,13 +32,19 @@ declare interface Commands {
 	UNBANEX: Commands["BANEX"];
 	INVITEX: { channels: Array<string>; masks: Array<MaskAddr> };
 	UNINVITEX: Commands["INVITEX"];
+	QUIET: { channels: Array<string>; masks: Array<MaskAddr> };
+	UNQUIET: Commands["QUIET"];
 
 	INVITE: { nickname: string; channel: ChannelID };
+	// biome-ignore lint/complexity/noBannedTypes: aucun paramètre.
+	"INVITE LIST": {};
+	UNINVITE: { nickname: string; channel: ChannelID };
 
 	JOIN: { channels: Array<ChannelID>; keys?: Array<string> };
 
 	KICK: { channels: Array<string>; knicks: Array<string>; comment?: string };
 	KILL: { nickname: string; comment?: string };
+	KNOCK: { channel: ChannelID; reason?: string };
 
 	// biome-ignore lint/complexity/noBannedTypes: ?
 	LIST: {};
@@ -16,9 +16,16 @@ declare interface ModeApplyFlag<F> {
 }
 
 declare interface AccessControlMode {
-	mask: {
-		nick: string;
-		ident: string;
-		host: string;
-	};
+	mask: AccessControlMask;
 }
+
+declare type AccessControlMask =
+	| {
+			nick: string;
+			ident: string;
+			host: string;
+	  }
+	| {
+			extban: "a" | "j" | "n" | "q" | "r";
+			value: string | AccessControlMask;
+	  };
@@ -25,11 +25,18 @@ declare interface ChannelOrigin extends Origin {
 declare interface CommandResponsesFromServer {
 	INVITE: { channel: ChannelID; nick: string };
 	RPL_INVITING: { channel: ChannelID; nick: string };
+	RPL_INVITELIST: { channel: ChannelID };
+	// biome-ignore lint/complexity/noBannedTypes: ne retourne rien de particulier.
+	RPL_ENDOFINVITELIST: {};
+	UNINVITE: { channel: ChannelID };
+	RPL_UNINVITED: { channel: ChannelID; nick: string };
 
 	JOIN: { channel: ChannelID; forced: boolean };
 
 	KICK: { channel: ChannelID; knick: Origin; reason: string };
 	KILL: { knick: Origin; reason: string };
+	KNOCK: { channel: ChannelID; reason?: string };
+	RPL_KNOCKDLVR: { channel: ChannelID };
 
 	MODE: {
 		target: RoomID;
@@ -39,6 +46,7 @@ declare interface CommandResponsesFromServer {
 			| ["b", ModeApplyFlag<AccessControlMode>]
 			| ["e", ModeApplyFlag<AccessControlMode>]
 			| ["I", ModeApplyFlag<AccessControlMode>]
+			| ["Q", ModeApplyFlag<AccessControlMode>]
 			| ["q", ModeApplyFlag<"owner">]
 			| ["a", ModeApplyFlag<"admin_operator">]
 			| ["o", ModeApplyFlag<"operator">]
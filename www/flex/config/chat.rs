@@ -9,10 +9,15 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::collections::HashSet;
+use std::net;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use flex_chat::user::Host;
 use flex_web_framework::FeatureConfig;
 use flex_web_framework::types::{email, secret};
+use flex_wildcard_matching::WildcardMatching;
+use serde::Deserialize;
 
 // --------- //
 // Structure //
@@ -21,6 +26,7 @@ use flex_web_framework::types::{email, secret};
 #[derive(Debug)]
 #[derive(Clone)]
 #[derive(serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self")]
 pub struct FlexChatConfig
 {
 	/// Configuration utilisateur du réseau.
@@ -35,6 +41,27 @@ pub struct FlexChatConfig
 	/// Configuration des opérateurs globaux du serveur.
 	#[serde(default)]
 	pub operators: Vec<FlexChatConfigOperatorAuth>,
+	/// Configuration de la passerelle IRC (clients IRC natifs, TCP/TLS).
+	#[serde(default)]
+	pub irc: Option<FlexChatConfigIrc>,
+	/// Chemin vers le fichier du message du jour (MOTD).
+	#[serde(default)]
+	pub motd: Option<PathBuf>,
+	/// Jetons annoncés aux clients via RPL_ISUPPORT.
+	#[serde(default)]
+	pub isupport: FlexChatConfigIsupport,
+	/// Configuration de l'historique des messages (CHATHISTORY).
+	#[serde(default)]
+	pub history: FlexChatConfigHistory,
+	/// Configuration de la protection des pseudonymes enregistrés.
+	#[serde(default)]
+	pub nicknames: FlexChatConfigNicknames,
+	/// Configuration de la commande KNOCK.
+	#[serde(default)]
+	pub knock: FlexChatConfigKnock,
+	/// Configuration des invitations sur les salons.
+	#[serde(default)]
+	pub invite: FlexChatConfigInvite,
 }
 
 #[derive(Debug)]
@@ -80,6 +107,114 @@ pub struct FlexChatConfigServer
 	pub password: Option<secret::Secret<Arc<str>>>,
 	/// Date de création du serveur.
 	pub created_at: Option<i64>,
+	/// Adresses IP des proxys de confiance (reverse proxy), seuls autorisés à
+	/// transmettre le protocole d'origine d'une connexion par l'en-tête
+	/// `X-Forwarded-Proto`.
+	#[serde(default)]
+	pub trusted_proxies: Vec<net::IpAddr>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigIrc
+{
+	/// IP d'écoute de la passerelle IRC.
+	pub ip: net::IpAddr,
+	/// Port d'écoute de la passerelle IRC.
+	pub port: u16,
+	/// Connexions en TLS, à partir des paramètres TLS du serveur HTTP.
+	#[serde(default)]
+	pub tls: bool,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigIsupport
+{
+	/// Préfixes autorisés pour les noms de salons (CHANTYPES).
+	#[serde(default = "FlexChatConfigIsupport::default_chantypes")]
+	pub chantypes: Arc<str>,
+	/// Taille maximale d'un pseudonyme (NICKLEN).
+	#[serde(default = "FlexChatConfigIsupport::default_nicklen")]
+	pub nicklen: usize,
+	/// Taille maximale d'un nom de salon (CHANNELLEN).
+	#[serde(default = "FlexChatConfigIsupport::default_channellen")]
+	pub channellen: usize,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigHistory
+{
+	/// Stockage de l'historique des messages.
+	#[serde(default)]
+	pub storage: FlexChatConfigHistoryStorage,
+	/// Nombre maximum de messages conservés par cible (salon ou conversation
+	/// privée).
+	#[serde(default = "FlexChatConfigHistory::default_max_messages")]
+	pub max_messages: usize,
+	/// Durée de conservation des messages, en secondes.
+	#[serde(default = "FlexChatConfigHistory::default_max_age")]
+	pub max_age: u64,
+	/// Nombre maximum de messages renvoyés par une commande CHATHISTORY.
+	#[serde(default = "FlexChatConfigHistory::default_max_limit")]
+	pub max_limit: usize,
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(Default)]
+#[derive(PartialEq, Eq)]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlexChatConfigHistoryStorage
+{
+	/// Historique en mémoire, perdu au redémarrage du serveur.
+	#[default]
+	Memory,
+	/// Historique persisté dans la base de données PostgreSQL.
+	PostgreSQL,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigNicknames
+{
+	/// Délai, en secondes, accordé à un client non identifié utilisant un
+	/// pseudonyme enregistré avant d'être renommé.
+	#[serde(default = "FlexChatConfigNicknames::default_grace_period")]
+	pub grace_period: u64,
+	/// Préfixe des pseudonymes d'invités attribués aux clients renommés.
+	#[serde(default = "FlexChatConfigNicknames::default_guest_prefix")]
+	pub guest_prefix: Arc<str>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigKnock
+{
+	/// Délai, en secondes, entre deux KNOCK d'un même client.
+	#[serde(default = "FlexChatConfigKnock::default_user_delay")]
+	pub user_delay: u64,
+	/// Délai, en secondes, entre deux KNOCK sur un même salon.
+	#[serde(default = "FlexChatConfigKnock::default_channel_delay")]
+	pub channel_delay: u64,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigInvite
+{
+	/// Durée de validité, en secondes, d'une invitation (0 = sans
+	/// expiration).
+	#[serde(default = "FlexChatConfigInvite::default_ttl")]
+	pub ttl: u64,
 }
 
 #[derive(Debug)]
@@ -88,6 +223,21 @@ pub struct FlexChatConfigServer
 pub struct FlexChatConfigOperator
 {
 	pub auto_join: Vec<Arc<str>>,
+	/// Classes d'opérateurs, chacune regroupant un ensemble de privilèges.
+	#[serde(default)]
+	pub classes: Vec<FlexChatConfigOperatorClass>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct FlexChatConfigOperatorClass
+{
+	/// Nom de la classe, référencé par les opérateurs.
+	pub name: Arc<str>,
+	/// Privilèges accordés aux opérateurs de cette classe.
+	#[serde(default)]
+	pub privileges: HashSet<FlexChatConfigOperatorPrivilege>,
 }
 
 #[derive(Debug)]
@@ -98,10 +248,21 @@ pub struct FlexChatConfigOperatorAuth
 	/// Identifiant de l'opérateur.
 	pub identifier: secret::Secret<Arc<str>>,
 	/// Mot de passe de l'opérateur (chiffré).
+	#[serde(
+		deserialize_with = "FlexChatConfigOperatorAuth::deserialize_password"
+	)]
 	pub password: secret::Secret<Arc<str>>,
 	/// Type d'opérateur.
 	#[serde(rename = "type")]
 	pub oper_type: FlexChatConfigOperatorType,
+	/// Nom de la classe de l'opérateur, qui DOIT exister parmi les classes
+	/// d'opérateurs.
+	#[serde(default = "FlexChatConfigOperatorAuth::default_class")]
+	pub class: Arc<str>,
+	/// Masques `ident@hôte` ou plages CIDR depuis lesquels l'opérateur est
+	/// autorisé à s'identifier (toutes les adresses si vide).
+	#[serde(default)]
+	pub hosts: Vec<Arc<str>>,
 	/// Hôte virtuel.
 	#[serde(rename = "vhost")]
 	pub virtual_host: Option<Arc<str>>,
@@ -131,10 +292,285 @@ pub enum FlexChatConfigOperatorFlags
 	NoKick,
 }
 
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum FlexChatConfigOperatorPrivilege
+{
+	/// Déconnecter un utilisateur du serveur (KILL).
+	Kill,
+	/// Forcer un utilisateur à rejoindre un salon (SAJOIN).
+	Sajoin,
+	/// Forcer un utilisateur à quitter un salon (SAPART).
+	Sapart,
+	/// Bannir localement du serveur (KLINE, ZLINE, SHUN).
+	Kline,
+	/// Bannir globalement du serveur (GLINE).
+	Gline,
+	/// Recharger la configuration du serveur (REHASH).
+	Rehash,
+	/// Voir l'adresse IP réelle des utilisateurs (WHOIS, WHOWAS).
+	SeeRealIp,
+	/// Appliquer les modes d'un salon sans en être opérateur.
+	OverrideChannelModes,
+	/// Obtenir l'hôte virtuel configuré lors de la commande OPER.
+	SetVhost,
+	/// Forcer l'application des modes d'un salon (SAMODE).
+	Samode,
+	/// Forcer le changement de pseudonyme d'un utilisateur (SANICK).
+	Sanick,
+	/// Forcer la déconnexion d'un utilisateur (SAQUIT).
+	Saquit,
+	/// Forcer le changement du sujet d'un salon (SATOPIC).
+	Satopic,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl FlexChatConfig
+{
+	/// Vérifie que la classe de chaque opérateur existe parmi les classes
+	/// d'opérateurs.
+	fn validate_operator_classes(&self) -> Result<(), String>
+	{
+		for operator in self.operators.iter() {
+			if self.operator.class(&operator.class).is_none() {
+				return Err(format!(
+					"la classe « {} » d'un opérateur n'existe pas dans \
+					 `operator.classes`",
+					operator.class,
+				));
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FlexChatConfigOperator
+{
+	/// Trouve une classe d'opérateurs à partir de son nom.
+	pub fn class(&self, name: &str) -> Option<&FlexChatConfigOperatorClass>
+	{
+		self.classes.iter().find(|class| class.name.as_ref() == name)
+	}
+}
+
+impl FlexChatConfigOperatorAuth
+{
+	/// Classe des opérateurs dont la classe n'est pas configurée.
+	pub const DEFAULT_CLASS: &'static str = "default";
+
+	fn default_class() -> Arc<str>
+	{
+		Arc::from(Self::DEFAULT_CLASS)
+	}
+
+	/// Est-ce que l'opérateur est autorisé à s'identifier depuis l'adresse
+	/// d'un utilisateur?
+	pub fn is_host_allowed(&self, ident: &str, host: &Host) -> bool
+	{
+		self.hosts.is_empty()
+			|| self.hosts.iter().any(|mask| {
+				let (mask_ident, mask_host) =
+					mask.split_once('@').unwrap_or(("*", mask));
+				ident.iswm(mask_ident) && host.matches_mask(mask_host)
+			})
+	}
+
+	// NOTE: les mots de passe en clair sont refusés au démarrage du serveur,
+	//       seuls les mots de passe chiffrés avec Argon2 sont acceptés.
+	fn deserialize_password<'de, D>(
+		de: D,
+	) -> Result<secret::Secret<Arc<str>>, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let password = secret::Secret::<Arc<str>>::deserialize(de)?;
+
+		if !password.expose().starts_with("$argon2") {
+			return Err(serde::de::Error::custom(
+				"le mot de passe d'un opérateur DOIT être chiffré avec \
+				 l'algorithme Argon2, les mots de passe en clair sont \
+				 refusés. Pour chiffrer un mot de passe, utilise la commande: \
+				 `APP_SECRET=<clé secrète> flex make-password <mot de passe>`",
+			));
+		}
+
+		Ok(password)
+	}
+}
+
+impl FlexChatConfigOperatorClass
+{
+	/// Est-ce que la classe accorde le privilège donné?
+	pub fn has_privilege(
+		&self,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> bool
+	{
+		self.privileges.contains(&privilege)
+	}
+}
+
+impl FlexChatConfigHistory
+{
+	fn default_max_messages() -> usize
+	{
+		1000
+	}
+
+	fn default_max_age() -> u64
+	{
+		7 * 24 * 60 * 60
+	}
+
+	fn default_max_limit() -> usize
+	{
+		100
+	}
+}
+
+impl FlexChatConfigNicknames
+{
+	fn default_grace_period() -> u64
+	{
+		60
+	}
+
+	fn default_guest_prefix() -> Arc<str>
+	{
+		Arc::from("Guest")
+	}
+}
+
+impl FlexChatConfigKnock
+{
+	fn default_user_delay() -> u64
+	{
+		60
+	}
+
+	fn default_channel_delay() -> u64
+	{
+		30
+	}
+}
+
+impl FlexChatConfigInvite
+{
+	fn default_ttl() -> u64
+	{
+		3600
+	}
+}
+
+impl FlexChatConfigIsupport
+{
+	fn default_chantypes() -> Arc<str>
+	{
+		Arc::from("#")
+	}
+
+	fn default_nicklen() -> usize
+	{
+		flex_chat::user::NICK_MAX_SIZE
+	}
+
+	fn default_channellen() -> usize
+	{
+		30
+	}
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl Default for FlexChatConfigHistory
+{
+	fn default() -> Self
+	{
+		Self {
+			storage: Default::default(),
+			max_messages: Self::default_max_messages(),
+			max_age: Self::default_max_age(),
+			max_limit: Self::default_max_limit(),
+		}
+	}
+}
+
+impl Default for FlexChatConfigNicknames
+{
+	fn default() -> Self
+	{
+		Self {
+			grace_period: Self::default_grace_period(),
+			guest_prefix: Self::default_guest_prefix(),
+		}
+	}
+}
+
+impl Default for FlexChatConfigKnock
+{
+	fn default() -> Self
+	{
+		Self {
+			user_delay: Self::default_user_delay(),
+			channel_delay: Self::default_channel_delay(),
+		}
+	}
+}
+
+impl Default for FlexChatConfigInvite
+{
+	fn default() -> Self
+	{
+		Self {
+			ttl: Self::default_ttl(),
+		}
+	}
+}
+
+impl Default for FlexChatConfigIsupport
+{
+	fn default() -> Self
+	{
+		Self {
+			chantypes: Self::default_chantypes(),
+			nicklen: Self::default_nicklen(),
+			channellen: Self::default_channellen(),
+		}
+	}
+}
+
+// NOTE: la classe de chaque opérateur est vérifiée au chargement de la
+//       configuration, le serveur refuse de démarrer si elle n'existe pas.
+impl<'de> serde::Deserialize<'de> for FlexChatConfig
+{
+	fn deserialize<D>(de: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let config = Self::deserialize(de)?;
+		config.validate_operator_classes().map_err(serde::de::Error::custom)?;
+		Ok(config)
+	}
+}
+
+impl serde::Serialize for FlexChatConfig
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		Self::serialize(self, serializer)
+	}
+}
+
 impl FeatureConfig for FlexChatConfig
 {
 	const FILENAME: &'static str = "chat";
@@ -24,6 +24,19 @@ pub trait AuthChatApplicationInterface
 {
 	type ClientSocket<'cs>: ClientSocketInterface;
 
+	/// Change le compte (ID, nom) auquel un client est identifié.
+	fn change_account_of_client(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		account: Option<(
+			<
+				<Self::ClientSocket<'_> as ClientSocketInterface>
+					 ::Client as ClientInterface
+			>::ClientID,
+			String,
+		)>,
+	);
+
 	/// Change l'ID d'un client
 	fn change_id_of_client(
 		&self,
@@ -50,6 +63,28 @@ impl AuthChatApplicationInterface for ChatApplication
 {
 	type ClientSocket<'cs> = Socket<'cs>;
 
+	fn change_account_of_client(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		account: Option<(
+			<
+				<Self::ClientSocket<'_> as ClientSocketInterface>
+					 ::Client as ClientInterface
+			>::ClientID,
+			String,
+		)>,
+	)
+	{
+		let (account_id, account) = account.unzip();
+		client_socket.client_mut().set_account_id(account_id);
+		client_socket.user_mut().set_account(account.clone());
+		self.clients.change_client_account(
+			client_socket.cid(),
+			account_id,
+			account,
+		);
+	}
+
 	fn change_id_of_client(
 		&self,
 		client_socket: &mut Self::ClientSocket<'_>,
@@ -50,6 +50,10 @@ impl WebSocketHandler for AuthIdentifyHandler
 
 		app.change_id_of_client(&mut client_socket, user_session.id);
 		app.change_nickname_of_client(&mut client_socket, &user_session.name);
+		app.change_account_of_client(
+			&mut client_socket,
+			Some((user_session.id, user_session.name.clone())),
+		);
 
 		let new_client_id = *client_socket.cid();
 		let new_nickname = client_socket.user().nickname().to_owned();
@@ -64,5 +68,7 @@ impl WebSocketHandler for AuthIdentifyHandler
 				Some(&user_session),
 			);
 		}
+
+		client_socket.emit_account_notify(Some(&user_session.name));
 	}
 }
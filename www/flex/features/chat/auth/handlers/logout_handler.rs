@@ -20,6 +20,7 @@ use crate::features::chat::auth::{
 	AuthLogoutFormData,
 	IdentifyCommandResponseInterface,
 };
+use crate::features::chat::nick_registration::NickRegistrationApplicationInterface;
 
 // --------- //
 // Structure //
@@ -59,6 +60,7 @@ impl WebSocketHandler for AuthLogoutHandler
 		let old_nickname = client_socket.user().nickname().to_owned();
 
 		app.change_id_of_client(&mut client_socket, Uuid::new_v4());
+		app.change_account_of_client(&mut client_socket, None);
 
 		let new_client_id = *client_socket.cid();
 		let new_nickname = client_socket.user().nickname().to_owned();
@@ -73,5 +75,9 @@ impl WebSocketHandler for AuthLogoutHandler
 				None,
 			);
 		}
+
+		client_socket.emit_account_notify(None);
+
+		app.protect_nickname(&client_socket);
 	}
 }
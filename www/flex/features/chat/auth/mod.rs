@@ -19,7 +19,7 @@ flex_kernel::import! {
 		pub mod identify_handler use *;
 	};
 
-	mod responses use {
+	pub(crate) mod responses use {
 		pub mod identify_command_response use *;
 	};
 
@@ -9,6 +9,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::client::{
+	Capability,
 	ClientID,
 	ClientInterface,
 	ClientSocketInterface,
@@ -32,6 +33,13 @@ command_response! {
 		new_nickname: &'n str,
 		user_session: Option<&'n UserSessionDTO>,
 	}
+
+	struct ACCOUNT<'a>
+	{
+		/// Nom du compte auquel le client est identifié. Vaut [None] lorsque
+		/// le client se déconnecte de son compte.
+		account: Option<&'a str>,
+	}
 }
 
 // --------- //
@@ -40,6 +48,10 @@ command_response! {
 
 pub trait IdentifyCommandResponseInterface: ClientSocketInterface
 {
+	/// Émet aux membres des salons du client ayant activé la capacité
+	/// serveur `account-notify`, le changement de compte du client.
+	fn emit_account_notify(&self, account: Option<&str>);
+
 	/// Émet au client la réponse liée à la commande /AUTH IDENTIFY.
 	fn emit_upgrade_user(
 		&self,
@@ -58,6 +70,26 @@ pub trait IdentifyCommandResponseInterface: ClientSocketInterface
 
 impl<'s> IdentifyCommandResponseInterface for Socket<'s>
 {
+	fn emit_account_notify(&self, account: Option<&str>)
+	{
+		let origin = Origin::from(self.client());
+		let account_command = AccountCommandResponse {
+			origin: &origin,
+			tags: AccountCommandResponse::default_tags(),
+			account,
+		};
+		let rooms = self
+			.channels_rooms_set()
+			.into_iter()
+			.map(|channel_id| format!("channel:{channel_id}"));
+		self.emit_to_capable(
+			rooms,
+			Capability::AccountNotify,
+			account_command.name(),
+			&account_command,
+		);
+	}
+
 	fn emit_upgrade_user(
 		&self,
 		channel_name: &str,
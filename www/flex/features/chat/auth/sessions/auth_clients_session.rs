@@ -9,6 +9,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::client::{ClientInterface, ClientsSessionInterface};
+use flex_chat::user::UserInterface;
 
 use crate::features::chat::sessions::ClientsSession;
 
@@ -18,6 +19,14 @@ use crate::features::chat::sessions::ClientsSession;
 
 pub trait AuthClientSessionInterface: ClientsSessionInterface
 {
+	/// Change le compte (ID, nom) auquel un client est identifié.
+	fn change_client_account(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		account_id: Option<<Self::Client as ClientInterface>::ClientID>,
+		account: Option<String>,
+	);
+
 	/// Change l'ID d'un client par un nouveau.
 	fn change_client_id(
 		&self,
@@ -32,15 +41,100 @@ pub trait AuthClientSessionInterface: ClientsSessionInterface
 
 impl AuthClientSessionInterface for ClientsSession
 {
+	fn change_client_account(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		account_id: Option<<Self::Client as ClientInterface>::ClientID>,
+		account: Option<String>,
+	)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+		client.set_account_id(account_id);
+		client.user_mut().set_account(account);
+	}
+
 	fn change_client_id(
 		&self,
 		old_client_id: &<Self::Client as ClientInterface>::ClientID,
 		new_client_id: <Self::Client as ClientInterface>::ClientID,
 	)
 	{
-		let (_, mut client) = self.clients.remove(old_client_id).unwrap();
+		// NOTE: le client a pu se déconnecter entre-temps.
+		let Some((_, mut client)) = self.clients.remove(old_client_id) else {
+			return;
+		};
 		client.set_cid(new_client_id);
 		client.new_token();
 		self.clients.insert(new_client_id, client);
+
+		// NOTE: les privilèges d'un client opérateur DOIVENT suivre son
+		//       nouvel ID.
+		if let Some((_, privileges)) = self.operators.remove(old_client_id) {
+			self.operators.insert(new_client_id, privileges);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::collections::HashSet;
+	use std::net::{IpAddr, Ipv4Addr};
+	use std::sync::Arc;
+
+	use flex_chat::client::Client;
+	use flex_web_framework::types::{secret, uuid};
+	use socketioxide::socket::Sid;
+
+	use super::*;
+	use crate::config::chat::{
+		FlexChatConfigOperatorAuth,
+		FlexChatConfigOperatorClass,
+		FlexChatConfigOperatorPrivilege,
+		FlexChatConfigOperatorType,
+	};
+	use crate::features::chat::oper::OperClientSessionInterface;
+
+	#[test]
+	fn test_change_client_id_of_operator()
+	{
+		let session = ClientsSession::default();
+		let old_client_id = uuid::Uuid::new_v4();
+		let client = Client::new(
+			IpAddr::V4(Ipv4Addr::LOCALHOST),
+			old_client_id,
+			Sid::new(),
+		);
+		session.clients.insert(old_client_id, client.clone());
+		session.register(&client);
+
+		let oper = FlexChatConfigOperatorAuth {
+			identifier: secret::Secret::new(Arc::from("JohnDoe")),
+			password: secret::Secret::new(Arc::from("$argon2id...")),
+			oper_type: FlexChatConfigOperatorType::GlobalOperator,
+			class: Arc::from("netadmin"),
+			hosts: Default::default(),
+			virtual_host: Default::default(),
+			flags: Default::default(),
+		};
+		let oper_class = FlexChatConfigOperatorClass {
+			name: Arc::from("netadmin"),
+			privileges: HashSet::from([FlexChatConfigOperatorPrivilege::Kill]),
+		};
+		session.marks_client_as_operator(&old_client_id, &oper, &oper_class);
+
+		let new_client_id = uuid::Uuid::new_v4();
+		session.change_client_id(&old_client_id, new_client_id);
+
+		assert!(!session.has_operator_privilege(
+			&old_client_id,
+			FlexChatConfigOperatorPrivilege::Kill
+		));
+		assert!(session.has_operator_privilege(
+			&new_client_id,
+			FlexChatConfigOperatorPrivilege::Kill
+		));
 	}
 }
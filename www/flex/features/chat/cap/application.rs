@@ -0,0 +1,92 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{
+	Capability,
+	ClientInterface,
+	ClientSocketInterface,
+	Socket,
+};
+
+use super::{
+	CapClientSocketCommandResponseInterface,
+	CapClientsSessionInterface,
+};
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait CapApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Active/désactive les capacités serveur demandées par le client. Les
+	/// capacités sont toutes acceptées (`ACK`) ou toutes refusées (`NAK`).
+	fn request_capabilities(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		capabilities: &[String],
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl CapApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn request_capabilities(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		capabilities: &[String],
+	)
+	{
+		let mut enabled = vec![];
+		let mut disabled = vec![];
+
+		for capability in capabilities {
+			let (list, name) = match capability.strip_prefix('-') {
+				| Some(name) => (&mut disabled, name),
+				| None => (&mut enabled, capability.as_str()),
+			};
+
+			let Ok(capability) = name.parse::<Capability>() else {
+				client_socket.emit_cap("NAK", capabilities);
+				return;
+			};
+
+			list.push(capability);
+		}
+
+		client_socket.client_mut().begin_capabilities_negotiation();
+		for capability in enabled.iter() {
+			client_socket.client_mut().enable_capability(*capability);
+		}
+		for capability in disabled.iter() {
+			client_socket.client_mut().disable_capability(*capability);
+		}
+
+		// NOTE(phisyx): le client en session est une copie du client de la
+		//               socket, il doit lui aussi être mis à jour.
+		if client_socket.client().is_registered() {
+			self.clients.update_capabilities_of_client(
+				client_socket.cid(),
+				&enabled,
+				&disabled,
+			);
+		}
+
+		client_socket.emit_cap("ACK", capabilities);
+	}
+}
@@ -0,0 +1,96 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{Capability, ClientSocketInterface};
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::FlexApplicationState;
+use crate::features::ChatApplication;
+use crate::features::chat::cap::*;
+use crate::features::chat::connect::ConnectionRegistrationHandler;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct CapHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl CapHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "CAP";
+
+	/// La commande `CAP` est utilisée pour la négociation des capacités
+	/// serveur. Lorsqu'un client envoie `CAP LS` ou `CAP REQ` avant d'être
+	/// enregistré, l'enregistrement de la connexion est suspendu jusqu'à ce
+	/// que le client envoie `CAP END`.
+	pub fn handle(
+		socket: SocketRef,
+		State(server_state): State<FlexApplicationState>,
+		State(app): State<ChatApplication>,
+		Data(data): Data<CapCommandFormData>,
+	)
+	{
+		let check = {
+			let mut client_socket = app.current_client_mut(&socket);
+
+			match data.subcommand.to_uppercase().as_str() {
+				| "LS" => {
+					client_socket.client_mut().begin_capabilities_negotiation();
+					let capabilities: Vec<_> = Capability::ALL
+						.iter()
+						.map(ToString::to_string)
+						.collect();
+					client_socket.emit_cap("LS", &capabilities);
+					Some(())
+				}
+				| "LIST" => {
+					let capabilities: Vec<_> = client_socket
+						.client()
+						.capabilities()
+						.map(ToString::to_string)
+						.collect();
+					client_socket.emit_cap("LIST", &capabilities);
+					Some(())
+				}
+				| "REQ" => {
+					app.request_capabilities(
+						&mut client_socket,
+						&data.capabilities,
+					);
+					Some(())
+				}
+				| "END" => {
+					if !client_socket.client().is_negotiating_capabilities() {
+						return;
+					}
+					client_socket.client_mut().end_capabilities_negotiation();
+					ConnectionRegistrationHandler::complete_registration(
+						server_state,
+						app,
+						client_socket,
+					)
+				}
+				| _ => {
+					client_socket.send_err_invalidcapcmd(&data.subcommand);
+					Some(())
+				}
+			}
+		};
+
+		if check.is_none() {
+			_ = socket.disconnect();
+		}
+	}
+}
@@ -0,0 +1,50 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+command_response! {
+	struct CAP<'subcommand, 'capabilities>
+	{
+		/// Sous-commande de la négociation des capacités serveur (`LS`,
+		/// `LIST`, `ACK`, `NAK`).
+		subcommand: &'subcommand str,
+		/// Les capacités serveur.
+		capabilities: &'capabilities [String],
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait CapClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client les réponses liées à la commande /CAP.
+	fn emit_cap(&self, subcommand: &str, capabilities: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let cap_command = CapCommandResponse {
+			origin: &origin,
+			tags: CapCommandResponse::default_tags(),
+			subcommand,
+			capabilities,
+		};
+		self.emit(cap_command.name(), cap_command);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> CapClientSocketCommandResponseInterface for Socket<'s> {}
@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::cap::errors::ErrInvalidcapcmdError;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait CapClientSocketErrorRepliesInterface: ClientSocketInterface
+{
+	/// Émet au client l'erreur [ErrInvalidcapcmdError].
+	fn send_err_invalidcapcmd(&self, subcommand: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_invalidcapcmd = ErrInvalidcapcmdError {
+			origin: &origin,
+			tags: ErrInvalidcapcmdError::default_tags(),
+			subcommand,
+		};
+		self.emit(err_invalidcapcmd.name(), err_invalidcapcmd);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> CapClientSocketErrorRepliesInterface for Socket<'s> {}
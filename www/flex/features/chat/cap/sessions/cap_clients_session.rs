@@ -0,0 +1,53 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{Capability, ClientInterface, ClientsSessionInterface};
+
+use crate::features::chat::sessions::ClientsSession;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait CapClientsSessionInterface: ClientsSessionInterface
+{
+	/// Active/désactive des capacités serveur d'un client en session.
+	fn update_capabilities_of_client(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: &[Capability],
+		disabled: &[Capability],
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl CapClientsSessionInterface for ClientsSession
+{
+	fn update_capabilities_of_client(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: &[Capability],
+		disabled: &[Capability],
+	)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+		for capability in enabled {
+			client.enable_capability(*capability);
+		}
+		for capability in disabled {
+			client.disable_capability(*capability);
+		}
+	}
+}
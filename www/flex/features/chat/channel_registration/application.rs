@@ -0,0 +1,262 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use flex_chat::channel::{
+	Channel,
+	ChannelAccessLevel,
+	ChannelInterface,
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
+	MemberInterface,
+};
+use flex_chat::client::{
+	ClientSocketInterface,
+	ClientsSessionInterface,
+	Socket,
+};
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase, http};
+use socketioxide::extract::SocketRef;
+
+use super::{
+	ChannelRegistrationRepository,
+	ChannelRegistrationRepositoryPostgreSQL,
+	RegisteredChannelEntity,
+};
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ChannelRegistrationApplicationInterface
+{
+	/// Le stockage des salons enregistrés.
+	fn channel_registration_repository(
+		&self,
+		extensions: &http::Extensions,
+	) -> Option<Arc<dyn ChannelRegistrationRepository>>;
+
+	/// Charge les salons enregistrés dans la session des salons. Les salons
+	/// ne sont chargés qu'une seule fois, au démarrage du serveur.
+	fn load_registered_channels(
+		&self,
+		extensions: &http::Extensions,
+	) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+	/// Restaure, sur un salon enregistré, les niveaux d'accès enregistrés du
+	/// compte auquel le client est identifié, et fait rejoindre au client les
+	/// salles de ces niveaux d'accès.
+	fn restore_access_level(
+		&self,
+		client_socket: &Socket<'_>,
+		channel: &mut Channel,
+	);
+
+	/// Sauvegarde un salon enregistré, en tâche de fond. Les niveaux d'accès
+	/// des membres identifiés à un compte sont enregistrés au préalable.
+	fn save_registered_channel(&self, socket: &SocketRef, channel_name: &str);
+
+	/// Sauvegarde un salon enregistré dans le stockage donné, en tâche de
+	/// fond.
+	fn save_registered_channel_in(
+		&self,
+		repository: Arc<dyn ChannelRegistrationRepository>,
+		channel_name: &str,
+	);
+
+	/// Supprime l'enregistrement d'un salon, en tâche de fond.
+	fn delete_registered_channel(&self, socket: &SocketRef, channel_name: &str);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ChannelRegistrationApplicationInterface for ChatApplication
+{
+	fn channel_registration_repository(
+		&self,
+		extensions: &http::Extensions,
+	) -> Option<Arc<dyn ChannelRegistrationRepository>>
+	{
+		let database =
+			extensions.get::<DatabaseService<PostgreSQLDatabase>>()?;
+		Some(Arc::new(ChannelRegistrationRepositoryPostgreSQL {
+			database: database.clone(),
+		}))
+	}
+
+	async fn load_registered_channels(
+		&self,
+		extensions: &http::Extensions,
+	) -> Result<(), sqlx::Error>
+	{
+		let Some(repository) = self.channel_registration_repository(extensions)
+		else {
+			return Ok(());
+		};
+
+		self.channel_registration
+			.load(async move {
+				for registered_channel in repository.find_all().await? {
+					let channel_id = registered_channel.channel.id.clone();
+
+					if !self.channels.has(&channel_id) {
+						self.channels.add(
+							channel_id.clone(),
+							Channel::new(&registered_channel.channel.name),
+						);
+					}
+
+					if let Some(mut channel) =
+						self.channels.get_mut(&channel_id)
+					{
+						registered_channel.apply_to(&mut channel);
+					}
+				}
+				Ok(())
+			})
+			.await
+	}
+
+	fn restore_access_level(
+		&self,
+		client_socket: &Socket<'_>,
+		channel: &mut Channel,
+	)
+	{
+		let Some(account_id) = client_socket.client().account_id() else {
+			return;
+		};
+
+		let Some(access_level) = channel
+			.registration
+			.as_ref()
+			.and_then(|registration| registration.access_level(account_id))
+			.cloned()
+		else {
+			return;
+		};
+
+		let Some(member) = channel.member_mut(client_socket.cid()) else {
+			return;
+		};
+
+		for level in access_level {
+			member.update_access_level(level);
+		}
+
+		// NOTE: comme pour un niveau d'accès attribué via la commande MODE,
+		//       le client rejoint les salles de son niveau d'accès le plus
+		//       élevé et des niveaux inférieurs.
+		let Some(highest_access_level) = member.highest_access_level().copied()
+		else {
+			return;
+		};
+
+		let channel_name = channel.id();
+		for level in ChannelAccessLevel::ALL
+			.into_iter()
+			.skip_while(|level| *level != highest_access_level)
+		{
+			_ = client_socket.socket().join(format!(
+				"channel:{}{}",
+				level.symbol(),
+				channel_name.to_lowercase(),
+			));
+		}
+	}
+
+	fn save_registered_channel(&self, socket: &SocketRef, channel_name: &str)
+	{
+		let Some(repository) = self
+			.channel_registration_repository(&socket.req_parts().extensions)
+		else {
+			return;
+		};
+
+		self.save_registered_channel_in(repository, channel_name);
+	}
+
+	fn save_registered_channel_in(
+		&self,
+		repository: Arc<dyn ChannelRegistrationRepository>,
+		channel_name: &str,
+	)
+	{
+		let Some(mut channel) = self.channels.get_mut(channel_name) else {
+			return;
+		};
+
+		if !channel.is_registered() {
+			return;
+		}
+
+		// NOTE: les niveaux d'accès sont sauvegardés par ID de compte
+		//       (`channels_access_levels.user_id`), l'ID d'un client ne
+		//       survivant pas à une reconnexion.
+		let identified_members: Vec<_> = channel
+			.members()
+			.values()
+			.filter_map(|member| {
+				let client = self.clients.get(member.id())?;
+				let account_id = *client.account_id()?;
+				Some((account_id, member.access_level().clone()))
+			})
+			.collect();
+
+		if let Some(registration) = channel.registration.as_mut() {
+			for (account_id, access_level) in identified_members {
+				registration.update_access_level(account_id, access_level);
+			}
+		}
+
+		let Some(registered_channel) =
+			RegisteredChannelEntity::from_channel(&channel)
+		else {
+			return;
+		};
+
+		drop(channel);
+
+		tokio::spawn(async move {
+			if let Err(error) = repository.save(&registered_channel).await {
+				tracing::error!(
+					?error,
+					channel = registered_channel.channel.name,
+					"Impossible de sauvegarder le salon enregistré"
+				);
+			}
+		});
+	}
+
+	fn delete_registered_channel(&self, socket: &SocketRef, channel_name: &str)
+	{
+		let Some(repository) = self
+			.channel_registration_repository(&socket.req_parts().extensions)
+		else {
+			return;
+		};
+
+		let channel_id = channel_name.to_lowercase();
+
+		tokio::spawn(async move {
+			if let Err(error) = repository.delete(&channel_id).await {
+				tracing::error!(
+					?error,
+					channel = channel_id,
+					"Impossible de supprimer le salon enregistré"
+				);
+			}
+		});
+	}
+}
@@ -0,0 +1,279 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	AccessControlMask,
+	CHANNEL_MODE_LIST_BAN,
+	CHANNEL_MODE_LIST_BAN_EXCEPT,
+	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
+	Channel,
+	ChannelAccessControlInterface,
+	ChannelAccessLevel,
+	ChannelInterface,
+	ChannelRegistration,
+	ChannelTopic,
+	ChannelTopicInterface,
+	SettingsFlag,
+	SettingsFlagInterface,
+	TopicInterface,
+};
+use flex_chat::mode::{ApplyMode, Mask};
+use flex_web_framework::types::{time, uuid};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un salon enregistré (table `channels`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChannelEntity
+{
+	/// ID du salon (son nom en minuscules).
+	pub id: String,
+	/// Nom du salon.
+	pub name: String,
+	/// ID de l'utilisateur propriétaire du salon.
+	pub owner_id: uuid::Uuid,
+	/// Sujet du salon.
+	pub topic: String,
+	/// Origine de la mise à jour du sujet.
+	pub topic_updated_by: String,
+	/// Date de mise à jour du sujet.
+	pub topic_updated_at: time::DateTime<time::Utc>,
+	/// Date d'enregistrement du salon.
+	pub created_at: time::DateTime<time::Utc>,
+}
+
+/// Un paramètre d'un salon enregistré (table `channels_settings`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChannelSettingEntity
+{
+	/// ID du salon.
+	pub channel_id: String,
+	/// Lettre du paramètre.
+	pub letter: String,
+	/// Le paramètre, au format JSON.
+	pub flag: String,
+	/// Par qui a été appliqué ce paramètre.
+	pub updated_by: String,
+	/// Quand a été appliqué ce paramètre.
+	pub updated_at: time::DateTime<time::Utc>,
+}
+
+/// Un masque d'une liste de contrôle d'accès (+b, +e, +I) d'un salon
+/// enregistré (table `channels_access_controls`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChannelAccessControlEntity
+{
+	/// ID du salon.
+	pub channel_id: String,
+	/// Lettre de la liste de contrôle d'accès.
+	pub letter: String,
+	/// Le masque.
+	pub mask: String,
+	/// Par qui a été appliqué ce masque.
+	pub updated_by: String,
+	/// Quand a été appliqué ce masque.
+	pub updated_at: time::DateTime<time::Utc>,
+	/// Quand expire ce masque (aucune date pour un masque permanent).
+	pub expires_at: Option<time::DateTime<time::Utc>>,
+	/// La raison de l'application du masque.
+	pub reason: Option<String>,
+}
+
+/// Un niveau d'accès d'un membre enregistré d'un salon enregistré (table
+/// `channels_access_levels`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChannelAccessLevelEntity
+{
+	/// ID du salon.
+	pub channel_id: String,
+	/// ID de l'utilisateur.
+	pub user_id: uuid::Uuid,
+	/// Lettre du niveau d'accès.
+	pub letter: String,
+}
+
+/// Un salon enregistré, avec ses paramètres, ses listes de contrôle d'accès
+/// et les niveaux d'accès de ses membres enregistrés.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct RegisteredChannelEntity
+{
+	pub channel: ChannelEntity,
+	pub settings: Vec<ChannelSettingEntity>,
+	pub access_controls: Vec<ChannelAccessControlEntity>,
+	pub access_levels: Vec<ChannelAccessLevelEntity>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl RegisteredChannelEntity
+{
+	/// Instantané d'un salon enregistré.
+	pub fn from_channel(channel: &Channel) -> Option<Self>
+	{
+		let registration = channel.registration.as_ref()?;
+		let channel_id = channel.id();
+
+		let settings = channel
+			.modes_settings
+			.values()
+			.filter_map(|mode| {
+				Some(ChannelSettingEntity {
+					channel_id: channel_id.clone(),
+					letter: mode.flag.letter().to_string(),
+					flag: serde_json::to_string(&mode.flag).ok()?,
+					updated_by: mode.updated_by.clone(),
+					updated_at: mode.updated_at,
+				})
+			})
+			.collect();
+
+		let access_controls = channel
+			.access_controls()
+			.into_iter()
+			.map(|(letter, mode)| {
+				ChannelAccessControlEntity {
+					channel_id: channel_id.clone(),
+					letter: letter.to_string(),
+					mask: mode.flag.mask.to_string(),
+					updated_by: mode.updated_by,
+					updated_at: mode.updated_at,
+					expires_at: mode.flag.expires_at,
+					reason: mode.flag.reason,
+				}
+			})
+			.collect();
+
+		let access_levels = registration
+			.access_levels
+			.iter()
+			.flat_map(|(user_id, access_level)| {
+				access_level.iter().map(|level| {
+					ChannelAccessLevelEntity {
+						channel_id: channel_id.clone(),
+						user_id: *user_id,
+						letter: level.letter().to_string(),
+					}
+				})
+			})
+			.collect();
+
+		let topic = channel.topic();
+
+		Some(Self {
+			channel: ChannelEntity {
+				id: channel_id.clone(),
+				name: channel.name.clone(),
+				owner_id: registration.owner,
+				topic: topic.get().to_owned(),
+				topic_updated_by: topic.updated_by().to_owned(),
+				topic_updated_at: *topic.updated_at(),
+				created_at: registration.registered_at,
+			},
+			settings,
+			access_controls,
+			access_levels,
+		})
+	}
+
+	/// Applique le salon enregistré à un salon de la session: son sujet, ses
+	/// paramètres, ses listes de contrôle d'accès et son enregistrement. Les
+	/// membres actuels du salon sont conservés.
+	pub fn apply_to(self, channel: &mut Channel)
+	{
+		*channel.topic_mut() = ChannelTopic::new(
+			self.channel.topic,
+			self.channel.topic_updated_by,
+			self.channel.topic_updated_at,
+		);
+
+		channel.modes_settings.clear();
+		for setting in self.settings {
+			let Ok(flag) = serde_json::from_str::<SettingsFlag>(&setting.flag)
+			else {
+				continue;
+			};
+			channel.modes_settings.insert(
+				flag.letter().to_string(),
+				ApplyMode {
+					flag,
+					args: Default::default(),
+					updated_by: setting.updated_by,
+					updated_at: setting.updated_at,
+				},
+			);
+		}
+
+		let access_control = &mut channel.access_control;
+		access_control.banlist.clear();
+		access_control.banlist_except.clear();
+		access_control.invitelist_except.clear();
+		access_control.quietlist.clear();
+		for entry in self.access_controls {
+			let mask = Mask::from(&entry.mask);
+			let mask_s = mask.to_string();
+			let mode = ApplyMode {
+				flag: AccessControlMask {
+					mask,
+					expires_at: entry.expires_at,
+					reason: entry.reason,
+				},
+				args: vec![mask_s.clone()],
+				updated_by: entry.updated_by,
+				updated_at: entry.updated_at,
+			};
+			let list = match entry.letter.chars().next() {
+				| Some(CHANNEL_MODE_LIST_BAN) => &mut access_control.banlist,
+				| Some(CHANNEL_MODE_LIST_BAN_EXCEPT) => {
+					&mut access_control.banlist_except
+				}
+				| Some(CHANNEL_MODE_LIST_INVITE_EXCEPT) => {
+					&mut access_control.invitelist_except
+				}
+				| Some(CHANNEL_MODE_LIST_QUIET) => {
+					&mut access_control.quietlist
+				}
+				| _ => continue,
+			};
+			list.insert(mask_s, mode);
+		}
+
+		let mut registration = ChannelRegistration::new(self.channel.owner_id);
+		registration.registered_at = self.channel.created_at;
+		registration.access_levels.clear();
+		for access_level in self.access_levels {
+			let Some(level) = ChannelAccessLevel::ALL
+				.into_iter()
+				.find(|level| access_level.letter.starts_with(level.letter()))
+			else {
+				continue;
+			};
+			registration
+				.access_levels
+				.entry(access_level.user_id)
+				.or_default()
+				.insert(level);
+		}
+		channel.registration.replace(registration);
+	}
+}
@@ -0,0 +1,105 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	ChannelAccessLevel,
+	ChannelRegistration,
+	ChannelsSessionInterface,
+};
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::{
+	ChannelRegisterCommandFormData,
+	ChannelRegistrationApplicationInterface,
+	ChannelRegistrationClientSocketCommandResponseInterface,
+};
+use crate::features::chat::mode::ModeChannelAccessLevelApplicationInterface;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChannelRegisterHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for ChannelRegisterHandler
+{
+	type App = ChatApplication;
+	type Data = ChannelRegisterCommandFormData;
+
+	const EVENT_NAME: &'static str = "CHANNEL REGISTER";
+
+	/// La commande CHANNEL REGISTER permet au propriétaire d'un salon,
+	/// identifié à un compte, d'enregistrer ce salon. Le sujet, les
+	/// paramètres, les listes de contrôle d'accès et les niveaux d'accès des
+	/// membres identifiés d'un salon enregistré sont sauvegardés, puis
+	/// restaurés au redémarrage du serveur.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<ChannelRegisterCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(account_id) = client_socket.client().account_id().copied()
+		else {
+			client_socket.send_err_needreggednick(&data.channel);
+			return;
+		};
+
+		if !app.channels.has(&data.channel) {
+			client_socket.send_err_nosuchchannel(&data.channel);
+			return;
+		}
+
+		if !app.channels.has_member(&data.channel, client_socket.cid()) {
+			client_socket.send_err_notonchannel(&data.channel);
+			return;
+		}
+
+		if !app.does_client_have_rights_on_channel(
+			&client_socket,
+			&data.channel,
+			ChannelAccessLevel::Owner,
+		) {
+			return;
+		}
+
+		{
+			let Some(mut channel) = app.channels.get_mut(&data.channel) else {
+				return;
+			};
+
+			if channel.is_registered() {
+				client_socket.send_fail_channel_registration(
+					"REGISTER",
+					"ALREADY_REGISTERED",
+					&data.channel,
+					"Ce salon est déjà enregistré",
+				);
+				return;
+			}
+
+			channel.registration.replace(ChannelRegistration::new(account_id));
+		}
+
+		app.save_registered_channel(&socket, &data.channel);
+
+		client_socket.emit_channel_registration(&data.channel, true);
+	}
+}
@@ -0,0 +1,89 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{ChannelMemberInterface, ChannelsSessionInterface};
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::{
+	ChannelRegistrationApplicationInterface,
+	ChannelRegistrationClientSocketCommandResponseInterface,
+	ChannelUnregisterCommandFormData,
+};
+use crate::features::chat::oper::OperApplicationInterface;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChannelUnregisterHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for ChannelUnregisterHandler
+{
+	type App = ChatApplication;
+	type Data = ChannelUnregisterCommandFormData;
+
+	const EVENT_NAME: &'static str = "CHANNEL UNREGISTER";
+
+	/// La commande CHANNEL UNREGISTER permet au propriétaire d'un salon
+	/// enregistré (ou à un opérateur global) de supprimer l'enregistrement de
+	/// ce salon. Un salon vide est supprimé de la session.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<ChannelUnregisterCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let is_global_operator = app.is_client_global_operator(&client_socket);
+
+		let is_empty = {
+			let Some(mut channel) = app.channels.get_mut(&data.channel) else {
+				client_socket.send_err_nosuchchannel(&data.channel);
+				return;
+			};
+
+			let Some(registration) = channel.registration.as_ref() else {
+				client_socket.send_fail_channel_registration(
+					"UNREGISTER",
+					"NOT_REGISTERED",
+					&data.channel,
+					"Ce salon n'est pas enregistré",
+				);
+				return;
+			};
+
+			if registration.owner != *client_socket.cid() && !is_global_operator
+			{
+				client_socket.send_err_chanoprivsneeded(&data.channel);
+				return;
+			}
+
+			channel.registration.take();
+			channel.members().is_empty() && !channel.is_permanent()
+		};
+
+		if is_empty {
+			app.channels.remove(&data.channel);
+		}
+
+		app.delete_registered_channel(&socket, &data.channel);
+
+		client_socket.emit_channel_registration(&data.channel, false);
+	}
+}
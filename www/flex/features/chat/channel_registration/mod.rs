@@ -0,0 +1,42 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod channel_register_handler use *;
+		pub mod channel_unregister_handler use *;
+	};
+
+	mod entities use {
+		pub(super) mod channel_registration_entity use *;
+	};
+
+	mod errors use {
+		pub(super) mod err_needreggednick use *;
+	};
+
+	mod forms use {
+		pub(super) mod channel_registration_form use *;
+	};
+
+	mod repositories use {
+		pub(super) mod channel_registration_repository use *;
+	};
+
+	mod responses use {
+		pub(super) mod channel_registration_command_response use *;
+	};
+
+	pub mod sessions use {
+		pub mod channel_registration_session use *;
+	};
+}
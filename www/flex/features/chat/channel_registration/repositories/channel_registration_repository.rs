@@ -0,0 +1,37 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod postgres;
+
+pub use self::postgres::*;
+use crate::features::chat::channel_registration::RegisteredChannelEntity;
+
+// --------- //
+// Interface //
+// --------- //
+
+#[flex_web_framework::async_trait]
+pub trait ChannelRegistrationRepository: Send + Sync
+{
+	/// Enregistre (ou met à jour) un salon enregistré, avec ses paramètres,
+	/// ses listes de contrôle d'accès et ses niveaux d'accès.
+	async fn save(
+		&self,
+		registered_channel: &RegisteredChannelEntity,
+	) -> Result<(), sqlx::Error>;
+
+	/// Supprime un salon enregistré.
+	async fn delete(&self, channel_id: &str) -> Result<(), sqlx::Error>;
+
+	/// Tous les salons enregistrés.
+	async fn find_all(
+		&self,
+	) -> Result<Vec<RegisteredChannelEntity>, sqlx::Error>;
+}
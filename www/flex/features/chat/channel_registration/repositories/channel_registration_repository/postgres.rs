@@ -0,0 +1,223 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+
+use super::ChannelRegistrationRepository;
+use crate::features::chat::channel_registration::{
+	ChannelAccessControlEntity,
+	ChannelAccessLevelEntity,
+	ChannelEntity,
+	ChannelSettingEntity,
+	RegisteredChannelEntity,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChannelRegistrationRepositoryPostgreSQL
+{
+	pub database: DatabaseService<PostgreSQLDatabase>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChannelRegistrationRepositoryPostgreSQL
+{
+	/// Nom de la table des listes de contrôle d'accès des salons enregistrés.
+	pub const ACCESS_CONTROLS_TABLE_NAME: &'static str =
+		"channels_access_controls";
+	/// Nom de la table des niveaux d'accès des salons enregistrés.
+	pub const ACCESS_LEVELS_TABLE_NAME: &'static str = "channels_access_levels";
+	/// Nom de la table des paramètres des salons enregistrés.
+	pub const SETTINGS_TABLE_NAME: &'static str = "channels_settings";
+	/// Nom de la table des salons enregistrés.
+	pub const TABLE_NAME: &'static str = "channels";
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[flex_web_framework::async_trait]
+impl ChannelRegistrationRepository for ChannelRegistrationRepositoryPostgreSQL
+{
+	async fn save(
+		&self,
+		registered_channel: &RegisteredChannelEntity,
+	) -> Result<(), sqlx::Error>
+	{
+		let channel = &registered_channel.channel;
+
+		let mut transaction = self.database.pool().begin().await?;
+
+		let sql = format!(
+			"INSERT INTO {} (id, name, owner_id, topic, topic_updated_by, \
+			 topic_updated_at, created_at, updated_at) VALUES ($1, $2, $3, \
+			 $4, $5, $6, $7, now()) ON CONFLICT (id) DO UPDATE SET name = $2, \
+			 owner_id = $3, topic = $4, topic_updated_by = $5, \
+			 topic_updated_at = $6, updated_at = now()",
+			Self::TABLE_NAME,
+		);
+		sqlx::query(&sql)
+			.bind(&channel.id)
+			.bind(&channel.name)
+			.bind(channel.owner_id)
+			.bind(&channel.topic)
+			.bind(&channel.topic_updated_by)
+			.bind(channel.topic_updated_at)
+			.bind(channel.created_at)
+			.execute(&mut *transaction)
+			.await?;
+
+		// NOTE: les paramètres, les listes et les niveaux d'accès sont
+		//       remplacés par ceux de l'instantané du salon.
+		for table in [
+			Self::SETTINGS_TABLE_NAME,
+			Self::ACCESS_CONTROLS_TABLE_NAME,
+			Self::ACCESS_LEVELS_TABLE_NAME,
+		] {
+			let sql = format!("DELETE FROM {table} WHERE channel_id = $1");
+			sqlx::query(&sql)
+				.bind(&channel.id)
+				.execute(&mut *transaction)
+				.await?;
+		}
+
+		let sql = format!(
+			"INSERT INTO {} (channel_id, letter, flag, updated_by, \
+			 updated_at) VALUES ($1, $2, $3, $4, $5)",
+			Self::SETTINGS_TABLE_NAME,
+		);
+		for setting in registered_channel.settings.iter() {
+			sqlx::query(&sql)
+				.bind(&setting.channel_id)
+				.bind(&setting.letter)
+				.bind(&setting.flag)
+				.bind(&setting.updated_by)
+				.bind(setting.updated_at)
+				.execute(&mut *transaction)
+				.await?;
+		}
+
+		let sql = format!(
+			"INSERT INTO {} (channel_id, letter, mask, updated_by, \
+			 updated_at, expires_at, reason) VALUES ($1, $2, $3, $4, $5, $6, \
+			 $7)",
+			Self::ACCESS_CONTROLS_TABLE_NAME,
+		);
+		for access_control in registered_channel.access_controls.iter() {
+			sqlx::query(&sql)
+				.bind(&access_control.channel_id)
+				.bind(&access_control.letter)
+				.bind(&access_control.mask)
+				.bind(&access_control.updated_by)
+				.bind(access_control.updated_at)
+				.bind(access_control.expires_at)
+				.bind(&access_control.reason)
+				.execute(&mut *transaction)
+				.await?;
+		}
+
+		let sql = format!(
+			"INSERT INTO {} (channel_id, user_id, letter) VALUES ($1, $2, $3)",
+			Self::ACCESS_LEVELS_TABLE_NAME,
+		);
+		for access_level in registered_channel.access_levels.iter() {
+			sqlx::query(&sql)
+				.bind(&access_level.channel_id)
+				.bind(access_level.user_id)
+				.bind(&access_level.letter)
+				.execute(&mut *transaction)
+				.await?;
+		}
+
+		transaction.commit().await
+	}
+
+	async fn delete(&self, channel_id: &str) -> Result<(), sqlx::Error>
+	{
+		let sql = format!("DELETE FROM {} WHERE id = $1", Self::TABLE_NAME);
+		sqlx::query(&sql)
+			.bind(channel_id)
+			.execute(self.database.pool())
+			.await?;
+		Ok(())
+	}
+
+	async fn find_all(
+		&self,
+	) -> Result<Vec<RegisteredChannelEntity>, sqlx::Error>
+	{
+		let pool = self.database.pool();
+
+		let sql = format!("SELECT * FROM {}", Self::TABLE_NAME);
+		let channels: Vec<ChannelEntity> =
+			sqlx::query_as(&sql).fetch_all(pool).await?;
+
+		let sql = format!("SELECT * FROM {}", Self::SETTINGS_TABLE_NAME);
+		let settings: Vec<ChannelSettingEntity> =
+			sqlx::query_as(&sql).fetch_all(pool).await?;
+
+		let sql = format!("SELECT * FROM {}", Self::ACCESS_CONTROLS_TABLE_NAME);
+		let access_controls: Vec<ChannelAccessControlEntity> =
+			sqlx::query_as(&sql).fetch_all(pool).await?;
+
+		let sql = format!("SELECT * FROM {}", Self::ACCESS_LEVELS_TABLE_NAME);
+		let access_levels: Vec<ChannelAccessLevelEntity> =
+			sqlx::query_as(&sql).fetch_all(pool).await?;
+
+		let mut registered_channels: HashMap<_, _> = channels
+			.into_iter()
+			.map(|channel| {
+				(
+					channel.id.clone(),
+					RegisteredChannelEntity {
+						channel,
+						settings: Default::default(),
+						access_controls: Default::default(),
+						access_levels: Default::default(),
+					},
+				)
+			})
+			.collect();
+
+		for setting in settings {
+			if let Some(registered_channel) =
+				registered_channels.get_mut(&setting.channel_id)
+			{
+				registered_channel.settings.push(setting);
+			}
+		}
+
+		for access_control in access_controls {
+			if let Some(registered_channel) =
+				registered_channels.get_mut(&access_control.channel_id)
+			{
+				registered_channel.access_controls.push(access_control);
+			}
+		}
+
+		for access_level in access_levels {
+			if let Some(registered_channel) =
+				registered_channels.get_mut(&access_level.channel_id)
+			{
+				registered_channel.access_levels.push(access_level);
+			}
+		}
+
+		Ok(registered_channels.into_values().collect())
+	}
+}
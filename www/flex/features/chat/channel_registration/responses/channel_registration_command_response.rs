@@ -0,0 +1,90 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+use crate::features::chat::channel_registration::ErrNeedreggednickError;
+use crate::features::chat::chathistory::FailCommandResponse;
+
+command_response! {
+	/// Enregistrement (ou suppression de l'enregistrement) d'un salon.
+	struct CHANNEL_REGISTRATION<'channel>
+	{
+		/// Le salon.
+		channel: &'channel str,
+		/// Le salon est-il enregistré?
+		registered: bool,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ChannelRegistrationClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'enregistrement (ou la suppression de
+	/// l'enregistrement) d'un salon.
+	fn emit_channel_registration(&self, channel: &str, registered: bool)
+	{
+		let origin = Origin::from(self.client());
+		let channel_registration_command = ChannelRegistrationCommandResponse {
+			origin: &origin,
+			tags: ChannelRegistrationCommandResponse::default_tags(),
+			channel,
+			registered,
+		};
+		self.emit(
+			channel_registration_command.name(),
+			channel_registration_command,
+		);
+	}
+
+	/// Émet au client l'erreur [ErrNeedreggednickError].
+	fn send_err_needreggednick(&self, channel: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_needreggednick = ErrNeedreggednickError {
+			channel,
+			origin: &origin,
+			tags: ErrNeedreggednickError::default_tags(),
+		};
+		self.emit(err_needreggednick.name(), err_needreggednick);
+	}
+
+	/// Émet au client un échec de l'enregistrement d'un salon.
+	fn send_fail_channel_registration(
+		&self,
+		command: &str,
+		code: &str,
+		channel: &str,
+		description: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let fail_command = FailCommandResponse {
+			origin: &origin,
+			tags: FailCommandResponse::default_tags(),
+			command,
+			code,
+			context: &[channel],
+			description,
+		};
+		self.emit(fail_command.name(), fail_command);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ChannelRegistrationClientSocketCommandResponseInterface for Socket<'s> {}
@@ -0,0 +1,42 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use tokio::sync::OnceCell;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// État du chargement des salons enregistrés.
+#[derive(Default)]
+pub struct ChannelRegistrationSession
+{
+	/// Les salons enregistrés ont-ils été chargés dans la session des salons?
+	loaded: OnceCell<()>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChannelRegistrationSession
+{
+	/// Charge les salons enregistrés. Le chargement n'a lieu qu'une seule
+	/// fois : les appels concurrents attendent la fin du chargement en cours.
+	/// En cas d'erreur, les salons enregistrés seront chargés à nouveau lors
+	/// du prochain appel.
+	pub async fn load<E>(
+		&self,
+		registered_channels: impl Future<Output = Result<(), E>>,
+	) -> Result<(), E>
+	{
+		self.loaded.get_or_try_init(|| registered_channels).await.map(|_| ())
+	}
+}
@@ -0,0 +1,163 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+use socketioxide::extract::SocketRef;
+
+use super::{
+	ChatHistoryEntity,
+	ChatHistoryRepository,
+	ChatHistoryRepositoryMemory,
+	ChatHistoryRepositoryPostgreSQL,
+};
+use crate::config::chat::{FlexChatConfig, FlexChatConfigHistoryStorage};
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ChatHistoryApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Le stockage de l'historique des messages, défini dans la
+	/// configuration.
+	fn chathistory_repository(
+		&'static self,
+		socket: &SocketRef,
+	) -> Option<Arc<dyn ChatHistoryRepository>>;
+
+	/// Identité d'un client dans l'historique de ses conversations privées.
+	fn chathistory_identity(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> String;
+
+	/// La cible de l'historique d'un salon, si le client courant en est
+	/// membre, ou d'une conversation privée avec l'identité d'un
+	/// interlocuteur.
+	fn chathistory_target(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		target: &str,
+		peer_identity: Option<&str>,
+	) -> Option<String>;
+
+	/// Ajoute un message à l'historique, en tâche de fond.
+	fn store_chathistory(
+		&'static self,
+		socket: &SocketRef,
+		message: ChatHistoryEntity,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ChatHistoryApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn chathistory_repository(
+		&'static self,
+		socket: &SocketRef,
+	) -> Option<Arc<dyn ChatHistoryRepository>>
+	{
+		let extensions = &socket.req_parts().extensions;
+		let config = extensions.get::<FlexChatConfig>()?;
+		let retention = config.history.clone();
+
+		Some(match config.history.storage {
+			| FlexChatConfigHistoryStorage::Memory => {
+				Arc::new(ChatHistoryRepositoryMemory {
+					session: &self.history,
+					retention,
+				})
+			}
+			| FlexChatConfigHistoryStorage::PostgreSQL => {
+				let database =
+					extensions.get::<DatabaseService<PostgreSQLDatabase>>()?;
+				Arc::new(ChatHistoryRepositoryPostgreSQL {
+					database: database.clone(),
+					retention,
+				})
+			}
+		})
+	}
+
+	fn chathistory_identity(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> String
+	{
+		// NOTE: l'ID d'un client identifié est l'ID de son compte (voir
+		//       IDENTIFY), l'historique de ses conversations privées le suit
+		//       donc d'une connexion à l'autre. L'ID d'un client non
+		//       identifié est propre à sa session.
+		client_socket.cid().to_string()
+	}
+
+	fn chathistory_target(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		target: &str,
+		peer_identity: Option<&str>,
+	) -> Option<String>
+	{
+		if target.starts_with('#') {
+			return client_socket
+				.client()
+				.channels()
+				.contains(&target.to_lowercase())
+				.then(|| ChatHistoryEntity::channel_target(target));
+		}
+
+		// NOTE: à défaut d'une conversation existante, l'interlocuteur est
+		//       le détenteur actuel du pseudonyme.
+		let peer_identity = match peer_identity {
+			| Some(peer_identity) => peer_identity.to_owned(),
+			| None => {
+				let peer_socket = self
+					.find_socket_by_nickname(client_socket.socket(), target)?;
+				self.chathistory_identity(&peer_socket)
+			}
+		};
+
+		Some(ChatHistoryEntity::private_target(
+			&self.chathistory_identity(client_socket),
+			&peer_identity,
+		))
+	}
+
+	fn store_chathistory(
+		&'static self,
+		socket: &SocketRef,
+		message: ChatHistoryEntity,
+	)
+	{
+		let Some(repository) = self.chathistory_repository(socket) else {
+			return;
+		};
+
+		tokio::spawn(async move {
+			if let Err(error) = repository.create(message).await {
+				tracing::error!(
+					?error,
+					"Impossible d'ajouter le message à l'historique"
+				);
+			}
+		});
+	}
+}
@@ -0,0 +1,171 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use chrono::SubsecRound;
+use flex_web_framework::types::{time, uuid};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un message de l'historique d'une cible (salon ou conversation privée).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChatHistoryEntity
+{
+	/// Identifiant du message (étiquette `msgid`).
+	pub id: uuid::Uuid,
+	/// Cible de l'historique, voir [ChatHistoryEntity::channel_target] et
+	/// [ChatHistoryEntity::private_target].
+	pub target: String,
+	/// Pseudonyme (en minuscules) de l'auteur du message.
+	pub sender: String,
+	/// Identité de l'auteur d'un message privé, vide pour un salon.
+	pub sender_id: String,
+	/// Salon ou pseudonyme (en minuscules) destinataire du message.
+	pub recipient: String,
+	/// Identité du destinataire d'un message privé, vide pour un salon.
+	pub recipient_id: String,
+	/// Nom de l'événement émis aux clients (PUBMSG, PRIVMSG, NOTICE).
+	pub command: String,
+	/// Réponse émise aux clients, au format JSON.
+	pub payload: String,
+	/// Date à laquelle le serveur a traité le message (étiquette `time`).
+	pub created_at: time::DateTime<time::Utc>,
+}
+
+/// Les étiquettes d'un message, partagées entre toutes ses émissions et son
+/// historique.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct ChatHistoryTags
+{
+	/// Identifiant du message (étiquette `msgid`).
+	pub msgid: uuid::Uuid,
+	/// Date à laquelle le serveur a traité le message (étiquette `time`).
+	pub time: time::DateTime<time::Utc>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChatHistoryEntity
+{
+	/// Cible de l'historique d'un salon.
+	pub fn channel_target(channel_name: &str) -> String
+	{
+		channel_name.to_lowercase()
+	}
+
+	/// Cible de l'historique d'une conversation privée entre deux
+	/// identités, identique quel que soit l'ordre des identités.
+	///
+	/// NOTE: une conversation privée n'est jamais associée aux pseudonymes,
+	///       sans quoi le prochain détenteur d'un pseudonyme aurait accès aux
+	///       messages de l'ancien.
+	pub fn private_target(identity: &str, other_identity: &str) -> String
+	{
+		let mut identities = [identity, other_identity];
+		identities.sort();
+		identities.join(" ")
+	}
+
+	/// Crée un message de l'historique à partir d'une réponse émise aux
+	/// clients.
+	pub fn new(
+		tags: &ChatHistoryTags,
+		target: String,
+		sender: &str,
+		recipient: &str,
+		command: &str,
+		payload: &serde_json::Value,
+	) -> Self
+	{
+		Self {
+			id: tags.msgid,
+			target,
+			sender: sender.to_lowercase(),
+			sender_id: Default::default(),
+			recipient: recipient.to_lowercase(),
+			recipient_id: Default::default(),
+			command: command.to_owned(),
+			payload: payload.to_string(),
+			created_at: tags.time,
+		}
+	}
+
+	/// Définit les identités de l'auteur et du destinataire d'un message
+	/// privé.
+	pub fn with_identities(
+		mut self,
+		sender_id: impl ToString,
+		recipient_id: impl ToString,
+	) -> Self
+	{
+		self.sender_id = sender_id.to_string();
+		self.recipient_id = recipient_id.to_string();
+		self
+	}
+
+	/// La réponse émise aux clients, avec l'étiquette `batch` de la commande
+	/// CHATHISTORY.
+	pub fn payload_with_batch(&self, batch: &str) -> serde_json::Value
+	{
+		let mut payload: serde_json::Value =
+			serde_json::from_str(&self.payload).unwrap_or_default();
+		if let Some(tags) = payload["tags"].as_object_mut() {
+			tags.insert(String::from("batch"), batch.into());
+		}
+		payload
+	}
+}
+
+impl ChatHistoryTags
+{
+	/// Étiquettes d'un nouveau message.
+	pub fn new() -> Self
+	{
+		Self {
+			msgid: uuid::Uuid::new_v4(),
+			// NOTE(phisyx): la précision de l'étiquette `time` est à la
+			//               milliseconde.
+			time: time::Utc::now().trunc_subsecs(3),
+		}
+	}
+
+	/// Étiquettes `msgid` et `time` à ajouter aux réponses.
+	pub fn to_tags(&self) -> [(&'static str, String); 2]
+	{
+		[
+			("msgid", self.msgid.to_string()),
+			("time", Self::format_time(&self.time)),
+		]
+	}
+
+	/// Date au format de l'étiquette `time`.
+	pub fn format_time(time: &time::DateTime<time::Utc>) -> String
+	{
+		time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for ChatHistoryTags
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
@@ -0,0 +1,158 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fmt, str};
+
+use flex_chat::macros::command_formdata;
+use flex_web_framework::types::{time, uuid};
+
+command_formdata! {
+	struct CHATHISTORY
+	{
+		/// Sous-commande de la commande CHATHISTORY.
+		subcommand: ChatHistorySubcommand,
+		/// Salon ou pseudonyme dont on veut l'historique (toutes les
+		/// sous-commandes sauf TARGETS).
+		#[serde(default)]
+		target: Option<String>,
+		/// Première référence de message (`*`, `timestamp=…` ou `msgid=…`).
+		#[serde(default)]
+		from: Option<ChatHistoryReference>,
+		/// Seconde référence de message (BETWEEN, TARGETS).
+		#[serde(default)]
+		to: Option<ChatHistoryReference>,
+		/// Nombre maximum de messages (ou de cibles) à retourner.
+		limit: usize,
+	}
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChatHistorySubcommand
+{
+	/// Les derniers messages d'une cible.
+	Latest,
+	/// Les messages antérieurs à une référence.
+	Before,
+	/// Les messages postérieurs à une référence.
+	After,
+	/// Les messages autour d'une référence.
+	Around,
+	/// Les messages entre deux références.
+	Between,
+	/// Les cibles ayant reçu des messages entre deux dates.
+	Targets,
+}
+
+/// Référence d'un message de l'historique.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum ChatHistoryReference
+{
+	/// `*`: aucune référence.
+	Wildcard,
+	/// `timestamp=YYYY-MM-DDThh:mm:ss.sssZ`: date d'un message.
+	Timestamp(time::DateTime<time::Utc>),
+	/// `msgid=…`: identifiant d'un message.
+	MsgId(uuid::Uuid),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChatHistorySubcommand
+{
+	/// Nom de la sous-commande.
+	pub fn as_str(&self) -> &'static str
+	{
+		match self {
+			| Self::Latest => "LATEST",
+			| Self::Before => "BEFORE",
+			| Self::After => "AFTER",
+			| Self::Around => "AROUND",
+			| Self::Between => "BETWEEN",
+			| Self::Targets => "TARGETS",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for ChatHistoryReference
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self {
+			| Self::Wildcard => write!(f, "*"),
+			| Self::Timestamp(time) => {
+				write!(f, "timestamp={}", time.format("%Y-%m-%dT%H:%M:%S%.3fZ"))
+			}
+			| Self::MsgId(msgid) => write!(f, "msgid={msgid}"),
+		}
+	}
+}
+
+impl str::FromStr for ChatHistoryReference
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		if s == "*" {
+			return Ok(Self::Wildcard);
+		}
+
+		let invalid = || format!("Référence de message invalide: {s}");
+
+		match s.split_once('=').ok_or_else(invalid)? {
+			| ("timestamp", time) => {
+				time::DateTime::parse_from_rfc3339(time)
+					.map(|time| Self::Timestamp(time.to_utc()))
+					.map_err(|_| invalid())
+			}
+			| ("msgid", msgid) => {
+				msgid.parse().map(Self::MsgId).map_err(|_| invalid())
+			}
+			| _ => Err(invalid()),
+		}
+	}
+}
+
+impl serde::Serialize for ChatHistoryReference
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> serde::Deserialize<'de> for ChatHistoryReference
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let reference = String::deserialize(deserializer)?;
+		reference.parse().map_err(serde::de::Error::custom)
+	}
+}
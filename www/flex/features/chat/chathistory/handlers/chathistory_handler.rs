@@ -0,0 +1,243 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface};
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::chathistory::*;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChatHistoryHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChatHistoryHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "CHATHISTORY";
+
+	/// La commande `CHATHISTORY` permet de récupérer les messages passés
+	/// d'un salon ou d'une conversation privée, par exemple après une
+	/// reconnexion. Les messages sont renvoyés dans un lot (BATCH), dans
+	/// l'ordre chronologique, avec leurs étiquettes `msgid` et `time`
+	/// d'origine.
+	pub async fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<ChathistoryCommandFormData>,
+	)
+	{
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>().cloned()
+		else {
+			return;
+		};
+
+		let Some(repository) = app.chathistory_repository(&socket) else {
+			return;
+		};
+
+		let subcommand = data.subcommand.as_str();
+		let limit = data.limit.min(config.history.max_limit);
+
+		if limit == 0 {
+			app.current_client(&socket).send_fail_chathistory(
+				"INVALID_PARAMS",
+				&[subcommand],
+				"Limite de messages invalide",
+			);
+			return;
+		}
+
+		if data.subcommand == ChatHistorySubcommand::Targets {
+			let (
+				Some(ChatHistoryReference::Timestamp(start)),
+				Some(ChatHistoryReference::Timestamp(end)),
+			) = (data.from, data.to)
+			else {
+				app.current_client(&socket).send_fail_chathistory(
+					"INVALID_PARAMS",
+					&[subcommand],
+					"Les références de TARGETS DOIVENT être des dates",
+				);
+				return;
+			};
+
+			let (identity, channels) = {
+				let client_socket = app.current_client(&socket);
+				let channels: Vec<_> =
+					client_socket.client().channels().iter().cloned().collect();
+				(app.chathistory_identity(&client_socket), channels)
+			};
+
+			let maybe_targets = repository
+				.targets(&identity, &channels, start, end, limit)
+				.await;
+
+			let client_socket = app.current_client(&socket);
+			match maybe_targets {
+				| Ok(targets) => {
+					client_socket.send_chathistory_targets(&targets)
+				}
+				| Err(_) => {
+					client_socket.send_fail_chathistory(
+						"MESSAGE_ERROR",
+						&[subcommand],
+						"Impossible de récupérer les cibles de l'historique",
+					);
+				}
+			}
+			return;
+		}
+
+		let Some(target_name) = data.target else {
+			app.current_client(&socket).send_fail_chathistory(
+				"NEED_MORE_PARAMS",
+				&[subcommand],
+				"Cible manquante",
+			);
+			return;
+		};
+
+		let is_private = !target_name.starts_with('#');
+
+		// NOTE: l'interlocuteur d'une conversation privée est retrouvé à
+		//       partir de l'historique du client, de sorte que le prochain
+		//       détenteur de son pseudonyme n'y ait pas accès.
+		let peer_identity = if is_private {
+			let identity =
+				app.chathistory_identity(&app.current_client(&socket));
+			repository
+				.find_private_peer(&identity, &target_name)
+				.await
+				.ok()
+				.flatten()
+		} else {
+			None
+		};
+
+		let maybe_target = {
+			let client_socket = app.current_client(&socket);
+			app.chathistory_target(
+				&client_socket,
+				&target_name,
+				peer_identity.as_deref(),
+			)
+		};
+
+		let Some(target) = maybe_target else {
+			if is_private {
+				app.current_client(&socket).send_chathistory(&target_name, &[]);
+				return;
+			}
+			app.current_client(&socket).send_fail_chathistory(
+				"INVALID_TARGET",
+				&[subcommand, &target_name],
+				"Tu n'as pas accès à l'historique de cette cible",
+			);
+			return;
+		};
+
+		let from = match Self::resolve(&*repository, &target, data.from).await {
+			| Ok(from) => from,
+			| Err(reference) => {
+				app.current_client(&socket).send_fail_chathistory(
+					"INVALID_MSGREFTYPE",
+					&[subcommand, &reference],
+					"Référence de message invalide",
+				);
+				return;
+			}
+		};
+
+		let to = match Self::resolve(&*repository, &target, data.to).await {
+			| Ok(to) => to,
+			| Err(reference) => {
+				app.current_client(&socket).send_fail_chathistory(
+					"INVALID_MSGREFTYPE",
+					&[subcommand, &reference],
+					"Référence de message invalide",
+				);
+				return;
+			}
+		};
+
+		let query = match (data.subcommand, from, to) {
+			| (ChatHistorySubcommand::Latest, after, _) => {
+				ChatHistoryQuery::Latest(after)
+			}
+			| (ChatHistorySubcommand::Before, Some(before), _) => {
+				ChatHistoryQuery::Before(before)
+			}
+			| (ChatHistorySubcommand::After, Some(after), _) => {
+				ChatHistoryQuery::After(after)
+			}
+			| (ChatHistorySubcommand::Around, Some(around), _) => {
+				ChatHistoryQuery::Around(around)
+			}
+			| (ChatHistorySubcommand::Between, Some(start), Some(end)) => {
+				ChatHistoryQuery::Between(start, end)
+			}
+			| _ => {
+				app.current_client(&socket).send_fail_chathistory(
+					"INVALID_PARAMS",
+					&[subcommand],
+					"Références de messages manquantes",
+				);
+				return;
+			}
+		};
+
+		let maybe_messages = repository.find_all(&target, query, limit).await;
+
+		let client_socket = app.current_client(&socket);
+		match maybe_messages {
+			| Ok(messages) => {
+				client_socket.send_chathistory(&target_name, &messages);
+			}
+			| Err(_) => {
+				client_socket.send_fail_chathistory(
+					"MESSAGE_ERROR",
+					&[subcommand, &target_name],
+					"Impossible de récupérer l'historique",
+				);
+			}
+		}
+	}
+
+	/// Date d'une référence de message. Une référence `msgid=…` est résolue
+	/// à partir de l'historique de la cible. En cas d'erreur, la référence
+	/// est retournée.
+	async fn resolve(
+		repository: &dyn ChatHistoryRepository,
+		target: &str,
+		reference: Option<ChatHistoryReference>,
+	) -> Result<Option<chrono::DateTime<chrono::Utc>>, String>
+	{
+		match reference {
+			| None | Some(ChatHistoryReference::Wildcard) => Ok(None),
+			| Some(ChatHistoryReference::Timestamp(time)) => Ok(Some(time)),
+			| Some(ChatHistoryReference::MsgId(msgid)) => {
+				repository
+					.find_by_id(target, &msgid)
+					.await
+					.map(|message| Some(message.created_at))
+					.map_err(|_| format!("msgid={msgid}"))
+			}
+		}
+	}
+}
@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod chathistory_handler use *;
+	};
+
+	// NOTE(vis): les autres features pourraient avoir besoin de ces éléments
+	pub(crate) mod entities use {
+		pub mod chathistory_entity use *;
+	};
+
+	mod forms use {
+		pub(super) mod chathistory_form use *;
+	};
+
+	pub(crate) mod repositories use {
+		pub mod chathistory_repository use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(crate) mod chathistory_command_response use *;
+	};
+
+	pub mod sessions use {
+		pub mod chathistory_session use *;
+	};
+}
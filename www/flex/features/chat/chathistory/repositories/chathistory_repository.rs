@@ -0,0 +1,152 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod memory;
+mod postgres;
+
+use flex_web_framework::types::{time, uuid};
+
+pub use self::memory::*;
+pub use self::postgres::*;
+use crate::features::chat::chathistory::ChatHistoryEntity;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Une cible de l'historique d'un client (commande `CHATHISTORY TARGETS`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ChatHistoryTarget
+{
+	/// Nom du salon ou pseudonyme de l'interlocuteur.
+	pub name: String,
+	/// Date du dernier message de la cible.
+	pub latest: time::DateTime<time::Utc>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Sélection des messages de l'historique d'une cible.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+pub enum ChatHistoryQuery
+{
+	/// Les derniers messages, postérieurs à une date facultative.
+	Latest(Option<time::DateTime<time::Utc>>),
+	/// Les messages antérieurs à une date.
+	Before(time::DateTime<time::Utc>),
+	/// Les messages postérieurs à une date.
+	After(time::DateTime<time::Utc>),
+	/// Les messages autour d'une date.
+	Around(time::DateTime<time::Utc>),
+	/// Les messages entre deux dates, en partant de la première.
+	Between(time::DateTime<time::Utc>, time::DateTime<time::Utc>),
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+#[flex_web_framework::async_trait]
+pub trait ChatHistoryRepository: Send + Sync
+{
+	/// Ajoute un message à l'historique de sa cible, en appliquant les
+	/// limites de conservation.
+	async fn create(
+		&self,
+		message: ChatHistoryEntity,
+	) -> Result<(), sqlx::Error>;
+
+	/// Cherche un message de l'historique d'une cible par son ID.
+	async fn find_by_id(
+		&self,
+		target: &str,
+		id: &uuid::Uuid,
+	) -> Result<ChatHistoryEntity, sqlx::Error>;
+
+	/// Les messages (au plus `limit`) d'une cible, strictement compris entre
+	/// deux dates facultatives, dans l'ordre chronologique. Lorsque `latest`
+	/// est vrai, les messages les plus récents de l'intervalle sont
+	/// sélectionnés, sinon les plus anciens.
+	async fn select(
+		&self,
+		target: &str,
+		after: Option<time::DateTime<time::Utc>>,
+		before: Option<time::DateTime<time::Utc>>,
+		latest: bool,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryEntity>, sqlx::Error>;
+
+	/// Les cibles (au plus `limit`) ayant reçu des messages entre deux
+	/// dates : les salons donnés et les conversations privées d'une
+	/// identité.
+	async fn targets(
+		&self,
+		identity: &str,
+		channels: &[String],
+		start: time::DateTime<time::Utc>,
+		end: time::DateTime<time::Utc>,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryTarget>, sqlx::Error>;
+
+	/// L'identité de l'interlocuteur le plus récent d'une identité portant
+	/// un pseudonyme donné.
+	async fn find_private_peer(
+		&self,
+		identity: &str,
+		nickname: &str,
+	) -> Result<Option<String>, sqlx::Error>;
+
+	/// Les messages (au plus `limit`) d'une cible, dans l'ordre
+	/// chronologique.
+	async fn find_all(
+		&self,
+		target: &str,
+		query: ChatHistoryQuery,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryEntity>, sqlx::Error>
+	{
+		match query {
+			| ChatHistoryQuery::Latest(after) => {
+				self.select(target, after, None, true, limit).await
+			}
+			| ChatHistoryQuery::Before(before) => {
+				self.select(target, None, Some(before), true, limit).await
+			}
+			| ChatHistoryQuery::After(after) => {
+				self.select(target, Some(after), None, false, limit).await
+			}
+			| ChatHistoryQuery::Around(around) => {
+				let mut messages = self
+					.select(target, None, Some(around), true, limit / 2)
+					.await?;
+				// NOTE(phisyx): la précision des dates est à la milliseconde,
+				//               les messages de la date donnée sont inclus.
+				let since = around - chrono::TimeDelta::milliseconds(1);
+				let after_limit = limit - messages.len();
+				messages.extend(
+					self.select(target, Some(since), None, false, after_limit)
+						.await?,
+				);
+				Ok(messages)
+			}
+			| ChatHistoryQuery::Between(start, end) if start <= end => {
+				self.select(target, Some(start), Some(end), false, limit).await
+			}
+			| ChatHistoryQuery::Between(start, end) => {
+				self.select(target, Some(end), Some(start), true, limit).await
+			}
+		}
+	}
+}
@@ -0,0 +1,219 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::types::{time, uuid};
+
+use super::{ChatHistoryRepository, ChatHistoryTarget};
+use crate::config::chat::FlexChatConfigHistory;
+use crate::features::chat::chathistory::{
+	ChatHistoryEntity,
+	ChatHistorySession,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChatHistoryRepositoryMemory
+{
+	pub session: &'static ChatHistorySession,
+	pub retention: FlexChatConfigHistory,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChatHistoryRepositoryMemory
+{
+	/// Date en deçà de laquelle les messages ont expiré.
+	fn expired_before(&self) -> time::DateTime<time::Utc>
+	{
+		let max_age = chrono::TimeDelta::seconds(
+			i64::try_from(self.retention.max_age).unwrap_or(i64::MAX),
+		);
+		time::Utc::now()
+			.checked_sub_signed(max_age)
+			.unwrap_or(time::DateTime::<time::Utc>::MIN_UTC)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[flex_web_framework::async_trait]
+impl ChatHistoryRepository for ChatHistoryRepositoryMemory
+{
+	async fn create(
+		&self,
+		message: ChatHistoryEntity,
+	) -> Result<(), sqlx::Error>
+	{
+		let expired_before = self.expired_before();
+
+		let mut messages =
+			self.session.targets.entry(message.target.clone()).or_default();
+
+		// NOTE(phisyx): les messages peuvent être ajoutés dans le désordre
+		//               par les tâches asynchrones.
+		let position = messages
+			.partition_point(|msg| msg.created_at <= message.created_at);
+		messages.insert(position, message);
+
+		while messages.len() > self.retention.max_messages
+			|| messages
+				.front()
+				.filter(|msg| msg.created_at < expired_before)
+				.is_some()
+		{
+			messages.pop_front();
+		}
+
+		Ok(())
+	}
+
+	async fn find_by_id(
+		&self,
+		target: &str,
+		id: &uuid::Uuid,
+	) -> Result<ChatHistoryEntity, sqlx::Error>
+	{
+		let expired_before = self.expired_before();
+		self.session
+			.targets
+			.get(target)
+			.and_then(|messages| {
+				messages
+					.iter()
+					.find(|msg| {
+						&msg.id == id && msg.created_at >= expired_before
+					})
+					.cloned()
+			})
+			.ok_or(sqlx::Error::RowNotFound)
+	}
+
+	async fn select(
+		&self,
+		target: &str,
+		after: Option<time::DateTime<time::Utc>>,
+		before: Option<time::DateTime<time::Utc>>,
+		latest: bool,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryEntity>, sqlx::Error>
+	{
+		let expired_before = self.expired_before();
+
+		let Some(messages) = self.session.targets.get(target) else {
+			return Ok(vec![]);
+		};
+
+		let selection = messages.iter().filter(|msg| {
+			msg.created_at >= expired_before
+				&& after.filter(|after| &msg.created_at <= after).is_none()
+				&& before.filter(|before| &msg.created_at >= before).is_none()
+		});
+
+		let messages: Vec<_> = if latest {
+			let mut messages: Vec<_> =
+				selection.rev().take(limit).cloned().collect();
+			messages.reverse();
+			messages
+		} else {
+			selection.take(limit).cloned().collect()
+		};
+
+		Ok(messages)
+	}
+
+	async fn targets(
+		&self,
+		identity: &str,
+		channels: &[String],
+		start: time::DateTime<time::Utc>,
+		end: time::DateTime<time::Utc>,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryTarget>, sqlx::Error>
+	{
+		let (start, end) =
+			(start.min(end).max(self.expired_before()), start.max(end));
+
+		let mut targets: Vec<_> = self
+			.session
+			.targets
+			.iter()
+			.filter_map(|rm| {
+				let latest = rm.value().iter().rev().find(|msg| {
+					msg.created_at > start && msg.created_at < end
+				})?;
+
+				let name = if latest.target == latest.recipient {
+					channels
+						.contains(&latest.recipient)
+						.then(|| latest.recipient.clone())?
+				} else if latest.sender_id == identity {
+					latest.recipient.clone()
+				} else if latest.recipient_id == identity {
+					latest.sender.clone()
+				} else {
+					return None;
+				};
+
+				Some(ChatHistoryTarget {
+					name,
+					latest: latest.created_at,
+				})
+			})
+			.collect();
+
+		targets.sort_by_key(|target| target.latest);
+		targets.truncate(limit);
+
+		Ok(targets)
+	}
+
+	async fn find_private_peer(
+		&self,
+		identity: &str,
+		nickname: &str,
+	) -> Result<Option<String>, sqlx::Error>
+	{
+		let nickname = nickname.to_lowercase();
+		let expired_before = self.expired_before();
+
+		let peer = self
+			.session
+			.targets
+			.iter()
+			.filter_map(|rm| {
+				rm.value().iter().rev().find_map(|msg| {
+					if msg.target == msg.recipient
+						|| msg.created_at < expired_before
+					{
+						return None;
+					}
+					if msg.sender_id == identity && msg.recipient == nickname {
+						Some((msg.created_at, msg.recipient_id.clone()))
+					} else if msg.recipient_id == identity
+						&& msg.sender == nickname
+					{
+						Some((msg.created_at, msg.sender_id.clone()))
+					} else {
+						None
+					}
+				})
+			})
+			.max_by_key(|(created_at, _)| *created_at)
+			.map(|(_, peer_identity)| peer_identity);
+
+		Ok(peer)
+	}
+}
@@ -0,0 +1,202 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::types::{time, uuid};
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+
+use super::{ChatHistoryRepository, ChatHistoryTarget};
+use crate::config::chat::FlexChatConfigHistory;
+use crate::features::chat::chathistory::ChatHistoryEntity;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ChatHistoryRepositoryPostgreSQL
+{
+	pub database: DatabaseService<PostgreSQLDatabase>,
+	pub retention: FlexChatConfigHistory,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChatHistoryRepositoryPostgreSQL
+{
+	/// Nom de la table de ce repository.
+	pub const TABLE_NAME: &'static str = "chat_history";
+
+	/// Date en deçà de laquelle les messages ont expiré.
+	fn expired_before(&self) -> time::DateTime<time::Utc>
+	{
+		let max_age = chrono::TimeDelta::seconds(
+			i64::try_from(self.retention.max_age).unwrap_or(i64::MAX),
+		);
+		time::Utc::now()
+			.checked_sub_signed(max_age)
+			.unwrap_or(time::DateTime::<time::Utc>::MIN_UTC)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[flex_web_framework::async_trait]
+impl ChatHistoryRepository for ChatHistoryRepositoryPostgreSQL
+{
+	async fn create(
+		&self,
+		message: ChatHistoryEntity,
+	) -> Result<(), sqlx::Error>
+	{
+		let sql = format!(
+			"INSERT INTO {} (id, target, sender, sender_id, recipient, \
+			 recipient_id, command, payload, created_at) VALUES ($1, $2, $3, \
+			 $4, $5, $6, $7, $8, $9)",
+			Self::TABLE_NAME,
+		);
+		sqlx::query(&sql)
+			.bind(message.id)
+			.bind(&message.target)
+			.bind(&message.sender)
+			.bind(&message.sender_id)
+			.bind(&message.recipient)
+			.bind(&message.recipient_id)
+			.bind(&message.command)
+			.bind(&message.payload)
+			.bind(message.created_at)
+			.execute(self.database.pool())
+			.await?;
+
+		let sql = format!(
+			"DELETE FROM {table} WHERE target = $1 AND (created_at < $2 OR id \
+			 IN (SELECT id FROM {table} WHERE target = $1 ORDER BY created_at \
+			 DESC OFFSET $3))",
+			table = Self::TABLE_NAME,
+		);
+		sqlx::query(&sql)
+			.bind(&message.target)
+			.bind(self.expired_before())
+			.bind(
+				i64::try_from(self.retention.max_messages).unwrap_or(i64::MAX),
+			)
+			.execute(self.database.pool())
+			.await?;
+
+		Ok(())
+	}
+
+	async fn find_by_id(
+		&self,
+		target: &str,
+		id: &uuid::Uuid,
+	) -> Result<ChatHistoryEntity, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT * FROM {} WHERE target = $1 AND id = $2 AND created_at >= \
+			 $3",
+			Self::TABLE_NAME,
+		);
+		sqlx::query_as(&sql)
+			.bind(target)
+			.bind(id)
+			.bind(self.expired_before())
+			.fetch_one(self.database.pool())
+			.await
+	}
+
+	async fn select(
+		&self,
+		target: &str,
+		after: Option<time::DateTime<time::Utc>>,
+		before: Option<time::DateTime<time::Utc>>,
+		latest: bool,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryEntity>, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT * FROM {} WHERE target = $1 AND created_at >= $2 AND \
+			 ($3::timestamptz IS NULL OR created_at > $3) AND \
+			 ($4::timestamptz IS NULL OR created_at < $4) ORDER BY created_at \
+			 {} LIMIT $5",
+			Self::TABLE_NAME,
+			if latest { "DESC" } else { "ASC" },
+		);
+
+		let mut messages: Vec<ChatHistoryEntity> = sqlx::query_as(&sql)
+			.bind(target)
+			.bind(self.expired_before())
+			.bind(after)
+			.bind(before)
+			.bind(i64::try_from(limit).unwrap_or(i64::MAX))
+			.fetch_all(self.database.pool())
+			.await?;
+
+		if latest {
+			messages.reverse();
+		}
+
+		Ok(messages)
+	}
+
+	async fn targets(
+		&self,
+		identity: &str,
+		channels: &[String],
+		start: time::DateTime<time::Utc>,
+		end: time::DateTime<time::Utc>,
+		limit: usize,
+	) -> Result<Vec<ChatHistoryTarget>, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT CASE WHEN target = recipient OR sender_id = $1 THEN \
+			 recipient ELSE sender END AS name, MAX(created_at) AS latest \
+			 FROM {} WHERE created_at > $2 AND created_at < $3 AND ((target = \
+			 recipient AND recipient = ANY($4)) OR (target <> recipient AND \
+			 (sender_id = $1 OR recipient_id = $1))) GROUP BY name ORDER BY \
+			 latest ASC LIMIT $5",
+			Self::TABLE_NAME,
+		);
+
+		sqlx::query_as(&sql)
+			.bind(identity)
+			.bind(start.min(end).max(self.expired_before()))
+			.bind(start.max(end))
+			.bind(channels)
+			.bind(i64::try_from(limit).unwrap_or(i64::MAX))
+			.fetch_all(self.database.pool())
+			.await
+	}
+
+	async fn find_private_peer(
+		&self,
+		identity: &str,
+		nickname: &str,
+	) -> Result<Option<String>, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT CASE WHEN sender_id = $1 AND recipient = $2 THEN \
+			 recipient_id ELSE sender_id END FROM {} WHERE target <> \
+			 recipient AND created_at >= $3 AND ((sender_id = $1 AND \
+			 recipient = $2) OR (recipient_id = $1 AND sender = $2)) ORDER BY \
+			 created_at DESC LIMIT 1",
+			Self::TABLE_NAME,
+		);
+
+		sqlx::query_scalar(&sql)
+			.bind(identity)
+			.bind(nickname.to_lowercase())
+			.bind(self.expired_before())
+			.fetch_optional(self.database.pool())
+			.await
+	}
+}
@@ -0,0 +1,149 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+use flex_web_framework::types::uuid;
+
+use crate::features::chat::chathistory::{
+	ChatHistoryEntity,
+	ChatHistoryTags,
+	ChatHistoryTarget,
+};
+
+command_response! {
+	/// Début (`+<reference>`) ou fin (`-<reference>`) d'un lot de réponses.
+	struct BATCH<'reference, 'kind, 'params>
+	{
+		/// Référence du lot, préfixée par `+` ou `-`.
+		reference: &'reference str,
+		/// Type du lot, au début du lot.
+		kind: Option<&'kind str>,
+		/// Paramètres du lot, au début du lot.
+		params: &'params [&'params str],
+	}
+
+	/// Une cible de l'historique (`CHATHISTORY TARGETS`).
+	struct CHATHISTORY<'subcommand, 'target>
+	{
+		/// La sous-commande.
+		subcommand: &'subcommand str,
+		/// Nom du salon ou pseudonyme de l'interlocuteur.
+		target: &'target str,
+		/// Date du dernier message de la cible.
+		timestamp: String,
+	}
+
+	/// Réponse standard d'échec d'une commande.
+	struct FAIL<'command, 'code, 'context, 'description>
+	{
+		/// La commande en échec.
+		command: &'command str,
+		/// Code de l'échec.
+		code: &'code str,
+		/// Contexte de l'échec.
+		context: &'context [&'context str],
+		/// Description de l'échec.
+		description: &'description str,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ChatHistoryClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client un lot de réponses, encadré par deux réponses BATCH.
+	fn emit_batch(&self, kind: &str, params: &[&str], emit: impl FnOnce(&str))
+	{
+		let origin = Origin::from(self.client());
+		let reference = uuid::Uuid::new_v4().simple().to_string();
+
+		let start_reference = format!("+{reference}");
+		let batch_start = BatchCommandResponse {
+			origin: &origin,
+			tags: BatchCommandResponse::default_tags(),
+			reference: &start_reference,
+			kind: Some(kind),
+			params,
+		};
+		self.emit(batch_start.name(), batch_start);
+
+		emit(&reference);
+
+		let end_reference = format!("-{reference}");
+		let batch_end = BatchCommandResponse {
+			origin: &origin,
+			tags: BatchCommandResponse::default_tags(),
+			reference: &end_reference,
+			kind: None,
+			params: &[],
+		};
+		self.emit(batch_end.name(), batch_end);
+	}
+
+	/// Émet au client les messages de l'historique d'une cible.
+	fn send_chathistory(&self, target: &str, messages: &[ChatHistoryEntity])
+	{
+		self.emit_batch("chathistory", &[target], |batch| {
+			for message in messages {
+				self.emit(&message.command, message.payload_with_batch(batch));
+			}
+		});
+	}
+
+	/// Émet au client les cibles de son historique.
+	fn send_chathistory_targets(&self, targets: &[ChatHistoryTarget])
+	{
+		let origin = Origin::from(self.client());
+
+		self.emit_batch("draft/chathistory-targets", &[], |batch| {
+			for target in targets {
+				let chathistory_command = ChathistoryCommandResponse {
+					origin: &origin,
+					tags: ChathistoryCommandResponse::default_tags(),
+					subcommand: "TARGETS",
+					target: &target.name,
+					timestamp: ChatHistoryTags::format_time(&target.latest),
+				}
+				.with_tags([("batch", batch)]);
+				self.emit(chathistory_command.name(), chathistory_command);
+			}
+		});
+	}
+
+	/// Émet au client un échec de la commande CHATHISTORY.
+	fn send_fail_chathistory(
+		&self,
+		code: &str,
+		context: &[&str],
+		description: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let fail_command = FailCommandResponse {
+			origin: &origin,
+			tags: FailCommandResponse::default_tags(),
+			command: "CHATHISTORY",
+			code,
+			context,
+			description,
+		};
+		self.emit(fail_command.name(), fail_command);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ChatHistoryClientSocketCommandResponseInterface for Socket<'s> {}
@@ -8,6 +8,8 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::net::{IpAddr, SocketAddr};
+
 use flex_chat::channel::{Channel, ChannelInterface};
 use flex_chat::client::{
 	ClientInterface,
@@ -15,9 +17,12 @@ use flex_chat::client::{
 	ClientsSessionInterface,
 	Socket,
 };
-use flex_web_framework::extract::InsecureClientIp;
+use flex_chat::user::Host;
+use flex_web_framework::http::request::ConnectInfo;
+use flex_web_framework::{ServerSettings, http};
 
 use super::sessions::ConnectClientsSessionInterface;
+use crate::config::chat::FlexChatConfig;
 use crate::features::ChatApplication;
 
 // --------- //
@@ -35,15 +40,26 @@ pub trait ConnectApplicationInterface
 	fn create_client(
 		&self,
 		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
 	) -> <Self::ClientSocket<'_> as ClientSocketInterface>::Client;
 
 	/// Crée une nouvelle session d'un client à partir d'une socket.
 	fn create_client_with_id(
 		&self,
 		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
 		cid: <<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
 	) -> <Self::ClientSocket<'_> as ClientSocketInterface>::Client;
 
+	/// L'adresse IP réelle du client d'une socket. Les en-têtes de proxy
+	/// (`X-Forwarded-For`, `X-Real-IP`) ne sont pris en compte que lorsque la
+	/// socket provient d'un proxy de confiance.
+	fn client_ip(
+		&self,
+		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
+	) -> Option<IpAddr>;
+
 	/// Peut-on localiser un client de session non enregistré?
 	fn can_locate_unregistered_client(
 		&self,
@@ -69,6 +85,13 @@ pub trait ConnectApplicationInterface
 		&self,
 		client: &<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
 	);
+
+	/// Est-ce que la connexion du client est sécurisée?
+	fn is_secure_connection(
+		&self,
+		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
+	) -> bool;
 }
 
 // -------------- //
@@ -83,14 +106,12 @@ impl ConnectApplicationInterface for ChatApplication
 	fn create_client(
 		&self,
 		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
 	) -> <Self::ClientSocket<'_> as ClientSocketInterface>::Client
 	{
-		// TODO: SecureClientIp ?
-		let InsecureClientIp(ip) = InsecureClientIp::from(
-			&socket.req_parts().headers,
-			&socket.req_parts().extensions,
-		)
-		.expect("Adresse IP de la Socket");
+		let ip = self
+			.client_ip(socket, server_settings)
+			.expect("Adresse IP de la Socket");
 		let sid = socket.id;
 		self.clients.create(ip, sid)
 	}
@@ -98,19 +119,39 @@ impl ConnectApplicationInterface for ChatApplication
 	fn create_client_with_id(
 		&self,
 		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
 		cid: <<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
 	) -> <Self::ClientSocket<'_> as ClientSocketInterface>::Client
 	{
-		// TODO: SecureClientIp ?
-		let InsecureClientIp(ip) = InsecureClientIp::from(
-			&socket.req_parts().headers,
-			&socket.req_parts().extensions,
-		)
-		.expect("Adresse IP de la Socket");
+		let ip = self
+			.client_ip(socket, server_settings)
+			.expect("Adresse IP de la Socket");
 		let sid = socket.id;
 		self.clients.create_with_id(ip, sid, cid)
 	}
 
+	fn client_ip(
+		&self,
+		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
+	) -> Option<IpAddr>
+	{
+		let req_parts = socket.req_parts();
+
+		let peer_ip = peer_ip(&req_parts.extensions)?;
+
+		let header = |name: &str| {
+			req_parts.headers.get(name).and_then(|value| value.to_str().ok())
+		};
+
+		Some(Host::client_ip(
+			peer_ip,
+			is_trusted_proxy(&req_parts.extensions, peer_ip, server_settings),
+			header("x-forwarded-for"),
+			header("x-real-ip"),
+		))
+	}
+
 	fn can_locate_unregistered_client(
 		&self,
 		client: &<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
@@ -147,4 +188,68 @@ impl ConnectApplicationInterface for ChatApplication
 		self.clients.upgrade(client);
 		self.clients.register(client);
 	}
+
+	fn is_secure_connection(
+		&self,
+		socket: &<Self::ClientSocket<'_> as ClientSocketInterface>::Socket,
+		server_settings: &ServerSettings,
+	) -> bool
+	{
+		let req_parts = socket.req_parts();
+
+		let is_trusted_proxy =
+			peer_ip(&req_parts.extensions).is_some_and(|ip| {
+				is_trusted_proxy(&req_parts.extensions, ip, server_settings)
+			});
+
+		if is_trusted_proxy {
+			return is_forwarded_secure_connection(&req_parts.headers);
+		}
+
+		server_settings.tls.is_some()
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// L'adresse IP du pair de la connexion, qui ne peut être falsifiée par le
+/// client, contrairement aux en-têtes de proxy.
+fn peer_ip(extensions: &http::Extensions) -> Option<IpAddr>
+{
+	extensions
+		.get::<ConnectInfo<SocketAddr>>()
+		.map(|ConnectInfo(peer_addr)| peer_addr.ip())
+}
+
+/// Est-ce que le pair de la connexion est un proxy de confiance : un proxy
+/// configuré par `trusted_proxies` ou la passerelle IRC, qui se connecte
+/// localement au serveur.
+fn is_trusted_proxy(
+	extensions: &http::Extensions,
+	peer_ip: IpAddr,
+	server_settings: &ServerSettings,
+) -> bool
+{
+	extensions.get::<FlexChatConfig>().is_some_and(|config| {
+		config.server.trusted_proxies.contains(&peer_ip)
+			|| (config.irc.is_some()
+				&& (peer_ip.is_loopback() || peer_ip == server_settings.ip))
+	})
+}
+
+/// Est-ce que la connexion transmise par un proxy de confiance (passerelle
+/// IRC, reverse proxy) est sécurisée? Le proxy transmet le protocole
+/// d'origine par l'en-tête `X-Forwarded-Proto`.
+fn is_forwarded_secure_connection(headers: &http::HeaderMap) -> bool
+{
+	if let Some(proto) = headers.get("x-forwarded-proto") {
+		return proto.as_bytes().eq_ignore_ascii_case(b"https");
+	}
+
+	headers
+		.get(http::header::ORIGIN)
+		.and_then(|origin| origin.to_str().ok())
+		.is_some_and(|origin| origin.starts_with("https://"))
 }
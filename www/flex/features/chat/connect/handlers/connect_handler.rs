@@ -14,7 +14,16 @@ use flex_chat::client::{
 	Origin,
 	Socket,
 };
-use flex_chat::user::{UserInterface, UserOperatorInterface};
+use flex_chat::mode::ApplyMode;
+use flex_chat::user::{
+	Flag,
+	ServerBanKind,
+	ServerNoticeMask,
+	UserAddressInterface,
+	UserFlagInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
 use flex_crypto::Hasher;
 use flex_web_framework::http::Cookies;
 use flex_web_framework::security::Argon2Password;
@@ -23,10 +32,20 @@ use flex_web_framework::types::time::TimeZone;
 use socketioxide::extract::{SocketRef, State, TryData};
 
 use crate::config::chat::FlexChatConfig;
+use crate::features::chat::cap::CapHandler;
 use crate::features::chat::connect::*;
+use crate::features::chat::monitor::MonitorApplicationInterface;
+use crate::features::chat::nick_registration::NickRegistrationApplicationInterface;
 use crate::features::chat::oper::*;
 use crate::features::chat::quit::*;
+use crate::features::chat::sasl::AuthenticateHandler;
+use crate::features::chat::server_ban::ServerBanApplicationInterface;
+use crate::features::chat::server_info::*;
 use crate::features::chat::silence::*;
+use crate::features::chat::user_mode::{
+	UserModeApplicationInterface,
+	UserModeClientSocketCommandResponse,
+};
 use crate::features::ChatApplication;
 use crate::FlexApplicationState;
 
@@ -50,17 +69,39 @@ impl ConnectionRegistrationHandler
 		TryData(data): TryData<RememberUserFormData>,
 	)
 	{
+		// NOTE: une adresse IP bannie (ZLINE) est refusée avant même
+		//       l'enregistrement de la connexion.
+		let server_settings = server_state.get_server_settings();
+		if let Some((ip_addr, zline)) =
+			app.find_zline_of_socket(socket, server_settings)
+		{
+			app.emit_server_notice_from_server(
+				socket,
+				ServerNoticeMask::Bans,
+				&format!(
+					"Connexion refusée de {}: {} sur {}",
+					ip_addr, zline.kind, zline.mask,
+				),
+			);
+			app.reject_banned_socket(socket, &zline);
+			return;
+		}
+
 		let maybe_user_id = data.as_ref().cloned().ok().and_then(|d| d.user_id);
 		let maybe_client_id =
 			data.as_ref().cloned().ok().and_then(|d| d.client_id);
 
 		let new_client = || {
-			let client = if let Some(user_id) = maybe_user_id {
-				app.create_client_with_id(socket, user_id)
+			let mut client = if let Some(user_id) = maybe_user_id {
+				app.create_client_with_id(socket, server_settings, user_id)
 			} else {
-				app.create_client(socket)
+				app.create_client(socket, server_settings)
 			};
 
+			if app.is_secure_connection(socket, server_settings) {
+				client.user_mut().set_flag(ApplyMode::new(Flag::Secure));
+			}
+
 			socket.extensions.insert(client);
 
 			// NOTE(phisyx): ces événements ne peuvent être envoyés qu'à la
@@ -70,6 +111,10 @@ impl ConnectionRegistrationHandler
 			socket.on(PassHandler::COMMAND_NAME, PassHandler::handle);
 			socket.on(UserHandler::COMMAND_NAME, UserHandler::handle);
 			socket.on(UNickHandler::COMMAND_NAME, UNickHandler::handle);
+			socket.on(
+				AuthenticateHandler::COMMAND_NAME,
+				AuthenticateHandler::handle,
+			);
 		};
 
 		let already_existing_client = |mut client_socket: Socket| {
@@ -93,6 +138,7 @@ impl ConnectionRegistrationHandler
 			.signed()
 			.get(TokenController::COOKIE_TOKEN_KEY);
 
+		socket.on(CapHandler::COMMAND_NAME, CapHandler::handle);
 		socket.on_disconnect(QuitHandler::handle_disconnect);
 
 		let Some(client) = (if let Some((user_id, token)) =
@@ -119,7 +165,7 @@ impl ConnectionRegistrationHandler
 	/// Compléter l'enregistrement d'un client.
 	pub fn complete_registration(
 		_server_state: &FlexApplicationState,
-		app: &ChatApplication,
+		app: &'static ChatApplication,
 		mut client_socket: Socket,
 	) -> Option<()>
 	{
@@ -129,6 +175,12 @@ impl ConnectionRegistrationHandler
 			return Some(());
 		}
 
+		// NOTE(phisyx): l'enregistrement est suspendu tant que le client
+		//               négocie ses capacités serveur (`CAP END`).
+		if client_socket.client().is_negotiating_capabilities() {
+			return Some(());
+		}
+
 		let config = client_socket
 			.socket()
 			.req_parts()
@@ -173,6 +225,29 @@ impl ConnectionRegistrationHandler
 			}
 		}
 
+		// NOTE: les utilisateurs bannis du serveur (KLINE, GLINE, ZLINE) sont
+		//       refusés.
+		if let Some(server_ban) = app.find_server_ban_of_client(
+			&client_socket,
+			&[
+				ServerBanKind::Kline,
+				ServerBanKind::Gline,
+				ServerBanKind::Zline,
+			],
+		) {
+			client_socket.emit_server_notice(
+				ServerNoticeMask::Bans,
+				&format!(
+					"Connexion refusée de {}: {} sur {}",
+					client_socket.user().full_address(),
+					server_ban.kind,
+					server_ban.mask,
+				),
+			);
+			app.reject_banned_socket(client_socket.socket(), &server_ban);
+			return None;
+		}
+
 		//
 		// NOTE(phisyx): Enregistrer le client dans la session.
 		//
@@ -198,6 +273,23 @@ impl ConnectionRegistrationHandler
 			.socket()
 			.join(client_socket.client().private_room());
 
+		// NOTE: un client reconnecté retrouve les chambres de ses drapeaux
+		//       utilisateurs.
+		app.join_user_modes_rooms(&client_socket);
+
+		// NOTE(phisyx): notifie les clients surveillant le pseudonyme du
+		//               client (MONITOR).
+		app.notify_monitor_online(&client_socket);
+
+		// NOTE: notifie les opérateurs abonnés aux notices des connexions.
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Connects,
+			&format!(
+				"Connexion du client {}",
+				client_socket.user().full_address()
+			),
+		);
+
 		//
 		// NOTE(phisyx): Émettre au client les messages de connexions.
 		//
@@ -214,6 +306,14 @@ impl ConnectionRegistrationHandler
 				time::Utc::now()
 			},
 		);
+		// Server info
+		client_socket.send_rpl_myinfo(&config.server.name);
+		client_socket.send_rpl_isupport(&app.isupport_tokens(&config));
+
+		// NOTE(phisyx): transmet à l'utilisateur les statistiques du serveur
+		//               et le message du jour.
+		app.send_lusers(&client_socket);
+		app.send_motd(&client_socket, &config);
 
 		// NOTE(phisyx): transmet à l'utilisateur ses modes utilisateurs.
 		client_socket.emit_all_user_modes();
@@ -244,6 +344,19 @@ impl ConnectionRegistrationHandler
 			client_socket.emit_silence(&users, None);
 		}
 
+		// NOTE(phisyx): protège le pseudonyme du client s'il est enregistré
+		//               par un autre compte.
+		app.protect_nickname(&client_socket);
+
+		// NOTE: les commandes des utilisateurs ignorés (SHUN) ne sont pas
+		//       traitées.
+		if app
+			.find_server_ban_of_client(&client_socket, &[ServerBanKind::Shun])
+			.is_some()
+		{
+			app.shun_socket(client_socket.socket());
+		}
+
 		Some(())
 	}
 }
@@ -9,7 +9,8 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::client::{ClientInterface, ClientSocketInterface};
-use flex_chat::user::UserInterface;
+use flex_chat::mode::ApplyMode;
+use flex_chat::user::{Flag, UserFlagInterface, UserInterface};
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::chat::connect::forms::UserCommandFormData;
@@ -57,6 +58,14 @@ impl UserHandler
 			} else {
 				client_socket.user_mut().set_realname(data.realname);
 
+				// NOTE: le bit 3 du paramètre `<mode>` définit le mode
+				//       utilisateur 'i' (invisible).
+				if data.mode & 0b1000 != 0 {
+					client_socket
+						.user_mut()
+						.set_flag(ApplyMode::new(Flag::Invisible));
+				}
+
 				ConnectionRegistrationHandler::complete_registration(
 					server_state,
 					app,
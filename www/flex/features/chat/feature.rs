@@ -8,44 +8,168 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_web_framework::{Feature, WebSocketFeature};
+use std::ops;
+use std::sync::Arc;
+
+use flex_web_framework::{
+	AxumRouter,
+	Config,
+	Feature,
+	WebSocketFeature,
+	WebSocketHandlersInterface,
+	http,
+};
+use socketioxide::SocketIo;
 use socketioxide::extract::{SocketRef, State, TryData};
 
 use crate::features::chat::auth::*;
+use crate::features::chat::channel_registration::*;
+use crate::features::chat::chathistory::*;
 use crate::features::chat::connect::*;
 use crate::features::chat::invite::*;
+use crate::features::chat::irc::*;
 use crate::features::chat::join::*;
 use crate::features::chat::kick::*;
 use crate::features::chat::kill::*;
+use crate::features::chat::knock::*;
 use crate::features::chat::list::*;
 use crate::features::chat::message::*;
 use crate::features::chat::mode::*;
+use crate::features::chat::monitor::*;
 use crate::features::chat::nick::*;
+use crate::features::chat::nick_registration::*;
 use crate::features::chat::notice::*;
 use crate::features::chat::oper::*;
 use crate::features::chat::part::*;
 use crate::features::chat::quit::*;
+use crate::features::chat::server_ban::*;
+use crate::features::chat::server_info::*;
 use crate::features::chat::silence::*;
 use crate::features::chat::topic::*;
+use crate::features::chat::user_mode::*;
 use crate::features::chat::user_status::*;
+use crate::features::chat::wallops::*;
+use crate::features::chat::who::*;
+use crate::features::chat::whois::*;
 use crate::features::chat::{routes, sessions};
 use crate::{config, FlexApplicationState, FlexState};
 
+// ---- //
+// Type //
+// ---- //
+
+/// Les gestionnaires des commandes des utilisateurs et des salons.
+type ChatCommandHandlers = (
+	AwayHandler,
+	InviteHandler,
+	IsonHandler,
+	JoinHandler,
+	KickHandler,
+	KillHandler,
+	ListHandler,
+	MonitorHandler,
+	NickHandler,
+	NoticeHandler,
+	OperHandler,
+	PartHandler,
+	PrivmsgHandler,
+	PubmsgHandler,
+	QuitHandler,
+	SajoinHandler,
+	SapartHandler,
+	SilenceHandler,
+	TopicHandler,
+	UserhostHandler,
+	WhoHandler,
+	WhoisHandler,
+	WhowasHandler,
+	/* Channel Modes */
+	ModeChannelSettingsHandler,
+	/* Auth */
+	AuthIdentifyHandler,
+	AuthLogoutHandler,
+);
+
+/// Les gestionnaires des commandes des informations du serveur et des
+/// fonctionnalités des salons.
+type ChatServerHandlers = (
+	/* Server Info */
+	AdminHandler,
+	InfoHandler,
+	LusersHandler,
+	MotdHandler,
+	TimeHandler,
+	VersionHandler,
+	/* Channel Registration */
+	ChannelRegisterHandler,
+	ChannelUnregisterHandler,
+	/* Channel Knock */
+	KnockHandler,
+	/* Channel Invite */
+	InviteListHandler,
+	UninviteHandler,
+	/* Channel Topic */
+	TopicHistoryHandler,
+	/* Server Ban */
+	StatsHandler,
+	/* Operator Override */
+	SamodeHandler,
+	SanickHandler,
+	SaquitHandler,
+	SatopicHandler,
+	/* User Modes */
+	UserModeHandler,
+	/* Wallops */
+	GlobopsHandler,
+	WallopsHandler,
+);
+
 // --------- //
 // Structure //
 // --------- //
 
+/// L'application du Chat. Ses clones partagent les mêmes sessions.
+#[derive(Clone)]
 #[derive(Default)]
 pub struct ChatApplication
 {
+	sessions: Arc<ChatSessions>,
+}
+
+/// Les sessions de l'application du Chat.
+#[derive(Default)]
+pub struct ChatSessions
+{
+	pub(crate) access_control_expiry: ChannelAccessControlExpirySession,
 	pub(crate) channels: sessions::ChannelsSession,
 	pub(crate) clients: sessions::ClientsSession,
+	pub(crate) channel_registration: ChannelRegistrationSession,
+	pub(crate) history: ChatHistorySession,
+	pub(crate) knock_throttle: KnockThrottleSession,
+	pub(crate) nick_registration: NickRegistrationSession,
+	pub(crate) server_bans: ServerBanSession,
 }
 
+/// Les gestionnaires des commandes du Chat.
+///
+/// NOTE(phisyx): les tuples de gestionnaires sont limités à 26 éléments, les
+///               gestionnaires sont donc répartis en plusieurs tuples.
+pub struct ChatHandlers;
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl ops::Deref for ChatApplication
+{
+	type Target = ChatSessions;
+
+	fn deref(&self) -> &Self::Target
+	{
+		&self.sessions
+	}
+}
+
 impl Feature for ChatApplication
 {
 	type Config = config::chat::FlexChatConfig;
@@ -53,51 +177,82 @@ impl Feature for ChatApplication
 	type State = FlexState;
 
 	const NAME: &'static str = "ChatApplication";
+
+	fn register_services(
+		config: &Config<Self::Config>,
+		state: &mut FlexApplicationState,
+		router: AxumRouter<Self::State>,
+	) -> AxumRouter<Self::State>
+	{
+		IrcGateway::spawn(
+			&config.user,
+			state.get_server_settings(),
+			<Self as WebSocketFeature<FlexState>>::ENDPOINT,
+		);
+		router
+	}
 }
 
 impl WebSocketFeature<FlexState> for ChatApplication
 {
 	type Auth = RememberUserFormData;
-	type Handlers = (
-		AwayHandler,
-		InviteHandler,
-		JoinHandler,
-		KickHandler,
-		KillHandler,
-		ListHandler,
-		NickHandler,
-		NoticeHandler,
-		OperHandler,
-		PartHandler,
-		PrivmsgHandler,
-		PubmsgHandler,
-		QuitHandler,
-		SajoinHandler,
-		SapartHandler,
-		SilenceHandler,
-		TopicHandler,
-		/* Channel Modes */
-		ModeChannelSettingsHandler,
-		/* Auth */
-		AuthIdentifyHandler,
-		AuthLogoutHandler,
-	);
+	type Handlers = ChatHandlers;
 	type Handlers2 = (
 		/* Channel Access Control */
 		ModeChannelAccessControlBanHandler,
 		ModeChannelAccessControlBanExceptionHandler,
 		ModeChannelAccessControlInviteExceptionHandler,
+		ModeChannelAccessControlQuietHandler,
 		/* Channel Access Level */
 		ModeChannelAccessLevelQOPHandler,
 		ModeChannelAccessLevelAOPHandler,
 		ModeChannelAccessLevelOPHandler,
 		ModeChannelAccessLevelHOPHandler,
 		ModeChannelAccessLevelVIPHandler,
+		/* Server Ban */
+		ServerBanKlineHandler,
+		ServerBanGlineHandler,
+		ServerBanZlineHandler,
+		ServerBanShunHandler,
 	);
 	type State = Self;
 
 	const ENDPOINT: &'static str = "/chat:ws";
 
+	async fn on_start(
+		io: SocketIo,
+		app: &'static <Self as WebSocketFeature<FlexState>>::State,
+		extensions: http::Extensions,
+	) -> Result<(), String>
+	{
+		// NOTE: les bannissements du serveur DOIVENT être chargés avant
+		//       d'accepter la moindre connexion, sans quoi un client banni
+		//       pourrait se connecter au démarrage du serveur.
+		app.load_server_bans(&extensions).await.map_err(|error| {
+			tracing::error!(
+				?error,
+				"Impossible de charger les bannissements du serveur"
+			);
+			String::from("Le serveur n'est pas disponible")
+		})?;
+
+		// NOTE: les salons enregistrés DOIVENT être chargés avant qu'un
+		//       client ne puisse rejoindre (et ainsi recréer) l'un d'eux.
+		app.load_registered_channels(&extensions).await.map_err(|error| {
+			tracing::error!(
+				?error,
+				"Impossible de charger les salons enregistrés"
+			);
+			String::from("Le serveur n'est pas disponible")
+		})?;
+
+		// NOTE: retire des salons les masques expirés des listes de contrôle
+		//       d'accès (une seule tâche pour tout le serveur).
+		app.expire_access_controls(&extensions, io);
+
+		Ok(())
+	}
+
 	fn on_connect(
 		socket: SocketRef,
 		server_state: State<FlexApplicationState>,
@@ -113,3 +268,42 @@ impl WebSocketFeature<FlexState> for ChatApplication
 		);
 	}
 }
+
+impl WebSocketHandlersInterface for ChatHandlers
+{
+	fn listen(socket: &SocketRef)
+	{
+		<ChatCommandHandlers as WebSocketHandlersInterface>::listen(socket);
+		<ChatServerHandlers as WebSocketHandlersInterface>::listen(socket);
+
+		// NOTE(phisyx): les gestionnaires asynchrones n'implémentent pas
+		//               `WebSocketHandler`.
+		socket.on(ChatHistoryHandler::COMMAND_NAME, ChatHistoryHandler::handle);
+		socket.on(GhostHandler::COMMAND_NAME, GhostHandler::handle);
+		socket.on(NickGroupHandler::COMMAND_NAME, NickGroupHandler::handle);
+		socket.on(
+			NickGroupListHandler::COMMAND_NAME,
+			NickGroupListHandler::handle,
+		);
+		socket.on(NickUngroupHandler::COMMAND_NAME, NickUngroupHandler::handle);
+		socket.on(RecoverHandler::COMMAND_NAME, RecoverHandler::handle);
+	}
+
+	fn events() -> Vec<&'static str>
+	{
+		let mut events =
+			<ChatCommandHandlers as WebSocketHandlersInterface>::events();
+		events.extend(
+			<ChatServerHandlers as WebSocketHandlersInterface>::events(),
+		);
+		events.extend([
+			ChatHistoryHandler::COMMAND_NAME,
+			GhostHandler::COMMAND_NAME,
+			NickGroupHandler::COMMAND_NAME,
+			NickGroupListHandler::COMMAND_NAME,
+			NickUngroupHandler::COMMAND_NAME,
+			RecoverHandler::COMMAND_NAME,
+		]);
+		events
+	}
+}
@@ -10,15 +10,19 @@
 
 use std::borrow::Cow;
 
+use chrono::TimeDelta;
 use flex_chat::channel::{
 	Channel,
 	ChannelAccessControlInviteInterface,
 	ChannelInterface,
+	ChannelInvite,
 	ChannelsSessionInterface,
 };
 use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::UserInterface;
 
 use crate::ChatApplication;
+use crate::config::chat::FlexChatConfig;
 
 // --------- //
 // Interface //
@@ -32,13 +36,30 @@ where
 	type ClientSocket<'cs>: ClientSocketInterface;
 
 	/// Ajoute un utilisateur dans la liste des invitations d'un salon.
+	/// L'invitation expire après la durée configurée.
 	fn add_user_to_invite_channel(
 		&self,
+		client_socket: &Self::ClientSocket<'_>,
 		channel_id: impl Into<
 			Cow<'a, <Self::Channel as ChannelInterface>::RefID<'a>>,
 		>,
 		user_invite_id: <<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
 	);
+
+	/// Les salons sur lesquels un client a une invitation en attente.
+	fn pending_invites_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Vec<String>;
+
+	/// Retire un utilisateur de la liste des invitations d'un salon.
+	fn remove_user_from_invite_channel(
+		&self,
+		channel_id: impl Into<
+			Cow<'a, <Self::Channel as ChannelInterface>::RefID<'a>>,
+		>,
+		user_invite_id: &<<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
+	) -> bool;
 }
 
 // -------------- //
@@ -52,17 +73,64 @@ impl<'a> InviteApplicationInterface<'a> for ChatApplication
 
 	fn add_user_to_invite_channel(
 		&self,
+		client_socket: &Self::ClientSocket<'_>,
 		channel_id: impl Into<
 			Cow<'a, <Self::Channel as ChannelInterface>::RefID<'a>>,
 		>,
 		user_invite_id: <<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
 	)
 	{
+		let config = client_socket
+			.socket()
+			.req_parts()
+			.extensions
+			.get::<FlexChatConfig>()
+			.map(|config| config.invite.clone())
+			.unwrap_or_default();
+
+		let mut invite = ChannelInvite::new(client_socket.user().nickname());
+		if config.ttl > 0 {
+			invite =
+				invite.with_duration(TimeDelta::seconds(config.ttl as i64));
+		}
+
 		let chid: &<Self::Channel as ChannelInterface>::RefID<'a> =
 			&channel_id.into();
 		let Some(mut channel) = self.channels.get_mut(chid) else {
 			return;
 		};
-		channel.add_invite(user_invite_id);
+		channel.add_invite(user_invite_id, invite);
+	}
+
+	fn pending_invites_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Vec<String>
+	{
+		let mut channels: Vec<String> = self
+			.channels
+			.list()
+			.filter(|channel| channel.is_invited(client_socket.cid()))
+			.map(|channel| channel.name().to_owned())
+			.collect();
+		channels.sort();
+		channels
+	}
+
+	fn remove_user_from_invite_channel(
+		&self,
+		channel_id: impl Into<
+			Cow<'a, <Self::Channel as ChannelInterface>::RefID<'a>>,
+		>,
+		user_invite_id: &<<Self::ClientSocket<'_> as ClientSocketInterface>::Client as ClientInterface>::ClientID,
+	) -> bool
+	{
+		let chid: &<Self::Channel as ChannelInterface>::RefID<'a> =
+			&channel_id.into();
+		let Some(mut channel) = self.channels.get_mut(chid) else {
+			return false;
+		};
+		channel.is_invited(user_invite_id)
+			&& channel.remove_invite(user_invite_id)
 	}
 }
@@ -101,6 +101,7 @@ impl WebSocketHandler for InviteHandler
 		drop(channel);
 
 		app.add_user_to_invite_channel(
+			&client_socket,
 			data.channel.as_ref(),
 			*target_client_socket.cid(),
 		);
@@ -0,0 +1,51 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::invite::{
+	InviteApplicationInterface,
+	InviteClientSocketCommandResponseInterface,
+	InviteListCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct InviteListHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for InviteListHandler
+{
+	type App = ChatApplication;
+	type Data = InviteListCommandFormData;
+
+	const EVENT_NAME: &'static str = "INVITE LIST";
+
+	/// La commande INVITE, sans paramètre, est utilisée pour obtenir la
+	/// liste des salons sur lesquels le client a une invitation en attente.
+	/// Les invitations expirées ne sont pas listées.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(_): Data<InviteListCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+		let channels = app.pending_invites_of_client(&client_socket);
+		client_socket.send_rpl_invitelist(&channels);
+	}
+}
@@ -0,0 +1,94 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::ChannelAccessLevel;
+use flex_chat::client::ClientInterface;
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::invite::{
+	InviteApplicationInterface,
+	InviteChannelClientSocketErrorReplies,
+	UninviteClientSocketCommandResponseInterface,
+	UninviteCommandFormData,
+};
+use crate::features::chat::mode::ModeChannelAccessLevelApplicationInterface;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct UninviteHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for UninviteHandler
+{
+	type App = ChatApplication;
+	type Data = UninviteCommandFormData;
+
+	const EVENT_NAME: &'static str = "UNINVITE";
+
+	/// La commande UNINVITE est utilisée par un opérateur de salon pour
+	/// retirer l'invitation en attente d'un utilisateur sur le salon
+	/// <channel>. L'utilisateur <nickname> n'a pas besoin d'être connecté.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UninviteCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(target_client) = app.clients.get_by_nickname(&data.nickname)
+		else {
+			client_socket.send_err_nosuchnick(&data.nickname);
+			return;
+		};
+		let Some(channel) = app.get_channel(&data.channel) else {
+			client_socket.send_err_nosuchchannel(&data.channel);
+			return;
+		};
+		let channel_name = channel.name.to_string();
+
+		drop(channel);
+
+		if !app.does_client_have_rights_on_channel(
+			&client_socket,
+			&channel_name,
+			ChannelAccessLevel::Operator,
+		) {
+			client_socket.send_err_chanoprivsneeded(&channel_name);
+			return;
+		}
+
+		if !app.remove_user_from_invite_channel(
+			channel_name.as_str(),
+			target_client.cid(),
+		) {
+			client_socket.send_err_notinvited(&data.nickname, &channel_name);
+			return;
+		}
+
+		let target_client_socket =
+			app.find_socket_by_id(&socket, target_client.cid());
+
+		client_socket.emit_uninvite(
+			&channel_name,
+			&data.nickname,
+			target_client_socket.as_ref(),
+		);
+	}
+}
@@ -13,19 +13,28 @@ flex_kernel::import! {
 
 	pub mod handlers use {
 		pub mod invite_handler use *;
+		pub mod invite_list_handler use *;
+		pub mod uninvite_handler use *;
 	};
 
 	mod errors use {
 		pub mod err_inviteonlychan use *;
+		pub mod err_notinvited use *;
 	};
 
 	mod forms use {
 		pub mod invite_form use *;
+		pub mod invite_list_form use *;
+		pub mod uninvite_form use *;
 	};
 
 	pub(crate) mod responses use {
 		pub(super) mod invite_command_response use *;
 		pub(crate) mod invite_error_response use *;
+		pub(super) mod rpl_endofinvitelist use *;
+		pub(super) mod rpl_invitelist use *;
 		pub(super) mod rpl_inviting use *;
+		pub(super) mod rpl_uninvited use *;
+		pub(super) mod uninvite_command_response use *;
 	};
 }
@@ -13,7 +13,7 @@ use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 use flex_chat::macros::command_response;
 use flex_chat::user::UserInterface;
 
-use super::RplInvitingReply;
+use super::{RplEndofinvitelistReply, RplInvitelistReply, RplInvitingReply};
 
 command_response! {
 	struct INVITE<'channel, 'nick>
@@ -37,6 +37,10 @@ pub trait InviteClientSocketCommandResponseInterface:
 	/// Émet au client les réponses liées à la commande /INVITE (sender + recv).
 	fn emit_invite(&self, channel: &Self::Channel, target: &Self);
 
+	/// Émet au client la liste des salons sur lesquels il a une invitation
+	/// en attente.
+	fn send_rpl_invitelist(&self, channels: &[String]);
+
 	/// Émet au client les réponses liées à la commande /INVITE (sender).
 	fn send_rpl_inviting(&self, channel: &Self::Channel, target: &str);
 }
@@ -66,6 +70,26 @@ impl<'s> InviteClientSocketCommandResponseInterface for Socket<'s>
 		target.emit(invite_command.name(), invite_command);
 	}
 
+	fn send_rpl_invitelist(&self, channels: &[String])
+	{
+		let origin = Origin::from(self.client());
+
+		for channel in channels {
+			let rpl_invitelist = RplInvitelistReply {
+				origin: &origin,
+				tags: RplInvitelistReply::default_tags(),
+				channel,
+			};
+			self.emit(rpl_invitelist.name(), rpl_invitelist);
+		}
+
+		let rpl_endofinvitelist = RplEndofinvitelistReply {
+			origin: &origin,
+			tags: RplEndofinvitelistReply::default_tags(),
+		};
+		self.emit(rpl_endofinvitelist.name(), rpl_endofinvitelist);
+	}
+
 	fn send_rpl_inviting(&self, channel: &Self::Channel, target: &str)
 	{
 		let origin = Origin::from(self.client());
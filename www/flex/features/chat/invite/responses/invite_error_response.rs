@@ -11,7 +11,10 @@
 use flex_chat::channel::{Channel, ChannelInterface};
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 
-use crate::features::chat::invite::ErrInviteonlychanError;
+use crate::features::chat::invite::{
+	ErrInviteonlychanError,
+	ErrNotinvitedError,
+};
 
 // --------- //
 // Interface //
@@ -25,6 +28,12 @@ pub trait InviteChannelClientSocketErrorReplies: ClientSocketInterface
 		&self,
 		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
 	);
+
+	fn send_err_notinvited(
+		&self,
+		nick: &str,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
 }
 
 // -------------- //
@@ -48,4 +57,20 @@ impl<'s> InviteChannelClientSocketErrorReplies for Socket<'s>
 		};
 		self.emit(err_inviteonlychan.name(), err_inviteonlychan);
 	}
+
+	fn send_err_notinvited(
+		&self,
+		nick: &str,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_notinvited = ErrNotinvitedError {
+			origin: &origin,
+			tags: ErrNotinvitedError::default_tags(),
+			nick,
+			channel,
+		};
+		self.emit(err_notinvited.name(), err_notinvited);
+	}
 }
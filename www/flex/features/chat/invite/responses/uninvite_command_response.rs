@@ -0,0 +1,75 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+use super::RplUninvitedReply;
+
+command_response! {
+	struct UNINVITE<'channel>
+	{
+		/// Le salon dont l'invitation a été retirée.
+		channel: &'channel str,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait UninviteClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client les réponses liées à la commande /UNINVITE (sender).
+	/// L'utilisateur dont l'invitation a été retirée en est informé s'il
+	/// est connecté.
+	fn emit_uninvite(
+		&self,
+		channel_name: &str,
+		nick: &str,
+		target: Option<&Self>,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> UninviteClientSocketCommandResponseInterface for Socket<'s>
+{
+	fn emit_uninvite(
+		&self,
+		channel_name: &str,
+		nick: &str,
+		target: Option<&Self>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_uninvited = RplUninvitedReply {
+			origin: &origin,
+			tags: RplUninvitedReply::default_tags(),
+			channel: channel_name,
+			nick,
+		};
+		self.emit(rpl_uninvited.name(), rpl_uninvited);
+
+		let Some(target) = target else {
+			return;
+		};
+
+		let uninvite_command = UninviteCommandResponse {
+			origin: &origin,
+			tags: UninviteCommandResponse::default_tags(),
+			channel: channel_name,
+		};
+		target.emit(uninvite_command.name(), uninvite_command);
+	}
+}
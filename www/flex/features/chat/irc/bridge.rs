@@ -0,0 +1,282 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio_rustls::{TlsConnector, rustls};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Flux d'entrée/sortie d'une connexion (TCP ou TLS).
+pub trait IrcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+// ---- //
+// Type //
+// ---- //
+
+pub type BoxedIrcStream = Box<dyn IrcStream>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Informations de connexion au point d'entrée WebSocket du serveur de Chat.
+#[derive(Clone)]
+pub struct SocketIoUpstream
+{
+	/// Adresse du serveur HTTP.
+	addr: SocketAddr,
+	/// Point d'entrée de la WebSocket.
+	endpoint: &'static str,
+	/// Connecteur TLS lorsque le serveur HTTP est en HTTPS.
+	tls: Option<TlsConnector>,
+}
+
+/// Client Socket.IO connecté au serveur de Chat pour le compte d'un client
+/// IRC. Les événements émis passent par les mêmes handlers que ceux du
+/// client web.
+pub struct SocketIoBridge
+{
+	ws: WebSocketStream<BoxedIrcStream>,
+}
+
+/// Vérificateur de certificat qui n'accepte que le certificat du serveur
+/// HTTP lui-même (connexion locale).
+struct PinnedCertificateVerifier
+{
+	certificate: rustls::Certificate,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl SocketIoUpstream
+{
+	pub fn new(
+		addr: SocketAddr,
+		endpoint: &'static str,
+		pinned_certificate: Option<Vec<u8>>,
+	) -> Self
+	{
+		let tls = pinned_certificate.map(|certificate| {
+			let config = rustls::ClientConfig::builder()
+				.with_safe_defaults()
+				.with_custom_certificate_verifier(Arc::new(
+					PinnedCertificateVerifier {
+						certificate: rustls::Certificate(certificate),
+					},
+				))
+				.with_no_client_auth();
+			TlsConnector::from(Arc::new(config))
+		});
+
+		Self {
+			addr,
+			endpoint,
+			tls,
+		}
+	}
+}
+
+impl SocketIoBridge
+{
+	/// Protocole Engine.IO utilisé par le serveur.
+	const EIO_VERSION: u8 = 4;
+
+	/// Ouvre une connexion Socket.IO pour le compte d'un client IRC, dont
+	/// l'adresse IP est transmise au serveur par l'en-tête
+	/// `X-Forwarded-For`, et la sécurité de la connexion par l'en-tête
+	/// `X-Forwarded-Proto`.
+	///
+	/// Le serveur n'honore ces en-têtes que lorsque la connexion provient
+	/// d'un pair de confiance (la passerelle, qui se connecte localement, ou
+	/// l'un des `trusted_proxies`).
+	pub async fn connect(
+		upstream: &SocketIoUpstream,
+		client_ip: IpAddr,
+		secure: bool,
+	) -> io::Result<Self>
+	{
+		let tcp_stream = TcpStream::connect(upstream.addr).await?;
+		tcp_stream.set_nodelay(true)?;
+
+		let stream: BoxedIrcStream = match upstream.tls.as_ref() {
+			| Some(connector) => {
+				let server_name =
+					rustls::ServerName::IpAddress(upstream.addr.ip());
+				Box::new(connector.connect(server_name, tcp_stream).await?)
+			}
+			| None => Box::new(tcp_stream),
+		};
+
+		let url = format!(
+			"ws://{}{}/?EIO={}&transport=websocket",
+			upstream.addr,
+			upstream.endpoint,
+			Self::EIO_VERSION,
+		);
+
+		let mut request =
+			url.into_client_request().map_err(io::Error::other)?;
+		request.headers_mut().insert(
+			"x-forwarded-for",
+			client_ip.to_string().parse().map_err(io::Error::other)?,
+		);
+		request.headers_mut().insert(
+			"x-forwarded-proto",
+			(if secure { "https" } else { "http" })
+				.parse()
+				.map_err(io::Error::other)?,
+		);
+
+		let (ws, _) = tokio_tungstenite::client_async(request, stream)
+			.await
+			.map_err(io::Error::other)?;
+
+		let mut bridge = Self { ws };
+
+		// NOTE(phisyx): paquet OPEN d'Engine.IO, puis connexion à l'espace de
+		//               nom par défaut de Socket.IO.
+		match bridge.next_text().await {
+			| Some(packet) if packet.starts_with('0') => {}
+			| _ => return Err(io::ErrorKind::ConnectionRefused.into()),
+		}
+
+		bridge.send_text("40{}").await?;
+
+		match bridge.next_text().await {
+			| Some(packet) if packet.starts_with("40") => Ok(bridge),
+			| _ => Err(io::ErrorKind::ConnectionRefused.into()),
+		}
+	}
+
+	/// Émet un événement au serveur de Chat.
+	pub async fn emit(&mut self, event: &str, data: Value) -> io::Result<()>
+	{
+		let payload = serde_json::to_string(&Value::Array(vec![
+			Value::String(event.to_owned()),
+			data,
+		]))?;
+		self.send_text(&format!("42{payload}")).await
+	}
+
+	/// Attend le prochain événement émis par le serveur de Chat.
+	///
+	/// Retourne [None] lorsque la connexion est fermée.
+	pub async fn recv(&mut self) -> Option<(String, Value)>
+	{
+		loop {
+			let packet = self.next_text().await?;
+
+			// NOTE(phisyx): PING Engine.IO.
+			if packet == "2" {
+				self.send_text("3").await.ok()?;
+				continue;
+			}
+
+			// NOTE(phisyx): CLOSE Engine.IO ou DISCONNECT Socket.IO.
+			if packet == "1" || packet.starts_with("41") {
+				return None;
+			}
+
+			let Some(payload) = packet.strip_prefix("42") else {
+				continue;
+			};
+
+			let Ok(Value::Array(mut event)) =
+				serde_json::from_str::<Value>(payload)
+			else {
+				continue;
+			};
+
+			if event.is_empty() {
+				continue;
+			}
+
+			let data = if event.len() > 1 {
+				event.remove(1)
+			} else {
+				Value::Null
+			};
+
+			let Value::String(name) = event.remove(0) else {
+				continue;
+			};
+
+			return Some((name, data));
+		}
+	}
+
+	/// Ferme la connexion avec le serveur de Chat.
+	pub async fn close(&mut self)
+	{
+		_ = self.send_text("41").await;
+		_ = self.ws.close(None).await;
+	}
+
+	async fn next_text(&mut self) -> Option<String>
+	{
+		loop {
+			match self.ws.next().await? {
+				| Ok(Message::Text(text)) => return Some(text),
+				| Ok(Message::Close(_)) | Err(_) => return None,
+				| Ok(_) => continue,
+			}
+		}
+	}
+
+	async fn send_text(&mut self, text: &str) -> io::Result<()>
+	{
+		self.ws
+			.send(Message::Text(text.to_owned()))
+			.await
+			.map_err(io::Error::other)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<T> IrcStream for T where T: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl rustls::client::ServerCertVerifier for PinnedCertificateVerifier
+{
+	fn verify_server_cert(
+		&self,
+		end_entity: &rustls::Certificate,
+		_: &[rustls::Certificate],
+		_: &rustls::ServerName,
+		_: &mut dyn Iterator<Item = &[u8]>,
+		_: &[u8],
+		_: SystemTime,
+	) -> Result<rustls::client::ServerCertVerified, rustls::Error>
+	{
+		if end_entity != &self.certificate {
+			return Err(rustls::Error::InvalidCertificate(
+				rustls::CertificateError::UnknownIssuer,
+			));
+		}
+		Ok(rustls::client::ServerCertVerified::assertion())
+	}
+}
@@ -0,0 +1,856 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	CHANNEL_MODE_LIST_BAN,
+	CHANNEL_MODE_LIST_BAN_EXCEPT,
+	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
+	ChannelAccessLevel,
+	parse_duration,
+};
+use flex_chat::irc::IrcMessage;
+use flex_chat::user::{
+	USER_FLAG_GLOBAL_OPERATOR,
+	USER_FLAG_INVISIBLE,
+	USER_FLAG_LOCAL_OPERATOR,
+	USER_FLAG_SERVER_NOTICES,
+	USER_FLAG_WALLOPS,
+};
+use serde_json::{Map, Value, json};
+
+use super::IrcConnection;
+
+// ---- //
+// Type //
+// ---- //
+
+/// Événement à émettre au serveur de Chat.
+pub type IrcEvent = (&'static str, Value);
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Erreur de traduction d'une commande IRC.
+pub enum IrcCommandError
+{
+	/// ERR_UNKNOWNCOMMAND (421)
+	UnknownCommand,
+	/// ERR_NEEDMOREPARAMS (461)
+	NeedMoreParams,
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Traduction des commandes IRC (RFC 1459) vers les événements des handlers
+/// du serveur de Chat.
+pub struct IrcCommand;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IrcCommand
+{
+	/// Traduit une commande IRC en événements WebSocket.
+	pub fn translate(
+		message: &IrcMessage,
+		connection: &IrcConnection,
+	) -> Result<Vec<IrcEvent>, IrcCommandError>
+	{
+		let params = &message.params;
+
+		let need = |n: usize| {
+			if params.len() < n {
+				return Err(IrcCommandError::NeedMoreParams);
+			}
+			Ok(())
+		};
+
+		let events = match message.command.as_str() {
+			| "CAP" => {
+				need(1)?;
+				vec![(
+					"CAP",
+					json!({
+						"subcommand": params[0].to_uppercase(),
+						"capabilities": params
+							.get(1)
+							.map(|caps| {
+								caps.split_whitespace().collect::<Vec<_>>()
+							})
+							.unwrap_or_default(),
+					}),
+				)]
+			}
+			| "AUTHENTICATE" => {
+				need(1)?;
+				vec![("AUTHENTICATE", json!({ "data": params[0] }))]
+			}
+			| "PASS" => {
+				need(1)?;
+				vec![("PASS", json!({ "password": params[0] }))]
+			}
+			| "NICK" => {
+				need(1)?;
+				let event = if connection.registered {
+					"NICK"
+				} else {
+					"NICK (unregistered)"
+				};
+				vec![(event, json!({ "nickname": params[0] }))]
+			}
+			| "USER" => {
+				need(4)?;
+				vec![(
+					"USER",
+					json!({
+						"user": params[0],
+						"mode": params[1].parse::<u8>().unwrap_or_default(),
+						"realname": params[3],
+					}),
+				)]
+			}
+			| "JOIN" => {
+				need(1)?;
+				vec![(
+					"JOIN",
+					json!({
+						"channels": Self::list(&params[0]),
+						"keys": params
+							.get(1)
+							.map(|keys| Self::list(keys))
+							.unwrap_or_default(),
+					}),
+				)]
+			}
+			| "PART" => {
+				need(1)?;
+				vec![(
+					"PART",
+					json!({
+						"channels": Self::list(&params[0]),
+						"message": params.get(1),
+					}),
+				)]
+			}
+			| "PRIVMSG" => {
+				need(2)?;
+				Self::list(&params[0])
+					.into_iter()
+					.map(|target| {
+						if Self::is_channel(&target) {
+							(
+								"PUBMSG",
+								json!({
+									"channels": [target],
+									"text": params[1],
+								}),
+							)
+						} else {
+							(
+								"PRIVMSG",
+								json!({
+									"targets": [target],
+									"text": params[1],
+								}),
+							)
+						}
+					})
+					.collect()
+			}
+			| "NOTICE" => {
+				need(2)?;
+				vec![(
+					"NOTICE",
+					json!({
+						"targets": Self::list(&params[0]),
+						"text": params[1],
+					}),
+				)]
+			}
+			| "TOPIC" => {
+				need(1)?;
+				// NOTE: `TOPIC <channel> HISTORY [<position>]` consulte
+				//       l'historique des sujets, ou en restaure un.
+				if params
+					.get(1)
+					.is_some_and(|param| param.eq_ignore_ascii_case("HISTORY"))
+				{
+					return Ok(vec![(
+						"TOPIC HISTORY",
+						json!({
+							"channel": params[0],
+							"restore": params
+								.get(2)
+								.and_then(|position| position.parse::<usize>().ok()),
+						}),
+					)]);
+				}
+				vec![(
+					"TOPIC",
+					json!({
+						"channel": params[0],
+						"topic": params.get(1),
+					}),
+				)]
+			}
+			| "KICK" => {
+				need(2)?;
+				vec![(
+					"KICK",
+					json!({
+						"channels": Self::list(&params[0]),
+						"knicks": Self::list(&params[1]),
+						"comment": params.get(2),
+					}),
+				)]
+			}
+			| "INVITE" => {
+				// NOTE: sans paramètre, la commande liste les invitations en
+				//       attente du client.
+				if params.is_empty() {
+					return Ok(vec![("INVITE LIST", json!({}))]);
+				}
+				need(2)?;
+				vec![(
+					"INVITE",
+					json!({
+						"nickname": params[0],
+						"channel": params[1],
+					}),
+				)]
+			}
+			| "UNINVITE" => {
+				need(2)?;
+				vec![(
+					"UNINVITE",
+					json!({
+						"nickname": params[0],
+						"channel": params[1],
+					}),
+				)]
+			}
+			| "KNOCK" => {
+				need(1)?;
+				vec![(
+					"KNOCK",
+					json!({
+						"channel": params[0],
+						"reason": params.get(1),
+					}),
+				)]
+			}
+			| "LIST" => {
+				vec![(
+					"LIST",
+					json!({
+						"channels": params
+							.first()
+							.map(|channels| Self::list(channels))
+							.unwrap_or_default(),
+					}),
+				)]
+			}
+			| "AWAY" => vec![("AWAY", json!({ "text": params.first() }))],
+			| "OPER" => {
+				need(2)?;
+				vec![(
+					"OPER",
+					json!({
+						"name": params[0],
+						"password": params[1],
+					}),
+				)]
+			}
+			| "KILL" => {
+				need(2)?;
+				vec![(
+					"KILL",
+					json!({
+						"nickname": params[0],
+						"comment": params[1],
+					}),
+				)]
+			}
+			| "QUIT" => vec![("QUIT", json!({ "message": params.first() }))],
+			| "WALLOPS" | "GLOBOPS" => {
+				need(1)?;
+				let event = if message.command == "WALLOPS" {
+					"WALLOPS"
+				} else {
+					"GLOBOPS"
+				};
+				vec![(event, json!({ "text": params[0] }))]
+			}
+			| "SAJOIN" => {
+				need(2)?;
+				vec![(
+					"SAJOIN",
+					json!({
+						"nicknames": Self::list(&params[0]),
+						"channels": Self::list(&params[1]),
+					}),
+				)]
+			}
+			| "SAPART" => {
+				need(2)?;
+				vec![(
+					"SAPART",
+					json!({
+						"nicknames": Self::list(&params[0]),
+						"channels": Self::list(&params[1]),
+						"message": params.get(2),
+					}),
+				)]
+			}
+			| "SAMODE" => {
+				need(2)?;
+				if !Self::is_channel(&params[0]) {
+					return Err(IrcCommandError::UnknownCommand);
+				}
+				Self::translate_samode(&params[0], &params[1], &params[2..])
+			}
+			| "SANICK" => {
+				need(2)?;
+				vec![(
+					"SANICK",
+					json!({
+						"nickname": params[0],
+						"new_nickname": params[1],
+					}),
+				)]
+			}
+			| "SAQUIT" => {
+				need(1)?;
+				vec![(
+					"SAQUIT",
+					json!({
+						"nickname": params[0],
+						"message": params.get(1),
+					}),
+				)]
+			}
+			| "SATOPIC" => {
+				need(2)?;
+				vec![(
+					"SATOPIC",
+					json!({
+						"channel": params[0],
+						"topic": params[1],
+					}),
+				)]
+			}
+			| "KLINE" => Self::translate_server_ban("KLINE", params)?,
+			| "GLINE" => Self::translate_server_ban("GLINE", params)?,
+			| "ZLINE" => Self::translate_server_ban("ZLINE", params)?,
+			| "SHUN" => Self::translate_server_ban("SHUN", params)?,
+			| "UNKLINE" => Self::translate_server_unban("UNKLINE", params)?,
+			| "UNGLINE" => Self::translate_server_unban("UNGLINE", params)?,
+			| "UNZLINE" => Self::translate_server_unban("UNZLINE", params)?,
+			| "UNSHUN" => Self::translate_server_unban("UNSHUN", params)?,
+			| "STATS" => {
+				need(1)?;
+				vec![("STATS", json!({ "query": params[0].chars().next() }))]
+			}
+			| "MONITOR" => {
+				need(1)?;
+				vec![(
+					"MONITOR",
+					json!({
+						"subcommand": params[0],
+						"targets": params
+							.get(1)
+							.map(|targets| Self::list(targets))
+							.unwrap_or_default(),
+					}),
+				)]
+			}
+			| "ISON" => {
+				need(1)?;
+				// NOTE: les pseudonymes peuvent être séparés par des espaces,
+				//       dans un ou plusieurs paramètres.
+				let nicknames: Vec<_> = params
+					.iter()
+					.flat_map(|param| param.split_whitespace())
+					.collect();
+				vec![("ISON", json!({ "nicknames": nicknames }))]
+			}
+			| "USERHOST" => {
+				need(1)?;
+				vec![("USERHOST", json!({ "nicknames": params }))]
+			}
+			| "CHATHISTORY" => {
+				need(2)?;
+				// NOTE: `CHATHISTORY TARGETS <timestamp> <timestamp> <limit>`
+				//       `CHATHISTORY BETWEEN <target> <ref> <ref> <limit>`
+				//       `CHATHISTORY <subcommand> <target> <ref> <limit>`
+				let subcommand = params[0].to_uppercase();
+				// NOTE: une limite invalide est refusée par le serveur.
+				let limit =
+					|param: &str| param.parse::<usize>().unwrap_or_default();
+				let data = match subcommand.as_str() {
+					| "TARGETS" => {
+						need(4)?;
+						json!({
+							"subcommand": subcommand,
+							"from": params[1],
+							"to": params[2],
+							"limit": limit(&params[3]),
+						})
+					}
+					| "BETWEEN" => {
+						need(5)?;
+						json!({
+							"subcommand": subcommand,
+							"target": params[1],
+							"from": params[2],
+							"to": params[3],
+							"limit": limit(&params[4]),
+						})
+					}
+					| _ => {
+						need(4)?;
+						json!({
+							"subcommand": subcommand,
+							"target": params[1],
+							"from": params[2],
+							"limit": limit(&params[3]),
+						})
+					}
+				};
+				vec![("CHATHISTORY", data)]
+			}
+			| "WHO" => {
+				// NOTE: `WHO [<mask> [<flags>[%<fields>[,<token>]]]]`
+				let (flags, whox) = params
+					.get(1)
+					.map(|options| {
+						options.split_once('%').unwrap_or((options, ""))
+					})
+					.unwrap_or_default();
+				let (fields, token) = whox.split_once(',').unwrap_or((whox, ""));
+				vec![(
+					"WHO",
+					json!({
+						"mask": params.first(),
+						"operators_only": flags.contains('o'),
+						"fields": fields,
+						"token": (!token.is_empty()).then_some(token),
+					}),
+				)]
+			}
+			| "WHOIS" => {
+				need(1)?;
+				// NOTE: `WHOIS [<server>] <nickmasks>`
+				let nicknames = params.last().map(|n| Self::list(n));
+				vec![("WHOIS", json!({ "nicknames": nicknames }))]
+			}
+			| "WHOWAS" => {
+				need(1)?;
+				vec![(
+					"WHOWAS",
+					json!({
+						"nickname": params[0],
+						"count": params
+							.get(1)
+							.and_then(|count| count.parse::<usize>().ok()),
+					}),
+				)]
+			}
+			| "ADMIN" => vec![("ADMIN", json!({ "target": params.first() }))],
+			| "INFO" => vec![("INFO", json!({ "target": params.first() }))],
+			| "LUSERS" => {
+				// NOTE: `LUSERS [<mask> [<server>]]`
+				vec![("LUSERS", json!({ "target": params.get(1) }))]
+			}
+			| "MOTD" => vec![("MOTD", json!({ "target": params.first() }))],
+			| "TIME" => vec![("TIME", json!({ "target": params.first() }))],
+			| "VERSION" => {
+				vec![("VERSION", json!({ "target": params.first() }))]
+			}
+			| "SILENCE" => {
+				need(1)?;
+				vec![("SILENCE", json!({ "nickname": params[0] }))]
+			}
+			| "GROUP" => vec![("NICK GROUP", json!({}))],
+			| "GLIST" => vec![("NICK GLIST", json!({}))],
+			| "UNGROUP" => {
+				need(1)?;
+				vec![("NICK UNGROUP", json!({ "nickname": params[0] }))]
+			}
+			| "GHOST" => {
+				need(1)?;
+				vec![("GHOST", json!({ "nickname": params[0] }))]
+			}
+			| "RECOVER" => {
+				need(1)?;
+				vec![("RECOVER", json!({ "nickname": params[0] }))]
+			}
+			| "REGISTER" | "UNREGISTER" => {
+				// NOTE: seul l'enregistrement des salons est supporté.
+				need(1)?;
+				if !Self::is_channel(&params[0]) {
+					return Err(IrcCommandError::UnknownCommand);
+				}
+				let event = if message.command == "REGISTER" {
+					"CHANNEL REGISTER"
+				} else {
+					"CHANNEL UNREGISTER"
+				};
+				vec![(event, json!({ "channel": params[0] }))]
+			}
+			| "MODE" => {
+				need(1)?;
+				let Some(modestring) = params.get(1) else {
+					// NOTE: `MODE <channel>` consulte les paramètres du salon,
+					//       `MODE <nickname>` n'est pas supporté.
+					if !Self::is_channel(&params[0]) {
+						return Ok(vec![]);
+					}
+					return Ok(vec![(
+						"MODE",
+						json!({ "target": params[0], "modes": {} }),
+					)]);
+				};
+				if !Self::is_channel(&params[0]) {
+					return Ok(Self::translate_user_mode(
+						modestring,
+						&params[2..],
+					));
+				}
+				Self::translate_channel_mode(
+					&params[0],
+					modestring,
+					&params[2..],
+				)
+			}
+			| _ => return Err(IrcCommandError::UnknownCommand),
+		};
+
+		Ok(events)
+	}
+
+	/// Traduit la commande `MODE <channel> <modestring> [<args>...]` vers les
+	/// événements des niveaux d'accès, des listes de contrôle d'accès et des
+	/// paramètres du salon.
+	fn translate_channel_mode(
+		channel: &str,
+		modestring: &str,
+		args: &[String],
+	) -> Vec<IrcEvent>
+	{
+		let mut args = args.iter();
+		let mut events = vec![];
+		let mut settings = Map::new();
+		let mut adding = true;
+
+		for letter in modestring.chars() {
+			match letter {
+				| '+' => adding = true,
+				| '-' => adding = false,
+
+				| CHANNEL_MODE_LIST_BAN
+				| CHANNEL_MODE_LIST_BAN_EXCEPT
+				| CHANNEL_MODE_LIST_INVITE_EXCEPT
+				| CHANNEL_MODE_LIST_QUIET => {
+					// NOTE: sans masque, le mode consulte la liste
+					//       (RPL_BANLIST, RPL_EXCEPTLIST, RPL_INVEXLIST,
+					//       RPL_QUIETLIST).
+					let Some(mask) = args.next() else {
+						let event = match letter {
+							| CHANNEL_MODE_LIST_BAN => "BAN",
+							| CHANNEL_MODE_LIST_BAN_EXCEPT => "BANEX",
+							| CHANNEL_MODE_LIST_QUIET => "QUIET",
+							| _ => "INVITEX",
+						};
+						events.push((
+							event,
+							json!({ "channels": [channel], "masks": [] }),
+						));
+						continue;
+					};
+					let event = match (letter, adding) {
+						| (CHANNEL_MODE_LIST_BAN, true) => "BAN",
+						| (CHANNEL_MODE_LIST_BAN, false) => "UNBAN",
+						| (CHANNEL_MODE_LIST_BAN_EXCEPT, true) => "BANEX",
+						| (CHANNEL_MODE_LIST_BAN_EXCEPT, false) => "UNBANEX",
+						| (CHANNEL_MODE_LIST_QUIET, true) => "QUIET",
+						| (CHANNEL_MODE_LIST_QUIET, false) => "UNQUIET",
+						| (_, true) => "INVITEX",
+						| (_, false) => "UNINVITEX",
+					};
+					events.push((
+						event,
+						json!({
+							"channels": [channel],
+							"masks": [mask],
+						}),
+					));
+				}
+
+				| 'q' | 'a' | 'o' | 'h' | 'v' => {
+					let Some(nickname) = args.next() else {
+						continue;
+					};
+					let level = match letter {
+						| 'q' => ChannelAccessLevel::Owner,
+						| 'a' => ChannelAccessLevel::AdminOperator,
+						| 'o' => ChannelAccessLevel::Operator,
+						| 'h' => ChannelAccessLevel::HalfOperator,
+						| _ => ChannelAccessLevel::Vip,
+					};
+					let event = match (level, adding) {
+						| (ChannelAccessLevel::Owner, true) => "QOP",
+						| (ChannelAccessLevel::Owner, false) => "DEQOP",
+						| (ChannelAccessLevel::AdminOperator, true) => "AOP",
+						| (ChannelAccessLevel::AdminOperator, false) => "DEAOP",
+						| (ChannelAccessLevel::Operator, true) => "OP",
+						| (ChannelAccessLevel::Operator, false) => "DEOP",
+						| (ChannelAccessLevel::HalfOperator, true) => "HOP",
+						| (ChannelAccessLevel::HalfOperator, false) => "DEHOP",
+						| (ChannelAccessLevel::Vip, true) => "VIP",
+						| (ChannelAccessLevel::Vip, false) => "DEVIP",
+					};
+					events.push((
+						event,
+						json!({
+							"channel": channel,
+							"nicknames": [nickname],
+						}),
+					));
+				}
+
+				| 'k' => {
+					let key = if adding {
+						let Some(key) = args.next() else {
+							continue;
+						};
+						key.to_owned()
+					} else {
+						// NOTE(phisyx): une clé vide retire le paramètre.
+						_ = args.next();
+						String::new()
+					};
+					settings.insert(String::from("k"), json!(key));
+				}
+				| 'f' => {
+					let rules = if adding {
+						let Some(rules) = args.next() else {
+							continue;
+						};
+						rules.to_owned()
+					} else {
+						// NOTE: des règles vides retirent le paramètre.
+						String::new()
+					};
+					settings.insert(String::from("f"), json!(rules));
+				}
+				| 'l' => {
+					let limit = if adding {
+						let Some(limit) = args.next() else {
+							continue;
+						};
+						limit.parse::<u16>().unwrap_or_default()
+					} else {
+						0
+					};
+					settings.insert(String::from("l"), json!(limit));
+				}
+				| 'c' | 'C' | 'D' | 'i' | 'K' | 'L' | 'm' | 'M' | 'n' | 'N'
+				| 'O' | 'P' | 'R' | 's' | 't' | 'T' | 'u' | 'z' => {
+					settings.insert(letter.to_string(), json!(adding));
+				}
+				| _ => continue,
+			}
+		}
+
+		if !settings.is_empty() {
+			events.push((
+				"MODE",
+				json!({
+					"target": channel,
+					"modes": settings,
+				}),
+			));
+		}
+
+		events
+	}
+
+	/// Traduit la commande `MODE <nickname> <modestring> [<snomask>]` vers
+	/// l'événement des modes utilisateur. Le mode `+s` sans masque abonne
+	/// l'opérateur à toutes les notices du serveur.
+	fn translate_user_mode(modestring: &str, args: &[String]) -> Vec<IrcEvent>
+	{
+		let mut args = args.iter();
+		let mut modes = Map::new();
+		let mut adding = true;
+
+		for letter in modestring.chars() {
+			match letter {
+				| '+' => adding = true,
+				| '-' => adding = false,
+				| USER_FLAG_SERVER_NOTICES => {
+					let snomask = if adding {
+						args.next().map_or("*", String::as_str)
+					} else {
+						// NOTE: un masque vide retire le mode.
+						""
+					};
+					modes.insert(letter.to_string(), json!(snomask));
+				}
+				| USER_FLAG_INVISIBLE | USER_FLAG_WALLOPS => {
+					modes.insert(letter.to_string(), json!(adding));
+				}
+				// NOTE: le statut d'opérateur ne peut qu'être retiré.
+				| USER_FLAG_GLOBAL_OPERATOR | USER_FLAG_LOCAL_OPERATOR
+					if !adding =>
+				{
+					modes.insert(
+						USER_FLAG_GLOBAL_OPERATOR.to_string(),
+						json!(false),
+					);
+				}
+				| _ => continue,
+			}
+		}
+
+		if modes.is_empty() {
+			return vec![];
+		}
+
+		vec![("USER MODE", json!({ "modes": modes }))]
+	}
+
+	/// Traduit la commande `SAMODE <channel> <modestring> [<args>...]` : les
+	/// événements de la commande MODE sont regroupés en un seul événement.
+	fn translate_samode(
+		channel: &str,
+		modestring: &str,
+		args: &[String],
+	) -> Vec<IrcEvent>
+	{
+		let mut modes = Map::new();
+		let mut access_levels = vec![];
+		let mut access_controls = vec![];
+
+		for (event, data) in
+			Self::translate_channel_mode(channel, modestring, args)
+		{
+			let list = match event.trim_start_matches("UN") {
+				| "BAN" => CHANNEL_MODE_LIST_BAN,
+				| "BANEX" => CHANNEL_MODE_LIST_BAN_EXCEPT,
+				| "INVITEX" => CHANNEL_MODE_LIST_INVITE_EXCEPT,
+				| "QUIET" => CHANNEL_MODE_LIST_QUIET,
+				| "MODE" => {
+					if let Value::Object(settings) = &data["modes"] {
+						modes.extend(settings.clone());
+					}
+					continue;
+				}
+				| _ => {
+					let access_level = match event.trim_start_matches("DE") {
+						| "QOP" => ChannelAccessLevel::Owner,
+						| "AOP" => ChannelAccessLevel::AdminOperator,
+						| "OP" => ChannelAccessLevel::Operator,
+						| "HOP" => ChannelAccessLevel::HalfOperator,
+						| _ => ChannelAccessLevel::Vip,
+					};
+					access_levels.push(json!({
+						"access_level": access_level,
+						"set": !event.starts_with("DE"),
+						"nicknames": data["nicknames"],
+					}));
+					continue;
+				}
+			};
+
+			access_controls.push(json!({
+				"list": list,
+				"set": !event.starts_with("UN"),
+				"masks": data["masks"],
+			}));
+		}
+
+		vec![(
+			"SAMODE",
+			json!({
+				"target": channel,
+				"modes": (!modes.is_empty()).then_some(modes),
+				"access_levels": access_levels,
+				"access_controls": access_controls,
+			}),
+		)]
+	}
+
+	/// Traduit les commandes de bannissement du serveur
+	/// `<command> [<durée>] <mask> [<reason>]`.
+	fn translate_server_ban(
+		event: &'static str,
+		params: &[String],
+	) -> Result<Vec<IrcEvent>, IrcCommandError>
+	{
+		// NOTE: le premier paramètre est une durée (`1d12h`) lorsqu'il est
+		//       suivi d'un masque.
+		let (duration, params) = match params.split_first() {
+			| Some((duration, rest))
+				if !rest.is_empty() && parse_duration(duration).is_some() =>
+			{
+				(Some(duration), rest)
+			}
+			| _ => (None, params),
+		};
+
+		let Some(mask) = params.first() else {
+			return Err(IrcCommandError::NeedMoreParams);
+		};
+
+		Ok(vec![(
+			event,
+			json!({
+				"mask": mask,
+				"duration": duration,
+				"reason": params.get(1),
+			}),
+		)])
+	}
+
+	/// Traduit les commandes de retrait d'un bannissement du serveur
+	/// `<command> <mask>`.
+	fn translate_server_unban(
+		event: &'static str,
+		params: &[String],
+	) -> Result<Vec<IrcEvent>, IrcCommandError>
+	{
+		let Some(mask) = params.first() else {
+			return Err(IrcCommandError::NeedMoreParams);
+		};
+		Ok(vec![(event, json!({ "mask": mask }))])
+	}
+
+	/// Liste d'éléments séparés par une virgule.
+	fn list(param: &str) -> Vec<String>
+	{
+		param
+			.split(',')
+			.filter(|item| !item.is_empty())
+			.map(String::from)
+			.collect()
+	}
+
+	fn is_channel(target: &str) -> bool
+	{
+		target.starts_with('#')
+	}
+}
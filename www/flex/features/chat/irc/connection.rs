@@ -0,0 +1,76 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use flex_chat::client::Capability;
+use serde_json::Value;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// État d'une connexion IRC (TCP/TLS) passant par la passerelle.
+pub struct IrcConnection
+{
+	/// Nom du serveur, utilisé comme préfixe des numériques.
+	pub server_name: Arc<str>,
+	/// ID du client côté serveur de Chat, connu à partir de RPL_WELCOME.
+	pub client_id: Option<String>,
+	/// Pseudonyme courant du client.
+	pub nickname: Option<String>,
+	/// Est-ce que le client a terminé son enregistrement?
+	pub registered: bool,
+	/// Salons pour lesquels le client attend une liste RPL_NAMREPLY.
+	pub pending_names: Vec<String>,
+	/// Capacités serveur acceptées (`CAP ACK`) pour le client.
+	pub capabilities: HashSet<Capability>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IrcConnection
+{
+	pub fn new(server_name: Arc<str>) -> Self
+	{
+		Self {
+			server_name,
+			client_id: Default::default(),
+			nickname: Default::default(),
+			registered: Default::default(),
+			pending_names: Default::default(),
+			capabilities: Default::default(),
+		}
+	}
+
+	/// Est-ce que le client a activé une capacité serveur donnée?
+	pub fn has_capability(&self, capability: Capability) -> bool
+	{
+		self.capabilities.contains(&capability)
+	}
+
+	/// Cible des numériques envoyées au client.
+	pub fn target(&self) -> &str
+	{
+		self.nickname.as_deref().unwrap_or("*")
+	}
+
+	/// Est-ce que l'origine d'un événement correspond au client courant?
+	pub fn is_itself(&self, origin: &Value) -> bool
+	{
+		self.client_id
+			.as_deref()
+			.zip(origin.get("id").and_then(Value::as_str))
+			.is_some_and(|(cid, origin_id)| cid == origin_id)
+	}
+}
@@ -0,0 +1,309 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use flex_chat::irc::{IRC_LINE_MAX_SIZE, IrcMessage};
+use flex_web_framework::ServerSettings;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::TlsAcceptor;
+
+use super::{
+	BoxedIrcStream,
+	IrcCommand,
+	IrcConnection,
+	IrcReply,
+	SocketIoBridge,
+	SocketIoUpstream,
+};
+use crate::config::chat::{FlexChatConfig, FlexChatConfigIrc};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Taille maximale d'une ligne reçue d'un client IRC, tags IRCv3 (8191
+/// octets) compris.
+const IRC_LINE_READ_LIMIT: usize = IRC_LINE_MAX_SIZE + 8191;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Passerelle IRC: écoute les connexions TCP (ou TLS) des clients IRC natifs
+/// et les relie au serveur de Chat, de sorte qu'ils partagent les mêmes
+/// sessions de clients et de salons que les clients web.
+pub struct IrcGateway
+{
+	/// Adresse d'écoute de la passerelle.
+	listen_addr: SocketAddr,
+	/// Accepteur TLS, lorsque la passerelle est configurée en TLS.
+	acceptor: Option<TlsAcceptor>,
+	/// Point d'entrée WebSocket du serveur de Chat.
+	upstream: SocketIoUpstream,
+	/// Nom du serveur.
+	server_name: Arc<str>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IrcGateway
+{
+	/// Démarre la passerelle IRC en tâche de fond, si celle-ci est
+	/// configurée.
+	pub fn spawn(
+		config: &FlexChatConfig,
+		server_settings: &ServerSettings,
+		endpoint: &'static str,
+	)
+	{
+		let Some(irc_config) = config.irc.clone() else {
+			return;
+		};
+
+		let server_name = config.server.name.clone();
+		let server_settings = server_settings.clone();
+
+		tokio::spawn(async move {
+			let gateway = match Self::new(
+				irc_config,
+				server_name,
+				&server_settings,
+				endpoint,
+			)
+			.await
+			{
+				| Ok(gateway) => Arc::new(gateway),
+				| Err(error) => {
+					tracing::error!(
+						?error,
+						"Impossible de démarrer la passerelle IRC"
+					);
+					return;
+				}
+			};
+
+			if let Err(error) = gateway.listen().await {
+				tracing::error!(?error, "Arrêt de la passerelle IRC");
+			}
+		});
+	}
+
+	async fn new(
+		irc_config: FlexChatConfigIrc,
+		server_name: Arc<str>,
+		server_settings: &ServerSettings,
+		endpoint: &'static str,
+	) -> io::Result<Self>
+	{
+		let tls_settings = server_settings.tls.as_ref();
+
+		let acceptor = if irc_config.tls {
+			let tls_settings = tls_settings.ok_or_else(|| {
+				io::Error::other(
+					"La passerelle IRC en TLS nécessite les paramètres TLS du \
+					 serveur",
+				)
+			})?;
+
+			let rustls_config =
+				axum_server::tls_rustls::RustlsConfig::from_pem_file(
+					&tls_settings.cert_file,
+					&tls_settings.key_file,
+				)
+				.await?;
+
+			// NOTE(phisyx): les protocoles ALPN HTTP du serveur HTTP n'ont
+			//               aucun sens pour un client IRC.
+			let mut server_config = (*rustls_config.get_inner()).clone();
+			server_config.alpn_protocols.clear();
+
+			Some(TlsAcceptor::from(Arc::new(server_config)))
+		} else {
+			None
+		};
+
+		let pinned_certificate = match tls_settings {
+			| Some(tls_settings) => {
+				let pem = tokio::fs::read(&tls_settings.cert_file).await?;
+				let certificate = rustls_pemfile::certs(&mut pem.as_slice())
+					.next()
+					.ok_or_else(|| {
+						io::Error::other("Certificat introuvable")
+					})??;
+				Some(certificate.to_vec())
+			}
+			| None => None,
+		};
+
+		Ok(Self {
+			listen_addr: SocketAddr::from((irc_config.ip, irc_config.port)),
+			acceptor,
+			upstream: SocketIoUpstream::new(
+				server_settings.socket_addr(),
+				endpoint,
+				pinned_certificate,
+			),
+			server_name,
+		})
+	}
+
+	async fn listen(self: Arc<Self>) -> io::Result<()>
+	{
+		let listener = TcpListener::bind(self.listen_addr).await?;
+
+		tracing::info!(
+			addr = %self.listen_addr,
+			tls = self.acceptor.is_some(),
+			"Passerelle IRC en écoute"
+		);
+
+		loop {
+			let (tcp_stream, peer_addr) = listener.accept().await?;
+			let gateway = Arc::clone(&self);
+			tokio::spawn(async move {
+				if let Err(error) = gateway.accept(tcp_stream, peer_addr).await
+				{
+					tracing::debug!(
+						?error,
+						%peer_addr,
+						"Connexion IRC terminée"
+					);
+				}
+			});
+		}
+	}
+
+	async fn accept(
+		&self,
+		tcp_stream: TcpStream,
+		peer_addr: SocketAddr,
+	) -> io::Result<()>
+	{
+		tcp_stream.set_nodelay(true)?;
+
+		let stream: BoxedIrcStream = match self.acceptor.as_ref() {
+			| Some(acceptor) => Box::new(acceptor.accept(tcp_stream).await?),
+			| None => Box::new(tcp_stream),
+		};
+
+		self.serve(stream, peer_addr).await
+	}
+
+	/// Relaie les lignes IRC du client vers le serveur de Chat, et les
+	/// événements du serveur de Chat vers le client.
+	async fn serve(
+		&self,
+		stream: BoxedIrcStream,
+		peer_addr: SocketAddr,
+	) -> io::Result<()>
+	{
+		let (reader, mut writer) = tokio::io::split(stream);
+		let mut reader = BufReader::new(reader);
+		let mut line = Vec::with_capacity(IRC_LINE_MAX_SIZE);
+
+		let mut bridge = SocketIoBridge::connect(
+			&self.upstream,
+			peer_addr.ip(),
+			self.acceptor.is_some(),
+		)
+		.await?;
+
+		let mut connection = IrcConnection::new(self.server_name.clone());
+
+		loop {
+			// NOTE: la lecture est limitée à la taille restante de la ligne,
+			//       de sorte qu'un client ne puisse pas envoyer une ligne
+			//       sans fin. Les octets partiellement lus restent dans
+			//       `line` lorsque l'autre branche termine en premier.
+			let mut limited_reader =
+				(&mut reader).take((IRC_LINE_READ_LIMIT - line.len()) as u64);
+
+			let outgoing = tokio::select! {
+				read = limited_reader.read_until(b'\n', &mut line) => {
+					read?;
+
+					if !line.ends_with(b"\n") {
+						if line.len() >= IRC_LINE_READ_LIMIT {
+							writer.write_all(b"ERROR :Line too long\r\n").await?;
+							writer.flush().await?;
+						}
+						break;
+					}
+
+					let raw_line = String::from_utf8_lossy(&line).into_owned();
+					line.clear();
+
+					let Some(message) = IrcMessage::parse(&raw_line) else {
+						continue;
+					};
+
+					match message.command.as_str() {
+						| "PING" => vec![
+							IrcMessage::new("PONG")
+								.with_prefix(&self.server_name)
+								.with_param(&self.server_name)
+								.with_params(message.param(0)),
+						],
+						| "PONG" => vec![],
+						| _ => {
+							match IrcCommand::translate(&message, &connection) {
+								| Ok(events) => {
+									for (event, data) in events {
+										bridge.emit(event, data).await?;
+									}
+									vec![]
+								}
+								| Err(error) => vec![
+									IrcReply::render_command_error(
+										&message.command,
+										error,
+										&connection,
+									),
+								],
+							}
+						}
+					}
+				}
+				event = bridge.recv() => {
+					let Some((event, payload)) = event else {
+						writer.write_all(b"ERROR :Closing link\r\n").await?;
+						break;
+					};
+					IrcReply::render(&event, &payload, &mut connection)
+				}
+			};
+
+			for message in outgoing {
+				// NOTE: un paramètre intermédiaire contenant un espace (ou
+				//       commençant par ':') décalerait les paramètres du
+				//       message pour le client.
+				if !message.is_valid() {
+					tracing::warn!(
+						command = %message.command,
+						"Message IRC invalide non envoyé"
+					);
+					continue;
+				}
+				writer.write_all(message.to_line().as_bytes()).await?;
+			}
+			writer.flush().await?;
+		}
+
+		bridge.close().await;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,742 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::ChannelAccessLevel;
+use flex_chat::client::Capability;
+use flex_chat::irc::IrcMessage;
+use serde_json::Value;
+
+use super::{IrcCommandError, IrcConnection};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Rendu des événements émis par le serveur de Chat au format texte IRC.
+pub struct IrcReply;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IrcReply
+{
+	/// Rendu d'un événement en lignes IRC, avec les étiquettes des capacités
+	/// serveur `server-time` et `message-tags` lorsque le client les a
+	/// activées.
+	pub fn render(
+		event: &str,
+		payload: &Value,
+		connection: &mut IrcConnection,
+	) -> Vec<IrcMessage>
+	{
+		let messages = Self::render_event(event, payload, connection);
+
+		let tags = &payload["tags"];
+		let mut with_tags: Vec<(&str, &str)> = vec![];
+		if connection.has_capability(Capability::ServerTime) {
+			with_tags.extend(tags["time"].as_str().map(|time| ("time", time)));
+		}
+		if connection.has_capability(Capability::MessageTags) {
+			with_tags
+				.extend(tags["msgid"].as_str().map(|msgid| ("msgid", msgid)));
+		}
+
+		messages
+			.into_iter()
+			.map(|message| {
+				with_tags.iter().fold(message, |message, (key, value)| {
+					message.with_tag(key, Some(value.to_string()))
+				})
+			})
+			.collect()
+	}
+
+	fn render_event(
+		event: &str,
+		payload: &Value,
+		connection: &mut IrcConnection,
+	) -> Vec<IrcMessage>
+	{
+		let origin = &payload["origin"];
+
+		match event {
+			| "ERROR" => {
+				vec![
+					IrcMessage::new("ERROR")
+						.with_param(payload.as_str().unwrap_or_default()),
+				]
+			}
+
+			| "CAP" => {
+				let subcommand =
+					payload["subcommand"].as_str().unwrap_or_default();
+				let capabilities: Vec<&str> = payload["capabilities"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.filter_map(Value::as_str)
+					.collect();
+
+				if subcommand == "ACK" {
+					for capability in capabilities.iter() {
+						match capability.strip_prefix('-') {
+							| Some(name) => {
+								if let Ok(capability) = name.parse() {
+									connection.capabilities.remove(&capability);
+								}
+							}
+							| None => {
+								if let Ok(capability) = capability.parse() {
+									connection.capabilities.insert(capability);
+								}
+							}
+						}
+					}
+				}
+
+				vec![
+					IrcMessage::new("CAP")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), subcommand])
+						.with_param(capabilities.join(" ")),
+				]
+			}
+
+			| "AUTHENTICATE" => {
+				vec![
+					IrcMessage::new("AUTHENTICATE")
+						.with_param(payload["data"].as_str().unwrap_or("+")),
+				]
+			}
+
+			| "AWAY" => {
+				let mut message =
+					IrcMessage::new("AWAY").with_prefix(Self::prefix(origin));
+				if let Some(text) = payload["text"].as_str() {
+					message = message.with_param(text);
+				}
+				vec![message]
+			}
+
+			| "ACCOUNT" => {
+				vec![
+					IrcMessage::new("ACCOUNT")
+						.with_prefix(Self::prefix(origin))
+						.with_param(payload["account"].as_str().unwrap_or("*")),
+				]
+			}
+
+			| "RPL_WELCOME" => {
+				connection.registered = true;
+				connection.client_id =
+					origin["id"].as_str().map(ToOwned::to_owned);
+				connection.nickname =
+					origin["nickname"].as_str().map(ToOwned::to_owned);
+				Self::render_numeric(payload, connection)
+			}
+
+			| "RPL_NAMREPLY" => Self::render_namreply(payload, connection),
+			| "RPL_ENDOFNAMES" => {
+				let channel = payload["channel"].as_str().unwrap_or_default();
+				let Some(pos) = connection
+					.pending_names
+					.iter()
+					.position(|name| name.eq_ignore_ascii_case(channel))
+				else {
+					return vec![];
+				};
+				connection.pending_names.remove(pos);
+				Self::render_numeric(payload, connection)
+			}
+
+			| "JOIN" => {
+				let channel = payload["channel"].as_str().unwrap_or_default();
+				if connection.is_itself(origin) {
+					connection.pending_names.push(channel.to_owned());
+				}
+				let mut message = IrcMessage::new("JOIN")
+					.with_prefix(Self::prefix(origin))
+					.with_param(channel);
+				if connection.has_capability(Capability::ExtendedJoin) {
+					message = message
+						.with_param(origin["account"].as_str().unwrap_or("*"))
+						.with_param(
+							payload["realname"].as_str().unwrap_or_default(),
+						);
+				}
+				vec![message]
+			}
+
+			| "PART" => {
+				let mut message = IrcMessage::new("PART")
+					.with_prefix(Self::prefix(origin))
+					.with_param(
+						payload["channel"].as_str().unwrap_or_default(),
+					);
+				if let Some(text) = payload["message"].as_str() {
+					message = message.with_param(text);
+				}
+				vec![message]
+			}
+
+			| "QUIT" => {
+				vec![
+					IrcMessage::new("QUIT")
+						.with_prefix(Self::prefix(origin))
+						.with_param(
+							payload["message"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			| "NICK" => {
+				let old_nickname =
+					payload["old_nickname"].as_str().unwrap_or_default();
+				let new_nickname =
+					payload["new_nickname"].as_str().unwrap_or_default();
+
+				if connection.is_itself(origin) || !connection.registered {
+					connection.nickname.replace(new_nickname.to_owned());
+				}
+
+				let prefix = format!(
+					"{}!{}@{}",
+					old_nickname,
+					origin["ident"].as_str().unwrap_or("*"),
+					Self::host(origin),
+				);
+
+				vec![
+					IrcMessage::new("NICK")
+						.with_prefix(prefix)
+						.with_param(new_nickname),
+				]
+			}
+
+			| "PRIVMSG" | "PUBMSG" | "NOTICE" => {
+				// NOTE(phisyx): le serveur renvoie ses propres messages à
+				//               l'émetteur, ce qu'un client IRC n'attend pas,
+				//               sauf s'il a activé la capacité `echo-message`,
+				//               ou s'il s'agit de l'historique (CHATHISTORY).
+				if connection.is_itself(origin)
+					&& !connection.has_capability(Capability::EchoMessage)
+					&& payload["tags"]["batch"].is_null()
+				{
+					return vec![];
+				}
+
+				let command = if event == "NOTICE" {
+					"NOTICE"
+				} else {
+					"PRIVMSG"
+				};
+				let target = payload["channel"]
+					.as_str()
+					.or_else(|| payload["target"].as_str())
+					.unwrap_or_default();
+
+				vec![
+					IrcMessage::new(command)
+						.with_prefix(Self::prefix(origin))
+						.with_param(target)
+						.with_param(
+							payload["text"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			| "KICK" => {
+				let mut message = IrcMessage::new("KICK")
+					.with_prefix(Self::prefix(origin))
+					.with_param(payload["channel"].as_str().unwrap_or_default())
+					.with_param(
+						payload["knick"]["nickname"]
+							.as_str()
+							.unwrap_or_default(),
+					);
+				if let Some(reason) = payload["reason"].as_str() {
+					message = message.with_param(reason);
+				}
+				vec![message]
+			}
+
+			| "KILL" => {
+				vec![
+					IrcMessage::new("KILL")
+						.with_prefix(Self::prefix(origin))
+						.with_param(
+							payload["knick"]["nickname"]
+								.as_str()
+								.unwrap_or_default(),
+						)
+						.with_param(
+							payload["reason"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			| "INVITE" => {
+				vec![
+					IrcMessage::new("INVITE")
+						.with_prefix(Self::prefix(origin))
+						.with_param(
+							payload["nick"].as_str().unwrap_or_default(),
+						)
+						.with_param(
+							payload["channel"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			// NOTE: les clients IRC n'ont pas de commande UNINVITE, une notice
+			//       du serveur les en informe.
+			| "UNINVITE" => {
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_param(connection.target())
+						.with_param(format!(
+							"*** Ton invitation sur {} a été retirée par {}",
+							payload["channel"].as_str().unwrap_or_default(),
+							origin["nickname"].as_str().unwrap_or_default(),
+						)),
+				]
+			}
+
+			// NOTE: les clients IRC reçoivent la numérique RPL_KNOCK (710).
+			| "KNOCK" => {
+				let reason = payload["reason"]
+					.as_str()
+					.unwrap_or("a demandé une invitation.");
+				vec![
+					IrcMessage::new("710")
+						.with_prefix(&connection.server_name)
+						.with_param(connection.target())
+						.with_param(
+							payload["channel"].as_str().unwrap_or_default(),
+						)
+						.with_param(Self::prefix(origin))
+						.with_param(reason),
+				]
+			}
+
+			| "MODE" => Self::render_mode(payload),
+
+			| "CHATHISTORY" => {
+				vec![
+					IrcMessage::new("CHATHISTORY")
+						.with_prefix(&connection.server_name)
+						.with_param(
+							payload["subcommand"].as_str().unwrap_or_default(),
+						)
+						.with_param(
+							payload["target"].as_str().unwrap_or_default(),
+						)
+						.with_param(format!(
+							"timestamp={}",
+							payload["timestamp"].as_str().unwrap_or_default()
+						)),
+				]
+			}
+
+			| "FAIL" => {
+				let context = payload["context"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.filter_map(|param| param.as_str());
+				vec![
+					IrcMessage::new("FAIL")
+						.with_prefix(&connection.server_name)
+						.with_param(
+							payload["command"].as_str().unwrap_or_default(),
+						)
+						.with_param(
+							payload["code"].as_str().unwrap_or_default(),
+						)
+						.with_params(context)
+						.with_param(
+							payload["description"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			| "CHANNEL_REGISTRATION" => {
+				let channel = payload["channel"].as_str().unwrap_or_default();
+				let text =
+					if payload["registered"].as_bool().unwrap_or_default() {
+						format!("Le salon {channel} est maintenant enregistré")
+					} else {
+						format!("Le salon {channel} n'est plus enregistré")
+					};
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "SERVER_BAN" => {
+				let ban = &payload["ban"];
+				let kind = ban["kind"].as_str().unwrap_or_default();
+				let mask = ban["mask"].as_str().unwrap_or_default();
+				let text = if payload["added"].as_bool().unwrap_or_default() {
+					let duration = ban["expires_at"].as_str().map_or_else(
+						|| String::from("permanent"),
+						|expires_at| format!("jusqu'au {expires_at}"),
+					);
+					format!(
+						"{kind} appliqué sur {mask} ({duration}): {}",
+						ban["reason"].as_str().unwrap_or_default(),
+					)
+				} else {
+					format!("{kind} retiré de {mask}")
+				};
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "OPER_NOTICE" => {
+				let text = format!(
+					"*** {}",
+					payload["text"].as_str().unwrap_or_default()
+				);
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "SERVER_NOTICE" => {
+				let text = format!(
+					"*** Notice -- {}",
+					payload["text"].as_str().unwrap_or_default()
+				);
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "WALLOPS" => {
+				vec![
+					IrcMessage::new("WALLOPS")
+						.with_prefix(Self::prefix(origin))
+						.with_param(
+							payload["text"].as_str().unwrap_or_default(),
+						),
+				]
+			}
+
+			| "GLOBOPS" => {
+				let text = format!(
+					"*** Global -- {}: {}",
+					origin["nickname"].as_str().unwrap_or_default(),
+					payload["text"].as_str().unwrap_or_default()
+				);
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "NICK_GROUP" => {
+				let account = payload["account"].as_str().unwrap_or_default();
+				let nicknames: Vec<&str> = payload["nicknames"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.filter_map(Value::as_str)
+					.collect();
+				let text = if nicknames.is_empty() {
+					format!("Aucun pseudonyme n'est groupé au compte {account}")
+				} else {
+					format!(
+						"Pseudonymes groupés au compte {account}: {}",
+						nicknames.join(", ")
+					)
+				};
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "NICKNAME_PROTECTED" => {
+				let text = format!(
+					"Le pseudonyme {} est enregistré. Identifie-toi ou change \
+					 de pseudonyme dans les {} secondes, sinon tu seras \
+					 renommé.",
+					payload["nickname"].as_str().unwrap_or_default(),
+					payload["grace_period"].as_u64().unwrap_or_default(),
+				);
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "GHOST" => {
+				let text = format!(
+					"La session utilisant le pseudonyme {} a été déconnectée",
+					payload["nickname"].as_str().unwrap_or_default(),
+				);
+				vec![
+					IrcMessage::new("NOTICE")
+						.with_prefix(&connection.server_name)
+						.with_params([connection.target(), text.as_str()]),
+				]
+			}
+
+			| "SILENCE" => {
+				let sign = if payload["removed"].as_bool().unwrap_or_default() {
+					'-'
+				} else {
+					'+'
+				};
+				payload["users"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.map(|user| {
+						IrcMessage::new("SILENCE")
+							.with_prefix(&connection.server_name)
+							.with_param(format!(
+								"{sign}{}",
+								user["nickname"].as_str().unwrap_or_default()
+							))
+					})
+					.collect()
+			}
+
+			| _ if payload["code"].is_u64() => {
+				Self::render_numeric(payload, connection)
+			}
+
+			| _ => {
+				tracing::debug!(event, "Événement non supporté par IRC");
+				vec![]
+			}
+		}
+	}
+
+	/// Rendu d'une erreur de traduction de commande IRC.
+	pub fn render_command_error(
+		command: &str,
+		error: IrcCommandError,
+		connection: &IrcConnection,
+	) -> IrcMessage
+	{
+		let (code, text) = match error {
+			| IrcCommandError::UnknownCommand => ("421", "Commande inconnue"),
+			| IrcCommandError::NeedMoreParams => {
+				("461", "Pas assez de paramètres")
+			}
+		};
+
+		IrcMessage::new(code)
+			.with_prefix(&connection.server_name)
+			.with_params([connection.target(), command, text])
+	}
+
+	/// Rendu d'une numérique (réponses réservées et erreurs), dont le
+	/// message est déjà au format IRC (`<params> :<trailing>`).
+	fn render_numeric(
+		payload: &Value,
+		connection: &IrcConnection,
+	) -> Vec<IrcMessage>
+	{
+		let code = payload["code"].as_u64().unwrap_or_default();
+		let text = payload["message"]
+			.as_str()
+			.or_else(|| payload["reason"].as_str())
+			.unwrap_or_default();
+
+		let params: Vec<String> = if let Some(trailing) = text.strip_prefix(':')
+		{
+			vec![trailing.to_owned()]
+		} else if let Some((middle, trailing)) = text.split_once(" :") {
+			middle
+				.split_whitespace()
+				.map(String::from)
+				.chain([trailing.to_owned()])
+				.collect()
+		} else {
+			vec![text.to_owned()]
+		};
+
+		vec![
+			IrcMessage::new(format!("{code:03}"))
+				.with_prefix(&connection.server_name)
+				.with_param(connection.target())
+				.with_params(params),
+		]
+	}
+
+	fn render_namreply(
+		payload: &Value,
+		connection: &IrcConnection,
+	) -> Vec<IrcMessage>
+	{
+		let channel = payload["channel"].as_str().unwrap_or_default();
+
+		// NOTE(phisyx): le serveur envoie aussi des listes partielles lors des
+		//               changements de niveaux d'accès, ce qu'un client IRC
+		//               interpréterait comme une liste complète.
+		if !connection
+			.pending_names
+			.iter()
+			.any(|name| name.eq_ignore_ascii_case(channel))
+		{
+			return vec![];
+		}
+
+		let names: Vec<String> = payload["users"]
+			.as_array()
+			.into_iter()
+			.flatten()
+			.map(|member| {
+				let mut levels: Vec<ChannelAccessLevel> = member
+					["access_level"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.filter_map(|level| {
+						serde_json::from_value(level.clone()).ok()
+					})
+					.collect();
+				levels.sort_by_key(|level| std::cmp::Reverse(level.flag()));
+				if !connection.has_capability(Capability::MultiPrefix) {
+					levels.truncate(1);
+				}
+				let symbols: String =
+					levels.iter().map(|level| level.symbol()).collect();
+
+				if connection.has_capability(Capability::UserhostInNames) {
+					format!("{symbols}{}", Self::prefix(member))
+				} else {
+					format!(
+						"{symbols}{}",
+						member["nickname"].as_str().unwrap_or_default()
+					)
+				}
+			})
+			.collect();
+
+		names
+			.chunks(20)
+			.map(|names| {
+				IrcMessage::new("353")
+					.with_prefix(&connection.server_name)
+					.with_params([connection.target(), "=", channel])
+					.with_param(names.join(" "))
+			})
+			.collect()
+	}
+
+	fn render_mode(payload: &Value) -> Vec<IrcMessage>
+	{
+		let mut modestring = String::new();
+		let mut args = vec![];
+
+		for (sign, key) in [('+', "added"), ('-', "removed")] {
+			let modes = payload[key].as_array().cloned().unwrap_or_default();
+			if modes.is_empty() {
+				continue;
+			}
+			modestring.push(sign);
+			for mode in modes {
+				let Some(letter) = mode[0].as_str() else {
+					continue;
+				};
+				modestring.push_str(letter);
+				args.extend(Self::mode_argument(&mode[1]));
+			}
+		}
+
+		if modestring.is_empty() {
+			return vec![];
+		}
+
+		vec![
+			IrcMessage::new("MODE")
+				.with_prefix(Self::prefix(&payload["origin"]))
+				.with_param(payload["target"].as_str().unwrap_or_default())
+				.with_param(modestring)
+				.with_params(args),
+		]
+	}
+
+	/// Argument d'un mode appliqué (masque, clé, limite, pseudonyme).
+	fn mode_argument(apply_mode: &Value) -> Option<String>
+	{
+		if let Some(arg) = apply_mode["args"]
+			.as_array()
+			.and_then(|args| args.first())
+			.and_then(Value::as_str)
+		{
+			return Some(arg.to_owned());
+		}
+
+		let Value::Object(flag) = &apply_mode["flag"] else {
+			return None;
+		};
+
+		if let Some(mask) = flag.get("mask") {
+			return Some(format!(
+				"{}!{}@{}",
+				mask["nick"].as_str().unwrap_or("*"),
+				mask["ident"].as_str().unwrap_or("*"),
+				mask["host"].as_str().unwrap_or("*"),
+			));
+		}
+
+		match flag.values().next()? {
+			| Value::String(s) if !s.is_empty() => Some(s.to_owned()),
+			| Value::Number(n) => Some(n.to_string()),
+			| _ => None,
+		}
+	}
+
+	/// Préfixe `nick!ident@host` d'une origine, ou le nom du serveur
+	/// lorsque l'origine est le serveur.
+	fn prefix(origin: &Value) -> String
+	{
+		if origin["ident"].is_null()
+			&& let Some(server_name) = origin["nickname"].as_str()
+		{
+			return server_name.to_owned();
+		}
+
+		format!(
+			"{}!{}@{}",
+			origin["nickname"].as_str().unwrap_or("*"),
+			origin["ident"].as_str().unwrap_or("*"),
+			Self::host(origin),
+		)
+	}
+
+	fn host(origin: &Value) -> &str
+	{
+		origin["host"]["vhost"]
+			.as_str()
+			.or_else(|| origin["host"]["cloaked"].as_str())
+			.unwrap_or("*")
+	}
+}
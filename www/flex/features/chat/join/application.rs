@@ -15,6 +15,7 @@ use flex_chat::channel::{
 	ChannelAccessControlInviteInterface,
 	ChannelInterface,
 	ChannelMember,
+	ChannelMemberInterface,
 	ChannelsSessionInterface,
 	MemberInterface,
 };
@@ -34,6 +35,7 @@ use super::{
 	JoinErrorResponseInterface,
 };
 use crate::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
 use crate::features::chat::mode::{
 	ChannelMemberDTO,
 	ModeAccessControlClientSocketCommandResponseInterface,
@@ -60,6 +62,14 @@ pub trait JoinApplicationInterface<'a>
 		forced: bool,
 	);
 
+	/// Révèle aux autres membres du salon un membre jusqu'ici masqué
+	/// (drapeau +D).
+	fn reveal_channel_member(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
 	/// Rejoint un salon du serveur ou le crée.
 	fn join_or_create_channel(
 		&self,
@@ -102,8 +112,19 @@ impl<'a> JoinApplicationInterface<'a> for ChatApplication
 	)
 	{
 		self.clients.add_channel_on_client(client_socket.cid(), &channel.id());
+		self.restore_access_level(client_socket, channel);
 
-		client_socket.emit_join(channel, forced);
+		// NOTE: sur un salon +D, l'arrivée du client n'est pas annoncée aux
+		//       autres membres, tant qu'il n'a pas parlé.
+		let delayed = channel.modes_settings.has_delayed_join_flag();
+		if delayed {
+			if let Some(member) = channel.member_mut(client_socket.cid()) {
+				member.set_hidden(true);
+			}
+			client_socket.emit_delayed_join(channel, forced);
+		} else {
+			client_socket.emit_join(channel, forced);
+		}
 
 		// NOTE: Émettre le sujet du salon au client courant.
 		client_socket.send_rpl_topic(channel, false);
@@ -113,16 +134,45 @@ impl<'a> JoinApplicationInterface<'a> for ChatApplication
 
 		// NOTE: Émettre au client courant les membres du salon.
 		let map_member = |member: &ChannelMember| -> Option<ChannelMemberDTO> {
+			if member.is_hidden() && member.id() != client_socket.cid() {
+				return None;
+			}
 			let client = self.clients.get(member.id())?;
 			Some(ChannelMemberDTO::from((client, member)))
 		};
-		client_socket.send_rpl_namreply(channel, map_member);
+		if delayed || channel.is_auditorium() {
+			client_socket.send_rpl_namreply_to_self(channel, map_member);
+		} else {
+			client_socket.send_rpl_namreply(channel, map_member);
+		}
 
 		channel.remove_invite(client_socket.cid());
 
 		client_socket.emit_all_channel_access_control(channel);
 	}
 
+	fn reveal_channel_member(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let Some(mut channel) = self.channels.get_mut(channel_name) else {
+			return;
+		};
+
+		let Some(member) = channel.member_mut(client_socket.cid()) else {
+			return;
+		};
+
+		if !member.is_hidden() {
+			return;
+		}
+
+		member.set_hidden(false);
+		client_socket.emit_revealed_join(&channel);
+	}
+
 	fn join_or_create_channel(
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
@@ -18,7 +18,9 @@ pub enum JoinChannelPermissionError
 	ERR_BANNEDFROMCHAN,
 	ERR_CHANNELISFULL,
 	ERR_INVITEONLYCHAN,
+	ERR_NEEDREGGEDNICK,
 	ERR_NOSUCHCHANNEL,
 	ERR_OPERONLY,
+	ERR_SECUREONLYCHAN,
 	ERR_USERONCHANNEL,
 }
@@ -8,6 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use flex_chat::channel::FloodKind;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
@@ -19,7 +20,10 @@ use crate::features::chat::join::{
 	JoinCommandFormData,
 	JoinErrorResponseInterface,
 };
-use crate::features::chat::mode::ModeAccessControlClientSocketErrorRepliesInterface;
+use crate::features::chat::mode::{
+	ModeAccessControlClientSocketErrorRepliesInterface,
+	ModeChannelFloodApplicationInterface,
+};
 use crate::features::chat::oper::OperClientSocketErrorRepliesInterface;
 
 // --------- //
@@ -89,7 +93,13 @@ impl WebSocketHandler for JoinHandler
 				channel_name.as_ref(),
 				channel_key.as_ref(),
 			) {
-				| Ok(_) => continue,
+				| Ok(_) => {
+					app.protect_channel_from_flood(
+						&client_socket,
+						channel_name.as_ref(),
+						FloodKind::Join,
+					);
+				}
 				| Err(err) => {
 					match err {
 						| JoinChannelPermissionError::ERR_NOSUCHCHANNEL => {}
@@ -106,6 +116,12 @@ impl WebSocketHandler for JoinHandler
 							client_socket.send_err_inviteonlychan(channel_name);
 						}
 						| JoinChannelPermissionError::ERR_USERONCHANNEL => {}
+						| JoinChannelPermissionError::ERR_NEEDREGGEDNICK => {
+							client_socket.send_err_needreggednick(channel_name);
+						}
+						| JoinChannelPermissionError::ERR_SECUREONLYCHAN => {
+							client_socket.send_err_secureonlychan(channel_name);
+						}
 						| JoinChannelPermissionError::ERR_OPERONLY => {
 							client_socket.send_err_operonly(channel_name);
 						}
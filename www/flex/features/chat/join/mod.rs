@@ -14,6 +14,8 @@ flex_kernel::import! {
 	pub(crate) mod errors use {
 		pub mod err_badchannelkey use *;
 		pub mod err_channelisfull use *;
+		pub mod err_needreggednick use *;
+		pub mod err_secureonlychan use *;
 		pub mod join_channel_permission_error use *;
 	};
 
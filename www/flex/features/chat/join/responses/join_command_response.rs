@@ -22,6 +22,8 @@ command_response! {
 		/// NOTE: Seuls les admins peuvent forcer un client de rejoindre un
 		/// salon.
 		forced: bool,
+		/// Le nom réel du client (capacité serveur `extended-join`).
+		realname: &'channel str,
 	}
 }
 
@@ -35,6 +37,15 @@ pub trait JoinCommandResponseInterface: ClientSocketInterface
 
 	/// Émet au client la réponse liée à la commande /JOIN.
 	fn emit_join(&self, channel: &Self::Channel, forced: bool);
+
+	/// Émet uniquement au client la réponse liée à la commande /JOIN. Les
+	/// autres membres du salon ne sont pas informés de l'arrivée du client
+	/// (drapeau +D).
+	fn emit_delayed_join(&self, channel: &Self::Channel, forced: bool);
+
+	/// Émet aux autres membres du salon la réponse liée à la commande /JOIN
+	/// d'un membre jusqu'ici masqué (drapeau +D).
+	fn emit_revealed_join(&self, channel: &Self::Channel);
 }
 
 // -------------- //
@@ -55,11 +66,53 @@ impl<'s> JoinCommandResponseInterface for Socket<'s>
 			origin: &origin,
 			channel: channel.name(),
 			forced,
+			realname: &self.user().realname,
 			tags: JoinCommandResponse::default_tags(),
 		};
 
+		// NOTE: sur un salon +u, l'arrivée d'un membre sans niveau d'accès
+		//       n'est annoncée qu'aux opérateurs du salon.
 		_ = self.socket().join(channel.room());
 		self.emit(cmd_join.name(), &cmd_join);
-		self.emit_to(channel.room(), cmd_join.name(), cmd_join);
+		self.emit_to(
+			channel.member_room(self.cid()),
+			cmd_join.name(),
+			cmd_join,
+		);
+	}
+
+	fn emit_delayed_join(&self, channel: &Self::Channel, forced: bool)
+	{
+		let origin = Origin::from(self.client());
+
+		let cmd_join = JoinCommandResponse {
+			origin: &origin,
+			channel: channel.name(),
+			forced,
+			realname: &self.user().realname,
+			tags: JoinCommandResponse::default_tags(),
+		};
+
+		_ = self.socket().join(channel.room());
+		self.emit(cmd_join.name(), cmd_join);
+	}
+
+	fn emit_revealed_join(&self, channel: &Self::Channel)
+	{
+		let origin = Origin::from(self.client());
+
+		let cmd_join = JoinCommandResponse {
+			origin: &origin,
+			channel: channel.name(),
+			forced: false,
+			realname: &self.user().realname,
+			tags: JoinCommandResponse::default_tags(),
+		};
+
+		self.emit_to(
+			channel.member_room(self.cid()),
+			cmd_join.name(),
+			cmd_join,
+		);
 	}
 }
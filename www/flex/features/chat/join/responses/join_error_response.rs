@@ -14,6 +14,8 @@ use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 use crate::features::chat::join::{
 	ErrBadchannelkeyError,
 	ErrChannelisfullError,
+	ErrNeedreggednickError,
+	ErrSecureonlychanError,
 };
 
 // --------- //
@@ -35,6 +37,18 @@ pub trait JoinErrorResponseInterface: ClientSocketInterface
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 	);
 
+	/// Émet au client l'erreur [crate::ERR_NEEDREGGEDNICK].
+	fn send_err_needreggednick(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
+	/// Émet au client l'erreur [crate::ERR_SECUREONLYCHAN].
+	fn send_err_secureonlychan(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
 	// TODO: ERR_TOOMANYCHANNELS
 	#[allow(dead_code)]
 	fn send_err_toomanychannels(&self) {}
@@ -75,4 +89,32 @@ impl<'s> JoinErrorResponseInterface for Socket<'s>
 		};
 		self.emit(err_channelisfull.name(), err_channelisfull);
 	}
+
+	fn send_err_needreggednick(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_needreggednick = ErrNeedreggednickError {
+			channel: channel_name,
+			tags: ErrNeedreggednickError::default_tags(),
+			origin: &origin,
+		};
+		self.emit(err_needreggednick.name(), err_needreggednick);
+	}
+
+	fn send_err_secureonlychan(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_secureonlychan = ErrSecureonlychanError {
+			channel: channel_name,
+			tags: ErrSecureonlychanError::default_tags(),
+			origin: &origin,
+		};
+		self.emit(err_secureonlychan.name(), err_secureonlychan);
+	}
 }
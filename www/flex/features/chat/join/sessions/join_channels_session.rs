@@ -11,12 +11,17 @@
 use flex_chat::channel::{
 	ChannelAccessControlBanInterface,
 	ChannelAccessControlInviteExceptInterface,
+	ChannelAccessControlInviteInterface,
 	ChannelInterface,
 	ChannelMemberInterface,
 	ChannelsSessionInterface,
 };
 use flex_chat::client::{Client, ClientInterface};
-use flex_chat::user::UserOperatorInterface;
+use flex_chat::user::{
+	UserFlagInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
 
 use crate::features::chat::join::JoinChannelPermissionError;
 use crate::features::chat::sessions::ChannelsSession;
@@ -67,7 +72,7 @@ impl JoinChannelsSessionInterface for ChannelsSession
 			return Err(JoinChannelPermissionError::ERR_CHANNELISFULL);
 		}
 
-		if channel.is_banned(client.user()) {
+		if channel.is_banned(client.user(), client.channels()) {
 			return Err(JoinChannelPermissionError::ERR_BANNEDFROMCHAN);
 		}
 
@@ -82,11 +87,13 @@ impl JoinChannelsSessionInterface for ChannelsSession
 		}
 
 		if channel.modes_settings.has_invite_only_flag() {
-			if channel.access_control.invite_list.contains(client.cid()) {
+			if channel.is_invited(client.cid()) {
 				return Ok(());
 			}
 
-			if channel.isin_invitelist_exception(client.user()) {
+			if channel
+				.isin_invitelist_exception(client.user(), client.channels())
+			{
 				return Ok(());
 			}
 			return Err(JoinChannelPermissionError::ERR_INVITEONLYCHAN);
@@ -98,6 +105,18 @@ impl JoinChannelsSessionInterface for ChannelsSession
 			return Err(JoinChannelPermissionError::ERR_OPERONLY);
 		}
 
+		if channel.modes_settings.has_registered_only_flag()
+			&& client.user().account().is_none()
+		{
+			return Err(JoinChannelPermissionError::ERR_NEEDREGGEDNICK);
+		}
+
+		if channel.modes_settings.has_secure_only_flag()
+			&& !client.user().has_secure_flag()
+		{
+			return Err(JoinChannelPermissionError::ERR_SECUREONLYCHAN);
+		}
+
 		Ok(())
 	}
 }
@@ -24,7 +24,7 @@ flex_kernel::import! {
 	};
 
 	pub(crate) mod responses use {
-		pub(super) mod kick_command_response use *;
+		pub mod kick_command_response use *;
 		pub(super) mod kick_error_response use *;
 	};
 }
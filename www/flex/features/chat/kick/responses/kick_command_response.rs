@@ -69,6 +69,6 @@ impl<'s> KickChannelClientSocketCommandResponseInterface for Socket<'s>
 		};
 
 		self.emit_within(channel.room(), cmd_kick.name(), cmd_kick);
-		_ = member_kicked.socket().leave(channel.room());
+		member_kicked.leave_channel_rooms(channel.name());
 	}
 }
@@ -10,16 +10,18 @@
 
 use flex_chat::client::ClientSocketInterface;
 use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
-use flex_chat::user::UserInterface;
+use flex_chat::user::{ServerNoticeMask, UserInterface};
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
 use crate::features::chat::kill::{
 	KillApplicationInterface,
 	KillClientSocketCommandResponseInterface,
 	KillCommandFormData,
 };
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
 
 // --------- //
 // Structure //
@@ -79,7 +81,10 @@ impl WebSocketHandler for KillHandler
 		Data(data): Data<KillCommandFormData>,
 	)
 	{
-		let Some(client_socket) = app.current_client_operator(&socket) else {
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Kill,
+		) else {
 			return;
 		};
 
@@ -95,6 +100,15 @@ impl WebSocketHandler for KillHandler
 		}
 
 		client_socket.emit_kill(&knick_socket, &data.comment);
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Kills,
+			&format!(
+				"{} a été sanctionné d'un KILL par {} ({})",
+				knick_socket.user().nickname(),
+				client_socket.user().nickname(),
+				&data.comment,
+			),
+		);
 
 		let error = format!(
 			"Déconnexion: tu as été sanctionné d'un KILL par {} ({})",
@@ -0,0 +1,103 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use chrono::TimeDelta;
+use flex_chat::channel::{
+	Channel,
+	ChannelAccessLevel,
+	ChannelInterface,
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
+	MemberInterface,
+};
+use flex_chat::client::{ClientID, ClientSocketInterface, Socket};
+
+use super::KnockThrottle;
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait KnockApplicationInterface
+{
+	type Channel: ChannelInterface;
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Les membres d'un salon habilités à inviter un client sur le salon
+	/// (demi-opérateurs et plus).
+	fn knock_recipients(
+		&self,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	) -> Vec<ClientID>;
+
+	/// Enregistre un KNOCK d'un client sur un salon, à moins que le client
+	/// ou le salon n'ait déjà reçu un KNOCK récemment.
+	fn throttle_knock(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	) -> Result<(), KnockThrottle>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl KnockApplicationInterface for ChatApplication
+{
+	type Channel = Channel;
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn knock_recipients(
+		&self,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	) -> Vec<ClientID>
+	{
+		let Some(channel) = self.channels.get(channel_id) else {
+			return vec![];
+		};
+
+		channel
+			.members()
+			.values()
+			.filter(|member| {
+				member.access_level().iter().any(|access_level| {
+					access_level.flag()
+						>= ChannelAccessLevel::HalfOperator.flag()
+				})
+			})
+			.map(|member| *member.id())
+			.collect()
+	}
+
+	fn throttle_knock(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	) -> Result<(), KnockThrottle>
+	{
+		let config = client_socket
+			.socket()
+			.req_parts()
+			.extensions
+			.get::<FlexChatConfig>()
+			.map(|config| config.knock.clone())
+			.unwrap_or_default();
+
+		self.knock_throttle.hit(
+			client_socket.cid(),
+			channel_id,
+			TimeDelta::seconds(config.user_delay as i64),
+			TimeDelta::seconds(config.channel_delay as i64),
+		)
+	}
+}
@@ -0,0 +1,114 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	ChannelAccessControlBanInterface,
+	ChannelInterface,
+	ChannelMemberInterface,
+};
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_chat::client::{ClientInterface, ClientSocketInterface};
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::knock::{
+	KnockApplicationInterface,
+	KnockClientSocketCommandResponseInterface,
+	KnockClientSocketErrorReplies,
+	KnockCommandFormData,
+};
+use crate::features::chat::mode::ModeAccessControlClientSocketErrorRepliesInterface;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct KnockHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for KnockHandler
+{
+	type App = ChatApplication;
+	type Data = KnockCommandFormData;
+
+	const EVENT_NAME: &'static str = "KNOCK";
+
+	/// La commande KNOCK permet à un client de demander une invitation sur un
+	/// salon en mode "invitation seulement" (+i). La demande est transmise
+	/// aux membres du salon ayant le niveau d'accès demi-opérateur ou plus,
+	/// qui PEUVENT y répondre par la commande INVITE. Les utilisateurs
+	/// bannis du salon, ainsi que les salons ayant le drapeau +K, ne
+	/// PEUVENT pas utiliser cette commande. Les demandes répétées d'un même
+	/// client ou sur un même salon sont limitées dans le temps.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<KnockCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(channel) = app.get_channel(&data.channel) else {
+			client_socket.send_err_nosuchchannel(&data.channel);
+			return;
+		};
+
+		if channel.member(client_socket.cid()).is_some() {
+			client_socket.send_err_knockonchan(channel.name());
+			return;
+		}
+
+		if !channel.modes_settings.has_invite_only_flag() {
+			client_socket.send_err_chanopen(channel.name());
+			return;
+		}
+
+		if channel.modes_settings.has_no_knock_flag() {
+			client_socket.send_err_cannotknock(channel.name());
+			return;
+		}
+
+		if channel
+			.is_banned(client_socket.user(), client_socket.client().channels())
+		{
+			client_socket.send_err_bannedfromchan(channel.name());
+			return;
+		}
+
+		let channel_name = channel.name().to_owned();
+
+		drop(channel);
+
+		if let Err(throttle) = app.throttle_knock(&client_socket, &channel_name)
+		{
+			client_socket.send_err_toomanyknock(&channel_name, throttle);
+			return;
+		}
+
+		for member_id in app.knock_recipients(&channel_name) {
+			let Some(member_socket) =
+				app.find_socket_by_id(&socket, &member_id)
+			else {
+				continue;
+			};
+			client_socket.emit_knock(
+				&channel_name,
+				data.reason.as_deref(),
+				&member_socket,
+			);
+		}
+
+		client_socket.send_rpl_knockdlvr(&channel_name);
+	}
+}
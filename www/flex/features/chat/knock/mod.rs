@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod knock_handler use *;
+	};
+
+	pub mod sessions use {
+		pub mod knock_throttle_session use *;
+	};
+
+	mod errors use {
+		pub mod err_cannotknock use *;
+		pub mod err_chanopen use *;
+		pub mod err_knockonchan use *;
+		pub mod err_toomanyknock use *;
+	};
+
+	mod forms use {
+		pub mod knock_form use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(super) mod knock_command_response use *;
+		pub(crate) mod knock_error_response use *;
+		pub(super) mod rpl_knockdlvr use *;
+	};
+}
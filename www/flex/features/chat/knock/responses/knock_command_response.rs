@@ -0,0 +1,79 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+use super::RplKnockdlvrReply;
+
+command_response! {
+	struct KNOCK<'channel, 'reason>
+	{
+		/// Le salon sur lequel le client demande une invitation.
+		channel: &'channel str,
+		/// La raison de la demande.
+		reason: Option<&'reason str>,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait KnockClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet à un opérateur du salon la demande d'invitation du client. Le
+	/// destinataire PEUT y répondre par la commande /INVITE.
+	fn emit_knock(
+		&self,
+		channel_name: &str,
+		reason: Option<&str>,
+		target: &Self,
+	);
+
+	/// Émet au client la confirmation de la transmission de son KNOCK.
+	fn send_rpl_knockdlvr(&self, channel_name: &str);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> KnockClientSocketCommandResponseInterface for Socket<'s>
+{
+	fn emit_knock(
+		&self,
+		channel_name: &str,
+		reason: Option<&str>,
+		target: &Self,
+	)
+	{
+		let client_origin = Origin::from(self.client());
+		let knock_command = KnockCommandResponse {
+			origin: &client_origin,
+			tags: KnockCommandResponse::default_tags(),
+			channel: channel_name,
+			reason,
+		};
+		target.emit(knock_command.name(), knock_command);
+	}
+
+	fn send_rpl_knockdlvr(&self, channel_name: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_knockdlvr = RplKnockdlvrReply {
+			origin: &origin,
+			tags: RplKnockdlvrReply::default_tags(),
+			channel: channel_name,
+		};
+		self.emit(rpl_knockdlvr.name(), rpl_knockdlvr);
+	}
+}
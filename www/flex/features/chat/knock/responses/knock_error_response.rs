@@ -0,0 +1,117 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{Channel, ChannelInterface};
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::knock::{
+	ErrCannotknockError,
+	ErrChanopenError,
+	ErrKnockonchanError,
+	ErrToomanyknockError,
+	KnockThrottle,
+};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait KnockClientSocketErrorReplies: ClientSocketInterface
+{
+	type Channel: ChannelInterface;
+
+	fn send_err_cannotknock(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
+	fn send_err_chanopen(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
+	fn send_err_knockonchan(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	);
+
+	fn send_err_toomanyknock(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		throttle: KnockThrottle,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> KnockClientSocketErrorReplies for Socket<'s>
+{
+	type Channel = Channel;
+
+	fn send_err_cannotknock(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_cannotknock = ErrCannotknockError {
+			origin: &origin,
+			tags: ErrCannotknockError::default_tags(),
+			channel,
+		};
+		self.emit(err_cannotknock.name(), err_cannotknock);
+	}
+
+	fn send_err_chanopen(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_chanopen = ErrChanopenError {
+			origin: &origin,
+			tags: ErrChanopenError::default_tags(),
+			channel,
+		};
+		self.emit(err_chanopen.name(), err_chanopen);
+	}
+
+	fn send_err_knockonchan(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_knockonchan = ErrKnockonchanError {
+			origin: &origin,
+			tags: ErrKnockonchanError::default_tags(),
+			channel,
+		};
+		self.emit(err_knockonchan.name(), err_knockonchan);
+	}
+
+	fn send_err_toomanyknock(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		throttle: KnockThrottle,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let err_toomanyknock = ErrToomanyknockError {
+			origin: &origin,
+			tags: ErrToomanyknockError::default_tags(),
+			channel,
+			scope: throttle.scope(),
+		};
+		self.emit(err_toomanyknock.name(), err_toomanyknock);
+	}
+}
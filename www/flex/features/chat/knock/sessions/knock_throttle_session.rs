@@ -0,0 +1,101 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use chrono::{DateTime, TimeDelta, Utc};
+use dashmap::DashMap;
+use flex_chat::client::ClientID;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Dates des derniers KNOCK, par client et par salon.
+#[derive(Default)]
+pub struct KnockThrottleSession
+{
+	/// Date du dernier KNOCK de chaque client.
+	clients: DashMap<ClientID, DateTime<Utc>>,
+	/// Date du dernier KNOCK sur chaque salon.
+	channels: DashMap<String, DateTime<Utc>>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum KnockThrottle
+{
+	/// Le client a déjà frappé à la porte d'un salon récemment.
+	Client,
+	/// Quelqu'un a déjà frappé à la porte du salon récemment.
+	Channel,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KnockThrottleSession
+{
+	/// Enregistre un KNOCK d'un client sur un salon, à moins qu'il ne soit
+	/// limité par l'un des délais donnés.
+	pub fn hit(
+		&self,
+		client_id: &ClientID,
+		channel_id: &str,
+		user_delay: TimeDelta,
+		channel_delay: TimeDelta,
+	) -> Result<(), KnockThrottle>
+	{
+		let now = Utc::now();
+		let channel_id = channel_id.to_lowercase();
+
+		if self
+			.clients
+			.get(client_id)
+			.is_some_and(|last_knock| now - *last_knock < user_delay)
+		{
+			return Err(KnockThrottle::Client);
+		}
+
+		if self
+			.channels
+			.get(&channel_id)
+			.is_some_and(|last_knock| now - *last_knock < channel_delay)
+		{
+			return Err(KnockThrottle::Channel);
+		}
+
+		self.clients.insert(*client_id, now);
+		self.channels.insert(channel_id, now);
+
+		// NOTE: les entrées expirées sont retirées au fil de l'eau, pour ne
+		//       pas garder en mémoire les clients déconnectés.
+		self.clients.retain(|_, last_knock| now - *last_knock < user_delay);
+		self.channels.retain(|_, last_knock| now - *last_knock < channel_delay);
+
+		Ok(())
+	}
+}
+
+impl KnockThrottle
+{
+	/// Portée de la limite, pour ERR_TOOMANYKNOCK.
+	pub fn scope(&self) -> &'static str
+	{
+		match self {
+			| Self::Client => "user",
+			| Self::Channel => "channel",
+		}
+	}
+}
@@ -11,6 +11,7 @@
 use flex_chat::channel::{
 	Channel,
 	ChannelAccessControlBanInterface,
+	ChannelAccessControlQuietInterface,
 	ChannelAccessLevel,
 	ChannelInterface,
 	ChannelMemberInterface,
@@ -18,7 +19,8 @@ use flex_chat::channel::{
 	ChannelWritePermission,
 	MemberInterface,
 };
-use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::UserInterface;
 
 use crate::features::ChatApplication;
 use crate::features::chat::oper::OperApplicationInterface;
@@ -34,6 +36,14 @@ pub trait MessageApplicationInterface
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 	) -> ChannelWritePermission;
+
+	/// Le contenu du message est-il autorisé sur le salon (drapeaux +c et
+	/// +C)?
+	fn is_message_allowed_on_channel(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		text: &str,
+	) -> Result<(), ChannelNoPermissionCause>;
 }
 
 // -------------- //
@@ -62,6 +72,10 @@ impl MessageApplicationInterface for ChatApplication
 		let no_external_messages_flag =
 			{ channel.modes_settings.has_no_external_messages_flag() };
 
+		let registered_moderated_flag =
+			channel.modes_settings.has_registered_moderated_flag()
+				&& client_socket.user().account().is_none();
+
 		let Some(member) = channel.member(client_socket.cid()) else {
 			if self.is_client_global_operator(client_socket) {
 				return ChannelWritePermission::Bypass;
@@ -79,6 +93,12 @@ impl MessageApplicationInterface for ChatApplication
 				);
 			}
 
+			if registered_moderated_flag {
+				return ChannelWritePermission::No(
+					ChannelNoPermissionCause::ERR_NEEDREGGEDNICK,
+				);
+			}
+
 			return ChannelWritePermission::Bypass;
 		};
 
@@ -87,12 +107,26 @@ impl MessageApplicationInterface for ChatApplication
 		}
 		let member_hal = member.highest_access_level();
 
-		if channel.is_banned(client_socket.user()) && member_hal.is_none() {
+		let user_channels = client_socket.client().channels();
+
+		if channel.is_banned(client_socket.user(), user_channels)
+			&& member_hal.is_none()
+		{
 			return ChannelWritePermission::No(
 				ChannelNoPermissionCause::ERR_BANNEDFROMCHAN,
 			);
 		}
 
+		// NOTE: un utilisateur muet (+Q ou masque `~q` de la liste des bans)
+		//       peut rester sur le salon, mais ne peut pas y parler.
+		if channel.is_quieted(client_socket.user(), user_channels)
+			&& member_hal.is_none()
+		{
+			return ChannelWritePermission::No(
+				ChannelNoPermissionCause::ERR_QUIETED,
+			);
+		}
+
 		if moderate_flag
 			&& member_hal
 				.filter(|level| level.flag() >= ChannelAccessLevel::Vip.flag())
@@ -103,6 +137,58 @@ impl MessageApplicationInterface for ChatApplication
 			);
 		}
 
+		if registered_moderated_flag
+			&& member_hal
+				.filter(|level| level.flag() >= ChannelAccessLevel::Vip.flag())
+				.is_none()
+		{
+			return ChannelWritePermission::No(
+				ChannelNoPermissionCause::ERR_NEEDREGGEDNICK,
+			);
+		}
+
 		ChannelWritePermission::Yes(member.clone())
 	}
+
+	fn is_message_allowed_on_channel(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		text: &str,
+	) -> Result<(), ChannelNoPermissionCause>
+	{
+		let Some(channel) = self.get_channel(channel_name) else {
+			return Err(ChannelNoPermissionCause::ERR_NOSUCHCHANNEL);
+		};
+
+		if channel.modes_settings.has_no_ctcp_flag() && is_ctcp(text) {
+			return Err(ChannelNoPermissionCause::ERR_NOCTCP);
+		}
+
+		if channel.modes_settings.has_no_colors_flag() && has_irc_formats(text)
+		{
+			return Err(ChannelNoPermissionCause::ERR_NOCOLORSONCHAN);
+		}
+
+		Ok(())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Est-ce que le texte est un message CTCP? Les actions (`/me`) ne sont pas
+/// considérées comme des messages CTCP.
+fn is_ctcp(text: &str) -> bool
+{
+	text.starts_with('\x01') && !text.starts_with("\x01ACTION ")
+}
+
+/// Est-ce que le texte contient des codes de couleurs ou de formats IRC?
+fn has_irc_formats(text: &str) -> bool
+{
+	const IRC_FORMAT_CODES: [char; 9] = [
+		'\x02', '\x03', '\x04', '\x0F', '\x11', '\x16', '\x1D', '\x1E', '\x1F',
+	];
+	text.contains(IRC_FORMAT_CODES)
 }
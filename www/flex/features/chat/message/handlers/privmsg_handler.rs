@@ -9,12 +9,22 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
-use flex_chat::client::{ClientInterface, ClientSocketInterface, Origin};
-use flex_chat::user::UserAwayInterface;
+use flex_chat::client::{
+	Capability,
+	ClientInterface,
+	ClientSocketInterface,
+	Origin,
+};
+use flex_chat::user::{UserAwayInterface, UserInterface};
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::ChatApplication;
+use crate::features::chat::chathistory::{
+	ChatHistoryApplicationInterface,
+	ChatHistoryEntity,
+	ChatHistoryTags,
+};
 use crate::features::chat::message::{
 	PrivmsgClientSocketCommandResponseInterface,
 	PrivmsgCommandFormData,
@@ -47,15 +57,42 @@ impl WebSocketHandler for PrivmsgHandler
 	)
 	{
 		let client_socket = app.current_client(&socket);
+		app.clients.update_last_activity(client_socket.cid());
 
 		for target in data.targets.iter() {
 			let origin = Origin::from(client_socket.client());
-			client_socket.emit_privmsg(
-				target,
-				data.formats.as_ref().zip(data.colors.as_ref()),
-				&data.text,
-				&origin,
-			);
+			let history_tags = ChatHistoryTags::new();
+			let sender_id = app.chathistory_identity(&client_socket);
+			let history_message = |payload, recipient_id: &str| {
+				ChatHistoryEntity::new(
+					&history_tags,
+					ChatHistoryEntity::private_target(&sender_id, recipient_id),
+					client_socket.user().nickname(),
+					target,
+					PrivmsgHandler::EVENT_NAME,
+					&payload,
+				)
+				.with_identities(&sender_id, recipient_id)
+			};
+
+			if client_socket.client().has_capability(Capability::EchoMessage)
+				|| client_socket.has_same_nickname(target)
+			{
+				let payload = client_socket.emit_privmsg(
+					&history_tags,
+					target,
+					data.formats.as_ref().zip(data.colors.as_ref()),
+					&data.text,
+					&origin,
+				);
+
+				if client_socket.has_same_nickname(target) {
+					app.store_chathistory(
+						&socket,
+						history_message(payload, &sender_id),
+					);
+				}
+			}
 
 			if client_socket.has_same_nickname(target) {
 				continue;
@@ -73,12 +110,21 @@ impl WebSocketHandler for PrivmsgHandler
 				continue;
 			}
 
-			target_client_socket.emit_privmsg(
+			let payload = target_client_socket.emit_privmsg(
+				&history_tags,
 				target,
 				data.formats.as_ref().zip(data.colors.as_ref()),
 				&data.text,
 				&origin,
 			);
+			app.store_chathistory(
+				&socket,
+				history_message(
+					payload,
+					&app.chathistory_identity(&target_client_socket),
+				),
+			);
+
 			if target_client_socket.client().user().is_away() {
 				client_socket.send_rpl_away(&target_client_socket);
 			}
@@ -8,19 +8,33 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_chat::channel::{ChannelNoPermissionCause, ChannelWritePermission};
-use flex_chat::client::ClientSocketInterface;
+use flex_chat::channel::{
+	ChannelNoPermissionCause,
+	ChannelWritePermission,
+	FloodKind,
+};
+use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_chat::user::UserInterface;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::ChatApplication;
+use crate::features::chat::chathistory::{
+	ChatHistoryApplicationInterface,
+	ChatHistoryEntity,
+	ChatHistoryTags,
+};
+use crate::features::chat::join::JoinApplicationInterface;
 use crate::features::chat::message::{
 	MessageApplicationInterface,
 	PubmsgClientSocketCommandResponseInterface,
 	PubmsgClientSocketErrorRepliesInterface,
 	PubmsgCommandFormData,
 };
-use crate::features::chat::mode::ChannelMemberDTO;
+use crate::features::chat::mode::{
+	ChannelMemberDTO,
+	ModeChannelFloodApplicationInterface,
+};
 
 // --------- //
 // Structure //
@@ -47,48 +61,134 @@ impl WebSocketHandler for PubmsgHandler
 	)
 	{
 		let client_socket = app.current_client(&socket);
+		app.clients.update_last_activity(client_socket.cid());
 
 		for channel in data.channels.iter() {
 			let channel_permission =
 				app.is_client_able_to_write_on_channel(&client_socket, channel);
 
-			match channel_permission {
+			let history_tags = ChatHistoryTags::new();
+
+			if let ChannelWritePermission::No(cause) = &channel_permission {
+				send_err_cannotsendtochan(&client_socket, channel, cause);
+				continue;
+			}
+
+			if let Err(cause) =
+				app.is_message_allowed_on_channel(channel, &data.text)
+			{
+				send_err_cannotsendtochan(&client_socket, channel, &cause);
+				continue;
+			}
+
+			// NOTE: les couleurs et formats de texte sont retirés du message
+			//       sur un salon +c.
+			let formats_colors = app
+				.get_channel(channel)
+				.filter(|channel| !channel.modes_settings.has_no_colors_flag())
+				.and(data.formats.as_ref().zip(data.colors.as_ref()));
+
+			let payload = match channel_permission {
 				| ChannelWritePermission::Yes(member) => {
+					app.protect_channel_from_flood(
+						&client_socket,
+						channel,
+						FloodKind::Message,
+					);
+					// NOTE: le client a été sanctionné du salon, son message
+					//       n'est pas transmis.
+					if app.protect_channel_from_flood(
+						&client_socket,
+						channel,
+						FloodKind::Text,
+					) {
+						continue;
+					}
+
+					// NOTE: un membre masqué d'un salon +D est révélé aux
+					//       autres membres lorsqu'il parle.
+					if member.is_hidden() {
+						app.reveal_channel_member(&client_socket, channel);
+					}
+
+					let Some(channel) = app.get_channel(channel) else {
+						continue;
+					};
+
+					// NOTE: les messages des membres sans niveau d'accès d'un
+					//       salon +u ne sont transmis qu'aux opérateurs du
+					//       salon, ils ne sont donc pas conservés dans
+					//       l'historique du salon.
+					let visible =
+						channel.is_member_visible(client_socket.cid());
+
 					let channel_member = ChannelMemberDTO::from((
 						client_socket.client(),
 						member,
 					));
-					client_socket.emit_pubmsg(
-						channel,
-						data.formats.as_ref().zip(data.colors.as_ref()),
+					let payload = client_socket.emit_pubmsg(
+						&history_tags,
+						&channel,
+						formats_colors,
 						&data.text,
 						&channel_member,
 					);
+
+					if !visible {
+						continue;
+					}
+
+					payload
 				}
 				| ChannelWritePermission::Bypass => {
 					client_socket.emit_external_pubmsg(
+						&history_tags,
 						channel,
-						data.formats.as_ref().zip(data.colors.as_ref()),
+						formats_colors,
 						&data.text,
 						client_socket.user(),
-					);
-				}
-				| ChannelWritePermission::No(cause) => {
-					let why = match cause {
-						| ChannelNoPermissionCause::ERR_NOSUCHCHANNEL => "",
-						| ChannelNoPermissionCause::ERR_BANNEDFROMCHAN => {
-							"(+b)"
-						}
-						| ChannelNoPermissionCause::ERR_CHANISINMODERATED => {
-							"(+m)"
-						}
-						| ChannelNoPermissionCause::ERR_NOTMEMBEROFCHAN => {
-							"(+n)"
-						}
-					};
-					client_socket.send_err_cannotsendtochan(channel, why);
+					)
 				}
-			}
+				| ChannelWritePermission::No(_) => continue,
+			};
+
+			app.store_chathistory(
+				&socket,
+				ChatHistoryEntity::new(
+					&history_tags,
+					ChatHistoryEntity::channel_target(channel),
+					client_socket.user().nickname(),
+					channel,
+					PubmsgHandler::EVENT_NAME,
+					&payload,
+				),
+			);
 		}
 	}
 }
+
+fn send_err_cannotsendtochan(
+	client_socket: &Socket,
+	channel_name: &str,
+	cause: &ChannelNoPermissionCause,
+)
+{
+	let why = match cause {
+		| ChannelNoPermissionCause::ERR_NOSUCHCHANNEL => "",
+		| ChannelNoPermissionCause::ERR_BANNEDFROMCHAN => "(+b)",
+		| ChannelNoPermissionCause::ERR_CHANISINMODERATED => "(+m)",
+		| ChannelNoPermissionCause::ERR_NOTMEMBEROFCHAN => "(+n)",
+		| ChannelNoPermissionCause::ERR_NEEDREGGEDNICK => "(+M)",
+		| ChannelNoPermissionCause::ERR_NONOTICE => "(+T)",
+		| ChannelNoPermissionCause::ERR_QUIETED => "(+Q)",
+		| ChannelNoPermissionCause::ERR_NOCOLORSONCHAN => {
+			client_socket.send_err_nocolorsonchan(channel_name);
+			return;
+		}
+		| ChannelNoPermissionCause::ERR_NOCTCP => {
+			client_socket.send_err_noctcp(channel_name);
+			return;
+		}
+	};
+	client_socket.send_err_cannotsendtochan(channel_name, why);
+}
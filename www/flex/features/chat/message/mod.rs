@@ -18,10 +18,12 @@ flex_kernel::import! {
 
 	mod errors use {
 		pub(super) mod err_cannotsendtochan use *;
+		pub(super) mod err_nocolorsonchan use *;
+		pub(super) mod err_noctcp use *;
 	};
 
 	mod forms use {
-		pub(super) mod format_color; 
+		pub(super) mod format_color;
 		pub(super) mod privmsg_form use *;
 		pub(super) mod pubmsg_form use *;
 	};
@@ -12,6 +12,7 @@ use flex_chat::client::{ClientSocketInterface, Socket};
 use flex_chat::macros::command_response;
 use serde_json::json;
 
+use crate::features::chat::chathistory::ChatHistoryTags;
 use crate::features::chat::message::format_color::{
 	MessageColors,
 	MessageFormats,
@@ -35,13 +36,16 @@ pub trait PrivmsgClientSocketCommandResponseInterface:
 	ClientSocketInterface
 {
 	/// Émet au client les réponses liées à la commande /PRIVMSG <nickname>
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_privmsg(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: impl serde::Serialize,
-	);
+	) -> serde_json::Value;
 }
 
 // -------------- //
@@ -52,11 +56,12 @@ impl<'s> PrivmsgClientSocketCommandResponseInterface for Socket<'s>
 {
 	fn emit_privmsg(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: impl serde::Serialize,
-	)
+	) -> serde_json::Value
 	{
 		let mut tags = PrivmsgCommandResponse::default_tags();
 
@@ -89,7 +94,9 @@ impl<'s> PrivmsgClientSocketCommandResponseInterface for Socket<'s>
 			target,
 			text,
 			tags,
-		};
+		}
+		.with_tags(history_tags.to_tags());
 		_ = self.socket().emit(privmsg_command.name(), &privmsg_command);
+		serde_json::to_value(&privmsg_command).unwrap_or_default()
 	}
 }
@@ -9,10 +9,16 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::channel::{Channel, ChannelInterface};
-use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::client::{
+	Capability,
+	ClientInterface,
+	ClientSocketInterface,
+	Socket,
+};
 use flex_chat::macros::command_response;
 use serde_json::json;
 
+use crate::features::chat::chathistory::ChatHistoryTags;
 use crate::features::chat::message::format_color::{
 	MessageColors,
 	MessageFormats,
@@ -40,25 +46,33 @@ pub trait PubmsgClientSocketCommandResponseInterface:
 	type Channel: ChannelInterface;
 
 	/// Émet au client courant les réponses liées à la commande /PUBMSG
-	/// <channel>
+	/// <channel>. Sur un salon +u, les messages des membres sans niveau
+	/// d'accès ne sont transmis qu'aux opérateurs du salon.
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_pubmsg<MemberDTO>(
 		&self,
-		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		history_tags: &ChatHistoryTags,
+		channel: &Self::Channel,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: &MemberDTO,
-	) where
+	) -> serde_json::Value
+	where
 		MemberDTO: serde::Serialize;
 
 	/// Émet au client courant les réponses liées à la commande /PUBMSG
 	/// <channel>
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_external_pubmsg(
 		&self,
+		history_tags: &ChatHistoryTags,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: &<Self::Client as ClientInterface>::User,
-	);
+	) -> serde_json::Value;
 }
 
 // -------------- //
@@ -71,11 +85,13 @@ impl<'s> PubmsgClientSocketCommandResponseInterface for Socket<'s>
 
 	fn emit_pubmsg<MemberDTO>(
 		&self,
-		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		history_tags: &ChatHistoryTags,
+		channel: &Self::Channel,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: &MemberDTO,
-	) where
+	) -> serde_json::Value
+	where
 		MemberDTO: serde::Serialize,
 	{
 		let mut tags = PubmsgCommandResponse::default_tags();
@@ -107,29 +123,35 @@ impl<'s> PubmsgClientSocketCommandResponseInterface for Socket<'s>
 		let pubmsg_command = PubmsgCommandResponse {
 			origin: &by,
 			tags,
-			channel: channel_name,
+			channel: channel.name(),
 			text,
 			external: false,
-		};
+		}
+		.with_tags(history_tags.to_tags());
 
-		_ = self.socket().emit(pubmsg_command.name(), &pubmsg_command);
+		if self.client().has_capability(Capability::EchoMessage) {
+			_ = self.socket().emit(pubmsg_command.name(), &pubmsg_command);
+		}
 
-		let target_room = format!("channel:{}", channel_name.to_lowercase());
+		let target_room = channel.member_room(self.cid());
 
 		_ = self
 			.socket()
 			.except(self.useless_people_room())
 			.to(target_room)
-			.emit(pubmsg_command.name(), pubmsg_command);
+			.emit(pubmsg_command.name(), &pubmsg_command);
+
+		serde_json::to_value(&pubmsg_command).unwrap_or_default()
 	}
 
 	fn emit_external_pubmsg(
 		&self,
+		history_tags: &ChatHistoryTags,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 		formats_colors: Option<(&MessageFormats, &MessageColors)>,
 		text: &str,
 		by: &<Self::Client as ClientInterface>::User,
-	)
+	) -> serde_json::Value
 	{
 		let mut tags = PubmsgCommandResponse::default_tags();
 
@@ -163,9 +185,12 @@ impl<'s> PubmsgClientSocketCommandResponseInterface for Socket<'s>
 			channel: channel_name,
 			text,
 			external: true,
-		};
+		}
+		.with_tags(history_tags.to_tags());
 
-		_ = self.socket().emit(pubmsg_command.name(), &pubmsg_command);
+		if self.client().has_capability(Capability::EchoMessage) {
+			_ = self.socket().emit(pubmsg_command.name(), &pubmsg_command);
+		}
 
 		let target_room = format!("channel:{}", channel_name.to_lowercase());
 
@@ -173,6 +198,8 @@ impl<'s> PubmsgClientSocketCommandResponseInterface for Socket<'s>
 			.socket()
 			.except(self.useless_people_room())
 			.to(target_room)
-			.emit(pubmsg_command.name(), pubmsg_command);
+			.emit(pubmsg_command.name(), &pubmsg_command);
+
+		serde_json::to_value(&pubmsg_command).unwrap_or_default()
 	}
 }
@@ -10,7 +10,11 @@
 
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 
-use crate::features::chat::message::ErrCannotsendtochanError;
+use crate::features::chat::message::{
+	ErrCannotsendtochanError,
+	ErrNocolorsonchanError,
+	ErrNoctcpError,
+};
 
 // --------- //
 // Interface //
@@ -31,6 +35,30 @@ pub trait PubmsgClientSocketErrorRepliesInterface:
 		};
 		self.emit(err_cannotsendtochan.name(), err_cannotsendtochan);
 	}
+
+	/// Émet au client l'erreur [ErrNocolorsonchanError].
+	fn send_err_nocolorsonchan(&self, channel_name: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_nocolorsonchan = ErrNocolorsonchanError {
+			channel_name,
+			origin: &origin,
+			tags: ErrNocolorsonchanError::default_tags(),
+		};
+		self.emit(err_nocolorsonchan.name(), err_nocolorsonchan);
+	}
+
+	/// Émet au client l'erreur [ErrNoctcpError].
+	fn send_err_noctcp(&self, channel_name: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_noctcp = ErrNoctcpError {
+			channel_name,
+			origin: &origin,
+			tags: ErrNoctcpError::default_tags(),
+		};
+		self.emit(err_noctcp.name(), err_noctcp);
+	}
 }
 // -------------- //
 // Implémentation // -> Interface
@@ -19,21 +19,35 @@ flex_kernel::import! {
 	};
 
 	pub mod auth;
+	pub mod cap;
+	pub mod channel_registration;
+	pub mod chathistory;
 	pub mod connect;
 	pub mod home;
 	pub mod invite;
+	pub mod irc;
 	pub mod join;
 	pub mod kick;
 	pub mod kill;
+	pub mod knock;
 	pub mod list;
 	pub mod message;
 	pub mod mode;
+	pub mod monitor;
 	pub mod nick;
+	pub mod nick_registration;
 	pub mod notice;
 	pub mod oper;
 	pub mod part;
 	pub mod quit;
+	pub mod sasl;
+	pub mod server_ban;
+	pub mod server_info;
 	pub mod silence;
 	pub mod topic;
+	pub mod user_mode;
 	pub mod user_status;
+	pub mod wallops;
+	pub mod who;
+	pub mod whois;
 }
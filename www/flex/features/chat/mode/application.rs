@@ -8,25 +8,53 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::time::Duration;
+
 use flex_chat::channel::{
 	AccessControlMask,
+	CHANNEL_MODE_LIST_BAN,
 	Channel,
 	ChannelAccessControlBanExceptInterface,
 	ChannelAccessControlBanInterface,
+	ChannelAccessControlInterface,
 	ChannelAccessControlInviteExceptInterface,
+	ChannelAccessControlQuietInterface,
 	ChannelAccessLevel,
 	ChannelInterface,
 	ChannelMember,
 	ChannelsSessionInterface,
+	FloodAction,
+	FloodKind,
 	SettingsFlag,
+	SettingsFlagInterface,
 };
 use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
 use flex_chat::client::{ClientSocketInterface, Socket};
 use flex_chat::mode::{ApplyMode, Mask};
-use flex_chat::user::UserInterface;
-
-use super::ModeChannelAccessLevelChannelsSessionInterface;
+use flex_chat::user::{ServerNoticeMask, UserAddressInterface, UserInterface};
+use flex_web_framework::http;
+use socketioxide::SocketIo;
+
+use super::{
+	ModeChannelAccessLevelChannelsSessionInterface,
+	ModeChannelFloodClientSocketCommandResponseInterface,
+	ModeCommandResponse,
+	ServerOrigin,
+};
+use crate::config::chat::FlexChatConfig;
 use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
+use crate::features::chat::oper::OperApplicationInterface;
+use crate::features::chat::part::PartChannelApplicationInterface;
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Intervalle de vérification des masques expirés des listes de contrôle
+/// d'accès.
+const ACCESS_CONTROL_EXPIRY_INTERVAL: Duration = Duration::from_secs(5);
 
 // --------- //
 // Interface //
@@ -42,7 +70,7 @@ pub trait ModeChannelAccessControlApplicationInterface
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>;
 
 	/// Applique une exception de ban sur un salon.
@@ -50,7 +78,7 @@ pub trait ModeChannelAccessControlApplicationInterface
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>;
 
 	/// Applique une exception d'invite sur un salon.
@@ -58,7 +86,15 @@ pub trait ModeChannelAccessControlApplicationInterface
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
+	) -> Option<ApplyMode<AccessControlMask>>;
+
+	/// Rend muet un masque sur un salon.
+	fn apply_quiet_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>;
 
 	/// Retire un ban sur un salon.
@@ -85,6 +121,14 @@ pub trait ModeChannelAccessControlApplicationInterface
 		mask: impl Into<Mask>,
 	) -> Option<ApplyMode<AccessControlMask>>;
 
+	/// Retire un masque de la liste des utilisateurs muets d'un salon.
+	fn apply_unquiet_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<Mask>,
+	) -> Option<ApplyMode<AccessControlMask>>;
+
 	/// Est-qu'un adresse mask d'un ban existe dans la liste des bannissement
 	/// d'un salon.
 	fn has_banmask_on_channel(
@@ -111,6 +155,24 @@ pub trait ModeChannelAccessControlApplicationInterface
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 		mask: impl Into<Mask>,
 	) -> bool;
+
+	/// Est-qu'un adresse mask existe dans la liste des utilisateurs muets
+	/// d'un salon.
+	fn has_quietmask_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<Mask>,
+	) -> bool;
+
+	/// Démarre la tâche de fond qui retire périodiquement les masques expirés
+	/// des listes de contrôle d'accès des salons. Les masques retirés sont
+	/// émis par le serveur aux membres des salons.
+	fn expire_access_controls(
+		&'static self,
+		extensions: &http::Extensions,
+		io: SocketIo,
+	);
 }
 
 pub trait ModeChannelAccessLevelApplicationInterface
@@ -143,6 +205,25 @@ pub trait ModeChannelAccessLevelApplicationInterface
 	) -> Option<ChannelMember>;
 }
 
+pub trait ModeChannelFloodApplicationInterface
+{
+	type Channel: ChannelInterface;
+	type ClientSocket<'cs>: ClientSocketInterface
+	where
+		Self: 'cs;
+
+	/// Enregistre un événement du client courant sur un salon ayant le
+	/// drapeau +f <rules>. Lorsque le seuil d'une règle est dépassé, l'action
+	/// de la règle est appliquée par le serveur, puis retirée à la fin de la
+	/// période. Retourne vrai lorsque le client a été sanctionné du salon.
+	fn protect_channel_from_flood(
+		&'static self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		kind: FloodKind,
+	) -> bool;
+}
+
 pub trait ModeChannelSettingsApplicationInterface
 {
 	type Channel: ChannelInterface;
@@ -178,7 +259,7 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	{
 		let Some(mut channel) = self.channels.get_mut(channel_name) else {
@@ -192,7 +273,7 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	{
 		let Some(mut channel) = self.channels.get_mut(channel_name) else {
@@ -206,7 +287,7 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
-		mask: impl Into<Mask>,
+		mask: impl Into<AccessControlMask>,
 	) -> Option<ApplyMode<AccessControlMask>>
 	{
 		let Some(mut channel) = self.channels.get_mut(channel_name) else {
@@ -216,6 +297,20 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		channel.add_invite_except(client_socket.user(), mask)
 	}
 
+	fn apply_quiet_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<AccessControlMask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	{
+		let Some(mut channel) = self.channels.get_mut(channel_name) else {
+			client_socket.send_err_nosuchchannel(channel_name);
+			return None;
+		};
+		channel.add_quiet(client_socket.user(), mask)
+	}
+
 	fn apply_unban_on_channel(
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
@@ -258,6 +353,20 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		channel.remove_invite_except(client_socket.user(), mask)
 	}
 
+	fn apply_unquiet_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<Mask>,
+	) -> Option<ApplyMode<AccessControlMask>>
+	{
+		let Some(mut channel) = self.channels.get_mut(channel_name) else {
+			client_socket.send_err_nosuchchannel(channel_name);
+			return None;
+		};
+		channel.remove_quiet(client_socket.user(), mask)
+	}
+
 	fn has_banmask_on_channel(
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
@@ -302,6 +411,107 @@ impl ModeChannelAccessControlApplicationInterface for ChatApplication
 		let mask_r = &mask.into();
 		channel.has_invitemask_except(mask_r)
 	}
+
+	fn has_quietmask_on_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mask: impl Into<Mask>,
+	) -> bool
+	{
+		let Some(channel) = self.channels.get(channel_name) else {
+			client_socket.send_err_nosuchchannel(channel_name);
+			return false;
+		};
+		let mask_r = &mask.into();
+		channel.has_quietmask(mask_r)
+	}
+
+	fn expire_access_controls(
+		&'static self,
+		extensions: &http::Extensions,
+		io: SocketIo,
+	)
+	{
+		let Some(server_name) = extensions
+			.get::<FlexChatConfig>()
+			.map(|config| config.server.name.to_string())
+		else {
+			return;
+		};
+
+		let repository = self.channel_registration_repository(extensions);
+
+		if !self.access_control_expiry.mark_as_started() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let mut interval =
+				tokio::time::interval(ACCESS_CONTROL_EXPIRY_INTERVAL);
+
+			loop {
+				interval.tick().await;
+
+				let now = chrono::Utc::now();
+
+				let channel_names: Vec<_> = self
+					.channels
+					.list()
+					.filter(|channel| {
+						channel
+							.access_controls()
+							.iter()
+							.any(|(_, mode)| mode.flag.is_expired(now))
+					})
+					.map(|channel| channel.name.clone())
+					.collect();
+
+				for channel_name in channel_names {
+					let Some(removed) = self
+						.channels
+						.get_mut(&channel_name)
+						.map(|mut channel| {
+							channel.access_control.remove_expired(now)
+						})
+					else {
+						continue;
+					};
+
+					if removed.is_empty() {
+						continue;
+					}
+
+					if let Some(repository) = repository.clone() {
+						self.save_registered_channel_in(
+							repository,
+							&channel_name,
+						);
+					}
+
+					let removed: Vec<_> = removed
+						.into_iter()
+						.map(|(letter, mode)| {
+							(letter, mode.with_update_by(&server_name))
+						})
+						.collect();
+
+					let origin = ServerOrigin { name: &server_name };
+					let mode_cmd = ModeCommandResponse {
+						origin: &origin,
+						tags: ModeCommandResponse::<()>::default_tags(),
+						added: Default::default(),
+						removed,
+						target: &channel_name,
+						updated: true,
+					};
+					let channel_room =
+						format!("channel:{}", channel_name.to_lowercase());
+					_ = io.within(channel_room).emit(mode_cmd.name(), mode_cmd);
+				}
+			}
+		});
+	}
 }
 
 impl ModeChannelAccessLevelApplicationInterface for ChatApplication
@@ -356,6 +566,94 @@ impl ModeChannelAccessLevelApplicationInterface for ChatApplication
 	}
 }
 
+impl ModeChannelFloodApplicationInterface for ChatApplication
+{
+	type Channel = Channel;
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn protect_channel_from_flood(
+		&'static self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		kind: FloodKind,
+	) -> bool
+	{
+		// NOTE: les opérateurs globaux et les opérateurs du salon ne sont pas
+		//       concernés par la protection.
+		if self.is_client_global_operator(client_socket)
+			|| self.channels.does_member_have_rights(
+				channel_name,
+				client_socket.cid(),
+				ChannelAccessLevel::HalfOperator,
+			) {
+			return false;
+		}
+
+		let Some((rule, duration)) =
+			self.channels.get_mut(channel_name).and_then(|mut channel| {
+				let rule = channel.record_flood(kind, *client_socket.cid())?;
+				let protection = channel.modes_settings.flood_protection()?;
+				Some((rule, protection.duration()))
+			})
+		else {
+			return false;
+		};
+
+		let Some(server_name) = client_socket
+			.socket()
+			.req_parts()
+			.extensions
+			.get::<FlexChatConfig>()
+			.map(|config| config.server.name.to_string())
+		else {
+			return false;
+		};
+
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Floods,
+			&format!(
+				"Protection anti-flood déclenchée sur {channel_name} par {}",
+				client_socket.user().full_address(),
+			),
+		);
+
+		match rule.action {
+			| FloodAction::InviteOnly => {
+				apply_flood_settings(
+					self,
+					client_socket,
+					channel_name,
+					&server_name,
+					SettingsFlag::InviteOnly,
+					duration,
+				);
+				false
+			}
+			| FloodAction::Moderate => {
+				apply_flood_settings(
+					self,
+					client_socket,
+					channel_name,
+					&server_name,
+					SettingsFlag::Moderate,
+					duration,
+				);
+				false
+			}
+			| FloodAction::KickBan => {
+				apply_flood_kickban(
+					self,
+					client_socket,
+					channel_name,
+					&server_name,
+					duration,
+				);
+				true
+			}
+		}
+	}
+}
+
 impl ModeChannelSettingsApplicationInterface for ChatApplication
 {
 	type Channel = Channel;
@@ -398,3 +696,149 @@ impl ModeChannelSettingsApplicationInterface for ChatApplication
 		)
 	}
 }
+
+/// Applique un paramètre de salon au nom du serveur, puis le retire à la fin
+/// de la période, s'il n'a pas été modifié entre temps.
+fn apply_flood_settings(
+	app: &'static ChatApplication,
+	client_socket: &Socket<'_>,
+	channel_name: &str,
+	server_name: &str,
+	flag: SettingsFlag,
+	duration: Duration,
+)
+{
+	let Some(mode) =
+		app.channels.get_mut(channel_name).and_then(|mut channel| {
+			channel
+				.modes_settings
+				.set(ApplyMode::new(flag).with_update_by(server_name))
+		})
+	else {
+		return;
+	};
+
+	client_socket.emit_flood_channel_settings(
+		server_name,
+		channel_name,
+		std::slice::from_ref(&mode),
+		&[],
+	);
+
+	let socket = client_socket.socket().clone();
+	let client = client_socket.client().clone();
+	let channel_name = channel_name.to_owned();
+	let server_name = server_name.to_owned();
+
+	tokio::spawn(async move {
+		tokio::time::sleep(duration).await;
+
+		let Some(removed) =
+			app.channels.get_mut(&channel_name).and_then(|mut channel| {
+				let letter = mode.letter().to_string();
+				let current = channel.modes_settings.get(&letter)?;
+				// NOTE: le paramètre a été modifié par un opérateur entre
+				//       temps.
+				if current.updated_by != mode.updated_by
+					|| current.updated_at != mode.updated_at
+				{
+					return None;
+				}
+				channel.modes_settings.unset(
+					ApplyMode::new(mode.flag).with_update_by(&server_name),
+				)
+			})
+		else {
+			return;
+		};
+
+		let client_socket = Socket::Owned {
+			socket,
+			client: Box::new(client),
+		};
+		client_socket.emit_flood_channel_settings(
+			&server_name,
+			&channel_name,
+			&[],
+			&[removed],
+		);
+	});
+}
+
+/// Sanctionne et bannit le client courant d'un salon au nom du serveur, puis
+/// retire le bannissement à la fin de la période, s'il n'a pas été modifié
+/// entre temps.
+fn apply_flood_kickban(
+	app: &'static ChatApplication,
+	client_socket: &Socket<'_>,
+	channel_name: &str,
+	server_name: &str,
+	duration: Duration,
+)
+{
+	let banmask = Mask::from(client_socket.user().address("*!*@hostname"));
+	let banmask_key = banmask.to_string();
+
+	let maybe_ban =
+		app.channels.get_mut(channel_name).and_then(|mut channel| {
+			let mode = ApplyMode::new(AccessControlMask::new(banmask.clone()))
+				.with_update_by(server_name)
+				.with_args([banmask_key.clone()]);
+			channel.access_control.add_ban(banmask, mode)
+		});
+
+	if let Some(ban) = maybe_ban.as_ref() {
+		client_socket.emit_flood_access_control(
+			server_name,
+			channel_name,
+			&[(CHANNEL_MODE_LIST_BAN, ban.clone())],
+			&[],
+		);
+	}
+
+	if let Some(channel) = app.channels.get(channel_name) {
+		client_socket.emit_flood_kick(server_name, &channel);
+	}
+	app.remove_member_from_channel(channel_name, client_socket);
+
+	let Some(ban) = maybe_ban else {
+		return;
+	};
+
+	let socket = client_socket.socket().clone();
+	let client = client_socket.client().clone();
+	let channel_name = channel_name.to_owned();
+	let server_name = server_name.to_owned();
+
+	tokio::spawn(async move {
+		tokio::time::sleep(duration).await;
+
+		let Some(removed) =
+			app.channels.get_mut(&channel_name).and_then(|mut channel| {
+				let current =
+					channel.access_control.banlist.get(&banmask_key)?;
+				// NOTE: le bannissement a été modifié par un opérateur entre
+				//       temps.
+				if current.updated_by != ban.updated_by
+					|| current.updated_at != ban.updated_at
+				{
+					return None;
+				}
+				channel.access_control.remove_ban(&banmask_key)
+			})
+		else {
+			return;
+		};
+
+		let client_socket = Socket::Owned {
+			socket,
+			client: Box::new(client),
+		};
+		client_socket.emit_flood_access_control(
+			&server_name,
+			&channel_name,
+			&[],
+			&[(CHANNEL_MODE_LIST_BAN, removed)],
+		);
+	});
+}
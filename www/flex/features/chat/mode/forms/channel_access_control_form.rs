@@ -10,15 +10,20 @@
 
 use std::sync::Arc;
 
-use flex_chat::channel::validate_channels;
+use flex_chat::channel::{AccessControlMask, validate_channels};
 use flex_chat::macros::command_formdata;
+use flex_chat::mode::Mask;
 
 command_formdata! {
 	struct BAN
 	{
 		#[serde(deserialize_with = "validate_channels")]
 		channels: Vec<Arc<str>>,
+		/// Les masques, préfixés éventuellement d'une durée
+		/// (`[<durée>:]<masque>`).
 		masks: Vec<Arc<str>>,
+		/// La raison de l'application des masques.
+		reason: Option<Arc<str>>,
 	}
 
 	struct UNBAN
@@ -42,3 +47,32 @@ command_formdata! {
 		masks: Vec<Arc<str>>,
 	}
 }
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl BanCommandFormData
+{
+	/// Les entrées des listes de contrôle d'accès à appliquer.
+	pub fn entries(&self) -> impl Iterator<Item = AccessControlMask> + '_
+	{
+		self.masks.iter().map(|mask| {
+			let entry = AccessControlMask::from(mask);
+			match self.reason.as_deref() {
+				| Some(reason) => entry.with_reason(reason),
+				| None => entry,
+			}
+		})
+	}
+}
+
+impl UnbanCommandFormData
+{
+	/// Les masques à retirer des listes de contrôle d'accès. Une éventuelle
+	/// durée est ignorée.
+	pub fn masks(&self) -> impl Iterator<Item = Mask> + '_
+	{
+		self.masks.iter().map(|mask| AccessControlMask::from(mask).mask)
+	}
+}
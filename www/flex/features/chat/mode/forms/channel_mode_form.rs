@@ -26,17 +26,29 @@ command_formdata! {
 
 #[derive(Debug)]
 #[derive(Clone)]
+#[derive(Default)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelModesSettings
 {
+	/// Salon en mode auditorium, où les membres sans niveau d'accès ne voient
+	/// que les membres ayant un niveau d'accès minimal à VIP.
+	#[serde(rename = "u")]
+	pub auditorium: Option<bool>,
 	/// Liste des bannissements à appliquer/retirer.
 	#[serde(rename = "b")]
 	pub bans: Option<Vec<Arc<str>>>,
 	/// Liste des exceptions de bannissements à appliquer/retirer.
 	#[serde(rename = "e")]
 	pub bans_except: Option<Vec<Arc<str>>>,
+	/// Les membres qui rejoignent le salon sont masqués jusqu'à ce qu'ils
+	/// parlent.
+	#[serde(rename = "D")]
+	pub delayed_join: Option<bool>,
+	/// Protection contre le flood, par exemple `[5j,10m,3n]:15`.
+	#[serde(rename = "f")]
+	pub flood: Option<Arc<str>>,
 	/// Salon sur invitation uniquement.
 	#[serde(rename = "i")]
 	pub invite_only: Option<bool>,
@@ -52,9 +64,24 @@ pub struct ChannelModesSettings
 	/// Salon en modéré.
 	#[serde(rename = "m")]
 	pub moderate: Option<bool>,
+	/// Interdire les couleurs et les formats de texte dans les messages.
+	#[serde(rename = "c")]
+	pub no_colors: Option<bool>,
+	/// Interdire les messages CTCP (hors ACTION) sur le salon.
+	#[serde(rename = "C")]
+	pub no_ctcp: Option<bool>,
 	/// Interdire les messages provenant des utilisateurs externes au salon.
 	#[serde(rename = "n")]
 	pub no_external_messages: Option<bool>,
+	/// Interdire la commande KNOCK sur le salon.
+	#[serde(rename = "K")]
+	pub no_knock: Option<bool>,
+	/// Interdire aux membres du salon de changer de pseudonyme.
+	#[serde(rename = "N")]
+	pub no_nick_change: Option<bool>,
+	/// Interdire les NOTICE à destination du salon.
+	#[serde(rename = "T")]
+	pub no_notice: Option<bool>,
 	/// Interdire le changement du sujet (topic) par les utilisateurs non
 	/// opérateurs.
 	#[serde(rename = "t")]
@@ -62,8 +89,28 @@ pub struct ChannelModesSettings
 	/// Salon réservé aux opérateurs globaux uniquement.
 	#[serde(rename = "O")]
 	pub oper_only: Option<bool>,
+	/// Salon permanent, qui n'est pas supprimé lorsqu'il est vide.
+	#[serde(rename = "P")]
+	pub permanent: Option<bool>,
+	/// Liste des utilisateurs muets à appliquer/retirer.
+	#[serde(rename = "Q")]
+	pub quiets: Option<Vec<Arc<str>>>,
+	/// Seuls les utilisateurs connectés à un compte peuvent parler sur le
+	/// salon.
+	#[serde(rename = "M")]
+	pub registered_moderated: Option<bool>,
+	/// Salon réservé aux utilisateurs connectés à un compte.
+	#[serde(rename = "R")]
+	pub registered_only: Option<bool>,
 	/// Salon secret. Ces salons ne seront pas affiché dans la liste des
 	/// salons.
 	#[serde(rename = "s")]
 	pub secret: Option<bool>,
+	/// Salon réservé aux utilisateurs utilisant une connexion sécurisée.
+	#[serde(rename = "z")]
+	pub secure_only: Option<bool>,
+	/// Verrouiller le sujet (topic) du salon. Seuls les propriétaires et les
+	/// opérateurs admin peuvent appliquer/retirer ce paramètre.
+	#[serde(rename = "L")]
+	pub topic_lock: Option<bool>,
 }
@@ -0,0 +1,65 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use flex_chat::channel::{ChannelAccessLevel, validate_channel};
+use flex_chat::macros::command_formdata;
+use flex_chat::user::validate_nicknames;
+
+use super::ChannelModesSettings;
+
+command_formdata! {
+	struct SAMODE
+	{
+		/// Le salon.
+		#[serde(deserialize_with = "validate_channel")]
+		target: Arc<str>,
+		/// Les paramètres du salon à appliquer.
+		#[serde(default)]
+		modes: Option<ChannelModesSettings>,
+		/// Les niveaux d'accès à appliquer/retirer.
+		#[serde(default)]
+		access_levels: Vec<SamodeAccessLevel>,
+		/// Les entrées des listes de contrôle d'accès à appliquer/retirer.
+		#[serde(default)]
+		access_controls: Vec<SamodeAccessControl>,
+	}
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SamodeAccessControl
+{
+	/// La lettre de la liste de contrôle d'accès (`b`, `e`, `I`, `Q`).
+	pub list: char,
+	/// Applique (`true`) ou retire (`false`) les masques.
+	pub set: bool,
+	/// Les masques, préfixés éventuellement d'une durée
+	/// (`[<durée>:]<masque>`).
+	pub masks: Vec<Arc<str>>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SamodeAccessLevel
+{
+	/// Le niveau d'accès.
+	pub access_level: ChannelAccessLevel,
+	/// Applique (`true`) ou retire (`false`) le niveau d'accès.
+	pub set: bool,
+	/// Les pseudonymes concernés.
+	#[serde(deserialize_with = "validate_nicknames")]
+	pub nicknames: Vec<String>,
+}
@@ -9,16 +9,24 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::channel::{
+	AccessControlMask,
 	CHANNEL_MODE_LIST_BAN,
 	CHANNEL_MODE_LIST_BAN_EXCEPT,
 	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
 	ChannelAccessLevel,
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
 };
+use flex_chat::client::ClientSocketInterface;
 use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_chat::mode::Mask;
 use flex_web_framework::WebSocketHandler2;
 use socketioxide::extract::{Data, SocketRef, State};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
 use crate::features::chat::mode::{
 	BanCommandFormData,
 	ModeAccessControlClientSocketCommandResponseInterface,
@@ -32,14 +40,229 @@ use crate::features::chat::oper::OperApplicationInterface;
 // Structure //
 // --------- //
 
+pub(crate) struct ModeChannelAccessControlHandler;
+
 pub struct ModeChannelAccessControlBanHandler;
 pub struct ModeChannelAccessControlBanExceptionHandler;
 pub struct ModeChannelAccessControlInviteExceptionHandler;
+pub struct ModeChannelAccessControlQuietHandler;
 
 // -------------- //
 // Implémentation //
 // -------------- //
 
+impl ModeChannelAccessControlHandler
+{
+	/// Émet au client une liste de contrôle d'accès (`b`, `e`, `I`, `Q`)
+	/// d'un salon dont il est membre, lorsque la commande ne contient aucun
+	/// masque.
+	pub(crate) fn list_access_control(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		channel_name: &str,
+		list: char,
+	)
+	{
+		let client_socket = app.current_client(socket);
+
+		let Some(channel) = app.channels.get(channel_name) else {
+			client_socket.send_err_nosuchchannel(channel_name);
+			return;
+		};
+
+		if channel.member(client_socket.cid()).is_none() {
+			client_socket.send_err_notonchannel(channel_name);
+			return;
+		}
+
+		client_socket.send_rpl_access_control_list(&channel, list);
+	}
+
+	/// Ajoute des entrées à une liste de contrôle d'accès (`b`, `e`, `I`,
+	/// `Q`) d'un salon. Lorsque `bypass_permission` vaut `true`, le niveau
+	/// d'accès du client sur le salon n'est pas vérifié (SAMODE). Retourne
+	/// `true` si au moins une entrée a été ajoutée.
+	pub(crate) fn add_access_control(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		channel_name: &str,
+		list: char,
+		entries: &[AccessControlMask],
+		bypass_permission: bool,
+	) -> bool
+	{
+		let client_socket = app.current_client(socket);
+
+		let can_override = bypass_permission
+			|| app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::OverrideChannelModes,
+			);
+
+		if !can_override
+			&& !app.does_client_have_rights_on_channel(
+				&client_socket,
+				channel_name,
+				ChannelAccessLevel::HalfOperator,
+			) {
+			return false;
+		}
+
+		let updated: Vec<_> = entries
+			.iter()
+			.cloned()
+			.filter_map(|entry| {
+				match list {
+					| CHANNEL_MODE_LIST_BAN => {
+						app.apply_ban_on_channel(
+							&client_socket,
+							channel_name,
+							entry,
+						)
+					}
+					| CHANNEL_MODE_LIST_BAN_EXCEPT => {
+						app.apply_ban_except_on_channel(
+							&client_socket,
+							channel_name,
+							entry,
+						)
+					}
+					| CHANNEL_MODE_LIST_INVITE_EXCEPT => {
+						app.apply_invite_except_on_channel(
+							&client_socket,
+							channel_name,
+							entry,
+						)
+					}
+					| CHANNEL_MODE_LIST_QUIET => {
+						app.apply_quiet_on_channel(
+							&client_socket,
+							channel_name,
+							entry,
+						)
+					}
+					| _ => None,
+				}
+			})
+			.collect();
+
+		if updated.is_empty() {
+			return false;
+		}
+
+		app.save_registered_channel(socket, channel_name);
+
+		let Some(channel) = app.get_channel(channel_name) else {
+			client_socket.send_err_notonchannel(channel_name);
+			return true;
+		};
+
+		let added_flags =
+			updated.into_iter().map(|mode| (list, mode)).collect();
+
+		client_socket.emit_channel_access_control(
+			&channel,
+			added_flags,
+			vec![],
+			true,
+		);
+
+		true
+	}
+
+	/// Retire des masques d'une liste de contrôle d'accès (`b`, `e`, `I`,
+	/// `Q`) d'un salon. Lorsque `bypass_permission` vaut `true`, le niveau
+	/// d'accès du client sur le salon n'est pas vérifié (SAMODE). Retourne
+	/// `true` si au moins un masque a été retiré.
+	pub(crate) fn remove_access_control(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		channel_name: &str,
+		list: char,
+		masks: &[Mask],
+		bypass_permission: bool,
+	) -> bool
+	{
+		let client_socket = app.current_client(socket);
+
+		let can_override = bypass_permission
+			|| app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::OverrideChannelModes,
+			);
+
+		if !can_override
+			&& !app.does_client_have_rights_on_channel(
+				&client_socket,
+				channel_name,
+				ChannelAccessLevel::HalfOperator,
+			) {
+			return false;
+		}
+
+		let updated: Vec<_> = masks
+			.iter()
+			.cloned()
+			.filter_map(|mask| {
+				match list {
+					| CHANNEL_MODE_LIST_BAN => {
+						app.apply_unban_on_channel(
+							&client_socket,
+							channel_name,
+							mask,
+						)
+					}
+					| CHANNEL_MODE_LIST_BAN_EXCEPT => {
+						app.apply_unban_except_on_channel(
+							&client_socket,
+							channel_name,
+							mask,
+						)
+					}
+					| CHANNEL_MODE_LIST_INVITE_EXCEPT => {
+						app.apply_uninvite_except_on_channel(
+							&client_socket,
+							channel_name,
+							mask,
+						)
+					}
+					| CHANNEL_MODE_LIST_QUIET => {
+						app.apply_unquiet_on_channel(
+							&client_socket,
+							channel_name,
+							mask,
+						)
+					}
+					| _ => None,
+				}
+			})
+			.collect();
+
+		if updated.is_empty() {
+			return false;
+		}
+
+		app.save_registered_channel(socket, channel_name);
+
+		let Some(channel) = app.get_channel(channel_name) else {
+			client_socket.send_err_notonchannel(channel_name);
+			return true;
+		};
+
+		let removed_flags =
+			updated.into_iter().map(|mode| (list, mode)).collect();
+
+		client_socket.emit_channel_access_control(
+			&channel,
+			vec![],
+			removed_flags,
+			true,
+		);
+
+		true
+	}
+}
+
 impl WebSocketHandler2 for ModeChannelAccessControlBanHandler
 {
 	type App = ChatApplication;
@@ -55,49 +278,28 @@ impl WebSocketHandler2 for ModeChannelAccessControlBanHandler
 		Data(data): Data<BanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
-
-		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
+		if data.masks.is_empty() {
+			for channel_name in data.channels {
+				ModeChannelAccessControlHandler::list_access_control(
+					&socket,
+					app,
 					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
+					CHANNEL_MODE_LIST_BAN,
+				);
 			}
+			return;
+		}
 
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_ban_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
+		let entries: Vec<_> = data.entries().collect();
 
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let added_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_BAN, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				added_flags,
-				vec![],
-				true,
+		for channel_name in data.channels {
+			ModeChannelAccessControlHandler::add_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_BAN,
+				&entries,
+				false,
 			);
 		}
 	}
@@ -108,49 +310,16 @@ impl WebSocketHandler2 for ModeChannelAccessControlBanHandler
 		Data(data): Data<UnbanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
+		let masks: Vec<_> = data.masks().collect();
 
 		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
-					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
-			}
-
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_unban_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
-
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let removed_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_BAN, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				vec![],
-				removed_flags,
-				true,
+			ModeChannelAccessControlHandler::remove_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_BAN,
+				&masks,
+				false,
 			);
 		}
 	}
@@ -171,49 +340,28 @@ impl WebSocketHandler2 for ModeChannelAccessControlBanExceptionHandler
 		Data(data): Data<BanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
-
-		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
+		if data.masks.is_empty() {
+			for channel_name in data.channels {
+				ModeChannelAccessControlHandler::list_access_control(
+					&socket,
+					app,
 					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
+					CHANNEL_MODE_LIST_BAN_EXCEPT,
+				);
 			}
+			return;
+		}
 
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_ban_except_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
+		let entries: Vec<_> = data.entries().collect();
 
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let added_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_BAN_EXCEPT, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				added_flags,
-				vec![],
-				true,
+		for channel_name in data.channels {
+			ModeChannelAccessControlHandler::add_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_BAN_EXCEPT,
+				&entries,
+				false,
 			);
 		}
 	}
@@ -224,49 +372,16 @@ impl WebSocketHandler2 for ModeChannelAccessControlBanExceptionHandler
 		Data(data): Data<UnbanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
+		let masks: Vec<_> = data.masks().collect();
 
 		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
-					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
-			}
-
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_unban_except_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
-
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let removed_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_BAN_EXCEPT, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				vec![],
-				removed_flags,
-				true,
+			ModeChannelAccessControlHandler::remove_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_BAN_EXCEPT,
+				&masks,
+				false,
 			);
 		}
 	}
@@ -287,49 +402,28 @@ impl WebSocketHandler2 for ModeChannelAccessControlInviteExceptionHandler
 		Data(data): Data<BanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
-
-		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
+		if data.masks.is_empty() {
+			for channel_name in data.channels {
+				ModeChannelAccessControlHandler::list_access_control(
+					&socket,
+					app,
 					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
+					CHANNEL_MODE_LIST_INVITE_EXCEPT,
+				);
 			}
+			return;
+		}
 
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_invite_except_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
+		let entries: Vec<_> = data.entries().collect();
 
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let added_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_INVITE_EXCEPT, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				added_flags,
-				vec![],
-				true,
+		for channel_name in data.channels {
+			ModeChannelAccessControlHandler::add_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_INVITE_EXCEPT,
+				&entries,
+				false,
 			);
 		}
 	}
@@ -340,49 +434,78 @@ impl WebSocketHandler2 for ModeChannelAccessControlInviteExceptionHandler
 		Data(data): Data<UnbanCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
+		let masks: Vec<_> = data.masks().collect();
 
 		for channel_name in data.channels {
-			if !app.is_client_global_operator(&client_socket)
-				&& !app.does_client_have_rights_on_channel(
-					&client_socket,
+			ModeChannelAccessControlHandler::remove_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_INVITE_EXCEPT,
+				&masks,
+				false,
+			);
+		}
+	}
+}
+
+impl WebSocketHandler2 for ModeChannelAccessControlQuietHandler
+{
+	type App = ChatApplication;
+	type SetData = BanCommandFormData;
+	type UnsetData = UnbanCommandFormData;
+
+	const SET_EVENT_NAME: &'static str = "QUIET";
+	const UNSET_EVENT_NAME: &'static str = "UNQUIET";
+
+	fn handle_set(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<BanCommandFormData>,
+	)
+	{
+		if data.masks.is_empty() {
+			for channel_name in data.channels {
+				ModeChannelAccessControlHandler::list_access_control(
+					&socket,
+					app,
 					&channel_name,
-					ChannelAccessLevel::HalfOperator,
-				) {
-				continue;
+					CHANNEL_MODE_LIST_QUIET,
+				);
 			}
+			return;
+		}
 
-			let updated: Vec<_> = data
-				.masks
-				.iter()
-				.filter_map(|mask| {
-					app.apply_uninvite_except_on_channel(
-						&client_socket,
-						&channel_name,
-						mask,
-					)
-				})
-				.collect();
-
-			if updated.is_empty() {
-				continue;
-			}
+		let entries: Vec<_> = data.entries().collect();
+
+		for channel_name in data.channels {
+			ModeChannelAccessControlHandler::add_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_QUIET,
+				&entries,
+				false,
+			);
+		}
+	}
+
+	fn handle_unset(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UnbanCommandFormData>,
+	)
+	{
+		let masks: Vec<_> = data.masks().collect();
 
-			let Some(channel) = app.get_channel(&channel_name) else {
-				client_socket.send_err_notonchannel(&channel_name);
-				continue;
-			};
-
-			let removed_flags = updated
-				.into_iter()
-				.map(|mode| (CHANNEL_MODE_LIST_INVITE_EXCEPT, mode))
-				.collect();
-
-			client_socket.emit_channel_access_control(
-				&channel,
-				vec![],
-				removed_flags,
-				true,
+		for channel_name in data.channels {
+			ModeChannelAccessControlHandler::remove_access_control(
+				&socket,
+				app,
+				&channel_name,
+				CHANNEL_MODE_LIST_QUIET,
+				&masks,
+				false,
 			);
 		}
 	}
@@ -25,7 +25,10 @@ use flex_web_framework::WebSocketHandler2;
 use flex_web_framework::types::time;
 use socketioxide::extract::{Data, SocketRef, State};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
+use crate::features::chat::join::JoinApplicationInterface;
 use crate::features::chat::mode::{
 	AccessLevelAdminOperatorCommandFormData,
 	AccessLevelHalfOperatorCommandFormData,
@@ -42,7 +45,7 @@ use crate::features::chat::oper::OperApplicationInterface;
 // Structure //
 // --------- //
 
-struct ModeChannelAccessLevelHandler;
+pub(crate) struct ModeChannelAccessLevelHandler;
 
 pub struct ModeChannelAccessLevelQOPHandler;
 pub struct ModeChannelAccessLevelAOPHandler;
@@ -56,24 +59,35 @@ pub struct ModeChannelAccessLevelVIPHandler;
 
 impl ModeChannelAccessLevelHandler
 {
-	fn update_member_access_level(
+	/// Élève les membres au niveau d'accès donné. Lorsque
+	/// `bypass_permission` vaut `true`, le niveau d'accès du client sur le
+	/// salon n'est pas vérifié (SAMODE). Retourne `true` si au moins un
+	/// membre a été mis à jour.
+	pub(crate) fn update_member_access_level(
 		socket: &SocketRef,
 		app: &ChatApplication,
 		channel_name: &str,
 		nicknames: &[String],
 		min_access_level: ChannelAccessLevel,
 		set_access_level: ChannelAccessLevel,
-	)
+		bypass_permission: bool,
+	) -> bool
 	{
 		let client_socket = app.current_client(socket);
 
-		if !app.is_client_global_operator(&client_socket)
+		let can_override = bypass_permission
+			|| app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::OverrideChannelModes,
+			);
+
+		if !can_override
 			&& !app.does_client_have_rights_on_channel(
 				&client_socket,
 				channel_name,
 				min_access_level,
 			) {
-			return;
+			return false;
 		}
 
 		let updated: Vec<_> = nicknames
@@ -144,12 +158,22 @@ impl ModeChannelAccessLevelHandler
 			.collect();
 
 		if updated.is_empty() {
-			return;
+			return false;
+		}
+
+		app.save_registered_channel(socket, channel_name);
+
+		// NOTE: un membre masqué d'un salon +D est révélé aux autres membres
+		//       lorsque son niveau d'accès change.
+		for target_member in updated.iter() {
+			if target_member.member.is_hidden() {
+				app.reveal_channel_member(&target_member.client, channel_name);
+			}
 		}
 
 		let Some(channel) = app.get_channel(channel_name) else {
 			client_socket.send_err_notonchannel(channel_name);
-			return;
+			return false;
 		};
 
 		let added_flags: Vec<_> = updated
@@ -180,20 +204,31 @@ impl ModeChannelAccessLevelHandler
 			let found_client = app.get_client_by_id(found.member.id())?;
 			Some(ChannelMemberDTO::from((found_client, &found.member)))
 		});
+
+		true
 	}
 
-	fn remove_member_access_level(
+	/// Retire le niveau d'accès donné aux membres. Lorsque
+	/// `bypass_permission` vaut `true`, le niveau d'accès du client sur le
+	/// salon n'est pas vérifié (SAMODE). Retourne `true` si au moins un
+	/// membre a été mis à jour.
+	pub(crate) fn remove_member_access_level(
 		socket: &SocketRef,
 		app: &ChatApplication,
 		channel_name: &str,
 		nicknames: &[String],
 		min_access_level: ChannelAccessLevel,
 		unset_access_level: ChannelAccessLevel,
-	)
+		bypass_permission: bool,
+	) -> bool
 	{
 		let client_socket = app.current_client(socket);
 
-		let is_client_operator = app.is_client_global_operator(&client_socket);
+		let is_client_operator = bypass_permission
+			|| app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::OverrideChannelModes,
+			);
 
 		let updated: Vec<_> = nicknames
 			.iter()
@@ -293,12 +328,22 @@ impl ModeChannelAccessLevelHandler
 			.collect();
 
 		if updated.is_empty() {
-			return;
+			return false;
+		}
+
+		app.save_registered_channel(socket, channel_name);
+
+		// NOTE: un membre masqué d'un salon +D est révélé aux autres membres
+		//       lorsque son niveau d'accès change.
+		for target_member in updated.iter() {
+			if target_member.member.is_hidden() {
+				app.reveal_channel_member(&target_member.client, channel_name);
+			}
 		}
 
 		let Some(channel) = app.get_channel(channel_name) else {
 			client_socket.send_err_notonchannel(channel_name);
-			return;
+			return false;
 		};
 
 		let removed_flags: Vec<_> = updated
@@ -334,6 +379,8 @@ impl ModeChannelAccessLevelHandler
 			let found_client = app.get_client_by_id(found.member.id())?;
 			Some(ChannelMemberDTO::from((found_client, &found.member)))
 		});
+
+		true
 	}
 }
 
@@ -359,6 +406,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelQOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Owner,
 			ChannelAccessLevel::Owner,
+			false,
 		);
 	}
 
@@ -375,6 +423,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelQOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Owner,
 			ChannelAccessLevel::Owner,
+			false,
 		);
 	}
 }
@@ -401,6 +450,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelAOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Owner,
 			ChannelAccessLevel::AdminOperator,
+			false,
 		);
 	}
 
@@ -417,6 +467,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelAOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Owner,
 			ChannelAccessLevel::AdminOperator,
+			false,
 		);
 	}
 }
@@ -443,6 +494,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Operator,
 			ChannelAccessLevel::Operator,
+			false,
 		);
 	}
 
@@ -459,6 +511,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Operator,
 			ChannelAccessLevel::Operator,
+			false,
 		);
 	}
 }
@@ -485,6 +538,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelHOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Operator,
 			ChannelAccessLevel::HalfOperator,
+			false,
 		);
 	}
 
@@ -501,6 +555,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelHOPHandler
 			&data.nicknames,
 			ChannelAccessLevel::Operator,
 			ChannelAccessLevel::HalfOperator,
+			false,
 		);
 	}
 }
@@ -527,6 +582,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelVIPHandler
 			&data.nicknames,
 			ChannelAccessLevel::HalfOperator,
 			ChannelAccessLevel::Vip,
+			false,
 		);
 	}
 
@@ -543,6 +599,7 @@ impl WebSocketHandler2 for ModeChannelAccessLevelVIPHandler
 			&data.nicknames,
 			ChannelAccessLevel::HalfOperator,
 			ChannelAccessLevel::Vip,
+			false,
 		);
 	}
 }
@@ -15,22 +15,42 @@ use flex_chat::channel::{
 	CHANNEL_MODE_LIST_BAN,
 	CHANNEL_MODE_LIST_BAN_EXCEPT,
 	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
+	CHANNEL_MODE_SETTINGS_FLOOD,
 	ChannelAccessLevel,
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
+	FloodProtection,
+	MemberInterface,
 	SettingsFlag,
 };
-use flex_chat::client::Socket;
+use flex_chat::client::channel::responses::{
+	ChannelClientSocketCommandResponse,
+	ChannelClientSocketErrorReplies,
+};
+use flex_chat::client::{
+	ClientServerApplicationInterface,
+	ClientSocketInterface,
+	Socket,
+};
 use flex_chat::mode::ApplyMode;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
+use crate::features::chat::join::JoinApplicationInterface;
 use crate::features::chat::mode::{
+	ChannelMemberDTO,
 	ChannelModeCommandFormData,
+	ChannelModesSettings,
 	ModeAccessControlClientSocketCommandResponseInterface,
 	ModeChannelAccessControlApplicationInterface,
 	ModeChannelAccessLevelApplicationInterface,
 	ModeChannelSettingsApplicationInterface,
 	ModeChannelSettingsClientSocketCommandResponseInterface,
+	ModeChannelSettingsClientSocketErrorRepliesInterface,
 };
 use crate::features::chat::oper::OperApplicationInterface;
 
@@ -59,124 +79,39 @@ impl WebSocketHandler for ModeChannelSettingsHandler
 	{
 		let client_socket = app.current_client(&socket);
 
+		// NOTE: sans mode, la commande consulte les paramètres du salon.
+		if data.modes == ChannelModesSettings::default() {
+			let Some(channel) = app.channels.get(&data.target) else {
+				client_socket.send_err_nosuchchannel(&data.target);
+				return;
+			};
+			if channel.member(client_socket.cid()).is_none() {
+				client_socket.send_err_notonchannel(&data.target);
+				return;
+			}
+			client_socket.emit_all_channels_settings(&channel, false);
+			return;
+		}
+
 		let mut added_list: Vec<(char, ApplyMode<_>)> = vec![];
 		let mut removed_list: Vec<(char, ApplyMode<_>)> = vec![];
 
 		let mut added_settings: Vec<ApplyMode<_>> = vec![];
 		let mut removed_settings: Vec<ApplyMode<_>> = vec![];
 
-		// NOTE: les opérateurs globaux peuvent appliquer n'importe quels modes.
-		if app.is_client_global_operator(&client_socket) {
-			apply_bans(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.bans.as_deref(),
-				&mut added_list,
-				&mut removed_list,
-			);
-			apply_bans_except(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.bans_except.as_deref(),
-				&mut added_list,
-				&mut removed_list,
-			);
-			apply_invites_except(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.invites_except.as_deref(),
-				&mut added_list,
-				&mut removed_list,
-			);
-			if let Some(limit) = data.modes.limit {
-				apply_limit_members(
-					app,
-					&client_socket,
-					&data.target,
-					data.modes.limit,
-					SettingsFlag::Limit(limit),
-					&mut added_settings,
-					&mut removed_settings,
-				);
-			}
-			apply_mode_settings_bool(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.invite_only,
-				SettingsFlag::InviteOnly,
-				&mut added_settings,
-				&mut removed_settings,
-			);
-			apply_mode_settings_bool(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.moderate,
-				SettingsFlag::Moderate,
-				&mut added_settings,
-				&mut removed_settings,
-			);
-			apply_mode_settings_bool(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.no_external_messages,
-				SettingsFlag::NoExternalMessages,
-				&mut added_settings,
-				&mut removed_settings,
-			);
-			apply_mode_settings_bool(
-				app,
-				&client_socket,
-				&data.target,
-				data.modes.no_topic,
-				SettingsFlag::NoTopic,
-				&mut added_settings,
-				&mut removed_settings,
-			);
-			apply_mode_settings_bool(
+		// NOTE: les opérateurs disposant du privilège adéquat peuvent appliquer
+		//       n'importe quels modes.
+		if app.does_client_have_operator_privilege(
+			&client_socket,
+			FlexChatConfigOperatorPrivilege::OverrideChannelModes,
+		) {
+			Self::apply_modes_bypass_permission(
+				&socket,
 				app,
 				&client_socket,
 				&data.target,
-				data.modes.secret,
-				SettingsFlag::Secret,
-				&mut added_settings,
-				&mut removed_settings,
+				data.modes,
 			);
-
-			if let Some(key) = data.modes.key.map(|s| s.to_string()).as_ref() {
-				apply_mode_settings_str(
-					app,
-					&client_socket,
-					&data.target,
-					key,
-					SettingsFlag::Key(key.to_owned()),
-					&mut added_settings,
-					&mut removed_settings,
-				);
-			}
-
-			if !added_list.is_empty() || !removed_list.is_empty() {
-				client_socket.emit_target_access_control(
-					&data.target,
-					&added_list,
-					&removed_list,
-					true,
-				);
-			}
-
-			if !added_settings.is_empty() || !removed_settings.is_empty() {
-				client_socket.emit_channel_settings(
-					&data.target,
-					&added_settings,
-					&removed_settings,
-				);
-			}
-
 			return;
 		}
 
@@ -216,6 +151,14 @@ impl WebSocketHandler for ModeChannelSettingsHandler
 			&mut added_list,
 			&mut removed_list,
 		);
+		apply_quiets(
+			app,
+			&client_socket,
+			&data.target,
+			data.modes.quiets.as_deref(),
+			&mut added_list,
+			&mut removed_list,
+		);
 		if let Some(limit) = data.modes.limit {
 			apply_limit_members(
 				app,
@@ -272,6 +215,22 @@ impl WebSocketHandler for ModeChannelSettingsHandler
 			&mut added_settings,
 			&mut removed_settings,
 		);
+		apply_extended_mode_settings(
+			app,
+			&client_socket,
+			&data.target,
+			&data.modes,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_topic_lock(
+			app,
+			&client_socket,
+			&data.target,
+			data.modes.topic_lock,
+			&mut added_settings,
+			&mut removed_settings,
+		);
 
 		if let Some(key) = data.modes.key.map(|s| s.to_string()).as_ref() {
 			apply_mode_settings_str(
@@ -285,6 +244,25 @@ impl WebSocketHandler for ModeChannelSettingsHandler
 			);
 		}
 
+		if let Some(flood) = data.modes.flood.as_deref() {
+			apply_flood_protection(
+				app,
+				&client_socket,
+				&data.target,
+				flood,
+				&mut added_settings,
+				&mut removed_settings,
+			);
+		}
+
+		if !added_list.is_empty()
+			|| !removed_list.is_empty()
+			|| !added_settings.is_empty()
+			|| !removed_settings.is_empty()
+		{
+			app.save_registered_channel(&socket, &data.target);
+		}
+
 		if !added_list.is_empty() || !removed_list.is_empty() {
 			client_socket.emit_target_access_control(
 				&data.target,
@@ -301,6 +279,192 @@ impl WebSocketHandler for ModeChannelSettingsHandler
 				&removed_settings,
 			);
 		}
+
+		apply_removed_mode_settings(
+			&socket,
+			app,
+			&data.target,
+			&removed_settings,
+		);
+	}
+}
+
+impl ModeChannelSettingsHandler
+{
+	/// Applique les paramètres d'un salon sans vérifier les niveaux d'accès du
+	/// client. Retourne `true` si au moins un paramètre a été modifié.
+	pub(crate) fn apply_modes_bypass_permission(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		client_socket: &Socket,
+		target: &str,
+		modes: ChannelModesSettings,
+	) -> bool
+	{
+		let mut added_list: Vec<(char, ApplyMode<_>)> = vec![];
+		let mut removed_list: Vec<(char, ApplyMode<_>)> = vec![];
+
+		let mut added_settings: Vec<ApplyMode<_>> = vec![];
+		let mut removed_settings: Vec<ApplyMode<_>> = vec![];
+
+		apply_bans(
+			app,
+			client_socket,
+			target,
+			modes.bans.as_deref(),
+			&mut added_list,
+			&mut removed_list,
+		);
+		apply_bans_except(
+			app,
+			client_socket,
+			target,
+			modes.bans_except.as_deref(),
+			&mut added_list,
+			&mut removed_list,
+		);
+		apply_invites_except(
+			app,
+			client_socket,
+			target,
+			modes.invites_except.as_deref(),
+			&mut added_list,
+			&mut removed_list,
+		);
+		apply_quiets(
+			app,
+			client_socket,
+			target,
+			modes.quiets.as_deref(),
+			&mut added_list,
+			&mut removed_list,
+		);
+		if let Some(limit) = modes.limit {
+			apply_limit_members(
+				app,
+				client_socket,
+				target,
+				modes.limit,
+				SettingsFlag::Limit(limit),
+				&mut added_settings,
+				&mut removed_settings,
+			);
+		}
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.invite_only,
+			SettingsFlag::InviteOnly,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.moderate,
+			SettingsFlag::Moderate,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.no_external_messages,
+			SettingsFlag::NoExternalMessages,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.no_topic,
+			SettingsFlag::NoTopic,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.secret,
+			SettingsFlag::Secret,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_extended_mode_settings(
+			app,
+			client_socket,
+			target,
+			&modes,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			target,
+			modes.topic_lock,
+			SettingsFlag::TopicLock,
+			&mut added_settings,
+			&mut removed_settings,
+		);
+
+		if let Some(key) = modes.key.map(|s| s.to_string()).as_ref() {
+			apply_mode_settings_str(
+				app,
+				client_socket,
+				target,
+				key,
+				SettingsFlag::Key(key.to_owned()),
+				&mut added_settings,
+				&mut removed_settings,
+			);
+		}
+
+		if let Some(flood) = modes.flood.as_deref() {
+			apply_flood_protection(
+				app,
+				client_socket,
+				target,
+				flood,
+				&mut added_settings,
+				&mut removed_settings,
+			);
+		}
+
+		let has_changed = !added_list.is_empty()
+			|| !removed_list.is_empty()
+			|| !added_settings.is_empty()
+			|| !removed_settings.is_empty();
+
+		if has_changed {
+			app.save_registered_channel(socket, target);
+		}
+
+		if !added_list.is_empty() || !removed_list.is_empty() {
+			client_socket.emit_target_access_control(
+				target,
+				&added_list,
+				&removed_list,
+				true,
+			);
+		}
+
+		if !added_settings.is_empty() || !removed_settings.is_empty() {
+			client_socket.emit_channel_settings(
+				target,
+				&added_settings,
+				&removed_settings,
+			);
+		}
+
+		apply_removed_mode_settings(socket, app, target, &removed_settings);
+
+		has_changed
 	}
 }
 
@@ -315,19 +479,23 @@ fn apply_bans(
 {
 	let bans = bans.unwrap_or_default();
 
-	for banmask in bans {
-		if app.has_banmask_on_channel(client_socket, channel_name, banmask) {
+	for entry in bans.iter().map(AccessControlMask::from) {
+		if app.has_banmask_on_channel(
+			client_socket,
+			channel_name,
+			entry.mask.clone(),
+		) {
 			rlist.extend(
 				app.apply_unban_on_channel(
 					client_socket,
 					channel_name,
-					banmask,
+					entry.mask,
 				)
 				.map(|mode| (CHANNEL_MODE_LIST_BAN, mode)),
 			);
 		} else {
 			alist.extend(
-				app.apply_ban_on_channel(client_socket, channel_name, banmask)
+				app.apply_ban_on_channel(client_socket, channel_name, entry)
 					.map(|mode| (CHANNEL_MODE_LIST_BAN, mode)),
 			);
 		}
@@ -345,17 +513,17 @@ fn apply_bans_except(
 {
 	let bans_except = bans_except.unwrap_or_default();
 
-	for banmask in bans_except {
+	for entry in bans_except.iter().map(AccessControlMask::from) {
 		if app.has_banmask_except_on_channel(
 			client_socket,
 			channel_name,
-			banmask,
+			entry.mask.clone(),
 		) {
 			rlist.extend(
 				app.apply_unban_except_on_channel(
 					client_socket,
 					channel_name,
-					banmask,
+					entry.mask,
 				)
 				.map(|mode| (CHANNEL_MODE_LIST_BAN_EXCEPT, mode)),
 			);
@@ -364,7 +532,7 @@ fn apply_bans_except(
 				app.apply_ban_except_on_channel(
 					client_socket,
 					channel_name,
-					banmask,
+					entry,
 				)
 				.map(|mode| (CHANNEL_MODE_LIST_BAN_EXCEPT, mode)),
 			);
@@ -383,17 +551,17 @@ fn apply_invites_except(
 {
 	let invites_except = invites_except.unwrap_or_default();
 
-	for invitemask in invites_except {
+	for entry in invites_except.iter().map(AccessControlMask::from) {
 		if app.has_invitemask_except_on_channel(
 			client_socket,
 			channel_name,
-			invitemask,
+			entry.mask.clone(),
 		) {
 			rlist.extend(
 				app.apply_uninvite_except_on_channel(
 					client_socket,
 					channel_name,
-					invitemask,
+					entry.mask,
 				)
 				.map(|mode| (CHANNEL_MODE_LIST_INVITE_EXCEPT, mode)),
 			);
@@ -402,7 +570,7 @@ fn apply_invites_except(
 				app.apply_invite_except_on_channel(
 					client_socket,
 					channel_name,
-					invitemask,
+					entry,
 				)
 				.map(|mode| (CHANNEL_MODE_LIST_INVITE_EXCEPT, mode)),
 			);
@@ -410,6 +578,40 @@ fn apply_invites_except(
 	}
 }
 
+fn apply_quiets(
+	app: &ChatApplication,
+	client_socket: &Socket,
+	channel_name: &str,
+	quiets: Option<&[Arc<str>]>,
+	alist: &mut Vec<(char, ApplyMode<AccessControlMask>)>,
+	rlist: &mut Vec<(char, ApplyMode<AccessControlMask>)>,
+)
+{
+	let quiets = quiets.unwrap_or_default();
+
+	for entry in quiets.iter().map(AccessControlMask::from) {
+		if app.has_quietmask_on_channel(
+			client_socket,
+			channel_name,
+			entry.mask.clone(),
+		) {
+			rlist.extend(
+				app.apply_unquiet_on_channel(
+					client_socket,
+					channel_name,
+					entry.mask,
+				)
+				.map(|mode| (CHANNEL_MODE_LIST_QUIET, mode)),
+			);
+		} else {
+			alist.extend(
+				app.apply_quiet_on_channel(client_socket, channel_name, entry)
+					.map(|mode| (CHANNEL_MODE_LIST_QUIET, mode)),
+			);
+		}
+	}
+}
+
 fn apply_mode_settings_bool(
 	app: &ChatApplication,
 	client_socket: &Socket,
@@ -462,6 +664,178 @@ fn apply_mode_settings_str(
 	}
 }
 
+fn apply_extended_mode_settings(
+	app: &ChatApplication,
+	client_socket: &Socket,
+	channel_name: &str,
+	modes: &ChannelModesSettings,
+	alist: &mut Vec<ApplyMode<SettingsFlag>>,
+	rlist: &mut Vec<ApplyMode<SettingsFlag>>,
+)
+{
+	let settings = [
+		(modes.auditorium, SettingsFlag::Auditorium),
+		(modes.delayed_join, SettingsFlag::DelayedJoin),
+		(modes.no_colors, SettingsFlag::NoColors),
+		(modes.no_ctcp, SettingsFlag::NoCtcp),
+		(modes.no_knock, SettingsFlag::NoKnock),
+		(modes.no_nick_change, SettingsFlag::NoNickChange),
+		(modes.no_notice, SettingsFlag::NoNotice),
+		(modes.permanent, SettingsFlag::Permanent),
+		(
+			modes.registered_moderated,
+			SettingsFlag::RegisteredModerated,
+		),
+		(modes.registered_only, SettingsFlag::RegisteredOnly),
+		(modes.secure_only, SettingsFlag::SecureOnly),
+	];
+
+	for (maybe_bool, flag) in settings {
+		apply_mode_settings_bool(
+			app,
+			client_socket,
+			channel_name,
+			maybe_bool,
+			flag,
+			alist,
+			rlist,
+		);
+	}
+}
+
+/// Le verrouillage du sujet d'un salon est réservé aux membres ayant un niveau
+/// d'accès minimal à [ChannelAccessLevel::AdminOperator].
+fn apply_topic_lock(
+	app: &ChatApplication,
+	client_socket: &Socket,
+	channel_name: &str,
+	maybe_bool: Option<bool>,
+	alist: &mut Vec<ApplyMode<SettingsFlag>>,
+	rlist: &mut Vec<ApplyMode<SettingsFlag>>,
+)
+{
+	if maybe_bool.is_none()
+		|| !app.does_client_have_rights_on_channel(
+			client_socket,
+			channel_name,
+			ChannelAccessLevel::AdminOperator,
+		) {
+		return;
+	}
+
+	apply_mode_settings_bool(
+		app,
+		client_socket,
+		channel_name,
+		maybe_bool,
+		SettingsFlag::TopicLock,
+		alist,
+		rlist,
+	);
+}
+
+/// Effets de bord des paramètres retirés d'un salon.
+fn apply_removed_mode_settings(
+	socket: &SocketRef,
+	app: &ChatApplication,
+	channel_name: &str,
+	rlist: &[ApplyMode<SettingsFlag>],
+)
+{
+	// NOTE: les membres masqués sont révélés lorsque le salon n'est plus en
+	//       mode +D.
+	if rlist.iter().any(|mode| mode.flag == SettingsFlag::DelayedJoin) {
+		let hidden_members: Vec<_> = app
+			.channels
+			.get(channel_name)
+			.map(|channel| {
+				channel
+					.members()
+					.values()
+					.filter(|member| member.is_hidden())
+					.map(|member| *member.id())
+					.collect()
+			})
+			.unwrap_or_default();
+
+		for member_id in hidden_members {
+			if let Some(member_socket) =
+				app.find_socket_by_id(socket, &member_id)
+			{
+				app.reveal_channel_member(&member_socket, channel_name);
+			}
+		}
+	}
+
+	// NOTE: les membres masqués par le mode +u sont révélés lorsque le salon
+	//       n'est plus en mode auditorium.
+	if rlist.iter().any(|mode| mode.flag == SettingsFlag::Auditorium)
+		&& let Some(channel) = app.get_channel(channel_name)
+	{
+		let client_socket = app.current_client(socket);
+		client_socket.send_rpl_namreply(&channel, |member| {
+			if member.is_hidden() {
+				return None;
+			}
+			let client = app.get_client_by_id(member.id())?;
+			Some(ChannelMemberDTO::from((client, member)))
+		});
+	}
+
+	// NOTE: un salon qui n'est plus permanent est supprimé s'il est vide.
+	if rlist.iter().any(|mode| mode.flag == SettingsFlag::Permanent) {
+		let is_empty = app.channels.get(channel_name).is_some_and(|channel| {
+			channel.members().is_empty() && !channel.is_registered()
+		});
+		if is_empty {
+			app.channels.remove(channel_name);
+		}
+	}
+}
+
+fn apply_flood_protection(
+	app: &ChatApplication,
+	client_socket: &Socket,
+	channel_name: &str,
+	rules: &str,
+	alist: &mut Vec<ApplyMode<SettingsFlag>>,
+	rlist: &mut Vec<ApplyMode<SettingsFlag>>,
+)
+{
+	if rules.is_empty() {
+		let maybe_protection =
+			app.channels.get(channel_name).and_then(|channel| {
+				channel.modes_settings.flood_protection().cloned()
+			});
+		if let Some(protection) = maybe_protection {
+			rlist.extend(app.unset_settings_on_channel(
+				client_socket,
+				channel_name,
+				SettingsFlag::Flood(protection),
+			));
+		}
+		return;
+	}
+
+	match rules.parse::<FloodProtection>() {
+		| Ok(protection) => {
+			alist.extend(app.set_settings_on_channel(
+				client_socket,
+				channel_name,
+				SettingsFlag::Flood(protection),
+			));
+		}
+		| Err(error) => {
+			client_socket.send_err_invalidmodeparam(
+				channel_name,
+				CHANNEL_MODE_SETTINGS_FLOOD,
+				rules,
+				error,
+			);
+		}
+	}
+}
+
 fn apply_limit_members(
 	app: &ChatApplication,
 	client_socket: &Socket,
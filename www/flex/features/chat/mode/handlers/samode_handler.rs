@@ -0,0 +1,174 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::AccessControlMask;
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_chat::user::UserInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
+use crate::features::ChatApplication;
+use crate::features::chat::mode::{
+	ModeChannelAccessControlHandler,
+	ModeChannelAccessLevelHandler,
+	ModeChannelSettingsHandler,
+	SamodeCommandFormData,
+};
+use crate::features::chat::oper::OperClientSocketCommandResponse;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct SamodeHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for SamodeHandler
+{
+	type App = ChatApplication;
+	type Data = SamodeCommandFormData;
+
+	const EVENT_NAME: &'static str = "SAMODE";
+
+	/// La commande SAMODE permet à un opérateur d'appliquer des paramètres,
+	/// des bannissements ou des niveaux d'accès sur un salon sans y disposer
+	/// du niveau d'accès requis.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<SamodeCommandFormData>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Samode,
+		) else {
+			return;
+		};
+
+		if app.get_channel(&data.target).is_none() {
+			client_socket.send_err_nosuchchannel(&data.target);
+			return;
+		}
+
+		let mut applied = vec![];
+
+		if let Some(modes) = data.modes
+			&& ModeChannelSettingsHandler::apply_modes_bypass_permission(
+				&socket,
+				app,
+				&client_socket,
+				&data.target,
+				modes,
+			) {
+			applied.push("paramètres".to_owned());
+		}
+
+		// NOTE: les fonctions de mise à jour des niveaux d'accès et des listes
+		//       de contrôle d'accès empruntent elles-mêmes le client courant.
+		let oper_nickname = client_socket.user().nickname().to_owned();
+		drop(client_socket);
+
+		for access_level in data.access_levels.iter() {
+			let updated = if access_level.set {
+				ModeChannelAccessLevelHandler::update_member_access_level(
+					&socket,
+					app,
+					&data.target,
+					&access_level.nicknames,
+					access_level.access_level,
+					access_level.access_level,
+					true,
+				)
+			} else {
+				ModeChannelAccessLevelHandler::remove_member_access_level(
+					&socket,
+					app,
+					&data.target,
+					&access_level.nicknames,
+					access_level.access_level,
+					access_level.access_level,
+					true,
+				)
+			};
+
+			if !updated {
+				continue;
+			}
+
+			applied.push(format!(
+				"{}{} {}",
+				if access_level.set { '+' } else { '-' },
+				access_level.access_level.letter(),
+				access_level.nicknames.join(" "),
+			));
+		}
+
+		for access_control in data.access_controls.iter() {
+			let updated = if access_control.set {
+				let entries: Vec<_> = access_control
+					.masks
+					.iter()
+					.map(AccessControlMask::from)
+					.collect();
+				ModeChannelAccessControlHandler::add_access_control(
+					&socket,
+					app,
+					&data.target,
+					access_control.list,
+					&entries,
+					true,
+				)
+			} else {
+				let masks: Vec<_> = access_control
+					.masks
+					.iter()
+					.map(|mask| AccessControlMask::from(mask).mask)
+					.collect();
+				ModeChannelAccessControlHandler::remove_access_control(
+					&socket,
+					app,
+					&data.target,
+					access_control.list,
+					&masks,
+					true,
+				)
+			};
+
+			if !updated {
+				continue;
+			}
+
+			applied.push(format!(
+				"{}{} {}",
+				if access_control.set { '+' } else { '-' },
+				access_control.list,
+				access_control.masks.join(" "),
+			));
+		}
+
+		if applied.is_empty() {
+			return;
+		}
+
+		let client_socket = app.current_client(&socket);
+		client_socket.emit_oper_notice(&format!(
+			"{} a utilisé SAMODE sur {}: {}",
+			oper_nickname,
+			data.target,
+			applied.join(", "),
+		));
+	}
+}
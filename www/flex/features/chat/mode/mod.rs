@@ -15,27 +15,41 @@ flex_kernel::import! {
 		pub mod channel_access_control_handler use *;
 		pub mod channel_access_level_handler use *;
 		pub mod channel_mode_handler use *;
+		pub mod samode_handler use *;
 	};
 
 	pub mod responses use {
 		pub mod channel_access_control_command_response use *;
 		pub mod channel_access_control_error_response use *;
 		pub mod channel_access_level_command_response use *;
+		pub mod channel_flood_command_response use *;
 		pub mod channel_settings_command_response use *;
+		pub mod channel_settings_error_response use *;
 		pub mod mode_response use *;
+		pub(super) mod rpl_banlist use *;
+		pub(super) mod rpl_endofbanlist use *;
+		pub(super) mod rpl_endofexceptlist use *;
+		pub(super) mod rpl_endofinvexlist use *;
+		pub(super) mod rpl_endofquietlist use *;
+		pub(super) mod rpl_exceptlist use *;
+		pub(super) mod rpl_invexlist use *;
+		pub(super) mod rpl_quietlist use *;
 	};
 
 	pub mod sessions use {
+		pub mod channel_access_control_expiry_session use *;
 		pub mod channel_access_level_channels_session use *;
 	};
 
 	mod errors use {
 		pub(super) mod err_bannedfromchan use *;
+		pub(super) mod err_invalidmodeparam use *;
 	};
 
 	mod forms use {
 		pub(super) mod channel_access_control_form use *;
 		pub(super) mod channel_access_level_form use *;
 		pub(super) mod channel_mode_form use *;
+		pub(super) mod samode_form use *;
 	};
 }
@@ -10,6 +10,10 @@
 
 use flex_chat::channel::{
 	AccessControlMask,
+	CHANNEL_MODE_LIST_BAN,
+	CHANNEL_MODE_LIST_BAN_EXCEPT,
+	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
 	Channel,
 	ChannelAccessControlInterface,
 	ChannelInterface,
@@ -17,7 +21,17 @@ use flex_chat::channel::{
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 use flex_chat::mode::ApplyMode;
 
-use super::ModeCommandResponse;
+use super::{
+	ModeCommandResponse,
+	RplBanlistReply,
+	RplEndofbanlistReply,
+	RplEndofexceptlistReply,
+	RplEndofinvexlistReply,
+	RplEndofquietlistReply,
+	RplExceptlistReply,
+	RplInvexlistReply,
+	RplQuietlistReply,
+};
 
 // --------- //
 // Interface //
@@ -48,6 +62,10 @@ pub trait ModeAccessControlClientSocketCommandResponseInterface:
 
 	/// Émet au client courant tous les controls d'accès du salon.
 	fn emit_all_channel_access_control(&self, channel: &Self::Channel);
+
+	/// Émet au client courant les masques d'une liste de contrôle d'accès
+	/// (`b`, `e`, `I`, `Q`) d'un salon, suivis de la fin de la liste.
+	fn send_rpl_access_control_list(&self, channel: &Self::Channel, list: char);
 }
 
 // -------------- //
@@ -117,4 +135,106 @@ impl<'s> ModeAccessControlClientSocketCommandResponseInterface for Socket<'s>
 
 		self.emit(mode_cmd.name(), mode_cmd);
 	}
+
+	fn send_rpl_access_control_list(&self, channel: &Self::Channel, list: char)
+	{
+		let origin = Origin::from(self.client());
+		let channel_name = channel.name();
+
+		for (_, mode) in channel
+			.access_controls()
+			.into_iter()
+			.filter(|(letter, _)| *letter == list)
+		{
+			let mask = mode.flag.mask.to_string();
+			let updated_by = mode.updated_by.as_str();
+			let updated_at = mode.updated_at.timestamp();
+
+			match list {
+				| CHANNEL_MODE_LIST_BAN => {
+					let rpl_banlist = RplBanlistReply {
+						origin: &origin,
+						tags: RplBanlistReply::default_tags(),
+						channel: channel_name,
+						mask: &mask,
+						updated_by,
+						updated_at: &updated_at,
+					};
+					self.emit(rpl_banlist.name(), rpl_banlist);
+				}
+				| CHANNEL_MODE_LIST_BAN_EXCEPT => {
+					let rpl_exceptlist = RplExceptlistReply {
+						origin: &origin,
+						tags: RplExceptlistReply::default_tags(),
+						channel: channel_name,
+						mask: &mask,
+						updated_by,
+						updated_at: &updated_at,
+					};
+					self.emit(rpl_exceptlist.name(), rpl_exceptlist);
+				}
+				| CHANNEL_MODE_LIST_INVITE_EXCEPT => {
+					let rpl_invexlist = RplInvexlistReply {
+						origin: &origin,
+						tags: RplInvexlistReply::default_tags(),
+						channel: channel_name,
+						mask: &mask,
+						updated_by,
+						updated_at: &updated_at,
+					};
+					self.emit(rpl_invexlist.name(), rpl_invexlist);
+				}
+				| CHANNEL_MODE_LIST_QUIET => {
+					let rpl_quietlist = RplQuietlistReply {
+						origin: &origin,
+						tags: RplQuietlistReply::default_tags(),
+						channel: channel_name,
+						mode: &list,
+						mask: &mask,
+						updated_by,
+						updated_at: &updated_at,
+					};
+					self.emit(rpl_quietlist.name(), rpl_quietlist);
+				}
+				| _ => {}
+			}
+		}
+
+		match list {
+			| CHANNEL_MODE_LIST_BAN => {
+				let rpl_endofbanlist = RplEndofbanlistReply {
+					origin: &origin,
+					tags: RplEndofbanlistReply::default_tags(),
+					channel: channel_name,
+				};
+				self.emit(rpl_endofbanlist.name(), rpl_endofbanlist);
+			}
+			| CHANNEL_MODE_LIST_BAN_EXCEPT => {
+				let rpl_endofexceptlist = RplEndofexceptlistReply {
+					origin: &origin,
+					tags: RplEndofexceptlistReply::default_tags(),
+					channel: channel_name,
+				};
+				self.emit(rpl_endofexceptlist.name(), rpl_endofexceptlist);
+			}
+			| CHANNEL_MODE_LIST_INVITE_EXCEPT => {
+				let rpl_endofinvexlist = RplEndofinvexlistReply {
+					origin: &origin,
+					tags: RplEndofinvexlistReply::default_tags(),
+					channel: channel_name,
+				};
+				self.emit(rpl_endofinvexlist.name(), rpl_endofinvexlist);
+			}
+			| CHANNEL_MODE_LIST_QUIET => {
+				let rpl_endofquietlist = RplEndofquietlistReply {
+					origin: &origin,
+					tags: RplEndofquietlistReply::default_tags(),
+					channel: channel_name,
+					mode: &list,
+				};
+				self.emit(rpl_endofquietlist.name(), rpl_endofquietlist);
+			}
+			| _ => {}
+		}
+	}
 }
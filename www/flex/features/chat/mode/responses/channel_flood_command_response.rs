@@ -0,0 +1,161 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	AccessControlMask,
+	Channel,
+	ChannelInterface,
+	SettingsFlag,
+	SettingsFlagInterface,
+};
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::mode::ApplyMode;
+
+use super::ModeCommandResponse;
+use crate::features::chat::kick::KickCommandResponse;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Origine des réponses émises automatiquement par le serveur.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(serde::Serialize)]
+pub struct ServerOrigin<'a>
+{
+	/// Nom du serveur.
+	#[serde(rename = "nickname")]
+	pub name: &'a str,
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ModeChannelFloodClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	type Channel: ChannelInterface;
+
+	/// Émet aux membres d'un salon les paramètres appliqués/retirés par le
+	/// serveur suite au dépassement d'une règle du mode +f.
+	fn emit_flood_channel_settings(
+		&self,
+		server_name: &str,
+		target: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		added: &[ApplyMode<SettingsFlag>],
+		removed: &[ApplyMode<SettingsFlag>],
+	);
+
+	/// Émet aux membres d'un salon les bannissements appliqués/retirés par le
+	/// serveur suite au dépassement d'une règle du mode +f.
+	fn emit_flood_access_control(
+		&self,
+		server_name: &str,
+		target: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		added: &[(char, ApplyMode<AccessControlMask>)],
+		removed: &[(char, ApplyMode<AccessControlMask>)],
+	);
+
+	/// Émet aux membres d'un salon la sanction du client courant par le
+	/// serveur suite au dépassement d'une règle du mode +f.
+	fn emit_flood_kick(&self, server_name: &str, channel: &Self::Channel);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ModeChannelFloodClientSocketCommandResponseInterface for Socket<'s>
+{
+	type Channel = Channel;
+
+	fn emit_flood_channel_settings(
+		&self,
+		server_name: &str,
+		target: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		added: &[ApplyMode<SettingsFlag>],
+		removed: &[ApplyMode<SettingsFlag>],
+	)
+	{
+		if added.is_empty() && removed.is_empty() {
+			return;
+		}
+
+		let origin = ServerOrigin { name: server_name };
+
+		let channel_settings = ModeCommandResponse {
+			origin: &origin,
+			tags: ModeCommandResponse::<()>::default_tags(),
+			target,
+			added: added
+				.iter()
+				.map(|mode| (mode.letter(), mode.clone()))
+				.collect(),
+			removed: removed
+				.iter()
+				.map(|mode| (mode.letter(), mode.clone()))
+				.collect(),
+			updated: true,
+		};
+
+		let channel_room = format!("channel:{}", target.to_lowercase());
+		self.emit_within(
+			channel_room,
+			channel_settings.name(),
+			channel_settings,
+		);
+	}
+
+	fn emit_flood_access_control(
+		&self,
+		server_name: &str,
+		target: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		added: &[(char, ApplyMode<AccessControlMask>)],
+		removed: &[(char, ApplyMode<AccessControlMask>)],
+	)
+	{
+		if added.is_empty() && removed.is_empty() {
+			return;
+		}
+
+		let origin = ServerOrigin { name: server_name };
+
+		let mode_cmd = ModeCommandResponse {
+			origin: &origin,
+			tags: ModeCommandResponse::<()>::default_tags(),
+			added: added.to_vec(),
+			removed: removed.to_vec(),
+			target,
+			updated: true,
+		};
+
+		let channel_room = format!("channel:{}", target.to_lowercase());
+		self.emit_within(channel_room, mode_cmd.name(), mode_cmd);
+	}
+
+	fn emit_flood_kick(&self, server_name: &str, channel: &Self::Channel)
+	{
+		let origin = ServerOrigin { name: server_name };
+		let knick_origin = Origin::from(self.client());
+
+		let cmd_kick = KickCommandResponse {
+			origin: &origin,
+			knick: &knick_origin,
+			channel: channel.name(),
+			reason: Some("Flood (+f)"),
+			tags: KickCommandResponse::<()>::default_tags(),
+		};
+
+		self.emit_within(channel.room(), cmd_kick.name(), cmd_kick);
+		_ = self.socket().leave(channel.room());
+	}
+}
@@ -0,0 +1,65 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{Channel, ChannelInterface};
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::mode::ErrInvalidmodeparamError;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ModeChannelSettingsClientSocketErrorRepliesInterface:
+	ClientSocketInterface
+{
+	type Channel: ChannelInterface;
+
+	fn send_err_invalidmodeparam(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mode: char,
+		param: &str,
+		description: impl ToString,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ModeChannelSettingsClientSocketErrorRepliesInterface for Socket<'s>
+{
+	type Channel = Channel;
+
+	fn send_err_invalidmodeparam(
+		&self,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		mode: char,
+		param: &str,
+		description: impl ToString,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let mode = mode.to_string();
+		let description = description.to_string();
+
+		let err_invalidmodeparam = ErrInvalidmodeparamError {
+			origin: &origin,
+			tags: ErrInvalidmodeparamError::default_tags(),
+			target: channel_name,
+			mode: &mode,
+			param,
+			description: &description,
+		};
+
+		self.emit(err_invalidmodeparam.name(), err_invalidmodeparam);
+	}
+}
@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// État de la tâche de fond retirant les masques expirés des listes de
+/// contrôle d'accès des salons.
+#[derive(Default)]
+pub struct ChannelAccessControlExpirySession
+{
+	/// La tâche de fond a-t-elle été démarrée?
+	started: AtomicBool,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ChannelAccessControlExpirySession
+{
+	/// Marque la tâche de fond comme démarrée. Retourne `false` si elle
+	/// l'était déjà.
+	pub fn mark_as_started(&self) -> bool
+	{
+		!self.started.swap(true, Ordering::SeqCst)
+	}
+}
@@ -0,0 +1,114 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::{UserAddressInterface, UserInterface};
+
+use super::{
+	MonitorClientSocketCommandResponseInterface,
+	MonitorClientsSessionInterface,
+};
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait MonitorApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Notifie les clients surveillant le pseudonyme d'un client que celui-ci
+	/// est en ligne.
+	fn notify_monitor_online(&self, client_socket: &Self::ClientSocket<'_>);
+
+	/// Notifie les clients surveillant un pseudonyme que celui-ci est
+	/// hors-ligne.
+	fn notify_monitor_offline(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		nickname: &str,
+	);
+
+	/// Le statut (en ligne, hors-ligne) de pseudonymes surveillés. Les
+	/// pseudonymes en ligne sont retournés sous la forme d'adresses
+	/// `nick!ident@host`.
+	fn monitor_status(
+		&self,
+		nicknames: &[String],
+	) -> (Vec<String>, Vec<String>);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl MonitorApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn notify_monitor_online(&self, client_socket: &Self::ClientSocket<'_>)
+	{
+		let nickname = client_socket.user().nickname();
+		let targets = [client_socket.user().full_address()];
+
+		for watcher_id in self.clients.monitor_watchers(nickname) {
+			if &watcher_id == client_socket.cid() {
+				client_socket.send_rpl_mononline(&targets);
+				continue;
+			}
+			let Some(watcher_socket) =
+				self.find_socket_by_id(client_socket.socket(), &watcher_id)
+			else {
+				continue;
+			};
+			watcher_socket.send_rpl_mononline(&targets);
+		}
+	}
+
+	fn notify_monitor_offline(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		nickname: &str,
+	)
+	{
+		let targets = [nickname.to_owned()];
+
+		for watcher_id in self.clients.monitor_watchers(nickname) {
+			if &watcher_id == client_socket.cid() {
+				continue;
+			}
+			let Some(watcher_socket) =
+				self.find_socket_by_id(client_socket.socket(), &watcher_id)
+			else {
+				continue;
+			};
+			watcher_socket.send_rpl_monoffline(&targets);
+		}
+	}
+
+	fn monitor_status(&self, nicknames: &[String])
+	-> (Vec<String>, Vec<String>)
+	{
+		let mut online = Vec::new();
+		let mut offline = Vec::new();
+
+		for nickname in nicknames {
+			match self.clients.get_by_nickname(nickname) {
+				| Some(client) if client.is_registered() => {
+					online.push(client.user().full_address());
+				}
+				| _ => offline.push(nickname.to_owned()),
+			}
+		}
+
+		(online, offline)
+	}
+}
@@ -0,0 +1,59 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientInterface;
+use flex_chat::user::UserInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::monitor::{
+	IsonCommandFormData,
+	MonitorClientSocketCommandResponseInterface,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct IsonHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for IsonHandler
+{
+	type App = ChatApplication;
+	type Data = IsonCommandFormData;
+
+	const EVENT_NAME: &'static str = "ISON";
+
+	/// La commande ISON permet de savoir si des pseudonymes sont actuellement
+	/// connectés au serveur.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<IsonCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let nicknames: Vec<_> = data
+			.nicknames
+			.iter()
+			.filter_map(|nickname| app.clients.get_by_nickname(nickname))
+			.filter(|client| client.is_registered())
+			.map(|client| client.user().nickname().to_owned())
+			.collect();
+
+		client_socket.send_rpl_ison(&nicknames);
+	}
+}
@@ -0,0 +1,135 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::monitor::{
+	MONITOR_LIMIT,
+	MonitorApplicationInterface,
+	MonitorClientSocketCommandResponseInterface,
+	MonitorClientSocketErrorRepliesInterface,
+	MonitorClientsSessionInterface,
+	MonitorCommandFormData,
+};
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Nombre maximum de pseudonymes par réponse.
+const MONITOR_TARGETS_PER_REPLY: usize = 10;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct MonitorHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for MonitorHandler
+{
+	type App = ChatApplication;
+	type Data = MonitorCommandFormData;
+
+	const EVENT_NAME: &'static str = "MONITOR";
+
+	/// La commande MONITOR permet à un client d'être notifié lorsque des
+	/// pseudonymes se connectent ou se déconnectent du serveur.
+	///
+	///   - `+` ajoute des pseudonymes à la liste de surveillance ;
+	///   - `-` supprime des pseudonymes de la liste de surveillance ;
+	///   - `C` vide la liste de surveillance ;
+	///   - `L` liste les pseudonymes de la liste de surveillance ;
+	///   - `S` émet le statut de chaque pseudonyme de la liste de surveillance.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<MonitorCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		match data.subcommand.to_uppercase().as_str() {
+			| "+" => {
+				let mut added = Vec::with_capacity(data.targets.len());
+
+				for (idx, target) in data.targets.iter().enumerate() {
+					let total =
+						app.clients.monitorlist(client_socket.cid()).len();
+					if total >= MONITOR_LIMIT {
+						client_socket.send_err_monlistfull(
+							MONITOR_LIMIT,
+							&data.targets[idx..],
+						);
+						break;
+					}
+
+					if app
+						.clients
+						.add_to_monitorlist(client_socket.cid(), target)
+					{
+						added.push(target.to_owned());
+					}
+				}
+
+				send_monitor_status(app, &client_socket, &added);
+			}
+			| "-" => {
+				for target in data.targets.iter() {
+					app.clients
+						.remove_from_monitorlist(client_socket.cid(), target);
+				}
+			}
+			| "C" => {
+				app.clients.clear_monitorlist(client_socket.cid());
+			}
+			| "L" => {
+				let targets = app.clients.monitorlist(client_socket.cid());
+				for chunk in targets.chunks(MONITOR_TARGETS_PER_REPLY) {
+					client_socket.send_rpl_monlist(chunk);
+				}
+				client_socket.send_rpl_endofmonlist();
+			}
+			| "S" => {
+				let targets = app.clients.monitorlist(client_socket.cid());
+				send_monitor_status(app, &client_socket, &targets);
+			}
+			| _ => {}
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Émet au client le statut (en ligne, hors-ligne) de pseudonymes surveillés.
+fn send_monitor_status(
+	app: &ChatApplication,
+	client_socket: &Socket<'_>,
+	nicknames: &[String],
+)
+{
+	let (online, offline) = app.monitor_status(nicknames);
+
+	for chunk in online.chunks(MONITOR_TARGETS_PER_REPLY) {
+		client_socket.send_rpl_mononline(chunk);
+	}
+
+	for chunk in offline.chunks(MONITOR_TARGETS_PER_REPLY) {
+		client_socket.send_rpl_monoffline(chunk);
+	}
+}
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientInterface;
+use flex_chat::user::{
+	UserAwayInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::monitor::{
+	MonitorClientSocketCommandResponseInterface,
+	UserhostCommandFormData,
+};
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Nombre maximum de pseudonymes traités par la commande USERHOST.
+const USERHOST_MAX_NICKNAMES: usize = 5;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct UserhostHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for UserhostHandler
+{
+	type App = ChatApplication;
+	type Data = UserhostCommandFormData;
+
+	const EVENT_NAME: &'static str = "USERHOST";
+
+	/// La commande USERHOST retourne l'hôte des pseudonymes demandés, sous la
+	/// forme `nick[*]=[+|-]ident@host`, où `*` indique un opérateur et `-` un
+	/// utilisateur absent.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UserhostCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let replies: Vec<_> = data
+			.nicknames
+			.iter()
+			.take(USERHOST_MAX_NICKNAMES)
+			.filter_map(|nickname| app.clients.get_by_nickname(nickname))
+			.filter(|client| client.is_registered())
+			.map(|client| {
+				let user = client.user();
+				format!(
+					"{}{}={}{}@{}",
+					user.nickname(),
+					if user.is_operator() { "*" } else { "" },
+					if user.is_away() { "-" } else { "+" },
+					user.ident(),
+					user.host(),
+				)
+			})
+			.collect();
+
+		client_socket.send_rpl_userhost(&replies);
+	}
+}
@@ -0,0 +1,44 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod ison_handler use *;
+		pub mod monitor_handler use *;
+		pub mod userhost_handler use *;
+	};
+
+	pub mod sessions use {
+		pub mod monitor_session use *;
+	};
+
+	mod errors use {
+		pub(super) mod err_monlistfull use *;
+	};
+
+	mod forms use {
+		pub(super) mod ison_form use *;
+		pub(super) mod monitor_form use *;
+		pub(super) mod userhost_form use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(crate) mod monitor_command_response use *;
+		pub(crate) mod monitor_error_response use *;
+		pub(super) mod rpl_endofmonlist use *;
+		pub(super) mod rpl_ison use *;
+		pub(super) mod rpl_monlist use *;
+		pub(super) mod rpl_monoffline use *;
+		pub(super) mod rpl_mononline use *;
+		pub(super) mod rpl_userhost use *;
+	};
+}
@@ -0,0 +1,111 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use super::{
+	RplEndofmonlistReply,
+	RplIsonReply,
+	RplMonlistReply,
+	RplMonofflineReply,
+	RplMononlineReply,
+	RplUserhostReply,
+};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait MonitorClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client la fin de sa liste de surveillance.
+	fn send_rpl_endofmonlist(&self)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_endofmonlist = RplEndofmonlistReply {
+			origin: &origin,
+			tags: RplEndofmonlistReply::default_tags(),
+		};
+		self.emit(rpl_endofmonlist.name(), rpl_endofmonlist);
+	}
+
+	/// Émet au client les pseudonymes connectés parmi ceux demandés.
+	fn send_rpl_ison(&self, nicknames: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let nicknames = nicknames.join(" ");
+		let rpl_ison = RplIsonReply {
+			origin: &origin,
+			tags: RplIsonReply::default_tags(),
+			nicknames: &nicknames,
+		};
+		self.emit(rpl_ison.name(), rpl_ison);
+	}
+
+	/// Émet au client une partie de sa liste de surveillance.
+	fn send_rpl_monlist(&self, targets: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let targets = targets.join(",");
+		let rpl_monlist = RplMonlistReply {
+			origin: &origin,
+			tags: RplMonlistReply::default_tags(),
+			targets: &targets,
+		};
+		self.emit(rpl_monlist.name(), rpl_monlist);
+	}
+
+	/// Émet au client les pseudonymes surveillés qui sont hors-ligne.
+	fn send_rpl_monoffline(&self, targets: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let targets = targets.join(",");
+		let rpl_monoffline = RplMonofflineReply {
+			origin: &origin,
+			tags: RplMonofflineReply::default_tags(),
+			targets: &targets,
+		};
+		self.emit(rpl_monoffline.name(), rpl_monoffline);
+	}
+
+	/// Émet au client les adresses des pseudonymes surveillés qui sont en
+	/// ligne.
+	fn send_rpl_mononline(&self, targets: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let targets = targets.join(",");
+		let rpl_mononline = RplMononlineReply {
+			origin: &origin,
+			tags: RplMononlineReply::default_tags(),
+			targets: &targets,
+		};
+		self.emit(rpl_mononline.name(), rpl_mononline);
+	}
+
+	/// Émet au client les réponses de la commande /USERHOST.
+	fn send_rpl_userhost(&self, replies: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let replies = replies.join(" ");
+		let rpl_userhost = RplUserhostReply {
+			origin: &origin,
+			tags: RplUserhostReply::default_tags(),
+			replies: &replies,
+		};
+		self.emit(rpl_userhost.name(), rpl_userhost);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> MonitorClientSocketCommandResponseInterface for Socket<'s> {}
@@ -0,0 +1,42 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::monitor::ErrMonlistfullError;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait MonitorClientSocketErrorRepliesInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'erreur [ErrMonlistfullError].
+	fn send_err_monlistfull(&self, limit: usize, targets: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let limit = limit.to_string();
+		let targets = targets.join(",");
+		let err_monlistfull = ErrMonlistfullError {
+			origin: &origin,
+			limit: &limit,
+			targets: &targets,
+			tags: ErrMonlistfullError::default_tags(),
+		};
+		self.emit(err_monlistfull.name(), err_monlistfull);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> MonitorClientSocketErrorRepliesInterface for Socket<'s> {}
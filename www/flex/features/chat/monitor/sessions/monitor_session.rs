@@ -0,0 +1,149 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use dashmap::{DashMap, DashSet};
+use flex_chat::client::{ClientID, ClientInterface, ClientsSessionInterface};
+
+use crate::features::chat::sessions::ClientsSession;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Nombre maximum de pseudonymes qu'un client peut surveiller.
+pub const MONITOR_LIMIT: usize = 100;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Listes de surveillance (MONITOR) des clients.
+#[derive(Default)]
+pub struct MonitorSession
+{
+	/// Les clients surveillant un pseudonyme (casemappé).
+	watchers: DashMap<String, DashSet<ClientID>>,
+	/// Les pseudonymes (casemappés) surveillés par un client, associés au
+	/// pseudonyme tel qu'il a été demandé.
+	targets: DashMap<ClientID, DashMap<String, String>>,
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait MonitorClientsSessionInterface: ClientsSessionInterface
+{
+	/// Ajoute un pseudonyme à la liste de surveillance d'un client.
+	fn add_to_monitorlist(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		nickname: &str,
+	) -> bool;
+
+	/// Vide la liste de surveillance d'un client.
+	fn clear_monitorlist(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+	);
+
+	/// La liste de surveillance d'un client.
+	fn monitorlist(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+	) -> Vec<String>;
+
+	/// Supprime un pseudonyme de la liste de surveillance d'un client.
+	fn remove_from_monitorlist(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		nickname: &str,
+	);
+
+	/// Les clients surveillant un pseudonyme.
+	fn monitor_watchers(
+		&self,
+		nickname: &str,
+	) -> Vec<<Self::Client as ClientInterface>::ClientID>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl MonitorClientsSessionInterface for ClientsSession
+{
+	fn add_to_monitorlist(&self, client_id: &ClientID, nickname: &str) -> bool
+	{
+		let casemapped_nickname = nickname.to_lowercase();
+
+		let targets = self.monitor.targets.entry(*client_id).or_default();
+		if targets.contains_key(&casemapped_nickname) {
+			return false;
+		}
+		targets.insert(casemapped_nickname.clone(), nickname.to_owned());
+
+		self.monitor
+			.watchers
+			.entry(casemapped_nickname)
+			.or_default()
+			.insert(*client_id);
+
+		true
+	}
+
+	fn clear_monitorlist(&self, client_id: &ClientID)
+	{
+		let Some((_, targets)) = self.monitor.targets.remove(client_id) else {
+			return;
+		};
+
+		for (casemapped_nickname, _) in targets {
+			self.monitor.watchers.remove_if(&casemapped_nickname, |_, ids| {
+				ids.remove(client_id);
+				ids.is_empty()
+			});
+		}
+	}
+
+	fn monitorlist(&self, client_id: &ClientID) -> Vec<String>
+	{
+		self.monitor
+			.targets
+			.get(client_id)
+			.map(|targets| {
+				targets.iter().map(|target| target.value().clone()).collect()
+			})
+			.unwrap_or_default()
+	}
+
+	fn remove_from_monitorlist(&self, client_id: &ClientID, nickname: &str)
+	{
+		let casemapped_nickname = nickname.to_lowercase();
+
+		if let Some(targets) = self.monitor.targets.get(client_id) {
+			targets.remove(&casemapped_nickname);
+		}
+
+		self.monitor.watchers.remove_if(&casemapped_nickname, |_, ids| {
+			ids.remove(client_id);
+			ids.is_empty()
+		});
+	}
+
+	fn monitor_watchers(&self, nickname: &str) -> Vec<ClientID>
+	{
+		self.monitor
+			.watchers
+			.get(&nickname.to_lowercase())
+			.map(|ids| ids.iter().map(|id| *id).collect())
+			.unwrap_or_default()
+	}
+}
@@ -8,8 +8,13 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_chat::client::{ClientSocketInterface, Socket};
-use flex_chat::user::UserInterface;
+use flex_chat::channel::{
+	ChannelAccessControlBanInterface,
+	ChannelAccessLevel,
+};
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::mode::EXTBAN_NICK;
+use flex_chat::user::{ServerNoticeMask, UserInterface};
 
 use super::{
 	NickClientSessionInterface,
@@ -17,6 +22,9 @@ use super::{
 	NickClientSocketErrorRepliesInterface,
 };
 use crate::ChatApplication;
+use crate::features::chat::mode::ModeChannelAccessLevelChannelsSessionInterface;
+use crate::features::chat::oper::OperApplicationInterface;
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
 
 // --------- //
 // Interface //
@@ -29,6 +37,20 @@ pub trait NickApplicationInterface
 	/// Peut-on localiser un client de session via un pseudonyme ?
 	fn can_locate_client_by_nickname(&self, nickname: impl AsRef<str>) -> bool;
 
+	/// Salon en mode +N sur lequel le client n'a pas le droit de changer de
+	/// pseudonyme.
+	fn channel_forbidding_nick_change(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Option<String>;
+
+	/// Salon sur lequel le client est concerné par un masque `~n` de la liste
+	/// des bans, et n'a donc pas le droit de changer de pseudonyme.
+	fn channel_banning_nick_change(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Option<String>;
+
 	/// Change le pseudonyme d'un client
 	fn change_nickname_of_client(
 		&self,
@@ -50,12 +72,66 @@ impl NickApplicationInterface for ChatApplication
 		self.clients.can_locate_by_nickname(nickname)
 	}
 
+	fn channel_forbidding_nick_change(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Option<String>
+	{
+		if self.is_client_global_operator(client_socket) {
+			return None;
+		}
+
+		// NOTE: seuls les membres ayant un niveau d'accès minimal à
+		//       [ChannelAccessLevel::HalfOperator] peuvent changer de
+		//       pseudonyme sur un salon +N.
+		client_socket.channels_rooms_set().into_iter().find(|channel_name| {
+			self.get_channel(channel_name).is_some_and(|channel| {
+				channel.modes_settings.has_no_nick_change_flag()
+			}) && !self.channels.does_member_have_rights(
+				channel_name,
+				client_socket.cid(),
+				ChannelAccessLevel::HalfOperator,
+			)
+		})
+	}
+
+	fn channel_banning_nick_change(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+	) -> Option<String>
+	{
+		if self.is_client_global_operator(client_socket) {
+			return None;
+		}
+
+		let user_channels = client_socket.client().channels();
+
+		user_channels
+			.iter()
+			.find(|channel_name| {
+				self.get_channel(channel_name).is_some_and(|channel| {
+					channel.is_banned_from_action(
+						client_socket.user(),
+						user_channels,
+						EXTBAN_NICK,
+					)
+				}) && !self.channels.does_member_have_rights(
+					channel_name,
+					client_socket.cid(),
+					ChannelAccessLevel::HalfOperator,
+				)
+			})
+			.cloned()
+	}
+
 	fn change_nickname_of_client(
 		&self,
 		client_socket: &mut Self::ClientSocket<'_>,
 		nickname: &str,
 	)
 	{
+		let old_nickname = client_socket.user().nickname().to_owned();
+
 		if let Err(error) = client_socket.user_mut().set_nickname(nickname) {
 			tracing::error!(?error, "Changement de pseudonyme impossible");
 			client_socket.send_err_erroneusnickname(nickname);
@@ -65,5 +141,17 @@ impl NickApplicationInterface for ChatApplication
 		self.clients.change_nickname(client_socket.cid(), nickname);
 
 		client_socket.emit_nick();
+
+		if client_socket.client().is_registered() {
+			client_socket.emit_server_notice(
+				ServerNoticeMask::Nicks,
+				&format!(
+					"{old_nickname} ({}@{}) a changé de pseudonyme en \
+					 {nickname}",
+					client_socket.user().ident(),
+					client_socket.user().host(),
+				),
+			);
+		}
 	}
 }
@@ -8,15 +8,22 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use flex_chat::channel::FloodKind;
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::user::UserInterface;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::ChatApplication;
+use crate::features::chat::mode::ModeChannelFloodApplicationInterface;
+use crate::features::chat::monitor::MonitorApplicationInterface;
 use crate::features::chat::nick::{
 	NickApplicationInterface,
 	NickClientSocketErrorRepliesInterface,
 	NickCommandFormData,
 };
+use crate::features::chat::nick_registration::NickRegistrationApplicationInterface;
+use crate::features::chat::whois::WhowasClientsSessionInterface;
 
 // --------- //
 // Structure //
@@ -51,6 +58,36 @@ impl WebSocketHandler for NickHandler
 			return;
 		}
 
+		if let Some(channel) =
+			app.channel_forbidding_nick_change(&client_socket)
+		{
+			client_socket.send_err_nonickchange(&channel);
+			return;
+		}
+
+		if let Some(channel) = app.channel_banning_nick_change(&client_socket) {
+			client_socket.send_err_banonchan(&channel);
+			return;
+		}
+
+		let old_nickname = client_socket.user().nickname().to_owned();
+
+		app.clients.record_identity_of_client(client_socket.client());
 		app.change_nickname_of_client(&mut client_socket, &data.nickname);
+
+		if client_socket.user().nickname() != old_nickname {
+			app.notify_monitor_offline(&client_socket, &old_nickname);
+			app.notify_monitor_online(&client_socket);
+			app.protect_nickname(&client_socket);
+
+			for channel_room in client_socket.channels_rooms() {
+				let channel_name = &channel_room[8..];
+				app.protect_channel_from_flood(
+					&client_socket,
+					channel_name,
+					FloodKind::Nick,
+				);
+			}
+		}
 	}
 }
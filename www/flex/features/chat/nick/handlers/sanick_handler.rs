@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
+use flex_chat::user::UserInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
+use crate::features::ChatApplication;
+use crate::features::chat::nick::{
+	NickApplicationInterface,
+	NickClientSocketErrorRepliesInterface,
+	SanickCommandFormData,
+};
+use crate::features::chat::nick_registration::NickRegistrationApplicationInterface;
+use crate::features::chat::oper::OperClientSocketCommandResponse;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct SanickHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for SanickHandler
+{
+	type App = ChatApplication;
+	type Data = SanickCommandFormData;
+
+	const EVENT_NAME: &'static str = "SANICK";
+
+	/// La commande SANICK permet à un opérateur de changer de force le
+	/// pseudonyme d'un client, sans tenir compte des restrictions des salons.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<SanickCommandFormData>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Sanick,
+		) else {
+			return;
+		};
+
+		let Some(nickname_socket) =
+			app.find_socket_by_nickname(&socket, &data.nickname)
+		else {
+			client_socket.send_err_nosuchnick(&data.nickname);
+			return;
+		};
+
+		// NOTE: Le pseudonyme existe déjà?
+		if app.can_locate_client_by_nickname(&data.new_nickname) {
+			client_socket.send_err_nicknameinuse(&data.new_nickname);
+			return;
+		}
+
+		// NOTE: l'opérateur peut être la cible de la commande, les emprunts
+		//       des sockets doivent être relâchés avant le changement.
+		let nickname_sref = nickname_socket.socket().clone();
+		drop(nickname_socket);
+		drop(client_socket);
+
+		app.take_nickname(&nickname_sref, &data.new_nickname);
+
+		let nickname_socket = app.current_client(&nickname_sref);
+		if nickname_socket.user().nickname() == data.nickname {
+			return;
+		}
+
+		app.protect_nickname(&nickname_socket);
+
+		let client_socket = app.current_client(&socket);
+		client_socket.emit_oper_notice(&format!(
+			"{} a utilisé SANICK pour renommer {} en {}",
+			client_socket.user().nickname(),
+			data.nickname,
+			nickname_socket.user().nickname(),
+		));
+	}
+}
@@ -14,6 +14,7 @@ flex_kernel::import! {
 
 	pub mod handlers use {
 		pub mod nick_handler use *;
+		pub mod sanick_handler use *;
 	};
 
 	pub mod sessions use {
@@ -21,12 +22,15 @@ flex_kernel::import! {
 	};
 
 	mod errors use {
+		pub(super) mod err_banonchan use *;
 		pub(super) mod err_erroneusnickname use *;
 		pub(super) mod err_nicknameinuse use *;
+		pub(super) mod err_nonickchange use *;
 	};
 
 	pub(crate) mod forms use {
 		pub(crate) mod nick_form use *;
+		pub(crate) mod sanick_form use *;
 	};
 
 	pub(crate) mod responses use {
@@ -11,8 +11,10 @@
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 
 use crate::features::chat::nick::{
+	ErrBanonchanError,
 	ErrErroneusnicknameError,
 	ErrNicknameinuseError,
+	ErrNonickchangeError,
 };
 
 // --------- //
@@ -44,6 +46,30 @@ pub trait NickClientSocketErrorRepliesInterface: ClientSocketInterface
 		};
 		self.emit(err_erroneusnickname.name(), err_erroneusnickname);
 	}
+
+	/// Émet au client l'erreur [ErrBanonchanError].
+	fn send_err_banonchan(&self, channel: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_banonchan = ErrBanonchanError {
+			origin: &origin,
+			channel,
+			tags: ErrBanonchanError::default_tags(),
+		};
+		self.emit(err_banonchan.name(), err_banonchan);
+	}
+
+	/// Émet au client l'erreur [ErrNonickchangeError].
+	fn send_err_nonickchange(&self, channel: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_nonickchange = ErrNonickchangeError {
+			origin: &origin,
+			channel,
+			tags: ErrNonickchangeError::default_tags(),
+		};
+		self.emit(err_nonickchange.name(), err_nonickchange);
+	}
 }
 
 // -------------- //
@@ -0,0 +1,199 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use flex_chat::client::{
+	Client,
+	ClientInterface,
+	ClientSocketInterface,
+	Socket,
+};
+use flex_chat::user::UserInterface;
+use flex_web_framework::types::uuid;
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+use socketioxide::extract::SocketRef;
+
+use super::{
+	NickRegistrationClientSocketCommandResponseInterface,
+	NickRegistrationRepository,
+	NickRegistrationRepositoryPostgreSQL,
+};
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::monitor::MonitorApplicationInterface;
+use crate::features::chat::nick::NickApplicationInterface;
+use crate::features::chat::whois::WhowasClientsSessionInterface;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait NickRegistrationApplicationInterface
+{
+	/// Le stockage des pseudonymes enregistrés.
+	fn nick_registration_repository(
+		&self,
+		socket: &SocketRef,
+	) -> Option<Arc<dyn NickRegistrationRepository>>;
+
+	/// Protège le pseudonyme courant d'un client, en tâche de fond. Un client
+	/// qui n'est pas identifié au compte propriétaire d'un pseudonyme
+	/// enregistré est averti, puis renommé en invité à la fin du délai de
+	/// grâce s'il utilise toujours ce pseudonyme.
+	fn protect_nickname(&'static self, client_socket: &Socket<'_>);
+
+	/// Renomme un client avec un pseudonyme d'invité libre.
+	fn rename_client_to_guest(&self, socket: &SocketRef, guest_prefix: &str);
+
+	/// Change le pseudonyme du client courant, en notifiant les clients qui
+	/// le surveillent.
+	fn take_nickname(&self, socket: &SocketRef, nickname: &str);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl NickRegistrationApplicationInterface for ChatApplication
+{
+	fn nick_registration_repository(
+		&self,
+		socket: &SocketRef,
+	) -> Option<Arc<dyn NickRegistrationRepository>>
+	{
+		let database = socket
+			.req_parts()
+			.extensions
+			.get::<DatabaseService<PostgreSQLDatabase>>()?;
+		Some(Arc::new(NickRegistrationRepositoryPostgreSQL {
+			database: database.clone(),
+		}))
+	}
+
+	fn protect_nickname(&'static self, client_socket: &Socket<'_>)
+	{
+		let socket = client_socket.socket();
+
+		let Some(config) = socket
+			.req_parts()
+			.extensions
+			.get::<FlexChatConfig>()
+			.map(|config| config.nicknames.clone())
+		else {
+			return;
+		};
+
+		let Some(repository) = self.nick_registration_repository(socket) else {
+			return;
+		};
+
+		let nickname = client_socket.user().nickname().to_owned();
+		let account = client_socket.user().account().map(ToOwned::to_owned);
+
+		if nickname.is_empty() {
+			return;
+		}
+
+		let token = self.nick_registration.start_protection(socket.id);
+		let socket = socket.clone();
+
+		tokio::spawn(async move {
+			let registered_nickname = match repository
+				.find_owner(&nickname)
+				.await
+			{
+				| Ok(Some(registered_nickname)) => registered_nickname,
+				| Ok(None) => {
+					self.nick_registration.end_protection(&socket.id, &token);
+					return;
+				}
+				| Err(error) => {
+					tracing::error!(
+						?error,
+						"Impossible de vérifier le pseudonyme enregistré"
+					);
+					self.nick_registration.end_protection(&socket.id, &token);
+					return;
+				}
+			};
+
+			let is_owner = |account: Option<&str>| {
+				account
+					.filter(|account| registered_nickname.is_owned_by(account))
+					.is_some()
+			};
+
+			if is_owner(account.as_deref()) {
+				self.nick_registration.end_protection(&socket.id, &token);
+				return;
+			}
+
+			if !self.nick_registration.is_protection_pending(&socket.id, &token)
+				|| socket.extensions.get::<Client>().is_none()
+			{
+				return;
+			}
+
+			self.current_client(&socket)
+				.emit_nickname_protected(&nickname, config.grace_period);
+
+			tokio::time::sleep(Duration::from_secs(config.grace_period)).await;
+
+			// NOTE: le client a changé de pseudonyme entre temps, une autre
+			//       protection a été lancée.
+			if !self.nick_registration.end_protection(&socket.id, &token) {
+				return;
+			}
+
+			let still_protected = {
+				let Some(client) = socket.extensions.get::<Client>() else {
+					return;
+				};
+				client.user().nickname().to_lowercase()
+					== nickname.to_lowercase()
+					&& !is_owner(client.user().account())
+			};
+
+			if still_protected {
+				self.rename_client_to_guest(&socket, &config.guest_prefix);
+			}
+		});
+	}
+
+	fn rename_client_to_guest(&self, socket: &SocketRef, guest_prefix: &str)
+	{
+		let guest_nickname = loop {
+			let suffix = uuid::Uuid::new_v4().as_u128() % 100_000;
+			let guest_nickname = format!("{guest_prefix}{suffix:05}");
+			if !self.can_locate_client_by_nickname(&guest_nickname) {
+				break guest_nickname;
+			}
+		};
+
+		self.take_nickname(socket, &guest_nickname);
+	}
+
+	fn take_nickname(&self, socket: &SocketRef, nickname: &str)
+	{
+		let mut client_socket = self.current_client_mut(socket);
+
+		let old_nickname = client_socket.user().nickname().to_owned();
+
+		self.clients.record_identity_of_client(client_socket.client());
+		self.change_nickname_of_client(&mut client_socket, nickname);
+
+		if client_socket.user().nickname() != old_nickname {
+			self.notify_monitor_offline(&client_socket, &old_nickname);
+			self.notify_monitor_online(&client_socket);
+		}
+	}
+}
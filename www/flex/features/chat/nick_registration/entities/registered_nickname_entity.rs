@@ -0,0 +1,50 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::types::uuid;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un pseudonyme enregistré, lié à un compte utilisateur. Le nom d'un compte
+/// est lui-même un pseudonyme enregistré, les autres pseudonymes du compte
+/// sont groupés dans la table `users_nicknames`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct RegisteredNicknameEntity
+{
+	/// Pseudonyme enregistré.
+	pub nickname: String,
+	/// ID de l'utilisateur propriétaire du pseudonyme.
+	pub user_id: uuid::Uuid,
+	/// Nom du compte de l'utilisateur propriétaire du pseudonyme.
+	pub account: String,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl RegisteredNicknameEntity
+{
+	/// Est-ce que le pseudonyme appartient au compte donné?
+	pub fn is_owned_by(&self, account: &str) -> bool
+	{
+		self.account.to_lowercase() == account.to_lowercase()
+	}
+
+	/// Est-ce que le pseudonyme est le nom du compte?
+	pub fn is_account_name(&self) -> bool
+	{
+		self.nickname.to_lowercase() == self.account.to_lowercase()
+	}
+}
@@ -0,0 +1,35 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::macros::command_formdata;
+use flex_chat::user::validate_nickname;
+
+command_formdata! {
+	struct NICK_UNGROUP
+	{
+		/// Le pseudonyme à dégrouper du compte.
+		#[serde(deserialize_with = "validate_nickname")]
+		nickname: String,
+	}
+
+	struct GHOST
+	{
+		/// Le pseudonyme dont la session doit être déconnectée.
+		#[serde(deserialize_with = "validate_nickname")]
+		nickname: String,
+	}
+
+	struct RECOVER
+	{
+		/// Le pseudonyme à récupérer.
+		#[serde(deserialize_with = "validate_nickname")]
+		nickname: String,
+	}
+}
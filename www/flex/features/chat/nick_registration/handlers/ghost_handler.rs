@@ -0,0 +1,172 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
+use flex_chat::user::UserInterface;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::nick_registration::*;
+use crate::features::chat::quit::QuitHandler;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct GhostHandler;
+
+pub struct RecoverHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl GhostHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "GHOST";
+
+	/// La commande `GHOST` déconnecte une session (souvent fantôme) utilisant
+	/// un pseudonyme enregistré par le compte auquel le client est identifié,
+	/// ou une autre session identifiée au même compte.
+	pub async fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<GhostCommandFormData>,
+	)
+	{
+		if Self::ghost(app, &socket, "GHOST", &data.nickname).await {
+			app.current_client(&socket).emit_ghost(&data.nickname);
+		}
+	}
+
+	/// Déconnecte la session utilisant un pseudonyme. Retourne `false` si le
+	/// client n'a pas le droit de déconnecter cette session.
+	pub(super) async fn ghost(
+		app: &ChatApplication,
+		socket: &SocketRef,
+		command: &str,
+		nickname: &str,
+	) -> bool
+	{
+		let Some(repository) = app.nick_registration_repository(socket) else {
+			return false;
+		};
+
+		let Some(account) = NickGroupHandler::identified_account(
+			app,
+			socket,
+			command,
+			&*repository,
+		)
+		.await
+		else {
+			return false;
+		};
+
+		let maybe_target = {
+			let client_socket = app.current_client(socket);
+			app.find_socket_by_nickname(socket, nickname).map(|target| {
+				(
+					target.cid() == client_socket.cid(),
+					target
+						.user()
+						.account()
+						.filter(|target_account| {
+							account.is_owned_by(target_account)
+						})
+						.is_some(),
+				)
+			})
+		};
+
+		let Some((is_itself, is_same_account)) = maybe_target else {
+			app.current_client(socket).send_err_nosuchnick(nickname);
+			return false;
+		};
+
+		if is_itself {
+			app.current_client(socket).send_fail_nick_registration(
+				command,
+				"CANNOT_GHOST_SELF",
+				nickname,
+				"Tu utilises déjà ce pseudonyme",
+			);
+			return false;
+		}
+
+		if !is_same_account {
+			match repository.find_owner(nickname).await {
+				| Ok(Some(registered_nickname))
+					if registered_nickname.user_id == account.user_id => {}
+				| Ok(_) => {
+					app.current_client(socket).send_fail_nick_registration(
+						command,
+						"NOT_OWNER",
+						nickname,
+						"Ce pseudonyme n'est pas enregistré par ton compte",
+					);
+					return false;
+				}
+				| Err(error) => {
+					tracing::error!(
+						?error,
+						"Impossible de vérifier le pseudonyme enregistré"
+					);
+					return false;
+				}
+			}
+		}
+
+		let Some(target_socket) = app.find_socket_by_nickname(socket, nickname)
+		else {
+			return true;
+		};
+
+		let reason = format!(
+			"Session libérée par {} ({command})",
+			app.current_client(socket).user().nickname(),
+		);
+
+		// NOTE: la session est retirée du serveur avant la fin de la
+		//       commande, le pseudonyme est ainsi aussitôt disponible.
+		let target_sref = target_socket.socket().clone();
+		target_socket.send_err(format!("Déconnexion: {reason}"));
+		drop(target_socket);
+
+		QuitHandler::quit(app, &target_sref, reason);
+
+		_ = target_sref.disconnect();
+
+		true
+	}
+}
+
+impl RecoverHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "RECOVER";
+
+	/// La commande `RECOVER` déconnecte la session utilisant un pseudonyme
+	/// (voir `GHOST`), puis attribue ce pseudonyme au client.
+	pub async fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<RecoverCommandFormData>,
+	)
+	{
+		if !GhostHandler::ghost(app, &socket, "RECOVER", &data.nickname).await {
+			return;
+		}
+
+		app.take_nickname(&socket, &data.nickname);
+	}
+}
@@ -0,0 +1,253 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::user::UserInterface;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::nick_registration::*;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct NickGroupHandler;
+
+pub struct NickGroupListHandler;
+
+pub struct NickUngroupHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl NickGroupHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "NICK GROUP";
+
+	/// La commande `NICK GROUP` groupe le pseudonyme courant du client au
+	/// compte auquel il est identifié. Un pseudonyme groupé est protégé au
+	/// même titre que le nom du compte.
+	pub async fn handle(socket: SocketRef, State(app): State<ChatApplication>)
+	{
+		let Some(repository) = app.nick_registration_repository(&socket) else {
+			return;
+		};
+
+		let Some(account) =
+			Self::identified_account(app, &socket, "GROUP", &*repository).await
+		else {
+			return;
+		};
+
+		let nickname = app.current_client(&socket).user().nickname().to_owned();
+
+		match repository.find_owner(&nickname).await {
+			| Ok(Some(registered_nickname))
+				if registered_nickname.user_id == account.user_id =>
+			{
+				app.current_client(&socket).send_fail_nick_registration(
+					"GROUP",
+					"ALREADY_GROUPED",
+					&nickname,
+					"Ce pseudonyme est déjà groupé à ton compte",
+				);
+				return;
+			}
+			| Ok(Some(_)) => {
+				app.current_client(&socket).send_fail_nick_registration(
+					"GROUP",
+					"NICKNAME_REGISTERED",
+					&nickname,
+					"Ce pseudonyme est enregistré par un autre compte",
+				);
+				return;
+			}
+			| Ok(None) => {}
+			| Err(error) => {
+				tracing::error!(?error, "Impossible de grouper le pseudonyme");
+				return;
+			}
+		}
+
+		if let Err(error) = repository.group(&account.user_id, &nickname).await
+		{
+			tracing::error!(?error, "Impossible de grouper le pseudonyme");
+			return;
+		}
+
+		Self::emit_group(app, &socket, &*repository, &account).await;
+	}
+
+	/// Le compte auquel le client est identifié. Un échec `ACCOUNT_REQUIRED`
+	/// est émis au client s'il n'est pas identifié.
+	pub(super) async fn identified_account(
+		app: &ChatApplication,
+		socket: &SocketRef,
+		command: &str,
+		repository: &dyn NickRegistrationRepository,
+	) -> Option<RegisteredNicknameEntity>
+	{
+		let (nickname, maybe_account) = {
+			let client_socket = app.current_client(socket);
+			(
+				client_socket.user().nickname().to_owned(),
+				client_socket.user().account().map(ToOwned::to_owned),
+			)
+		};
+
+		let maybe_registered_account = match maybe_account {
+			| Some(account) => {
+				repository.find_owner(&account).await.unwrap_or_else(|error| {
+					tracing::error!(?error, "Impossible de trouver le compte");
+					None
+				})
+			}
+			| None => None,
+		};
+
+		let registered_account = maybe_registered_account
+			.filter(RegisteredNicknameEntity::is_account_name);
+
+		if registered_account.is_none() {
+			app.current_client(socket).send_fail_nick_registration(
+				command,
+				"ACCOUNT_REQUIRED",
+				&nickname,
+				"Tu dois être identifié à un compte",
+			);
+		}
+
+		registered_account
+	}
+
+	/// Émet au client les pseudonymes groupés à son compte.
+	pub(super) async fn emit_group(
+		app: &ChatApplication,
+		socket: &SocketRef,
+		repository: &dyn NickRegistrationRepository,
+		account: &RegisteredNicknameEntity,
+	)
+	{
+		let nicknames: Vec<_> =
+			match repository.find_all_by_user(&account.user_id).await {
+				| Ok(registered_nicknames) => {
+					registered_nicknames
+						.into_iter()
+						.map(|registered_nickname| registered_nickname.nickname)
+						.collect()
+				}
+				| Err(error) => {
+					tracing::error!(
+						?error,
+						"Impossible de lister les pseudonymes groupés"
+					);
+					return;
+				}
+			};
+
+		app.current_client(socket)
+			.emit_nick_group(&account.account, &nicknames);
+	}
+}
+
+impl NickGroupListHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "NICK GLIST";
+
+	/// La commande `NICK GLIST` liste les pseudonymes groupés au compte
+	/// auquel le client est identifié.
+	pub async fn handle(socket: SocketRef, State(app): State<ChatApplication>)
+	{
+		let Some(repository) = app.nick_registration_repository(&socket) else {
+			return;
+		};
+
+		let Some(account) = NickGroupHandler::identified_account(
+			app,
+			&socket,
+			"GLIST",
+			&*repository,
+		)
+		.await
+		else {
+			return;
+		};
+
+		NickGroupHandler::emit_group(app, &socket, &*repository, &account)
+			.await;
+	}
+}
+
+impl NickUngroupHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "NICK UNGROUP";
+
+	/// La commande `NICK UNGROUP` dégroupe un pseudonyme du compte auquel le
+	/// client est identifié. Le nom du compte ne peut pas être dégroupé.
+	pub async fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<NickUngroupCommandFormData>,
+	)
+	{
+		let Some(repository) = app.nick_registration_repository(&socket) else {
+			return;
+		};
+
+		let Some(account) = NickGroupHandler::identified_account(
+			app,
+			&socket,
+			"UNGROUP",
+			&*repository,
+		)
+		.await
+		else {
+			return;
+		};
+
+		if account.nickname.to_lowercase() == data.nickname.to_lowercase() {
+			app.current_client(&socket).send_fail_nick_registration(
+				"UNGROUP",
+				"ACCOUNT_NAME",
+				&data.nickname,
+				"Le nom du compte ne peut pas être dégroupé",
+			);
+			return;
+		}
+
+		match repository.ungroup(&account.user_id, &data.nickname).await {
+			| Ok(true) => {}
+			| Ok(false) => {
+				app.current_client(&socket).send_fail_nick_registration(
+					"UNGROUP",
+					"NOT_GROUPED",
+					&data.nickname,
+					"Ce pseudonyme n'est pas groupé à ton compte",
+				);
+				return;
+			}
+			| Err(error) => {
+				tracing::error!(
+					?error,
+					"Impossible de dégrouper le pseudonyme"
+				);
+				return;
+			}
+		}
+
+		NickGroupHandler::emit_group(app, &socket, &*repository, &account)
+			.await;
+	}
+}
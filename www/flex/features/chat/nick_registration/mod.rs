@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod ghost_handler use *;
+		pub mod nick_group_handler use *;
+	};
+
+	mod entities use {
+		pub(super) mod registered_nickname_entity use *;
+	};
+
+	mod forms use {
+		pub(super) mod nick_registration_form use *;
+	};
+
+	mod repositories use {
+		pub(super) mod nick_registration_repository use *;
+	};
+
+	mod responses use {
+		pub(super) mod nick_registration_command_response use *;
+	};
+
+	pub mod sessions use {
+		pub mod nick_registration_session use *;
+	};
+}
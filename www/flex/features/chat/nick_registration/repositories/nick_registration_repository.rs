@@ -0,0 +1,51 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod postgres;
+
+pub use self::postgres::*;
+use crate::features::chat::nick_registration::RegisteredNicknameEntity;
+
+// --------- //
+// Interface //
+// --------- //
+
+#[flex_web_framework::async_trait]
+pub trait NickRegistrationRepository: Send + Sync
+{
+	/// Cherche le propriétaire d'un pseudonyme, qu'il s'agisse du nom d'un
+	/// compte ou d'un pseudonyme groupé à un compte.
+	async fn find_owner(
+		&self,
+		nickname: &str,
+	) -> Result<Option<RegisteredNicknameEntity>, sqlx::Error>;
+
+	/// Les pseudonymes groupés à un compte (sans le nom du compte).
+	async fn find_all_by_user(
+		&self,
+		user_id: &flex_web_framework::types::uuid::Uuid,
+	) -> Result<Vec<RegisteredNicknameEntity>, sqlx::Error>;
+
+	/// Groupe un pseudonyme à un compte. Retourne `false` si le pseudonyme
+	/// est déjà groupé.
+	async fn group(
+		&self,
+		user_id: &flex_web_framework::types::uuid::Uuid,
+		nickname: &str,
+	) -> Result<bool, sqlx::Error>;
+
+	/// Dégroupe un pseudonyme d'un compte. Retourne `false` si le pseudonyme
+	/// n'est pas groupé à ce compte.
+	async fn ungroup(
+		&self,
+		user_id: &flex_web_framework::types::uuid::Uuid,
+		nickname: &str,
+	) -> Result<bool, sqlx::Error>;
+}
@@ -0,0 +1,128 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::types::uuid;
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+
+use super::NickRegistrationRepository;
+use crate::features::chat::nick_registration::RegisteredNicknameEntity;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct NickRegistrationRepositoryPostgreSQL
+{
+	pub database: DatabaseService<PostgreSQLDatabase>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl NickRegistrationRepositoryPostgreSQL
+{
+	/// Nom de la table des pseudonymes groupés.
+	pub const TABLE_NAME: &'static str = "users_nicknames";
+	/// Nom de la table des utilisateurs.
+	pub const USERS_TABLE_NAME: &'static str = "users";
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[flex_web_framework::async_trait]
+impl NickRegistrationRepository for NickRegistrationRepositoryPostgreSQL
+{
+	async fn find_owner(
+		&self,
+		nickname: &str,
+	) -> Result<Option<RegisteredNicknameEntity>, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT name AS nickname, id AS user_id, name AS account FROM {} \
+			 WHERE lower(name) = lower($1) LIMIT 1",
+			Self::USERS_TABLE_NAME,
+		);
+		let maybe_account = sqlx::query_as(&sql)
+			.bind(nickname)
+			.fetch_optional(self.database.pool())
+			.await?;
+
+		if maybe_account.is_some() {
+			return Ok(maybe_account);
+		}
+
+		let sql = format!(
+			"SELECT n.nickname, n.user_id, u.name AS account FROM {} n INNER \
+			 JOIN {} u ON u.id = n.user_id WHERE n.nickname = lower($1) LIMIT \
+			 1",
+			Self::TABLE_NAME,
+			Self::USERS_TABLE_NAME,
+		);
+		sqlx::query_as(&sql)
+			.bind(nickname)
+			.fetch_optional(self.database.pool())
+			.await
+	}
+
+	async fn find_all_by_user(
+		&self,
+		user_id: &uuid::Uuid,
+	) -> Result<Vec<RegisteredNicknameEntity>, sqlx::Error>
+	{
+		let sql = format!(
+			"SELECT n.nickname, n.user_id, u.name AS account FROM {} n INNER \
+			 JOIN {} u ON u.id = n.user_id WHERE n.user_id = $1 ORDER BY \
+			 n.created_at ASC",
+			Self::TABLE_NAME,
+			Self::USERS_TABLE_NAME,
+		);
+		sqlx::query_as(&sql).bind(user_id).fetch_all(self.database.pool()).await
+	}
+
+	async fn group(
+		&self,
+		user_id: &uuid::Uuid,
+		nickname: &str,
+	) -> Result<bool, sqlx::Error>
+	{
+		let sql = format!(
+			"INSERT INTO {} (nickname, user_id, created_at) VALUES \
+			 (lower($1), $2, now()) ON CONFLICT (nickname) DO NOTHING",
+			Self::TABLE_NAME,
+		);
+		let result = sqlx::query(&sql)
+			.bind(nickname)
+			.bind(user_id)
+			.execute(self.database.pool())
+			.await?;
+		Ok(result.rows_affected() > 0)
+	}
+
+	async fn ungroup(
+		&self,
+		user_id: &uuid::Uuid,
+		nickname: &str,
+	) -> Result<bool, sqlx::Error>
+	{
+		let sql = format!(
+			"DELETE FROM {} WHERE nickname = lower($1) AND user_id = $2",
+			Self::TABLE_NAME,
+		);
+		let result = sqlx::query(&sql)
+			.bind(nickname)
+			.bind(user_id)
+			.execute(self.database.pool())
+			.await?;
+		Ok(result.rows_affected() > 0)
+	}
+}
@@ -0,0 +1,118 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+use crate::features::chat::chathistory::FailCommandResponse;
+
+command_response! {
+	/// Les pseudonymes groupés au compte du client.
+	struct NICK_GROUP<'a>
+	{
+		/// Nom du compte.
+		account: &'a str,
+		/// Les pseudonymes groupés au compte.
+		nicknames: &'a [String],
+	}
+
+	/// Le pseudonyme du client est enregistré par un autre compte.
+	struct NICKNAME_PROTECTED<'a>
+	{
+		/// Le pseudonyme enregistré.
+		nickname: &'a str,
+		/// Délai, en secondes, avant que le client soit renommé.
+		grace_period: u64,
+	}
+
+	/// La session utilisant un pseudonyme a été déconnectée.
+	struct GHOST<'a>
+	{
+		/// Le pseudonyme libéré.
+		nickname: &'a str,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait NickRegistrationClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client la session déconnectée d'un pseudonyme.
+	fn emit_ghost(&self, nickname: &str)
+	{
+		let origin = Origin::from(self.client());
+		let ghost_command = GhostCommandResponse {
+			origin: &origin,
+			tags: GhostCommandResponse::default_tags(),
+			nickname,
+		};
+		self.emit(ghost_command.name(), ghost_command);
+	}
+
+	/// Émet au client les pseudonymes groupés à son compte.
+	fn emit_nick_group(&self, account: &str, nicknames: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let nick_group_command = NickGroupCommandResponse {
+			origin: &origin,
+			tags: NickGroupCommandResponse::default_tags(),
+			account,
+			nicknames,
+		};
+		self.emit(nick_group_command.name(), nick_group_command);
+	}
+
+	/// Émet au client que son pseudonyme est enregistré par un autre compte.
+	fn emit_nickname_protected(&self, nickname: &str, grace_period: u64)
+	{
+		let origin = Origin::from(self.client());
+		let nickname_protected_command = NicknameProtectedCommandResponse {
+			origin: &origin,
+			tags: NicknameProtectedCommandResponse::default_tags(),
+			nickname,
+			grace_period,
+		};
+		self.emit(
+			nickname_protected_command.name(),
+			nickname_protected_command,
+		);
+	}
+
+	/// Émet au client un échec d'une commande liée aux pseudonymes
+	/// enregistrés.
+	fn send_fail_nick_registration(
+		&self,
+		command: &str,
+		code: &str,
+		nickname: &str,
+		description: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let fail_command = FailCommandResponse {
+			origin: &origin,
+			tags: FailCommandResponse::default_tags(),
+			command,
+			code,
+			context: &[nickname],
+			description,
+		};
+		self.emit(fail_command.name(), fail_command);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> NickRegistrationClientSocketCommandResponseInterface for Socket<'s> {}
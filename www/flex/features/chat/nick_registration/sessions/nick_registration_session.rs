@@ -0,0 +1,65 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use dashmap::DashMap;
+use flex_chat::client::SocketID;
+use flex_web_framework::types::uuid;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Protections en cours des pseudonymes enregistrés, pour chaque socket.
+#[derive(Default)]
+pub struct NickRegistrationSession
+{
+	/// Jeton de la dernière protection lancée pour une socket. Une protection
+	/// dont le jeton n'est plus celui de la socket est obsolète.
+	protections: DashMap<SocketID, uuid::Uuid>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl NickRegistrationSession
+{
+	/// Démarre une nouvelle protection pour une socket, rendant obsolète la
+	/// précédente.
+	pub fn start_protection(&self, sid: SocketID) -> uuid::Uuid
+	{
+		let token = uuid::Uuid::new_v4();
+		self.protections.insert(sid, token);
+		token
+	}
+
+	/// Termine une protection, si elle est toujours la protection en cours de
+	/// la socket. Retourne `false` si la protection est obsolète.
+	pub fn end_protection(&self, sid: &SocketID, token: &uuid::Uuid) -> bool
+	{
+		self.protections
+			.remove_if(sid, |_, current_token| current_token == token)
+			.is_some()
+	}
+
+	/// Est-ce que la protection est toujours la protection en cours de la
+	/// socket?
+	pub fn is_protection_pending(
+		&self,
+		sid: &SocketID,
+		token: &uuid::Uuid,
+	) -> bool
+	{
+		self.protections
+			.get(sid)
+			.filter(|current_token| current_token.value() == token)
+			.is_some()
+	}
+}
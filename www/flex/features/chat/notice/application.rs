@@ -11,6 +11,7 @@
 use flex_chat::channel::{
 	Channel,
 	ChannelAccessControlBanInterface,
+	ChannelAccessControlQuietInterface,
 	ChannelAccessLevel,
 	ChannelInterface,
 	ChannelMemberInterface,
@@ -18,7 +19,8 @@ use flex_chat::channel::{
 	ChannelWritePermission,
 	MemberInterface,
 };
-use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::UserInterface;
 
 use crate::features::ChatApplication;
 use crate::features::chat::oper::OperApplicationInterface;
@@ -64,6 +66,12 @@ impl NoticeApplicationInterface for ChatApplication
 		let no_external_messages_flag =
 			channel.modes_settings.has_no_external_messages_flag();
 
+		let no_notice_flag = channel.modes_settings.has_no_notice_flag();
+
+		let registered_moderated_flag =
+			channel.modes_settings.has_registered_moderated_flag()
+				&& client_socket.user().account().is_none();
+
 		let Some(member) = channel.member(client_socket.cid()) else {
 			if self.is_client_global_operator(client_socket) {
 				return ChannelWritePermission::Bypass;
@@ -81,6 +89,18 @@ impl NoticeApplicationInterface for ChatApplication
 				);
 			}
 
+			if no_notice_flag {
+				return ChannelWritePermission::No(
+					ChannelNoPermissionCause::ERR_NONOTICE,
+				);
+			}
+
+			if registered_moderated_flag {
+				return ChannelWritePermission::No(
+					ChannelNoPermissionCause::ERR_NEEDREGGEDNICK,
+				);
+			}
+
 			return ChannelWritePermission::Bypass;
 		};
 
@@ -90,12 +110,26 @@ impl NoticeApplicationInterface for ChatApplication
 
 		let member_hal = member.highest_access_level();
 
-		if channel.is_banned(client_socket.user()) && member_hal.is_none() {
+		let user_channels = client_socket.client().channels();
+
+		if channel.is_banned(client_socket.user(), user_channels)
+			&& member_hal.is_none()
+		{
 			return ChannelWritePermission::No(
 				ChannelNoPermissionCause::ERR_BANNEDFROMCHAN,
 			);
 		}
 
+		// NOTE: un utilisateur muet (+Q ou masque `~q` de la liste des bans)
+		//       peut rester sur le salon, mais ne peut pas y parler.
+		if channel.is_quieted(client_socket.user(), user_channels)
+			&& member_hal.is_none()
+		{
+			return ChannelWritePermission::No(
+				ChannelNoPermissionCause::ERR_QUIETED,
+			);
+		}
+
 		if moderate_flag
 			&& member_hal
 				.filter(|level| level.flag() >= ChannelAccessLevel::Vip.flag())
@@ -106,6 +140,31 @@ impl NoticeApplicationInterface for ChatApplication
 			);
 		}
 
+		// NOTE: seuls les membres ayant un niveau d'accès minimal à
+		//       [ChannelAccessLevel::HalfOperator] peuvent envoyer des
+		//       NOTICE sur un salon +T.
+		if no_notice_flag
+			&& member_hal
+				.filter(|level| {
+					level.flag() >= ChannelAccessLevel::HalfOperator.flag()
+				})
+				.is_none()
+		{
+			return ChannelWritePermission::No(
+				ChannelNoPermissionCause::ERR_NONOTICE,
+			);
+		}
+
+		if registered_moderated_flag
+			&& member_hal
+				.filter(|level| level.flag() >= ChannelAccessLevel::Vip.flag())
+				.is_none()
+		{
+			return ChannelWritePermission::No(
+				ChannelNoPermissionCause::ERR_NEEDREGGEDNICK,
+			);
+		}
+
 		ChannelWritePermission::Yes(member.clone())
 	}
 }
@@ -13,11 +13,18 @@ use flex_chat::channel::{
 	ChannelWritePermission,
 	MemberInterface,
 };
-use flex_chat::client::{ClientSocketInterface, Origin};
+use flex_chat::client::{Capability, ClientSocketInterface, Origin};
+use flex_chat::user::UserInterface;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::ChatApplication;
+use crate::features::chat::chathistory::{
+	ChatHistoryApplicationInterface,
+	ChatHistoryEntity,
+	ChatHistoryTags,
+};
+use crate::features::chat::join::JoinApplicationInterface;
 use crate::features::chat::mode::ChannelMemberDTO;
 use crate::features::chat::notice::{
 	NoticeApplicationInterface,
@@ -124,38 +131,104 @@ impl WebSocketHandler for NoticeHandler
 				continue;
 			}
 
+			let history_tags = ChatHistoryTags::new();
+
 			if target.starts_with('#') {
-				match app
+				let payload = match app
 					.is_client_able_to_notice_on_channel(&client_socket, target)
 				{
 					| ChannelWritePermission::Yes(member) => {
+						if member.is_hidden() {
+							app.reveal_channel_member(&client_socket, target);
+						}
+
+						let Some(channel) = app.get_channel(target) else {
+							continue;
+						};
+
+						// NOTE: les notices des membres sans niveau d'accès
+						//       d'un salon +u ne sont transmises qu'aux
+						//       opérateurs du salon, elles ne sont donc pas
+						//       conservées dans l'historique du salon.
+						let visible =
+							channel.is_member_visible(client_socket.cid());
+
 						let channel_member = ChannelMemberDTO::from((
 							client_socket.client(),
 							member,
 						));
-						client_socket.emit_notice_on_channel(
-							target,
+						let payload = client_socket.emit_notice_on_channel(
+							&history_tags,
+							&channel,
 							&data.text,
 							&channel_member,
 						);
+
+						if !visible {
+							continue;
+						}
+
+						payload
 					}
 					| ChannelWritePermission::Bypass => {
 						client_socket.emit_external_notice_on_channel(
+							&history_tags,
 							target,
 							&data.text,
 							client_socket.user(),
-						);
+						)
 					}
 					| ChannelWritePermission::No(_) => {
 						continue;
 					}
-				}
+				};
+
+				app.store_chathistory(
+					&socket,
+					ChatHistoryEntity::new(
+						&history_tags,
+						ChatHistoryEntity::channel_target(target),
+						client_socket.user().nickname(),
+						target,
+						NoticeHandler::EVENT_NAME,
+						&payload,
+					),
+				);
 
 				continue;
 			}
 
+			let sender_id = app.chathistory_identity(&client_socket);
+			let history_message = |payload, recipient_id: &str| {
+				ChatHistoryEntity::new(
+					&history_tags,
+					ChatHistoryEntity::private_target(&sender_id, recipient_id),
+					client_socket.user().nickname(),
+					target,
+					NoticeHandler::EVENT_NAME,
+					&payload,
+				)
+				.with_identities(&sender_id, recipient_id)
+			};
+
 			let origin = Origin::from(client_socket.client());
-			client_socket.emit_notice_on_nick(target, &data.text, &origin);
+			if client_socket.client().has_capability(Capability::EchoMessage)
+				|| client_socket.has_same_nickname(target)
+			{
+				let payload = client_socket.emit_notice_on_nick(
+					&history_tags,
+					target,
+					&data.text,
+					&origin,
+				);
+
+				if client_socket.has_same_nickname(target) {
+					app.store_chathistory(
+						&socket,
+						history_message(payload, &sender_id),
+					);
+				}
+			}
 
 			if client_socket.has_same_nickname(target) {
 				continue;
@@ -172,8 +245,19 @@ impl WebSocketHandler for NoticeHandler
 				continue;
 			}
 
-			target_client_socket
-				.emit_notice_on_nick(target, &data.text, &origin);
+			let payload = target_client_socket.emit_notice_on_nick(
+				&history_tags,
+				target,
+				&data.text,
+				&origin,
+			);
+			app.store_chathistory(
+				&socket,
+				history_message(
+					payload,
+					&app.chathistory_identity(&target_client_socket),
+				),
+			);
 		}
 	}
 }
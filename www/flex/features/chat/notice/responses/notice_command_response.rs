@@ -9,9 +9,16 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::channel::{Channel, ChannelInterface};
-use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::client::{
+	Capability,
+	ClientInterface,
+	ClientSocketInterface,
+	Socket,
+};
 use flex_chat::macros::command_response;
 
+use crate::features::chat::chathistory::ChatHistoryTags;
+
 command_response! {
 	struct NOTICE<'target, 'text>
 	{
@@ -32,28 +39,39 @@ pub trait NoticeClientSocketCommandResponseInterface
 	type Channel: ChannelInterface;
 
 	/// Émet au client les réponses liées à la commande /NOTICE <nickname>
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_notice_on_nick(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		text: &str,
 		by: impl serde::Serialize,
-	);
+	) -> serde_json::Value;
 
 	/// Émet au client les réponses liées à la commande /NOTICE <channel>
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_external_notice_on_channel(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		text: &str,
 		by: &<Self::Client as ClientInterface>::User,
-	);
+	) -> serde_json::Value;
 
-	/// Émet au client les réponses liées à la commande /NOTICE <channel>
+	/// Émet au client les réponses liées à la commande /NOTICE <channel>. Sur
+	/// un salon +u, les notices des membres sans niveau d'accès ne sont
+	/// transmises qu'aux opérateurs du salon.
+	///
+	/// La réponse émise est retournée, pour l'historique des messages.
 	fn emit_notice_on_channel(
 		&self,
-		target: &str,
+		history_tags: &ChatHistoryTags,
+		channel: &Self::Channel,
 		text: &str,
 		by: &impl serde::Serialize,
-	);
+	) -> serde_json::Value;
 
 	/// Émet au client les réponses liées à la commande
 	/// /NOTICE <<prefix>channel>
@@ -76,36 +94,44 @@ impl<'s> NoticeClientSocketCommandResponseInterface for Socket<'s>
 
 	fn emit_notice_on_nick(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		text: &str,
 		by: impl serde::Serialize,
-	)
+	) -> serde_json::Value
 	{
 		let notice_command = NoticeCommandResponse {
 			origin: &by,
 			target,
 			text,
 			tags: NoticeCommandResponse::default_tags(),
-		};
+		}
+		.with_tags(history_tags.to_tags());
 
 		_ = self.socket().emit(notice_command.name(), &notice_command);
+
+		serde_json::to_value(&notice_command).unwrap_or_default()
 	}
 
 	fn emit_external_notice_on_channel(
 		&self,
+		history_tags: &ChatHistoryTags,
 		target: &str,
 		text: &str,
 		by: &<Self::Client as ClientInterface>::User,
-	)
+	) -> serde_json::Value
 	{
 		let notice_command = NoticeCommandResponse {
 			origin: &by,
 			tags: NoticeCommandResponse::default_tags(),
 			target,
 			text,
-		};
+		}
+		.with_tags(history_tags.to_tags());
 
-		_ = self.socket().emit(notice_command.name(), &notice_command);
+		if self.client().has_capability(Capability::EchoMessage) {
+			_ = self.socket().emit(notice_command.name(), &notice_command);
+		}
 
 		let target_room = format!("channel:{}", target.to_lowercase());
 
@@ -113,32 +139,40 @@ impl<'s> NoticeClientSocketCommandResponseInterface for Socket<'s>
 			.socket()
 			.except(self.useless_people_room())
 			.to(target_room)
-			.emit(notice_command.name(), notice_command);
+			.emit(notice_command.name(), &notice_command);
+
+		serde_json::to_value(&notice_command).unwrap_or_default()
 	}
 
 	fn emit_notice_on_channel(
 		&self,
-		target: &str,
+		history_tags: &ChatHistoryTags,
+		channel: &Self::Channel,
 		text: &str,
 		by: &impl serde::Serialize,
-	)
+	) -> serde_json::Value
 	{
 		let notice_command = NoticeCommandResponse {
 			origin: &by,
 			tags: NoticeCommandResponse::default_tags(),
-			target,
+			target: channel.name(),
 			text,
-		};
+		}
+		.with_tags(history_tags.to_tags());
 
-		_ = self.socket().emit(notice_command.name(), &notice_command);
+		if self.client().has_capability(Capability::EchoMessage) {
+			_ = self.socket().emit(notice_command.name(), &notice_command);
+		}
 
-		let target_room = format!("channel:{}", target.to_lowercase());
+		let target_room = channel.member_room(self.cid());
 
 		_ = self
 			.socket()
 			.except(self.useless_people_room())
 			.to(target_room)
-			.emit(notice_command.name(), notice_command);
+			.emit(notice_command.name(), &notice_command);
+
+		serde_json::to_value(&notice_command).unwrap_or_default()
 	}
 
 	fn emit_notice_on_prefixed_channel(
@@ -156,7 +190,9 @@ impl<'s> NoticeClientSocketCommandResponseInterface for Socket<'s>
 			text,
 		};
 
-		_ = self.socket().emit(notice_command.name(), &notice_command);
+		if self.client().has_capability(Capability::EchoMessage) {
+			_ = self.socket().emit(notice_command.name(), &notice_command);
+		}
 
 		let target_room =
 			format!("channel:{}{}", prefix, target.to_lowercase());
@@ -21,7 +21,12 @@ use flex_chat::client::{
 	Socket,
 };
 use flex_chat::mode::ApplyMode;
-use flex_chat::user::{Flag, UserInterface, UserOperatorInterface};
+use flex_chat::user::{
+	Flag,
+	UserFlagInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
 
 use super::{
 	OperClientSessionInterface,
@@ -31,7 +36,9 @@ use super::{
 use crate::ChatApplication;
 use crate::config::chat::{
 	FlexChatConfigOperatorAuth,
+	FlexChatConfigOperatorClass,
 	FlexChatConfigOperatorFlags,
+	FlexChatConfigOperatorPrivilege,
 	FlexChatConfigOperatorType,
 };
 use crate::features::chat::connect::UserClientSocketInterface;
@@ -43,6 +50,17 @@ use crate::features::chat::join::{
 	JoinErrorResponseInterface,
 };
 use crate::features::chat::mode::ModeAccessControlClientSocketErrorRepliesInterface;
+use crate::features::chat::user_mode::{
+	GLOBOPS_ROOM,
+	UserModeApplicationInterface,
+};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Chambre regroupant les clients opérateurs du serveur.
+pub const OPERATORS_ROOM: &str = "operators";
 
 // --------- //
 // Interface //
@@ -53,6 +71,14 @@ pub trait OperApplicationInterface
 	type Channel: ChannelInterface;
 	type ClientSocket<'cs>: ClientSocketInterface;
 
+	/// Est-ce que le client est un opérateur dont la classe accorde le
+	/// privilège donné?
+	fn does_client_have_operator_privilege(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> bool;
+
 	/// Est-ce que le client est un opérateur global?
 	fn is_client_global_operator(
 		&self,
@@ -66,11 +92,22 @@ pub trait OperApplicationInterface
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 	);
 
-	/// Marque le client en session comme étant un opérateur.
+	/// Marque le client en session comme étant un opérateur de la classe
+	/// donnée.
 	fn marks_client_as_operator(
 		&self,
 		client_socket: &mut Self::ClientSocket<'_>,
 		oper: &FlexChatConfigOperatorAuth,
+		oper_class: &FlexChatConfigOperatorClass,
+	);
+
+	/// Retire au client en session son statut d'opérateur. Ses catégories de
+	/// notices du serveur (drapeau `+s`) lui sont retirées et il quitte les
+	/// chambres réservées aux opérateurs.
+	fn unmarks_client_as_operator(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		removed: &mut Vec<ApplyMode<Flag>>,
 	);
 }
 
@@ -83,6 +120,18 @@ impl OperApplicationInterface for ChatApplication
 	type Channel = Channel;
 	type ClientSocket<'cs> = Socket<'cs>;
 
+	fn does_client_have_operator_privilege(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> bool
+	{
+		client_socket.user().is_operator()
+			&& self
+				.clients
+				.has_operator_privilege(client_socket.cid(), privilege)
+	}
+
 	fn is_client_global_operator(
 		&self,
 		client_socket: &Self::ClientSocket<'_>,
@@ -146,6 +195,12 @@ impl OperApplicationInterface for ChatApplication
 				| JoinChannelPermissionError::ERR_INVITEONLYCHAN => {
 					client_socket.send_err_inviteonlychan(channel_name);
 				}
+				| JoinChannelPermissionError::ERR_NEEDREGGEDNICK => {
+					client_socket.send_err_needreggednick(channel_name);
+				}
+				| JoinChannelPermissionError::ERR_SECUREONLYCHAN => {
+					client_socket.send_err_secureonlychan(channel_name);
+				}
 				| JoinChannelPermissionError::ERR_USERONCHANNEL => {}
 				| JoinChannelPermissionError::ERR_OPERONLY => {
 					client_socket.send_err_operonly(channel_name);
@@ -158,11 +213,18 @@ impl OperApplicationInterface for ChatApplication
 		&self,
 		client_socket: &mut Self::ClientSocket<'_>,
 		oper: &FlexChatConfigOperatorAuth,
+		oper_class: &FlexChatConfigOperatorClass,
 	)
 	{
-		self.clients.marks_client_as_operator(client_socket.cid(), oper);
+		self.clients.marks_client_as_operator(
+			client_socket.cid(),
+			oper,
+			oper_class,
+		);
 
-		if let Some(vhost) = oper.virtual_host.as_deref() {
+		if let Some(vhost) = oper.virtual_host.as_deref().filter(|_| {
+			oper_class.has_privilege(FlexChatConfigOperatorPrivilege::SetVhost)
+		}) {
 			client_socket.user_mut().set_vhost(vhost);
 		}
 
@@ -170,6 +232,11 @@ impl OperApplicationInterface for ChatApplication
 			.client_mut()
 			.marks_client_as_operator(oper.oper_type, &oper.flags);
 
+		_ = client_socket.socket().join(OPERATORS_ROOM);
+		if client_socket.user().has_wallops_flag() {
+			_ = client_socket.socket().join(GLOBOPS_ROOM);
+		}
+
 		let flag_oper = match oper.oper_type {
 			| FlexChatConfigOperatorType::LocalOperator => Flag::LocalOperator,
 			| FlexChatConfigOperatorType::GlobalOperator => {
@@ -181,6 +248,33 @@ impl OperApplicationInterface for ChatApplication
 
 		client_socket.send_rpl_youreoper(&flag_oper);
 	}
+
+	fn unmarks_client_as_operator(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	)
+	{
+		let Some(flag_oper) = client_socket.user().operator_type().cloned()
+		else {
+			return;
+		};
+
+		self.update_server_notices_flag(
+			client_socket,
+			"-*",
+			&mut vec![],
+			removed,
+		);
+
+		self.clients.unmarks_client_as_operator(client_socket.cid());
+		client_socket.client_mut().unmarks_client_as_operator();
+
+		_ = client_socket.socket().leave(OPERATORS_ROOM);
+		_ = client_socket.socket().leave(GLOBOPS_ROOM);
+
+		removed.push(ApplyMode::new(flag_oper));
+	}
 }
 
 impl From<FlexChatConfigOperatorType> for flex_chat::user::Flag
@@ -189,7 +283,9 @@ impl From<FlexChatConfigOperatorType> for flex_chat::user::Flag
 	{
 		match ty {
 			| FlexChatConfigOperatorType::LocalOperator => Self::LocalOperator,
-			| FlexChatConfigOperatorType::GlobalOperator => Self::GlobalOperator,
+			| FlexChatConfigOperatorType::GlobalOperator => {
+				Self::GlobalOperator
+			}
 		}
 	}
 }
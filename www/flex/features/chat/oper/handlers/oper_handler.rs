@@ -9,6 +9,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use flex_chat::client::ClientSocketInterface;
+use flex_chat::user::{ServerNoticeMask, UserAddressInterface, UserInterface};
 use flex_crypto::Hasher;
 use flex_web_framework::WebSocketHandler;
 use flex_web_framework::security::Argon2Password;
@@ -21,6 +22,7 @@ use crate::features::chat::oper::{
 	OperClientSocketErrorRepliesInterface,
 	OperCommandFormData,
 };
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
 
 // --------- //
 // Structure //
@@ -60,30 +62,78 @@ impl WebSocketHandler for OperHandler
 			.cloned()
 			.expect("Configuration de notre application serveur");
 
-		let Some(operator) = config
+		let password_hasher = socket
+			.req_parts()
+			.extensions
+			.get::<Argon2Password>()
+			.expect("Le service d'encodage Argon2.");
+
+		// NOTE: l'identifiant, le mot de passe et l'hôte sont vérifiés
+		//       ensemble et tout échec renvoie la même erreur, afin de ne pas
+		//       révéler au client quels identifiants existent, ni depuis
+		//       quels hôtes ils sont autorisés.
+		let authentication = match config
 			.operators
 			.iter()
 			.find(|operator| operator.identifier.eq(&data.name))
 			.cloned()
+		{
+			| Some(operator) => {
+				let is_password_valid = password_hasher
+					.cmp(operator.password.expose(), data.password.expose());
+				let is_host_allowed = operator.is_host_allowed(
+					client_socket.user().ident(),
+					client_socket.user().host(),
+				);
+
+				match (is_password_valid, is_host_allowed) {
+					| (true, true) => Ok(operator),
+					| (false, _) => Err("mot de passe incorrect"),
+					| (true, false) => Err("hôte non autorisé"),
+				}
+			}
+			| None => Err("identifiant inconnu"),
+		};
+
+		let operator = match authentication {
+			| Ok(operator) => operator,
+			| Err(reason) => {
+				client_socket.emit_server_notice(
+					ServerNoticeMask::FailedOpers,
+					&format!(
+						"Tentative OPER échouée de {}: {reason}",
+						client_socket.user().full_address(),
+					),
+				);
+				client_socket.send_err_passwdmismatch();
+				return;
+			}
+		};
+
+		let Some(operator_class) = config.operator.class(&operator.class)
 		else {
+			tracing::error!(
+				class = %operator.class,
+				"Classe d'opérateur introuvable dans la configuration"
+			);
 			client_socket.send_err_nooperhost();
 			return;
 		};
 
-		let password_hasher = socket
-			.req_parts()
-			.extensions
-			.get::<Argon2Password>()
-			.expect("Le service d'encodage Argon2.");
-
-		if !password_hasher
-			.cmp(operator.password.expose(), data.password.expose())
-		{
-			client_socket.send_err_passwdmismatch();
-			return;
-		}
+		app.marks_client_as_operator(
+			&mut client_socket,
+			&operator,
+			operator_class,
+		);
 
-		app.marks_client_as_operator(&mut client_socket, &operator);
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Opers,
+			&format!(
+				"{} est maintenant opérateur (classe {})",
+				client_socket.user().full_address(),
+				operator.class,
+			),
+		);
 
 		for channel_name in config.operator.auto_join.iter() {
 			app.join_or_create_oper_channel(&client_socket, channel_name);
@@ -33,6 +33,7 @@ flex_kernel::import! {
 	pub(crate) mod responses use {
 		pub(crate) mod oper_command_response use *;
 		pub(crate) mod oper_error_response use *;
+		pub(super) mod oper_notice_command_response use *;
 		pub(super) mod rpl_youreoper use *;
 	};
 }
@@ -11,7 +11,8 @@
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 use flex_chat::user::Flag;
 
-use super::RplYoureoperReply;
+use super::{OperNoticeCommandResponse, RplYoureoperReply};
+use crate::features::chat::oper::OPERATORS_ROOM;
 
 // --------- //
 // Interface //
@@ -19,6 +20,18 @@ use super::RplYoureoperReply;
 
 pub trait OperClientSocketCommandResponse: ClientSocketInterface
 {
+	/// Émet une annonce aux autres opérateurs du serveur.
+	fn emit_oper_notice(&self, text: &str)
+	{
+		let origin = Origin::from(self.client());
+		let oper_notice = OperNoticeCommandResponse {
+			origin: &origin,
+			tags: OperNoticeCommandResponse::default_tags(),
+			text,
+		};
+		self.emit_to(OPERATORS_ROOM, oper_notice.name(), oper_notice);
+	}
+
 	/// Émet au client les réponses liées à la commande /OPER.
 	fn send_rpl_youreoper(&self, oper_type: &Flag)
 	{
@@ -10,7 +10,11 @@
 
 use flex_chat::client::{ClientInterface, ClientsSessionInterface};
 
-use crate::config::chat::FlexChatConfigOperatorAuth;
+use crate::config::chat::{
+	FlexChatConfigOperatorAuth,
+	FlexChatConfigOperatorClass,
+	FlexChatConfigOperatorPrivilege,
+};
 use crate::features::chat::sessions::ClientsSession;
 
 // --------- //
@@ -19,11 +23,25 @@ use crate::features::chat::sessions::ClientsSession;
 
 pub trait OperClientSessionInterface: ClientsSessionInterface
 {
-	/// Marque un client comme étant un opérateur.
+	/// Est-ce que le client opérateur dispose du privilège donné?
+	fn has_operator_privilege(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> bool;
+
+	/// Marque un client comme étant un opérateur de la classe donnée.
 	fn marks_client_as_operator(
 		&self,
 		client_id: &<Self::Client as ClientInterface>::ClientID,
 		oper: &FlexChatConfigOperatorAuth,
+		oper_class: &FlexChatConfigOperatorClass,
+	);
+
+	/// Retire à un client son statut d'opérateur.
+	fn unmarks_client_as_operator(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
 	);
 }
 
@@ -33,10 +51,23 @@ pub trait OperClientSessionInterface: ClientsSessionInterface
 
 impl OperClientSessionInterface for ClientsSession
 {
+	fn has_operator_privilege(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> bool
+	{
+		self.operators
+			.get(client_id)
+			.filter(|privileges| privileges.contains(&privilege))
+			.is_some()
+	}
+
 	fn marks_client_as_operator(
 		&self,
 		client_id: &<Self::Client as ClientInterface>::ClientID,
 		oper: &FlexChatConfigOperatorAuth,
+		oper_class: &FlexChatConfigOperatorClass,
 	)
 	{
 		let Some(mut client) = self.get_mut(client_id) else {
@@ -44,8 +75,24 @@ impl OperClientSessionInterface for ClientsSession
 		};
 
 		client.marks_client_as_operator(oper.oper_type, &oper.flags);
-		if let Some(vhost) = oper.virtual_host.as_deref() {
+		if let Some(vhost) = oper.virtual_host.as_deref().filter(|_| {
+			oper_class.has_privilege(FlexChatConfigOperatorPrivilege::SetVhost)
+		}) {
 			client.set_vhost(vhost);
 		}
+
+		self.operators.insert(*client_id, oper_class.privileges.clone());
+	}
+
+	fn unmarks_client_as_operator(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+	)
+	{
+		if let Some(mut client) = self.get_mut(client_id) {
+			client.unmarks_client_as_operator();
+		}
+
+		self.operators.remove(client_id);
 	}
 }
@@ -126,9 +126,28 @@ impl PartChannelApplicationInterface for ChatApplication
 			return;
 		}
 
+		// NOTE: le départ d'un membre masqué d'un salon +D n'est pas annoncé
+		//       aux autres membres.
+		let hidden = self
+			.channels
+			.get_member(channel_name, client_socket.cid())
+			.is_some_and(|member| member.is_hidden());
+
+		// NOTE: le départ d'un membre sans niveau d'accès d'un salon +u n'est
+		//       annoncé qu'aux opérateurs du salon.
+		let visible = self.channels.get(channel_name).is_some_and(|channel| {
+			channel.is_member_visible(client_socket.cid())
+		});
+
 		self.remove_member_from_channel(channel_name, client_socket);
 
-		client_socket.emit_part(channel_name, message, forced)
+		if hidden {
+			client_socket.emit_hidden_part(channel_name, message, forced)
+		} else if !visible {
+			client_socket.emit_part_to_operators(channel_name, message, forced)
+		} else {
+			client_socket.emit_part(channel_name, message, forced)
+		}
 	}
 
 	fn remove_member_from_channel(
@@ -12,6 +12,7 @@ use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
 use crate::features::chat::part::{
 	PartChannelApplicationInterface,
@@ -42,7 +43,10 @@ impl WebSocketHandler for SapartHandler
 		Data(data): Data<SapartCommandFormData>,
 	)
 	{
-		let Some(client_socket) = app.current_client_operator(&socket) else {
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Sapart,
+		) else {
 			return;
 		};
 
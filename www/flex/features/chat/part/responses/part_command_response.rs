@@ -8,7 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_chat::channel::{Channel, ChannelInterface};
+use flex_chat::channel::{Channel, ChannelAccessLevel, ChannelInterface};
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 use flex_chat::macros::command_response;
 
@@ -41,6 +41,29 @@ pub trait PartClientSocketCommandResponseInterface:
 		forced_by: Option<&str>,
 	) where
 		S: std::ops::Deref<Target = str>;
+
+	/// Émet uniquement au client la réponse liée à la commande /PART. Les
+	/// autres membres du salon ne sont pas informés du départ d'un membre
+	/// masqué (drapeau +D).
+	fn emit_hidden_part<S>(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		message: Option<S>,
+		forced_by: Option<&str>,
+	) where
+		S: std::ops::Deref<Target = str>;
+
+	/// Émet au client la réponse liée à la commande /PART, ainsi qu'aux seuls
+	/// opérateurs du salon. Le départ d'un membre sans niveau d'accès d'un
+	/// salon en mode auditorium (drapeau +u) n'est pas annoncé aux autres
+	/// membres.
+	fn emit_part_to_operators<S>(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		message: Option<S>,
+		forced_by: Option<&str>,
+	) where
+		S: std::ops::Deref<Target = str>;
 }
 
 // -------------- //
@@ -71,7 +94,57 @@ impl<'s> PartClientSocketCommandResponseInterface for Socket<'s>
 
 		let channel_room = format!("channel:{}", channel.to_lowercase());
 		self.emit(cmd_part.name(), &cmd_part);
-		self.emit_to(channel_room.clone(), cmd_part.name(), cmd_part);
-		_ = self.socket().leave(channel_room);
+		self.emit_to(channel_room, cmd_part.name(), cmd_part);
+		self.leave_channel_rooms(channel);
+	}
+
+	fn emit_hidden_part<S>(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		message: Option<S>,
+		forced_by: Option<&str>,
+	) where
+		S: std::ops::Deref<Target = str>,
+	{
+		let origin = Origin::from(self.client());
+
+		let cmd_part = PartCommandResponse {
+			origin: &origin,
+			channel,
+			message: message.as_deref(),
+			forced_by,
+			tags: PartCommandResponse::default_tags(),
+		};
+
+		self.emit(cmd_part.name(), cmd_part);
+		self.leave_channel_rooms(channel);
+	}
+
+	fn emit_part_to_operators<S>(
+		&self,
+		channel: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		message: Option<S>,
+		forced_by: Option<&str>,
+	) where
+		S: std::ops::Deref<Target = str>,
+	{
+		let origin = Origin::from(self.client());
+
+		let cmd_part = PartCommandResponse {
+			origin: &origin,
+			channel,
+			message: message.as_deref(),
+			forced_by,
+			tags: PartCommandResponse::default_tags(),
+		};
+
+		let operators_room = format!(
+			"channel:{}{}",
+			ChannelAccessLevel::HalfOperator.symbol(),
+			channel.to_lowercase()
+		);
+		self.emit(cmd_part.name(), &cmd_part);
+		self.emit_to(operators_room, cmd_part.name(), cmd_part);
+		self.leave_channel_rooms(channel);
 	}
 }
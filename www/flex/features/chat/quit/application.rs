@@ -50,8 +50,16 @@ impl QuitApplicationInterface for ChatApplication
 			return;
 		};
 
+		// NOTE: le départ d'un membre sans niveau d'accès d'un salon +u n'est
+		//       annoncé qu'aux opérateurs du salon.
 		for channel_name in session_client.channels.iter() {
-			client_socket.emit_quit(channel_name, reason.to_string());
+			let Some(channel) = self.get_channel(channel_name) else {
+				continue;
+			};
+			client_socket.emit_quit(
+				channel.member_room(client_socket.cid()),
+				reason.to_string(),
+			);
 		}
 
 		self.channels.remove_client_from_all_his_channels(&session_client);
@@ -8,16 +8,23 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_chat::client::Client;
+use flex_chat::client::{Client, ClientInterface, ClientSocketInterface};
+use flex_chat::user::{ServerNoticeMask, UserAddressInterface, UserInterface};
 use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 use socketioxide::socket;
 
 use crate::features::ChatApplication;
+use crate::features::chat::monitor::{
+	MonitorApplicationInterface,
+	MonitorClientsSessionInterface,
+};
 use crate::features::chat::quit::{
 	QuitApplicationInterface,
 	QuitCommandFormData,
 };
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
+use crate::features::chat::whois::WhowasClientsSessionInterface;
 
 // --------- //
 // Structure //
@@ -42,12 +49,11 @@ impl WebSocketHandler for QuitHandler
 		Data(data): Data<QuitCommandFormData>,
 	)
 	{
-		let client_socket = app.current_client(&socket);
-		app.disconnect_client(
-			client_socket,
+		Self::quit(
+			app,
+			&socket,
 			data.message.as_deref().unwrap_or("Client Quit"),
 		);
-		socket.extensions.remove::<Client>();
 		drop(socket);
 	}
 }
@@ -61,9 +67,41 @@ impl QuitHandler
 		reason: socket::DisconnectReason,
 	)
 	{
-		let client_socket = app.current_client(&socket);
+		// NOTE: le client a déjà quitté le serveur (SAQUIT, par exemple).
+		if socket.extensions.get::<Client>().is_none() {
+			return;
+		}
+
+		Self::quit(app, &socket, reason);
+		drop(socket);
+	}
+
+	/// Termine la session du client avec la raison donnée : les salons et les
+	/// listes MONITOR en sont informés, puis le client est retiré du serveur.
+	pub fn quit(
+		app: &ChatApplication,
+		socket: &SocketRef,
+		reason: impl ToString,
+	)
+	{
+		let reason = reason.to_string();
+		let client_socket = app.current_client(socket);
+		app.clients.record_identity_of_client(client_socket.client());
+		app.clients.clear_monitorlist(client_socket.cid());
+		if client_socket.client().is_registered() {
+			app.notify_monitor_offline(
+				&client_socket,
+				client_socket.user().nickname(),
+			);
+			client_socket.emit_server_notice(
+				ServerNoticeMask::Connects,
+				&format!(
+					"Déconnexion du client {} ({reason})",
+					client_socket.user().full_address(),
+				),
+			);
+		}
 		app.disconnect_client(client_socket, reason);
 		socket.extensions.remove::<Client>();
-		drop(socket);
 	}
 }
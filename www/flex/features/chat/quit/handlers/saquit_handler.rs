@@ -0,0 +1,86 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
+use flex_chat::user::UserInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
+use crate::features::ChatApplication;
+use crate::features::chat::oper::OperClientSocketCommandResponse;
+use crate::features::chat::quit::{QuitHandler, SaquitCommandFormData};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct SaquitHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for SaquitHandler
+{
+	type App = ChatApplication;
+	type Data = SaquitCommandFormData;
+
+	const EVENT_NAME: &'static str = "SAQUIT";
+
+	/// La commande SAQUIT permet à un opérateur de forcer la déconnexion d'un
+	/// client, comme si ce dernier avait lui-même envoyé la commande QUIT avec
+	/// le message donné.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<SaquitCommandFormData>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Saquit,
+		) else {
+			return;
+		};
+
+		let Some(nickname_socket) =
+			app.find_socket_by_nickname(&socket, &data.nickname)
+		else {
+			client_socket.send_err_nosuchnick(&data.nickname);
+			return;
+		};
+
+		let reason = data.message.as_deref().unwrap_or("Client Quit");
+
+		client_socket.emit_oper_notice(&format!(
+			"{} a utilisé SAQUIT sur {} ({})",
+			client_socket.user().nickname(),
+			data.nickname,
+			reason,
+		));
+
+		// NOTE: l'opérateur peut être la cible de la commande, les emprunts
+		//       des sockets doivent être relâchés avant la déconnexion.
+		let nickname_sref = nickname_socket.socket().clone();
+		drop(nickname_socket);
+		drop(client_socket);
+
+		{
+			let nickname_socket = app.current_client(&nickname_sref);
+			nickname_socket.send_err(format!("Déconnexion: {reason}"));
+		}
+
+		QuitHandler::quit(app, &nickname_sref, reason);
+
+		_ = nickname_sref.disconnect();
+	}
+}
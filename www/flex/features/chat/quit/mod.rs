@@ -13,10 +13,12 @@ flex_kernel::import! {
 
 	pub mod handlers use {
 		pub mod quit_handler use *;
+		pub mod saquit_handler use *;
 	};
 
 	pub(crate) mod forms use {
 		pub(crate) mod quit_form use *;
+		pub(crate) mod saquit_form use *;
 	};
 
 	mod responses use {
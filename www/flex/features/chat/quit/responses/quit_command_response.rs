@@ -27,8 +27,9 @@ command_response! {
 
 pub trait QuitClientSocketInterface: ClientSocketInterface
 {
-	/// Émet au client les réponses liées à la commande /QUIT.
-	fn emit_quit(&self, room: &str, reason: impl ToString)
+	/// Émet aux clients de la chambre d'un salon les réponses liées à la
+	/// commande /QUIT.
+	fn emit_quit(&self, room: String, reason: impl ToString)
 	{
 		let msg = reason.to_string();
 
@@ -39,11 +40,7 @@ pub trait QuitClientSocketInterface: ClientSocketInterface
 			message: msg.as_str(),
 		};
 
-		self.emit_to(
-			format!("channel:{}", room.to_lowercase()),
-			quit_command.name(),
-			quit_command,
-		);
+		self.emit_to(room, quit_command.name(), quit_command);
 	}
 }
 
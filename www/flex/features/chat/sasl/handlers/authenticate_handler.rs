@@ -0,0 +1,276 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface};
+use flex_chat::user::UserInterface;
+use flex_crypto::Hasher;
+use flex_web_framework::http::{Cookies, TowerCookies};
+use flex_web_framework::query_builder::SQLQueryBuilder;
+use flex_web_framework::security::Argon2Password;
+use flex_web_framework::types::uuid::Uuid;
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::FlexApplicationState;
+use crate::features::ChatApplication;
+use crate::features::auth::controllers::LoginController;
+use crate::features::chat::auth::{
+	AuthChatApplicationInterface,
+	IdentifyCommandResponseInterface,
+};
+use crate::features::chat::sasl::*;
+use crate::features::users::dto::UserSessionDTO;
+use crate::features::users::entities::UserEntity;
+use crate::features::users::repositories::{
+	UserRepository,
+	UserRepositoryPostgreSQL,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct AuthenticateHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl AuthenticateHandler
+{
+	/// Nom de la commande envoyée par la socket.
+	pub const COMMAND_NAME: &'static str = "AUTHENTICATE";
+
+	/// La commande `AUTHENTICATE` est utilisée pour l'authentification SASL
+	/// lors de l'enregistrement de la connexion. Le client choisit d'abord un
+	/// mécanisme (`PLAIN`, `COOKIE`), puis envoie ses données encodées en
+	/// base64. En cas de succès, le client est identifié au compte, comme
+	/// avec la commande /AUTH IDENTIFY, avant le message de bienvenue.
+	pub async fn handle(
+		socket: SocketRef,
+		State(server_state): State<FlexApplicationState>,
+		State(app): State<ChatApplication>,
+		Data(data): Data<AuthenticateCommandFormData>,
+	)
+	{
+		{
+			let client_socket = app.current_client(&socket);
+
+			if client_socket.user().account().is_some() {
+				client_socket.send_err_saslalready();
+				return;
+			}
+
+			// NOTE(phisyx): l'authentification SASL n'est possible que lors
+			//               de l'enregistrement de la connexion.
+			if client_socket.client().is_registered() {
+				client_socket.send_err_saslfail();
+				return;
+			}
+		}
+
+		if data.data == "*" {
+			socket.extensions.remove::<SaslSession>();
+			app.current_client(&socket).send_err_saslaborted();
+			return;
+		}
+
+		let maybe_step = socket
+			.extensions
+			.get_mut::<SaslSession>()
+			.map(|mut session| (session.mechanism, session.push(&data.data)));
+
+		let Some((mechanism, step)) = maybe_step else {
+			let Ok(mechanism) = data.data.parse::<SaslMechanism>() else {
+				let client_socket = app.current_client(&socket);
+				client_socket.send_rpl_saslmechs(&SaslMechanism::list());
+				client_socket.send_err_saslfail();
+				return;
+			};
+
+			socket.extensions.insert(SaslSession::new(mechanism));
+			app.current_client(&socket).emit_authenticate("+");
+			return;
+		};
+
+		let payload = match step {
+			| SaslStep::Continue => return,
+			| SaslStep::Done(payload) => payload,
+			| SaslStep::Invalid => {
+				socket.extensions.remove::<SaslSession>();
+				app.current_client(&socket).send_err_saslfail();
+				Self::count_failure(&socket, app);
+				return;
+			}
+			| SaslStep::TooLong => {
+				socket.extensions.remove::<SaslSession>();
+				app.current_client(&socket).send_err_sasltoolong();
+				Self::count_failure(&socket, app);
+				return;
+			}
+		};
+
+		socket.extensions.remove::<SaslSession>();
+
+		let maybe_user = match mechanism {
+			| SaslMechanism::Plain => {
+				Self::verify_plain(&socket, &payload).await
+			}
+			| SaslMechanism::Cookie => {
+				Self::verify_cookie(&socket, server_state).await
+			}
+		};
+
+		// NOTE: la connexion a pu être fermée, ou le client identifié par une
+		//       autre commande, pendant la vérification des identifiants.
+		if !socket.connected() {
+			return;
+		}
+
+		let Some(user) = maybe_user else {
+			app.current_client(&socket).send_err_saslfail();
+			Self::count_failure(&socket, app);
+			return;
+		};
+
+		let mut client_socket = app.current_client_mut(&socket);
+
+		if client_socket.user().account().is_some() {
+			client_socket.send_err_saslalready();
+			return;
+		}
+
+		if client_socket.client().is_registered() {
+			client_socket.send_err_saslfail();
+			return;
+		}
+
+		let user_session = UserSessionDTO::from(user);
+
+		// NOTE: même traitement que la commande /AUTH IDENTIFY, sans changer
+		//       le pseudonyme choisi par le client.
+		let old_client_id = *client_socket.cid();
+		let nickname = client_socket.user().nickname().to_owned();
+
+		app.change_id_of_client(&mut client_socket, user_session.id);
+		app.change_account_of_client(
+			&mut client_socket,
+			Some((user_session.id, user_session.name.clone())),
+		);
+
+		let new_client_id = *client_socket.cid();
+
+		for channel_id in client_socket.channels_rooms_set() {
+			client_socket.emit_upgrade_user(
+				&channel_id,
+				old_client_id,
+				new_client_id,
+				&nickname,
+				&nickname,
+				Some(&user_session),
+			);
+		}
+
+		client_socket.emit_account_notify(Some(&user_session.name));
+		client_socket.send_rpl_loggedin();
+		client_socket.send_rpl_saslsuccess();
+	}
+
+	/// Compte un échec d'authentification de la connexion. Lorsque le nombre
+	/// maximal d'échecs est atteint, l'authentification est interrompue et
+	/// la connexion fermée.
+	fn count_failure(socket: &SocketRef, app: &ChatApplication)
+	{
+		if socket.extensions.get::<SaslFailures>().is_none() {
+			socket.extensions.insert(SaslFailures::default());
+		}
+
+		let is_limit_reached = socket
+			.extensions
+			.get_mut::<SaslFailures>()
+			.is_some_and(|mut failures| failures.increment());
+
+		if !is_limit_reached {
+			return;
+		}
+
+		app.current_client(socket).send_err_saslaborted();
+		_ = socket.clone().disconnect();
+	}
+
+	/// Vérifie les identifiants du mécanisme `PLAIN`
+	/// (`authzid\0authcid\0passwd`) par rapport aux utilisateurs du site.
+	async fn verify_plain(
+		socket: &SocketRef,
+		payload: &[u8],
+	) -> Option<UserEntity>
+	{
+		let payload = core::str::from_utf8(payload).ok()?;
+		let mut parts = payload.splitn(3, '\0');
+		let (authzid, authcid, password) =
+			(parts.next()?, parts.next()?, parts.next()?);
+
+		if !authzid.is_empty() && authzid != authcid {
+			return None;
+		}
+
+		let (user_repository, password_service) = Self::services(socket)?;
+
+		let Ok(user) = user_repository.find_by_name(authcid).await else {
+			// SECURITY: timing attacks.
+			_ = password_service.hash("1234567890");
+			return None;
+		};
+
+		if !password_service.cmp(&user.password, password) {
+			return None;
+		}
+
+		Some(user)
+	}
+
+	/// Vérifie le cookie de connexion au site, envoyé lors de la poignée de
+	/// main de la socket, du mécanisme `COOKIE`.
+	async fn verify_cookie(
+		socket: &SocketRef,
+		server_state: &FlexApplicationState,
+	) -> Option<UserEntity>
+	{
+		let user_id = {
+			let cookie_manager =
+				socket.req_parts().extensions.get::<TowerCookies>()?;
+			let cookies = Cookies::new(
+				cookie_manager.clone(),
+				server_state.get_cookie_key().clone(),
+			);
+			let cookie = cookies.signed().get(LoginController::COOKIE_NAME)?;
+			cookie.value().parse::<Uuid>().ok()?
+		};
+
+		let (user_repository, _) = Self::services(socket)?;
+		user_repository.find_by_id(&user_id).await.ok()
+	}
+
+	/// Services nécessaires à la vérification des identifiants.
+	fn services(
+		socket: &SocketRef,
+	) -> Option<(UserRepositoryPostgreSQL, Argon2Password)>
+	{
+		let extensions = &socket.req_parts().extensions;
+		let db_service =
+			extensions.get::<DatabaseService<PostgreSQLDatabase>>()?;
+		let password_service = extensions.get::<Argon2Password>()?;
+
+		let query_builder = SQLQueryBuilder::new(db_service.clone());
+		let user_repository = UserRepositoryPostgreSQL { query_builder };
+
+		Some((user_repository, password_service.clone()))
+	}
+}
@@ -0,0 +1,38 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	mod errors {
+		pub(super) mod err_saslaborted use *;
+		pub(super) mod err_saslalready use *;
+		pub(super) mod err_saslfail use *;
+		pub(super) mod err_sasltoolong use *;
+	};
+
+	pub mod handlers use {
+		pub mod authenticate_handler use *;
+	};
+
+	pub mod sessions use {
+		pub mod sasl_session use *;
+	};
+
+	mod forms use {
+		pub(super) mod authenticate_form use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(crate) mod authenticate_command_response use *;
+		pub(super) mod authenticate_error_response use *;
+		mod rpl_loggedin use *;
+		mod rpl_saslmechs use *;
+		mod rpl_saslsuccess use *;
+	};
+}
@@ -0,0 +1,86 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+use flex_chat::user::{UserAddressInterface, UserInterface};
+
+use super::{RplLoggedinReply, RplSaslmechsReply, RplSaslsuccessReply};
+
+command_response! {
+	struct AUTHENTICATE<'data>
+	{
+		/// Données envoyées au client (`+` lorsque le serveur attend les
+		/// données du client).
+		data: &'data str,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait AuthenticateClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client la réponse liée à la commande /AUTHENTICATE.
+	fn emit_authenticate(&self, data: &str)
+	{
+		let origin = Origin::from(self.client());
+		let authenticate_command = AuthenticateCommandResponse {
+			origin: &origin,
+			tags: AuthenticateCommandResponse::default_tags(),
+			data,
+		};
+		self.emit(authenticate_command.name(), authenticate_command);
+	}
+
+	/// Émet au client la réponse [RplLoggedinReply].
+	fn send_rpl_loggedin(&self)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_loggedin = RplLoggedinReply {
+			origin: &origin,
+			tags: RplLoggedinReply::default_tags(),
+			mask: &self.user().full_address(),
+			account: self.user().account().unwrap_or_default(),
+		};
+		self.emit(rpl_loggedin.name(), rpl_loggedin);
+	}
+
+	/// Émet au client la réponse [RplSaslmechsReply].
+	fn send_rpl_saslmechs(&self, mechanisms: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_saslmechs = RplSaslmechsReply {
+			origin: &origin,
+			tags: RplSaslmechsReply::default_tags(),
+			mechanisms,
+		};
+		self.emit(rpl_saslmechs.name(), rpl_saslmechs);
+	}
+
+	/// Émet au client la réponse [RplSaslsuccessReply].
+	fn send_rpl_saslsuccess(&self)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_saslsuccess = RplSaslsuccessReply {
+			origin: &origin,
+			tags: RplSaslsuccessReply::default_tags(),
+		};
+		self.emit(rpl_saslsuccess.name(), rpl_saslsuccess);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> AuthenticateClientSocketCommandResponseInterface for Socket<'s> {}
@@ -0,0 +1,71 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::sasl::errors::*;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait AuthenticateClientSocketErrorRepliesInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'erreur [ErrSaslabortedError].
+	fn send_err_saslaborted(&self)
+	{
+		let origin = Origin::from(self.client());
+		let err_saslaborted = ErrSaslabortedError {
+			origin: &origin,
+			tags: ErrSaslabortedError::default_tags(),
+		};
+		self.emit(err_saslaborted.name(), err_saslaborted);
+	}
+
+	/// Émet au client l'erreur [ErrSaslalreadyError].
+	fn send_err_saslalready(&self)
+	{
+		let origin = Origin::from(self.client());
+		let err_saslalready = ErrSaslalreadyError {
+			origin: &origin,
+			tags: ErrSaslalreadyError::default_tags(),
+		};
+		self.emit(err_saslalready.name(), err_saslalready);
+	}
+
+	/// Émet au client l'erreur [ErrSaslfailError].
+	fn send_err_saslfail(&self)
+	{
+		let origin = Origin::from(self.client());
+		let err_saslfail = ErrSaslfailError {
+			origin: &origin,
+			tags: ErrSaslfailError::default_tags(),
+		};
+		self.emit(err_saslfail.name(), err_saslfail);
+	}
+
+	/// Émet au client l'erreur [ErrSasltoolongError].
+	fn send_err_sasltoolong(&self)
+	{
+		let origin = Origin::from(self.client());
+		let err_sasltoolong = ErrSasltoolongError {
+			origin: &origin,
+			tags: ErrSasltoolongError::default_tags(),
+		};
+		self.emit(err_sasltoolong.name(), err_sasltoolong);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> AuthenticateClientSocketErrorRepliesInterface for Socket<'s> {}
@@ -0,0 +1,159 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use core::str;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Mécanismes SASL supportés par le serveur.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum SaslMechanism
+{
+	/// Identifiants (nom d'utilisateur, mot de passe) d'un compte.
+	Plain,
+	/// Cookie de connexion au site, envoyé lors de la poignée de main de la
+	/// socket.
+	Cookie,
+}
+
+/// Étape de l'authentification SASL après réception d'un morceau de données.
+pub enum SaslStep
+{
+	/// Le client doit envoyer la suite des données.
+	Continue,
+	/// Les données sont complètes (décodées).
+	Done(Vec<u8>),
+	/// Les données ne sont pas encodées en base64.
+	Invalid,
+	/// Les données sont trop longues.
+	TooLong,
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Authentification SASL en cours d'une socket.
+pub struct SaslSession
+{
+	/// Mécanisme choisi par le client.
+	pub mechanism: SaslMechanism,
+	/// Données (encodées en base64) reçues.
+	buffer: String,
+}
+
+/// Nombre d'échecs d'authentification SASL d'une connexion.
+#[derive(Default)]
+pub struct SaslFailures(u8);
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl SaslMechanism
+{
+	/// Tous les mécanismes supportés par le serveur.
+	pub const ALL: [Self; 2] = [Self::Plain, Self::Cookie];
+
+	/// Nom du mécanisme.
+	pub fn as_str(&self) -> &'static str
+	{
+		match self {
+			| Self::Plain => "PLAIN",
+			| Self::Cookie => "COOKIE",
+		}
+	}
+
+	/// Liste des mécanismes supportés par le serveur, séparés par une
+	/// virgule.
+	pub fn list() -> String
+	{
+		Self::ALL.map(|mechanism| mechanism.as_str()).join(",")
+	}
+}
+
+impl SaslSession
+{
+	/// Taille d'un morceau de données envoyé par la commande `AUTHENTICATE`.
+	pub const CHUNK_SIZE: usize = 400;
+	/// Taille maximale des données (encodées) d'une authentification.
+	pub const MAX_SIZE: usize = 8192;
+
+	pub fn new(mechanism: SaslMechanism) -> Self
+	{
+		Self {
+			mechanism,
+			buffer: Default::default(),
+		}
+	}
+
+	/// Ajoute un morceau de données reçu.
+	pub fn push(&mut self, chunk: &str) -> SaslStep
+	{
+		if chunk.len() > Self::CHUNK_SIZE {
+			return SaslStep::TooLong;
+		}
+
+		if chunk != "+" {
+			self.buffer.push_str(chunk);
+		}
+
+		if self.buffer.len() > Self::MAX_SIZE {
+			return SaslStep::TooLong;
+		}
+
+		if chunk.len() == Self::CHUNK_SIZE {
+			return SaslStep::Continue;
+		}
+
+		match BASE64.decode(&self.buffer) {
+			| Ok(data) => SaslStep::Done(data),
+			| Err(_) => SaslStep::Invalid,
+		}
+	}
+}
+
+impl SaslFailures
+{
+	/// Nombre maximal d'échecs d'authentification d'une connexion.
+	pub const MAX: u8 = 3;
+
+	/// Compte un nouvel échec. Retourne `true` lorsque le nombre maximal
+	/// d'échecs est atteint.
+	pub fn increment(&mut self) -> bool
+	{
+		self.0 = self.0.saturating_add(1);
+		self.0 >= Self::MAX
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl str::FromStr for SaslMechanism
+{
+	type Err = ();
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Self::ALL
+			.into_iter()
+			.find(|mechanism| mechanism.as_str().eq_ignore_ascii_case(s))
+			.ok_or(())
+	}
+}
@@ -0,0 +1,366 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::{ServerBan, ServerBanKind};
+use flex_web_framework::{
+	DatabaseService,
+	PostgreSQLDatabase,
+	ServerSettings,
+	WebSocketFeature,
+	WebSocketHandler,
+	WebSocketHandlers2Interface,
+	WebSocketHandlersInterface,
+	http,
+};
+use socketioxide::extract::SocketRef;
+
+use super::{
+	ServerBanEntity,
+	ServerBanRepository,
+	ServerBanRepositoryPostgreSQL,
+};
+use crate::features::chat::connect::ConnectApplicationInterface;
+use crate::features::chat::quit::QuitHandler;
+use crate::features::chat::ChatHandlers;
+use crate::features::ChatApplication;
+use crate::FlexState;
+
+// ---- //
+// Type //
+// ---- //
+
+type ChatHandlers2 =
+	<ChatApplication as WebSocketFeature<FlexState>>::Handlers2;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ServerBanApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Le stockage des bannissements du serveur.
+	fn server_ban_repository(
+		&self,
+		extensions: &http::Extensions,
+	) -> Option<Arc<dyn ServerBanRepository>>;
+
+	/// Charge les bannissements du serveur dans la session. Les
+	/// bannissements ne sont chargés qu'une seule fois, au démarrage du
+	/// serveur.
+	fn load_server_bans(
+		&self,
+		extensions: &http::Extensions,
+	) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+	/// L'adresse IP d'une socket et le ZLINE lui correspondant.
+	fn find_zline_of_socket(
+		&self,
+		socket: &SocketRef,
+		server_settings: &ServerSettings,
+	) -> Option<(IpAddr, ServerBan)>;
+
+	/// Le bannissement, d'un des types donnés, correspondant à un client.
+	fn find_server_ban_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		kinds: &[ServerBanKind],
+	) -> Option<ServerBan>;
+
+	/// Applique un bannissement du serveur. Les clients correspondant au
+	/// bannissement sont déconnectés (KLINE, GLINE, ZLINE) ou ignorés
+	/// (SHUN). Retourne `false` si le masque est déjà banni.
+	fn add_server_ban(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		server_ban: ServerBan,
+	) -> bool;
+
+	/// Retire un bannissement du serveur. Les commandes des clients qui ne
+	/// correspondent plus à aucun SHUN sont à nouveau traitées.
+	fn remove_server_ban(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		kind: ServerBanKind,
+		mask: &str,
+	) -> Option<ServerBan>;
+
+	/// Les bannissements du serveur d'un type. Les bannissements expirés
+	/// sont supprimés au préalable.
+	fn server_bans(
+		&self,
+		socket: &SocketRef,
+		kind: ServerBanKind,
+	) -> Vec<ServerBan>;
+
+	/// Refuse la connexion d'une socket bannie du serveur.
+	fn reject_banned_socket(&self, socket: &SocketRef, server_ban: &ServerBan);
+
+	/// Les commandes d'une socket, à l'exception de QUIT, sont ignorées.
+	fn shun_socket(&self, socket: &SocketRef);
+
+	/// Les commandes d'une socket sont à nouveau traitées.
+	fn unshun_socket(&self, socket: &SocketRef);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ServerBanApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn server_ban_repository(
+		&self,
+		extensions: &http::Extensions,
+	) -> Option<Arc<dyn ServerBanRepository>>
+	{
+		let database =
+			extensions.get::<DatabaseService<PostgreSQLDatabase>>()?;
+		Some(Arc::new(ServerBanRepositoryPostgreSQL {
+			database: database.clone(),
+		}))
+	}
+
+	async fn load_server_bans(
+		&self,
+		extensions: &http::Extensions,
+	) -> Result<(), sqlx::Error>
+	{
+		let Some(repository) = self.server_ban_repository(extensions) else {
+			return Ok(());
+		};
+
+		self.server_bans
+			.load(async move {
+				let server_bans = repository.find_all().await?;
+				Ok(server_bans
+					.into_iter()
+					.filter_map(ServerBanEntity::into_server_ban)
+					.collect())
+			})
+			.await
+	}
+
+	fn find_zline_of_socket(
+		&self,
+		socket: &SocketRef,
+		server_settings: &ServerSettings,
+	) -> Option<(IpAddr, ServerBan)>
+	{
+		let ip_addr = self.client_ip(socket, server_settings)?;
+
+		let zline =
+			self.server_bans.find(&[ServerBanKind::Zline], |server_ban| {
+				server_ban.matches_ip(&ip_addr)
+			})?;
+		Some((ip_addr, zline))
+	}
+
+	fn find_server_ban_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		kinds: &[ServerBanKind],
+	) -> Option<ServerBan>
+	{
+		self.server_bans
+			.find(kinds, |server_ban| server_ban.matches(client_socket.user()))
+	}
+
+	fn add_server_ban(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		server_ban: ServerBan,
+	) -> bool
+	{
+		if !self.server_bans.add(server_ban.clone()) {
+			return false;
+		}
+
+		if let Some(repository) = self.server_ban_repository(
+			&client_socket.socket().req_parts().extensions,
+		) {
+			let entity = ServerBanEntity::from(&server_ban);
+			tokio::spawn(async move {
+				if let Err(error) = repository.save(&entity).await {
+					tracing::error!(
+						?error,
+						mask = entity.mask,
+						"Impossible de sauvegarder le bannissement du serveur"
+					);
+				}
+			});
+		}
+
+		let client_ids: Vec<_> = self
+			.clients
+			.clients
+			.iter()
+			.filter(|client| {
+				client.key() != client_socket.cid()
+					&& client.is_registered()
+					&& client.is_connected()
+					&& server_ban.matches(client.user())
+			})
+			.map(|client| *client.key())
+			.collect();
+
+		for client_id in client_ids {
+			let Some(banned_client_socket) =
+				self.find_socket_by_id(client_socket.socket(), &client_id)
+			else {
+				continue;
+			};
+
+			if server_ban.kind == ServerBanKind::Shun {
+				self.shun_socket(banned_client_socket.socket());
+				continue;
+			}
+
+			self.reject_banned_socket(
+				banned_client_socket.socket(),
+				&server_ban,
+			);
+		}
+
+		true
+	}
+
+	fn remove_server_ban(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		kind: ServerBanKind,
+		mask: &str,
+	) -> Option<ServerBan>
+	{
+		let server_ban = self.server_bans.remove(kind, mask)?;
+
+		if let Some(repository) = self.server_ban_repository(
+			&client_socket.socket().req_parts().extensions,
+		) {
+			let entity = ServerBanEntity::from(&server_ban);
+			tokio::spawn(async move {
+				if let Err(error) =
+					repository.delete(&entity.kind, &entity.mask).await
+				{
+					tracing::error!(
+						?error,
+						mask = entity.mask,
+						"Impossible de supprimer le bannissement du serveur"
+					);
+				}
+			});
+		}
+
+		if kind != ServerBanKind::Shun {
+			return Some(server_ban);
+		}
+
+		let client_ids: Vec<_> = self
+			.clients
+			.clients
+			.iter()
+			.filter(|client| {
+				client.is_registered()
+					&& client.is_connected()
+					&& server_ban.matches(client.user())
+			})
+			.map(|client| *client.key())
+			.collect();
+
+		for client_id in client_ids {
+			let Some(shunned_client_socket) =
+				self.find_socket_by_id(client_socket.socket(), &client_id)
+			else {
+				continue;
+			};
+
+			if self
+				.find_server_ban_of_client(
+					&shunned_client_socket,
+					&[ServerBanKind::Shun],
+				)
+				.is_none()
+			{
+				self.unshun_socket(shunned_client_socket.socket());
+			}
+		}
+
+		Some(server_ban)
+	}
+
+	fn server_bans(
+		&self,
+		socket: &SocketRef,
+		kind: ServerBanKind,
+	) -> Vec<ServerBan>
+	{
+		let expired = self.server_bans.remove_expired();
+
+		if let Some(repository) = self
+			.server_ban_repository(&socket.req_parts().extensions)
+			.filter(|_| !expired.is_empty())
+		{
+			tokio::spawn(async move {
+				for server_ban in expired {
+					let entity = ServerBanEntity::from(&server_ban);
+					if let Err(error) =
+						repository.delete(&entity.kind, &entity.mask).await
+					{
+						tracing::error!(
+							?error,
+							mask = entity.mask,
+							"Impossible de supprimer le bannissement expiré"
+						);
+					}
+				}
+			});
+		}
+
+		self.server_bans.list(kind)
+	}
+
+	fn reject_banned_socket(&self, socket: &SocketRef, server_ban: &ServerBan)
+	{
+		let error = format!(
+			"Déconnexion: tu as été banni du serveur ({}) par {} ({})",
+			server_ban.kind, server_ban.updated_by, server_ban.reason,
+		);
+		_ = socket.emit("ERROR", error);
+		_ = socket.clone().disconnect();
+	}
+
+	fn shun_socket(&self, socket: &SocketRef)
+	{
+		// NOTE: les gestionnaires des événements sont remplacés par un
+		//       gestionnaire ne faisant rien, seule la commande QUIT reste
+		//       traitée.
+		let events = ChatHandlers::events()
+			.into_iter()
+			.chain(ChatHandlers2::events())
+			.filter(|event| *event != QuitHandler::EVENT_NAME);
+
+		for event in events {
+			socket.on(event, || {});
+		}
+	}
+
+	fn unshun_socket(&self, socket: &SocketRef)
+	{
+		ChatHandlers::listen(socket);
+		ChatHandlers2::listen(socket);
+	}
+}
@@ -0,0 +1,77 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::user::ServerBan;
+use flex_web_framework::types::time;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un bannissement du serveur (table `server_bans`).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(sqlx::FromRow)]
+pub struct ServerBanEntity
+{
+	/// Type du bannissement (`KLINE`, `GLINE`, `ZLINE`, `SHUN`).
+	pub kind: String,
+	/// Le masque.
+	pub mask: String,
+	/// La raison du bannissement.
+	pub reason: String,
+	/// Par qui a été appliqué ce bannissement.
+	pub updated_by: String,
+	/// Quand a été appliqué ce bannissement.
+	pub updated_at: time::DateTime<time::Utc>,
+	/// Quand expire ce bannissement (aucune date pour un bannissement
+	/// permanent).
+	pub expires_at: Option<time::DateTime<time::Utc>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerBanEntity
+{
+	/// Le bannissement de la session. Un type de bannissement inconnu est
+	/// ignoré.
+	pub fn into_server_ban(self) -> Option<ServerBan>
+	{
+		Some(ServerBan {
+			kind: self.kind.parse().ok()?,
+			mask: self.mask,
+			reason: self.reason,
+			updated_by: self.updated_by,
+			updated_at: self.updated_at,
+			expires_at: self.expires_at,
+		})
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<&ServerBan> for ServerBanEntity
+{
+	fn from(server_ban: &ServerBan) -> Self
+	{
+		Self {
+			kind: server_ban.kind.to_string(),
+			mask: server_ban.mask.clone(),
+			reason: server_ban.reason.clone(),
+			updated_by: server_ban.updated_by.clone(),
+			updated_at: server_ban.updated_at,
+			expires_at: server_ban.expires_at,
+		}
+	}
+}
@@ -0,0 +1,79 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use flex_chat::macros::command_formdata;
+
+command_formdata! {
+	struct KLINE
+	{
+		/// Le masque `ident@hôte`.
+		mask: Arc<str>,
+		/// La durée du bannissement (`1d12h`). Aucune durée signifie que le
+		/// bannissement est permanent.
+		duration: Option<Arc<str>>,
+		/// La raison du bannissement.
+		reason: Option<Arc<str>>,
+	}
+
+	struct UNKLINE
+	{
+		mask: Arc<str>,
+	}
+
+	struct GLINE
+	{
+		/// Le masque `ident@hôte`.
+		mask: Arc<str>,
+		/// La durée du bannissement (`1d12h`). Aucune durée signifie que le
+		/// bannissement est permanent.
+		duration: Option<Arc<str>>,
+		/// La raison du bannissement.
+		reason: Option<Arc<str>>,
+	}
+
+	struct UNGLINE
+	{
+		mask: Arc<str>,
+	}
+
+	struct ZLINE
+	{
+		/// L'adresse IP ou la plage d'adresses IP au format CIDR.
+		mask: Arc<str>,
+		/// La durée du bannissement (`1d12h`). Aucune durée signifie que le
+		/// bannissement est permanent.
+		duration: Option<Arc<str>>,
+		/// La raison du bannissement.
+		reason: Option<Arc<str>>,
+	}
+
+	struct UNZLINE
+	{
+		mask: Arc<str>,
+	}
+
+	struct SHUN
+	{
+		/// Le masque `ident@hôte`.
+		mask: Arc<str>,
+		/// La durée du bannissement (`1d12h`). Aucune durée signifie que le
+		/// bannissement est permanent.
+		duration: Option<Arc<str>>,
+		/// La raison du bannissement.
+		reason: Option<Arc<str>>,
+	}
+
+	struct UNSHUN
+	{
+		mask: Arc<str>,
+	}
+}
@@ -0,0 +1,356 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::parse_duration;
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::user::{
+	ServerBan,
+	ServerBanKind,
+	ServerNoticeMask,
+	UserInterface,
+};
+use flex_web_framework::WebSocketHandler2;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
+use crate::features::ChatApplication;
+use crate::features::chat::server_ban::{
+	GlineCommandFormData,
+	KlineCommandFormData,
+	ServerBanApplicationInterface,
+	ServerBanClientSocketCommandResponseInterface,
+	ShunCommandFormData,
+	UnglineCommandFormData,
+	UnklineCommandFormData,
+	UnshunCommandFormData,
+	UnzlineCommandFormData,
+	ZlineCommandFormData,
+};
+use crate::features::chat::user_mode::UserModeClientSocketCommandResponse;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ServerBanKlineHandler;
+pub struct ServerBanGlineHandler;
+pub struct ServerBanZlineHandler;
+pub struct ServerBanShunHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerBanKlineHandler
+{
+	/// Privilège d'opérateur requis pour un type de bannissement du serveur:
+	/// un bannissement du réseau (GLINE) est soumis à un privilège distinct.
+	fn required_privilege(
+		kind: ServerBanKind,
+	) -> FlexChatConfigOperatorPrivilege
+	{
+		match kind {
+			| ServerBanKind::Gline => FlexChatConfigOperatorPrivilege::Gline,
+			| ServerBanKind::Kline
+			| ServerBanKind::Zline
+			| ServerBanKind::Shun => FlexChatConfigOperatorPrivilege::Kline,
+		}
+	}
+
+	/// Applique un bannissement du serveur d'un type donné.
+	fn handle_server_ban(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		kind: ServerBanKind,
+		mask: &str,
+		duration: Option<&str>,
+		reason: Option<&str>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			socket,
+			Self::required_privilege(kind),
+		) else {
+			return;
+		};
+
+		let Some(normalized_mask) = kind.parse_mask(mask) else {
+			client_socket.send_fail_server_ban(
+				kind.command(),
+				"INVALID_MASK",
+				mask,
+				"Le masque du bannissement est invalide",
+			);
+			return;
+		};
+
+		let reason = reason
+			.filter(|reason| !reason.is_empty())
+			.unwrap_or("Aucune raison");
+
+		let mut server_ban = ServerBan::new(
+			kind,
+			normalized_mask,
+			client_socket.user().nickname(),
+		)
+		.with_reason(reason);
+
+		if let Some(duration) = duration.filter(|duration| !duration.is_empty())
+		{
+			let Some(duration) = parse_duration(duration) else {
+				client_socket.send_fail_server_ban(
+					kind.command(),
+					"INVALID_DURATION",
+					duration,
+					"La durée du bannissement est invalide",
+				);
+				return;
+			};
+			server_ban = server_ban.with_duration(duration);
+		}
+
+		if !app.add_server_ban(&client_socket, server_ban.clone()) {
+			client_socket.send_fail_server_ban(
+				kind.command(),
+				"ALREADY_BANNED",
+				&server_ban.mask,
+				"Ce masque est déjà banni",
+			);
+			return;
+		}
+
+		client_socket.emit_server_ban(&server_ban, true);
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Bans,
+			&format!(
+				"{} a ajouté un {} sur {} ({})",
+				client_socket.user().nickname(),
+				server_ban.kind,
+				server_ban.mask,
+				server_ban.reason,
+			),
+		);
+	}
+
+	/// Retire un bannissement du serveur d'un type donné.
+	fn handle_server_unban(
+		socket: &SocketRef,
+		app: &ChatApplication,
+		kind: ServerBanKind,
+		mask: &str,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			socket,
+			Self::required_privilege(kind),
+		) else {
+			return;
+		};
+
+		let normalized_mask =
+			kind.parse_mask(mask).unwrap_or_else(|| mask.to_owned());
+
+		let Some(server_ban) =
+			app.remove_server_ban(&client_socket, kind, &normalized_mask)
+		else {
+			client_socket.send_fail_server_ban(
+				&format!("UN{kind}"),
+				"NO_SUCH_BAN",
+				mask,
+				"Ce masque n'est pas banni",
+			);
+			return;
+		};
+
+		client_socket.emit_server_ban(&server_ban, false);
+		client_socket.emit_server_notice(
+			ServerNoticeMask::Bans,
+			&format!(
+				"{} a retiré le {} sur {}",
+				client_socket.user().nickname(),
+				server_ban.kind,
+				server_ban.mask,
+			),
+		);
+	}
+}
+
+impl WebSocketHandler2 for ServerBanKlineHandler
+{
+	type App = ChatApplication;
+	type SetData = KlineCommandFormData;
+	type UnsetData = UnklineCommandFormData;
+
+	const SET_EVENT_NAME: &'static str = "KLINE";
+	const UNSET_EVENT_NAME: &'static str = "UNKLINE";
+
+	/// La commande KLINE est utilisée par un opérateur pour bannir du serveur
+	/// les utilisateurs correspondant au masque `ident@hôte`, pour une
+	/// durée donnée ou de manière permanente. Les utilisateurs connectés
+	/// correspondant au masque sont déconnectés.
+	fn handle_set(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<KlineCommandFormData>,
+	)
+	{
+		Self::handle_server_ban(
+			&socket,
+			app,
+			ServerBanKind::Kline,
+			&data.mask,
+			data.duration.as_deref(),
+			data.reason.as_deref(),
+		);
+	}
+
+	fn handle_unset(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UnklineCommandFormData>,
+	)
+	{
+		Self::handle_server_unban(
+			&socket,
+			app,
+			ServerBanKind::Kline,
+			&data.mask,
+		);
+	}
+}
+
+impl WebSocketHandler2 for ServerBanGlineHandler
+{
+	type App = ChatApplication;
+	type SetData = GlineCommandFormData;
+	type UnsetData = UnglineCommandFormData;
+
+	const SET_EVENT_NAME: &'static str = "GLINE";
+	const UNSET_EVENT_NAME: &'static str = "UNGLINE";
+
+	/// La commande GLINE est utilisée par un opérateur global pour bannir du
+	/// réseau les utilisateurs correspondant au masque `ident@hôte`.
+	fn handle_set(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<GlineCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_ban(
+			&socket,
+			app,
+			ServerBanKind::Gline,
+			&data.mask,
+			data.duration.as_deref(),
+			data.reason.as_deref(),
+		);
+	}
+
+	fn handle_unset(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UnglineCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_unban(
+			&socket,
+			app,
+			ServerBanKind::Gline,
+			&data.mask,
+		);
+	}
+}
+
+impl WebSocketHandler2 for ServerBanZlineHandler
+{
+	type App = ChatApplication;
+	type SetData = ZlineCommandFormData;
+	type UnsetData = UnzlineCommandFormData;
+
+	const SET_EVENT_NAME: &'static str = "ZLINE";
+	const UNSET_EVENT_NAME: &'static str = "UNZLINE";
+
+	/// La commande ZLINE est utilisée par un opérateur pour bannir une
+	/// adresse IP, ou une plage d'adresses IP au format CIDR. Les connexions
+	/// correspondantes sont refusées avant même leur enregistrement.
+	fn handle_set(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<ZlineCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_ban(
+			&socket,
+			app,
+			ServerBanKind::Zline,
+			&data.mask,
+			data.duration.as_deref(),
+			data.reason.as_deref(),
+		);
+	}
+
+	fn handle_unset(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UnzlineCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_unban(
+			&socket,
+			app,
+			ServerBanKind::Zline,
+			&data.mask,
+		);
+	}
+}
+
+impl WebSocketHandler2 for ServerBanShunHandler
+{
+	type App = ChatApplication;
+	type SetData = ShunCommandFormData;
+	type UnsetData = UnshunCommandFormData;
+
+	const SET_EVENT_NAME: &'static str = "SHUN";
+	const UNSET_EVENT_NAME: &'static str = "UNSHUN";
+
+	/// La commande SHUN est utilisée par un opérateur pour ignorer les
+	/// utilisateurs correspondant au masque `ident@hôte`: ils restent
+	/// connectés, mais toutes leurs commandes, à l'exception de QUIT (et PONG
+	/// pour les clients IRC), sont ignorées.
+	fn handle_set(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<ShunCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_ban(
+			&socket,
+			app,
+			ServerBanKind::Shun,
+			&data.mask,
+			data.duration.as_deref(),
+			data.reason.as_deref(),
+		);
+	}
+
+	fn handle_unset(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UnshunCommandFormData>,
+	)
+	{
+		ServerBanKlineHandler::handle_server_unban(
+			&socket,
+			app,
+			ServerBanKind::Shun,
+			&data.mask,
+		);
+	}
+}
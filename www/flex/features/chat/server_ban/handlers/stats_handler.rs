@@ -0,0 +1,59 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::user::ServerBanKind;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::server_ban::{
+	ServerBanApplicationInterface,
+	ServerBanClientSocketCommandResponseInterface,
+	StatsCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct StatsHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for StatsHandler
+{
+	type App = ChatApplication;
+	type Data = StatsCommandFormData;
+
+	const EVENT_NAME: &'static str = "STATS";
+
+	/// La commande STATS est utilisée par un opérateur pour obtenir les
+	/// bannissements du serveur: `k` pour les KLINE, `g` pour les GLINE,
+	/// `z` pour les ZLINE et `s` pour les SHUN. Une requête non supportée
+	/// renvoie uniquement la fin de la liste.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<StatsCommandFormData>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator(&socket) else {
+			return;
+		};
+
+		let server_bans = ServerBanKind::from_letter(data.query)
+			.map(|kind| app.server_bans(&socket, kind))
+			.unwrap_or_default();
+
+		client_socket.send_rpl_stats_server_bans(data.query, &server_bans);
+	}
+}
@@ -0,0 +1,42 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod server_ban_handler use *;
+		pub mod stats_handler use *;
+	};
+
+	mod entities use {
+		pub(super) mod server_ban_entity use *;
+	};
+
+	mod forms use {
+		pub(super) mod server_ban_form use *;
+		pub(super) mod stats_form use *;
+	};
+
+	mod repositories use {
+		pub(super) mod server_ban_repository use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(super) mod rpl_endofstats use *;
+		pub(super) mod rpl_statsgline use *;
+		pub(super) mod rpl_statskline use *;
+		pub(crate) mod server_ban_command_response use *;
+	};
+
+	pub mod sessions use {
+		pub mod server_ban_session use *;
+	};
+}
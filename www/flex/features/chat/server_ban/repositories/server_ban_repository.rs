@@ -0,0 +1,35 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod postgres;
+
+pub use self::postgres::*;
+use crate::features::chat::server_ban::ServerBanEntity;
+
+// --------- //
+// Interface //
+// --------- //
+
+#[flex_web_framework::async_trait]
+pub trait ServerBanRepository: Send + Sync
+{
+	/// Enregistre (ou met à jour) un bannissement du serveur.
+	async fn save(
+		&self,
+		server_ban: &ServerBanEntity,
+	) -> Result<(), sqlx::Error>;
+
+	/// Supprime un bannissement du serveur.
+	async fn delete(&self, kind: &str, mask: &str) -> Result<(), sqlx::Error>;
+
+	/// Tous les bannissements du serveur n'ayant pas expiré. Les
+	/// bannissements expirés sont supprimés.
+	async fn find_all(&self) -> Result<Vec<ServerBanEntity>, sqlx::Error>;
+}
@@ -0,0 +1,93 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::{DatabaseService, PostgreSQLDatabase};
+
+use super::ServerBanRepository;
+use crate::features::chat::server_ban::ServerBanEntity;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct ServerBanRepositoryPostgreSQL
+{
+	pub database: DatabaseService<PostgreSQLDatabase>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerBanRepositoryPostgreSQL
+{
+	/// Nom de la table des bannissements du serveur.
+	pub const TABLE_NAME: &'static str = "server_bans";
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[flex_web_framework::async_trait]
+impl ServerBanRepository for ServerBanRepositoryPostgreSQL
+{
+	async fn save(
+		&self,
+		server_ban: &ServerBanEntity,
+	) -> Result<(), sqlx::Error>
+	{
+		let sql = format!(
+			"INSERT INTO {} (kind, mask, reason, updated_by, updated_at, \
+			 expires_at) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT (kind, \
+			 mask) DO UPDATE SET reason = $3, updated_by = $4, updated_at = \
+			 $5, expires_at = $6",
+			Self::TABLE_NAME,
+		);
+		sqlx::query(&sql)
+			.bind(&server_ban.kind)
+			.bind(&server_ban.mask)
+			.bind(&server_ban.reason)
+			.bind(&server_ban.updated_by)
+			.bind(server_ban.updated_at)
+			.bind(server_ban.expires_at)
+			.execute(self.database.pool())
+			.await?;
+		Ok(())
+	}
+
+	async fn delete(&self, kind: &str, mask: &str) -> Result<(), sqlx::Error>
+	{
+		let sql = format!(
+			"DELETE FROM {} WHERE kind = $1 AND mask = $2",
+			Self::TABLE_NAME
+		);
+		sqlx::query(&sql)
+			.bind(kind)
+			.bind(mask)
+			.execute(self.database.pool())
+			.await?;
+		Ok(())
+	}
+
+	async fn find_all(&self) -> Result<Vec<ServerBanEntity>, sqlx::Error>
+	{
+		let pool = self.database.pool();
+
+		let sql = format!(
+			"DELETE FROM {} WHERE expires_at <= now()",
+			Self::TABLE_NAME
+		);
+		sqlx::query(&sql).execute(pool).await?;
+
+		let sql = format!("SELECT * FROM {}", Self::TABLE_NAME);
+		sqlx::query_as(&sql).fetch_all(pool).await
+	}
+}
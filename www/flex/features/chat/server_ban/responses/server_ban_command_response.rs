@@ -0,0 +1,121 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+use flex_chat::user::{ServerBan, ServerBanKind};
+
+use super::{RplEndofstatsReply, RplStatsglineReply, RplStatsklineReply};
+use crate::features::chat::chathistory::FailCommandResponse;
+
+command_response! {
+	/// Application (ou retrait) d'un bannissement du serveur.
+	struct SERVER_BAN<'ban>
+	{
+		/// Le bannissement.
+		ban: &'ban ServerBan,
+		/// Le bannissement est-il appliqué?
+		added: bool,
+	}
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ServerBanClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'application (ou le retrait) d'un bannissement du
+	/// serveur.
+	fn emit_server_ban(&self, ban: &ServerBan, added: bool)
+	{
+		let origin = Origin::from(self.client());
+		let server_ban_command = ServerBanCommandResponse {
+			origin: &origin,
+			tags: ServerBanCommandResponse::default_tags(),
+			ban,
+			added,
+		};
+		self.emit(server_ban_command.name(), server_ban_command);
+	}
+
+	/// Émet au client un échec d'une commande de bannissement du serveur.
+	fn send_fail_server_ban(
+		&self,
+		command: &str,
+		code: &str,
+		context: &str,
+		description: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let fail_command = FailCommandResponse {
+			origin: &origin,
+			tags: FailCommandResponse::default_tags(),
+			command,
+			code,
+			context: &[context],
+			description,
+		};
+		self.emit(fail_command.name(), fail_command);
+	}
+
+	/// Émet au client les bannissements du serveur, en réponse à une requête
+	/// STATS.
+	fn send_rpl_stats_server_bans(&self, query: char, bans: &[ServerBan])
+	{
+		let origin = Origin::from(self.client());
+
+		for ban in bans {
+			let expires_at = ban
+				.expires_at
+				.map(|expires_at| expires_at.timestamp())
+				.unwrap_or_default();
+
+			if ban.kind == ServerBanKind::Kline {
+				let rpl_statskline = RplStatsklineReply {
+					origin: &origin,
+					tags: RplStatsklineReply::default_tags(),
+					mask: &ban.mask,
+					expires_at: &expires_at,
+					updated_by: &ban.updated_by,
+					reason: &ban.reason,
+				};
+				self.emit(rpl_statskline.name(), rpl_statskline);
+				continue;
+			}
+
+			let rpl_statsgline = RplStatsglineReply {
+				origin: &origin,
+				tags: RplStatsglineReply::default_tags(),
+				letter: &ban.kind.letter(),
+				mask: &ban.mask,
+				expires_at: &expires_at,
+				updated_by: &ban.updated_by,
+				reason: &ban.reason,
+			};
+			self.emit(rpl_statsgline.name(), rpl_statsgline);
+		}
+
+		let rpl_endofstats = RplEndofstatsReply {
+			origin: &origin,
+			tags: RplEndofstatsReply::default_tags(),
+			query: &query,
+		};
+		self.emit(rpl_endofstats.name(), rpl_endofstats);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ServerBanClientSocketCommandResponseInterface for Socket<'s> {}
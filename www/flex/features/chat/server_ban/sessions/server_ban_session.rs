@@ -0,0 +1,136 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use dashmap::DashMap;
+use flex_chat::user::{ServerBan, ServerBanKind};
+use flex_web_framework::types::time;
+use tokio::sync::OnceCell;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Les bannissements du serveur (KLINE, GLINE, ZLINE, SHUN).
+#[derive(Default)]
+pub struct ServerBanSession
+{
+	/// Les bannissements, par type et masque.
+	bans: DashMap<(ServerBanKind, String), ServerBan>,
+	/// Les bannissements ont-ils été chargés depuis la base de données?
+	loaded: OnceCell<()>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ServerBanSession
+{
+	/// Ajoute les bannissements chargés depuis la base de données. Le
+	/// chargement n'a lieu qu'une seule fois : les appels concurrents
+	/// attendent la fin du chargement en cours. En cas d'erreur, les
+	/// bannissements seront chargés à nouveau lors du prochain appel.
+	pub async fn load<E>(
+		&self,
+		server_bans: impl Future<Output = Result<Vec<ServerBan>, E>>,
+	) -> Result<(), E>
+	{
+		self.loaded
+			.get_or_try_init(|| {
+				async {
+					for server_ban in server_bans.await? {
+						self.add(server_ban);
+					}
+					Ok(())
+				}
+			})
+			.await
+			.map(|_| ())
+	}
+
+	/// Ajoute un bannissement. Retourne `false` si un bannissement du même
+	/// type existe déjà pour ce masque.
+	pub fn add(&self, server_ban: ServerBan) -> bool
+	{
+		let key = (server_ban.kind, server_ban.mask.clone());
+
+		if self
+			.bans
+			.get(&key)
+			.filter(|ban| !ban.is_expired(time::Utc::now()))
+			.is_some()
+		{
+			return false;
+		}
+
+		self.bans.insert(key, server_ban);
+		true
+	}
+
+	/// Retire un bannissement.
+	pub fn remove(&self, kind: ServerBanKind, mask: &str) -> Option<ServerBan>
+	{
+		self.bans
+			.remove(&(kind, mask.to_owned()))
+			.map(|(_, server_ban)| server_ban)
+	}
+
+	/// Retire les bannissements expirés.
+	pub fn remove_expired(&self) -> Vec<ServerBan>
+	{
+		let now = time::Utc::now();
+
+		let expired: Vec<_> = self
+			.bans
+			.iter()
+			.filter(|ban| ban.is_expired(now))
+			.map(|ban| ban.key().clone())
+			.collect();
+
+		expired
+			.into_iter()
+			.filter_map(|key| self.bans.remove(&key))
+			.map(|(_, server_ban)| server_ban)
+			.collect()
+	}
+
+	/// Les bannissements d'un type, n'ayant pas expiré, du plus ancien au
+	/// plus récent.
+	pub fn list(&self, kind: ServerBanKind) -> Vec<ServerBan>
+	{
+		let now = time::Utc::now();
+
+		let mut server_bans: Vec<_> = self
+			.bans
+			.iter()
+			.filter(|ban| ban.kind == kind && !ban.is_expired(now))
+			.map(|ban| ban.value().clone())
+			.collect();
+		server_bans.sort_by_key(|ban| ban.updated_at);
+		server_bans
+	}
+
+	/// Le premier bannissement, n'ayant pas expiré, d'un des types donnés
+	/// validant le prédicat.
+	pub fn find(
+		&self,
+		kinds: &[ServerBanKind],
+		predicate: impl Fn(&ServerBan) -> bool,
+	) -> Option<ServerBan>
+	{
+		let now = time::Utc::now();
+
+		self.bans
+			.iter()
+			.filter(|ban| kinds.contains(&ban.kind) && !ban.is_expired(now))
+			.find(|ban| predicate(ban))
+			.map(|ban| ban.value().clone())
+	}
+}
@@ -0,0 +1,205 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	CHANNEL_MODE_LIST_BAN,
+	CHANNEL_MODE_LIST_BAN_EXCEPT,
+	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
+	CHANNEL_MODE_SETTINGS_AUDITORIUM,
+	CHANNEL_MODE_SETTINGS_DELAYED_JOIN,
+	CHANNEL_MODE_SETTINGS_FLOOD,
+	CHANNEL_MODE_SETTINGS_INVITE_ONLY,
+	CHANNEL_MODE_SETTINGS_KEY,
+	CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS,
+	CHANNEL_MODE_SETTINGS_MODERATE,
+	CHANNEL_MODE_SETTINGS_NO_COLORS,
+	CHANNEL_MODE_SETTINGS_NO_CTCP,
+	CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES,
+	CHANNEL_MODE_SETTINGS_NO_KNOCK,
+	CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE,
+	CHANNEL_MODE_SETTINGS_NO_NOTICE,
+	CHANNEL_MODE_SETTINGS_NOTOPIC,
+	CHANNEL_MODE_SETTINGS_OPERONLY,
+	CHANNEL_MODE_SETTINGS_PERMANENT,
+	CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED,
+	CHANNEL_MODE_SETTINGS_REGISTERED_ONLY,
+	CHANNEL_MODE_SETTINGS_SECRET,
+	CHANNEL_MODE_SETTINGS_SECURE_ONLY,
+	CHANNEL_MODE_SETTINGS_TOPIC_LOCK,
+	ChannelAccessLevel,
+	ChannelsSessionInterface,
+};
+use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_chat::mode::{
+	EXTBAN_ACCOUNT,
+	EXTBAN_CHANNEL,
+	EXTBAN_NICK,
+	EXTBAN_PREFIX,
+	EXTBAN_QUIET,
+	EXTBAN_REALNAME,
+};
+
+use super::{
+	LusersClientsSessionInterface,
+	ServerInfoClientSocketCommandResponseInterface,
+	ServerInfoClientSocketErrorRepliesInterface,
+};
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::monitor::MONITOR_LIMIT;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ServerInfoApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Les lignes décrivant le logiciel du serveur (INFO).
+	fn info(&self) -> Vec<String>;
+
+	/// Les jetons annoncés aux clients via RPL_ISUPPORT.
+	fn isupport_tokens(&self, config: &FlexChatConfig) -> Vec<String>;
+
+	/// Les lignes du message du jour (MOTD), lues depuis le fichier défini
+	/// dans la configuration.
+	fn motd(&self, config: &FlexChatConfig) -> Option<Vec<String>>;
+
+	/// Émet au client les statistiques du serveur (LUSERS).
+	fn send_lusers(&self, client_socket: &Self::ClientSocket<'_>);
+
+	/// Émet au client le message du jour (MOTD).
+	fn send_motd(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		config: &FlexChatConfig,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ServerInfoApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn info(&self) -> Vec<String>
+	{
+		vec![
+			format!(
+				"{} v{}",
+				env!("CARGO_PKG_NAME"),
+				env!("CARGO_PKG_VERSION")
+			),
+			String::from("Copyright: (c) 2024, Mike 'PhiSyX' S."),
+			String::from("SPDX-License-Identifier: MPL-2.0"),
+		]
+	}
+
+	fn isupport_tokens(&self, config: &FlexChatConfig) -> Vec<String>
+	{
+		let (prefix_letters, prefix_symbols): (String, String) =
+			ChannelAccessLevel::ALL
+				.iter()
+				.map(|access_level| {
+					(access_level.letter(), access_level.symbol())
+				})
+				.unzip();
+
+		let settings: String = [
+			CHANNEL_MODE_SETTINGS_NO_COLORS,
+			CHANNEL_MODE_SETTINGS_NO_CTCP,
+			CHANNEL_MODE_SETTINGS_DELAYED_JOIN,
+			CHANNEL_MODE_SETTINGS_INVITE_ONLY,
+			CHANNEL_MODE_SETTINGS_NO_KNOCK,
+			CHANNEL_MODE_SETTINGS_TOPIC_LOCK,
+			CHANNEL_MODE_SETTINGS_MODERATE,
+			CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED,
+			CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES,
+			CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE,
+			CHANNEL_MODE_SETTINGS_OPERONLY,
+			CHANNEL_MODE_SETTINGS_PERMANENT,
+			CHANNEL_MODE_SETTINGS_REGISTERED_ONLY,
+			CHANNEL_MODE_SETTINGS_SECRET,
+			CHANNEL_MODE_SETTINGS_NOTOPIC,
+			CHANNEL_MODE_SETTINGS_NO_NOTICE,
+			CHANNEL_MODE_SETTINGS_AUDITORIUM,
+			CHANNEL_MODE_SETTINGS_SECURE_ONLY,
+		]
+		.iter()
+		.collect();
+
+		let extbans: String = [
+			EXTBAN_ACCOUNT,
+			EXTBAN_CHANNEL,
+			EXTBAN_NICK,
+			EXTBAN_QUIET,
+			EXTBAN_REALNAME,
+		]
+		.iter()
+		.collect();
+
+		let chanmodes = format!(
+			"{}{}{}{},{},{}{},{settings}",
+			CHANNEL_MODE_LIST_BAN,
+			CHANNEL_MODE_LIST_BAN_EXCEPT,
+			CHANNEL_MODE_LIST_INVITE_EXCEPT,
+			CHANNEL_MODE_LIST_QUIET,
+			CHANNEL_MODE_SETTINGS_KEY,
+			CHANNEL_MODE_SETTINGS_FLOOD,
+			CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS,
+		);
+
+		vec![
+			format!("CHANMODES={chanmodes}"),
+			format!("CHANNELLEN={}", config.isupport.channellen),
+			format!("CHATHISTORY={}", config.history.max_limit),
+			format!("CHANTYPES={}", config.isupport.chantypes),
+			format!("EXTBAN={EXTBAN_PREFIX},{extbans}"),
+			String::from("KNOCK"),
+			format!("MONITOR={MONITOR_LIMIT}"),
+			String::from("MSGREFTYPES=msgid,timestamp"),
+			format!("NETWORK={}", config.network.name),
+			format!("NICKLEN={}", config.isupport.nicklen),
+			format!("PREFIX=({prefix_letters}){prefix_symbols}"),
+			String::from("WHOX"),
+		]
+	}
+
+	fn motd(&self, config: &FlexChatConfig) -> Option<Vec<String>>
+	{
+		let path = config.motd.as_ref()?;
+		let content = std::fs::read_to_string(path).ok()?;
+		Some(content.lines().map(ToOwned::to_owned).collect())
+	}
+
+	fn send_lusers(&self, client_socket: &Self::ClientSocket<'_>)
+	{
+		let lusers = self.clients.lusers();
+		let channels = self.channels.list().count();
+		client_socket.send_rpl_lusers(&lusers, channels);
+	}
+
+	fn send_motd(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		config: &FlexChatConfig,
+	)
+	{
+		let Some(lines) = self.motd(config) else {
+			client_socket.send_err_nomotd();
+			return;
+		};
+		client_socket.send_rpl_motd(&config.server.name, &lines);
+	}
+}
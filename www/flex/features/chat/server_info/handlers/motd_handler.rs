@@ -0,0 +1,60 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::server_info::*;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct MotdHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for MotdHandler
+{
+	type App = ChatApplication;
+	type Data = MotdCommandFormData;
+
+	const EVENT_NAME: &'static str = "MOTD";
+
+	/// La commande MOTD est utilisée pour obtenir le message du jour du
+	/// serveur.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<MotdCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>()
+		else {
+			return;
+		};
+
+		if let Some(target) = data.target.as_deref()
+			&& !target.eq_ignore_ascii_case(&config.server.name)
+		{
+			client_socket.send_err_nosuchserver(target);
+			return;
+		}
+
+		app.send_motd(&client_socket, config);
+	}
+}
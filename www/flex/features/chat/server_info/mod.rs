@@ -0,0 +1,63 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod admin_handler use *;
+		pub mod info_handler use *;
+		pub mod lusers_handler use *;
+		pub mod motd_handler use *;
+		pub mod time_handler use *;
+		pub mod version_handler use *;
+	};
+
+	pub mod sessions use {
+		pub mod lusers_session use *;
+	};
+
+	mod errors use {
+		pub(super) mod err_nomotd use *;
+		pub(super) mod err_nosuchserver use *;
+	};
+
+	mod forms use {
+		pub(super) mod admin_form use *;
+		pub(super) mod info_form use *;
+		pub(super) mod lusers_form use *;
+		pub(super) mod motd_form use *;
+		pub(super) mod time_form use *;
+		pub(super) mod version_form use *;
+	};
+
+	pub(crate) mod responses use {
+		pub(crate) mod server_info_command_response use *;
+		pub(crate) mod server_info_error_response use *;
+		pub(super) mod rpl_adminemail use *;
+		pub(super) mod rpl_adminloc1 use *;
+		pub(super) mod rpl_adminloc2 use *;
+		pub(super) mod rpl_adminme use *;
+		pub(super) mod rpl_endofinfo use *;
+		pub(super) mod rpl_endofmotd use *;
+		pub(super) mod rpl_info use *;
+		pub(super) mod rpl_isupport use *;
+		pub(super) mod rpl_luserchannels use *;
+		pub(super) mod rpl_luserclient use *;
+		pub(super) mod rpl_luserme use *;
+		pub(super) mod rpl_luserop use *;
+		pub(super) mod rpl_luserunknown use *;
+		pub(super) mod rpl_motd use *;
+		pub(super) mod rpl_motdstart use *;
+		pub(super) mod rpl_myinfo use *;
+		pub(super) mod rpl_time use *;
+		pub(super) mod rpl_version use *;
+	};
+}
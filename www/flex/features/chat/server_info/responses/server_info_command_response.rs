@@ -0,0 +1,352 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	CHANNEL_MODE_LIST_BAN,
+	CHANNEL_MODE_LIST_BAN_EXCEPT,
+	CHANNEL_MODE_LIST_INVITE_EXCEPT,
+	CHANNEL_MODE_LIST_QUIET,
+	CHANNEL_MODE_SETTINGS_DELAYED_JOIN,
+	CHANNEL_MODE_SETTINGS_FLOOD,
+	CHANNEL_MODE_SETTINGS_INVITE_ONLY,
+	CHANNEL_MODE_SETTINGS_KEY,
+	CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS,
+	CHANNEL_MODE_SETTINGS_MODERATE,
+	CHANNEL_MODE_SETTINGS_NO_COLORS,
+	CHANNEL_MODE_SETTINGS_NO_CTCP,
+	CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES,
+	CHANNEL_MODE_SETTINGS_NO_KNOCK,
+	CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE,
+	CHANNEL_MODE_SETTINGS_NO_NOTICE,
+	CHANNEL_MODE_SETTINGS_NOTOPIC,
+	CHANNEL_MODE_SETTINGS_OPERONLY,
+	CHANNEL_MODE_SETTINGS_PERMANENT,
+	CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED,
+	CHANNEL_MODE_SETTINGS_REGISTERED_ONLY,
+	CHANNEL_MODE_SETTINGS_SECRET,
+	CHANNEL_MODE_SETTINGS_SECURE_ONLY,
+	ChannelAccessLevel,
+};
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::user::{
+	USER_FLAG_AWAY,
+	USER_FLAG_GLOBAL_OPERATOR,
+	USER_FLAG_INVISIBLE,
+	USER_FLAG_LOCAL_OPERATOR,
+	USER_FLAG_NOKICK,
+	USER_FLAG_SECURE,
+	USER_FLAG_SERVER_NOTICES,
+	USER_FLAG_WALLOPS,
+};
+use flex_web_framework::types::time;
+
+use super::{
+	RplAdminemailReply,
+	RplAdminloc1Reply,
+	RplAdminloc2Reply,
+	RplAdminmeReply,
+	RplEndofinfoReply,
+	RplEndofmotdReply,
+	RplInfoReply,
+	RplIsupportReply,
+	RplLuserchannelsReply,
+	RplLuserclientReply,
+	RplLusermeReply,
+	RplLuseropReply,
+	RplLuserunknownReply,
+	RplMotdReply,
+	RplMotdstartReply,
+	RplMyinfoReply,
+	RplTimeReply,
+	RplVersionReply,
+};
+use crate::features::chat::server_info::Lusers;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Nombre maximum de jetons par réponse RPL_ISUPPORT.
+const ISUPPORT_TOKENS_PER_REPLY: usize = 13;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ServerInfoClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client les informations administratives du serveur.
+	fn send_rpl_admin(
+		&self,
+		servername: &str,
+		name: &str,
+		description: &str,
+		email: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+
+		let rpl_adminme = RplAdminmeReply {
+			origin: &origin,
+			tags: RplAdminmeReply::default_tags(),
+			servername,
+		};
+		self.emit(rpl_adminme.name(), rpl_adminme);
+
+		let rpl_adminloc1 = RplAdminloc1Reply {
+			origin: &origin,
+			tags: RplAdminloc1Reply::default_tags(),
+			name,
+		};
+		self.emit(rpl_adminloc1.name(), rpl_adminloc1);
+
+		let rpl_adminloc2 = RplAdminloc2Reply {
+			origin: &origin,
+			tags: RplAdminloc2Reply::default_tags(),
+			description,
+		};
+		self.emit(rpl_adminloc2.name(), rpl_adminloc2);
+
+		let rpl_adminemail = RplAdminemailReply {
+			origin: &origin,
+			tags: RplAdminemailReply::default_tags(),
+			email,
+		};
+		self.emit(rpl_adminemail.name(), rpl_adminemail);
+	}
+
+	/// Émet au client les informations sur le logiciel du serveur.
+	fn send_rpl_info(&self, lines: &[String])
+	{
+		let origin = Origin::from(self.client());
+
+		for line in lines {
+			let rpl_info = RplInfoReply {
+				origin: &origin,
+				tags: RplInfoReply::default_tags(),
+				line,
+			};
+			self.emit(rpl_info.name(), rpl_info);
+		}
+
+		let rpl_endofinfo = RplEndofinfoReply {
+			origin: &origin,
+			tags: RplEndofinfoReply::default_tags(),
+		};
+		self.emit(rpl_endofinfo.name(), rpl_endofinfo);
+	}
+
+	/// Émet au client les jetons supportés par le serveur, en plusieurs
+	/// réponses si nécessaire.
+	fn send_rpl_isupport(&self, tokens: &[String])
+	{
+		let origin = Origin::from(self.client());
+
+		for chunk in tokens.chunks(ISUPPORT_TOKENS_PER_REPLY) {
+			let tokens = chunk.join(" ");
+			let rpl_isupport = RplIsupportReply {
+				origin: &origin,
+				tags: RplIsupportReply::default_tags(),
+				tokens: &tokens,
+			};
+			self.emit(rpl_isupport.name(), rpl_isupport);
+		}
+	}
+
+	/// Émet au client les statistiques du serveur.
+	fn send_rpl_lusers(&self, lusers: &Lusers, channels: usize)
+	{
+		let origin = Origin::from(self.client());
+		let servers = 1;
+
+		let rpl_luserclient = RplLuserclientReply {
+			origin: &origin,
+			tags: RplLuserclientReply::default_tags(),
+			users: &lusers.users,
+			invisible: &lusers.invisible,
+			servers: &servers,
+		};
+		self.emit(rpl_luserclient.name(), rpl_luserclient);
+
+		if lusers.operators > 0 {
+			let rpl_luserop = RplLuseropReply {
+				origin: &origin,
+				tags: RplLuseropReply::default_tags(),
+				operators: &lusers.operators,
+			};
+			self.emit(rpl_luserop.name(), rpl_luserop);
+		}
+
+		if lusers.unknown > 0 {
+			let rpl_luserunknown = RplLuserunknownReply {
+				origin: &origin,
+				tags: RplLuserunknownReply::default_tags(),
+				unknown: &lusers.unknown,
+			};
+			self.emit(rpl_luserunknown.name(), rpl_luserunknown);
+		}
+
+		if channels > 0 {
+			let rpl_luserchannels = RplLuserchannelsReply {
+				origin: &origin,
+				tags: RplLuserchannelsReply::default_tags(),
+				channels: &channels,
+			};
+			self.emit(rpl_luserchannels.name(), rpl_luserchannels);
+		}
+
+		let clients = lusers.clients();
+		let rpl_luserme = RplLusermeReply {
+			origin: &origin,
+			tags: RplLusermeReply::default_tags(),
+			clients: &clients,
+			servers: &servers,
+		};
+		self.emit(rpl_luserme.name(), rpl_luserme);
+	}
+
+	/// Émet au client le message du jour (MOTD).
+	fn send_rpl_motd(&self, servername: &str, lines: &[String])
+	{
+		let origin = Origin::from(self.client());
+
+		let rpl_motdstart = RplMotdstartReply {
+			origin: &origin,
+			tags: RplMotdstartReply::default_tags(),
+			servername,
+		};
+		self.emit(rpl_motdstart.name(), rpl_motdstart);
+
+		for line in lines {
+			let rpl_motd = RplMotdReply {
+				origin: &origin,
+				tags: RplMotdReply::default_tags(),
+				line,
+			};
+			self.emit(rpl_motd.name(), rpl_motd);
+		}
+
+		let rpl_endofmotd = RplEndofmotdReply {
+			origin: &origin,
+			tags: RplEndofmotdReply::default_tags(),
+		};
+		self.emit(rpl_endofmotd.name(), rpl_endofmotd);
+	}
+
+	/// Émet au client les réponses de connexion. 4) RPL_MYINFO
+	fn send_rpl_myinfo(&self, servername: &str)
+	{
+		let origin = Origin::from(self.client());
+		let program_version = format!("v{}", env!("CARGO_PKG_VERSION"));
+
+		let user_modes: String = [
+			USER_FLAG_AWAY,
+			USER_FLAG_INVISIBLE,
+			USER_FLAG_LOCAL_OPERATOR,
+			USER_FLAG_GLOBAL_OPERATOR,
+			USER_FLAG_NOKICK,
+			USER_FLAG_SERVER_NOTICES,
+			USER_FLAG_WALLOPS,
+			USER_FLAG_SECURE,
+		]
+		.iter()
+		.collect();
+
+		let access_levels = ChannelAccessLevel::ALL.map(|al| al.letter());
+		let lists = [
+			CHANNEL_MODE_LIST_BAN,
+			CHANNEL_MODE_LIST_BAN_EXCEPT,
+			CHANNEL_MODE_LIST_INVITE_EXCEPT,
+			CHANNEL_MODE_LIST_QUIET,
+		];
+		let settings_with_param = [
+			CHANNEL_MODE_SETTINGS_KEY,
+			CHANNEL_MODE_SETTINGS_FLOOD,
+			CHANNEL_MODE_SETTINGS_LIMIT_MEMBERS,
+		];
+		let settings = [
+			CHANNEL_MODE_SETTINGS_NO_COLORS,
+			CHANNEL_MODE_SETTINGS_NO_CTCP,
+			CHANNEL_MODE_SETTINGS_DELAYED_JOIN,
+			CHANNEL_MODE_SETTINGS_INVITE_ONLY,
+			CHANNEL_MODE_SETTINGS_NO_KNOCK,
+			CHANNEL_MODE_SETTINGS_MODERATE,
+			CHANNEL_MODE_SETTINGS_REGISTERED_MODERATED,
+			CHANNEL_MODE_SETTINGS_NO_EXTERNAL_MESSAGES,
+			CHANNEL_MODE_SETTINGS_NO_NICK_CHANGE,
+			CHANNEL_MODE_SETTINGS_OPERONLY,
+			CHANNEL_MODE_SETTINGS_PERMANENT,
+			CHANNEL_MODE_SETTINGS_REGISTERED_ONLY,
+			CHANNEL_MODE_SETTINGS_SECRET,
+			CHANNEL_MODE_SETTINGS_NOTOPIC,
+			CHANNEL_MODE_SETTINGS_NO_NOTICE,
+			CHANNEL_MODE_SETTINGS_SECURE_ONLY,
+		];
+
+		let channel_modes: String = lists
+			.iter()
+			.chain(settings_with_param.iter())
+			.chain(settings.iter())
+			.chain(access_levels.iter())
+			.collect();
+		let channel_modes_with_param: String = lists
+			.iter()
+			.chain(settings_with_param.iter())
+			.chain(access_levels.iter())
+			.collect();
+
+		let rpl_myinfo = RplMyinfoReply {
+			origin: &origin,
+			tags: RplMyinfoReply::default_tags(),
+			servername,
+			version: &program_version,
+			user_modes: &user_modes,
+			channel_modes: &channel_modes,
+			channel_modes_with_param: &channel_modes_with_param,
+		};
+		self.emit(rpl_myinfo.name(), rpl_myinfo);
+	}
+
+	/// Émet au client l'heure locale du serveur.
+	fn send_rpl_time(&self, servername: &str)
+	{
+		let origin = Origin::from(self.client());
+		let time = time::Utc::now().to_rfc2822();
+		let rpl_time = RplTimeReply {
+			origin: &origin,
+			tags: RplTimeReply::default_tags(),
+			servername,
+			time: &time,
+		};
+		self.emit(rpl_time.name(), rpl_time);
+	}
+
+	/// Émet au client la version du serveur.
+	fn send_rpl_version(&self, servername: &str, comments: &str)
+	{
+		let origin = Origin::from(self.client());
+		let program_version = format!("v{}", env!("CARGO_PKG_VERSION"));
+		let rpl_version = RplVersionReply {
+			origin: &origin,
+			tags: RplVersionReply::default_tags(),
+			version: &program_version,
+			servername,
+			comments,
+		};
+		self.emit(rpl_version.name(), rpl_version);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ServerInfoClientSocketCommandResponseInterface for Socket<'s> {}
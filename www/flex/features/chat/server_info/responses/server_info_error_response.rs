@@ -0,0 +1,53 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::server_info::{
+	ErrNomotdError,
+	ErrNosuchserverError,
+};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait ServerInfoClientSocketErrorRepliesInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'erreur [ErrNomotdError].
+	fn send_err_nomotd(&self)
+	{
+		let origin = Origin::from(self.client());
+		let err_nomotd = ErrNomotdError {
+			origin: &origin,
+			tags: ErrNomotdError::default_tags(),
+		};
+		self.emit(err_nomotd.name(), err_nomotd);
+	}
+
+	/// Émet au client l'erreur [ErrNosuchserverError].
+	fn send_err_nosuchserver(&self, servername: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_nosuchserver = ErrNosuchserverError {
+			origin: &origin,
+			servername,
+			tags: ErrNosuchserverError::default_tags(),
+		};
+		self.emit(err_nosuchserver.name(), err_nosuchserver);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> ServerInfoClientSocketErrorRepliesInterface for Socket<'s> {}
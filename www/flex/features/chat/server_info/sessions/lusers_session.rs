@@ -0,0 +1,90 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientInterface;
+use flex_chat::user::{UserFlagInterface, UserOperatorInterface};
+
+use crate::features::chat::sessions::ClientsSession;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Statistiques des clients de session (LUSERS).
+#[derive(Debug, Default)]
+pub struct Lusers
+{
+	/// Nombre de clients enregistrés et visibles.
+	pub users: usize,
+	/// Nombre de clients enregistrés ayant le drapeau invisible.
+	pub invisible: usize,
+	/// Nombre d'opérateurs (globaux et locaux) en ligne.
+	pub operators: usize,
+	/// Nombre de connexions non enregistrées.
+	pub unknown: usize,
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait LusersClientsSessionInterface
+{
+	/// Compte les clients de la session.
+	fn lusers(&self) -> Lusers;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Lusers
+{
+	/// Nombre total de clients enregistrés.
+	pub fn clients(&self) -> usize
+	{
+		self.users + self.invisible
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LusersClientsSessionInterface for ClientsSession
+{
+	fn lusers(&self) -> Lusers
+	{
+		let mut lusers = Lusers::default();
+
+		for client in self.clients.iter() {
+			if !client.is_connected() {
+				continue;
+			}
+
+			if !client.is_registered() {
+				lusers.unknown += 1;
+				continue;
+			}
+
+			if client.user().has_invisible_flag() {
+				lusers.invisible += 1;
+			} else {
+				lusers.users += 1;
+			}
+
+			if client.user().is_operator() {
+				lusers.operators += 1;
+			}
+		}
+
+		lusers
+	}
+}
@@ -114,7 +114,12 @@ impl ChannelsSessionInterface for ChannelsSession
 	{
 		let mut channel_entity = self.get_mut(channel_id)?;
 
-		if channel_entity.members().is_empty() {
+		// NOTE: les niveaux d'accès des membres d'un salon enregistré sont
+		//       restaurés à partir de leur compte, lorsqu'ils rejoignent le
+		//       salon.
+		if channel_entity.is_registered() {
+			channel_entity.add_member(member_id, ChannelMember::new(member_id));
+		} else if channel_entity.members().is_empty() {
 			channel_entity.add_member(
 				member_id,
 				ChannelMember::new(member_id)
@@ -248,7 +253,12 @@ impl ChannelsSessionInterface for ChannelsSession
 	{
 		let mut channel_entity = self.get_mut(channel_id)?;
 		channel_entity.members_mut().remove(member_id);
-		if channel_entity.members().is_empty() {
+		// NOTE: un salon enregistré ou permanent est conservé, même sans
+		//       membres.
+		if channel_entity.members().is_empty()
+			&& !channel_entity.is_registered()
+			&& !channel_entity.is_permanent()
+		{
 			drop(channel_entity);
 			self.remove(channel_id);
 			return None;
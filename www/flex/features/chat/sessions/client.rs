@@ -8,6 +8,8 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::HashSet;
+
 use dashmap::mapref::multiple::RefMutMulti;
 use dashmap::{DashMap, DashSet};
 use flex_chat::client::{
@@ -21,8 +23,14 @@ use flex_chat::client::{
 };
 use flex_chat::user::{UserInterface, UserOperatorInterface};
 
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
 use crate::features::ChatApplication;
-use crate::features::chat::oper::OperClientSocketErrorRepliesInterface;
+use crate::features::chat::monitor::MonitorSession;
+use crate::features::chat::oper::{
+	OperClientSessionInterface,
+	OperClientSocketErrorRepliesInterface,
+};
+use crate::features::chat::whois::WhowasSession;
 
 // ---- //
 // Type //
@@ -42,6 +50,12 @@ pub struct ClientsSession
 	pub blocklist: DashMap<BlockedByID, DashSet<BlockedID>>,
 	/// Les clients de session.
 	pub clients: DashMap<ClientID, Client>,
+	/// Les listes de surveillance (MONITOR) des clients.
+	pub monitor: MonitorSession,
+	/// Les privilèges des clients opérateurs, issus de leur classe.
+	pub operators: DashMap<ClientID, HashSet<FlexChatConfigOperatorPrivilege>>,
+	/// Historique des identités passées des clients (WHOWAS).
+	pub whowas: WhowasSession,
 }
 
 // -------------- //
@@ -81,6 +95,26 @@ impl ChatApplication
 		Some(client_socket)
 	}
 
+	/// Récupère le client courant (immuable) à partir d'une socket, à
+	/// condition qu'il soit opérateur et que sa classe lui accorde le
+	/// privilège donné.
+	pub fn current_client_operator_with_privilege<'a>(
+		&'a self,
+		socket: &'a socketioxide::extract::SocketRef,
+		privilege: FlexChatConfigOperatorPrivilege,
+	) -> Option<Socket<'a>>
+	{
+		let client_socket = self.current_client_operator(socket)?;
+
+		if !self.clients.has_operator_privilege(client_socket.cid(), privilege)
+		{
+			client_socket.send_err_noprivileges();
+			return None;
+		}
+
+		Some(client_socket)
+	}
+
 	/// Récupère le client courant (mutable) à partir d'une socket.
 	pub fn current_client_mut<'a>(
 		&'a self,
@@ -107,6 +141,21 @@ impl ChatApplication
 		})
 	}
 
+	/// Cherche un [Socket] à partir de l'ID d'un client.
+	pub fn find_socket_by_id(
+		&self,
+		socket: &socketioxide::extract::SocketRef,
+		client_id: &ClientID,
+	) -> Option<Socket<'_>>
+	{
+		let client = self.clients.get(client_id)?;
+		let socket = socket.broadcast().get_socket(*client.maybe_sid()?)?;
+		Some(Socket::Owned {
+			client: Box::new(client),
+			socket,
+		})
+	}
+
 	/// Récupère un client à partir de son ID.
 	pub fn get_client_mut_by_id(
 		&self,
@@ -142,6 +191,15 @@ impl ClientsSession
 				.then_some(client.clone())
 		})
 	}
+
+	/// Met à jour la date de la dernière activité d'un client.
+	pub fn update_last_activity(&self, client_id: &ClientID)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+		client.update_last_activity();
+	}
 }
 
 // -------------- //
@@ -208,6 +266,7 @@ impl ClientsSessionInterface for ClientsSession
 		client_id: &<Self::Client as ClientInterface>::ClientID,
 	) -> Option<(<Self::Client as ClientInterface>::ClientID, Self::Client)>
 	{
+		self.operators.remove(client_id);
 		self.clients.remove(client_id)
 	}
 
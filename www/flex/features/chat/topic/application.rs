@@ -44,6 +44,15 @@ pub trait TopicApplicationInterface
 		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
 		topic: impl AsRef<str>,
 	);
+
+	/// Restaure un sujet de l'historique d'un salon, à partir de sa position
+	/// (à partir de 1).
+	fn restore_topic(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		position: usize,
+	) -> bool;
 }
 
 // -------------- //
@@ -108,4 +117,31 @@ impl TopicApplicationInterface for ChatApplication
 
 		client_socket.send_rpl_topic(&channel, true);
 	}
+
+	fn restore_topic(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		position: usize,
+	) -> bool
+	{
+		let Some(index) = position.checked_sub(1) else {
+			return false;
+		};
+
+		if self
+			.channels
+			.restore_topic(channel_name, index, client_socket.user().nickname())
+			.is_none()
+		{
+			return false;
+		}
+
+		let Some(channel) = self.get_channel(channel_name) else {
+			return false;
+		};
+
+		client_socket.send_rpl_topic(&channel, true);
+		true
+	}
 }
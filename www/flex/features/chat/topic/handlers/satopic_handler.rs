@@ -0,0 +1,78 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::client::channel::responses::ChannelClientSocketErrorReplies;
+use flex_chat::user::UserInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfigOperatorPrivilege;
+use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
+use crate::features::chat::oper::OperClientSocketCommandResponse;
+use crate::features::chat::topic::{
+	SatopicCommandFormData,
+	TopicApplicationInterface,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct SatopicHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for SatopicHandler
+{
+	type App = ChatApplication;
+	type Data = SatopicCommandFormData;
+
+	const EVENT_NAME: &'static str = "SATOPIC";
+
+	/// La commande SATOPIC permet à un opérateur de modifier le sujet d'un
+	/// salon sans en être membre ni disposer d'un niveau d'accès, y compris
+	/// lorsque le sujet est verrouillé.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<SatopicCommandFormData>,
+	)
+	{
+		let Some(client_socket) = app.current_client_operator_with_privilege(
+			&socket,
+			FlexChatConfigOperatorPrivilege::Satopic,
+		) else {
+			return;
+		};
+
+		let Some(topic) = data.topic.as_deref() else {
+			return;
+		};
+
+		if app.get_channel(&data.channel).is_none() {
+			client_socket.send_err_nosuchchannel(&data.channel);
+			return;
+		}
+
+		app.update_topic(&client_socket, &data.channel, topic);
+		app.save_registered_channel(&socket, &data.channel);
+
+		client_socket.emit_oper_notice(&format!(
+			"{} a utilisé SATOPIC sur {}: {}",
+			client_socket.user().nickname(),
+			data.channel,
+			topic,
+		));
+	}
+}
@@ -12,6 +12,7 @@ use flex_web_framework::WebSocketHandler;
 use socketioxide::extract::{Data, SocketRef, State};
 
 use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
 use crate::features::chat::topic::{
 	TopicApplicationInterface,
 	TopicClientSocketInterface,
@@ -55,6 +56,7 @@ impl WebSocketHandler for TopicHandler
 
 		if let Some(topic) = data.topic.as_deref() {
 			app.update_topic(&client_socket, &data.channel, topic);
+			app.save_registered_channel(&socket, &data.channel);
 		} else {
 			let Some(channel) = app.get_channel(&data.channel) else {
 				return;
@@ -0,0 +1,80 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::ChannelAccessLevel;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::channel_registration::ChannelRegistrationApplicationInterface;
+use crate::features::chat::mode::ModeChannelAccessLevelApplicationInterface;
+use crate::features::chat::oper::OperApplicationInterface;
+use crate::features::chat::topic::{
+	TopicApplicationInterface,
+	TopicClientSocketInterface,
+	TopicHistoryCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct TopicHistoryHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for TopicHistoryHandler
+{
+	type App = ChatApplication;
+	type Data = TopicHistoryCommandFormData;
+
+	const EVENT_NAME: &'static str = "TOPIC HISTORY";
+
+	/// La commande TOPIC HISTORY permet aux opérateurs d'un salon de consulter
+	/// les derniers sujets du salon <channel>. Si le paramètre <restore> est
+	/// présent, le sujet à cette position de l'historique redevient le sujet
+	/// du salon, si cette action est autorisée pour l'utilisateur qui la
+	/// demande. Une position inconnue renvoie l'historique.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<TopicHistoryCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		if !app.is_client_global_operator(&client_socket)
+			&& !app.does_client_have_rights_on_channel(
+				&client_socket,
+				&data.channel,
+				ChannelAccessLevel::HalfOperator,
+			) {
+			return;
+		}
+
+		if let Some(position) = data.restore {
+			if !app.is_client_can_edit_topic(&client_socket, &data.channel) {
+				return;
+			}
+
+			if app.restore_topic(&client_socket, &data.channel, position) {
+				app.save_registered_channel(&socket, &data.channel);
+				return;
+			}
+		}
+
+		let Some(channel) = app.get_channel(&data.channel) else {
+			return;
+		};
+		client_socket.send_rpl_topichistory(&channel);
+	}
+}
@@ -12,7 +12,9 @@ flex_kernel::import! {
 	pub mod application use *;
 
 	pub mod handlers use {
+		pub mod satopic_handler use *;
 		pub mod topic_handler use *;
+		pub mod topic_history_handler use *;
 	};
 
 	pub mod sessions use {
@@ -24,13 +26,17 @@ flex_kernel::import! {
 	};
 
 	mod forms use {
+		pub(super) mod satopic_form use *;
 		pub(super) mod topic_form use *;
+		pub(super) mod topic_history_form use *;
 	};
 
 
 	pub(crate) mod responses use {
 		pub(crate) mod topic_command_response use *;
+		pub(super) mod rpl_endoftopichistory use *;
 		pub(super) mod rpl_notopic use *;
 		pub(super) mod rpl_topic use *;
+		pub(super) mod rpl_topichistory use *;
 	};
 }
@@ -16,7 +16,12 @@ use flex_chat::channel::{
 };
 use flex_chat::client::{ClientSocketInterface, Origin, Socket};
 
-use super::{RplNotopicReply, RplTopicReply};
+use super::{
+	RplEndoftopichistoryReply,
+	RplNotopicReply,
+	RplTopicReply,
+	RplTopichistoryReply,
+};
 
 // --------- //
 // Interface //
@@ -28,6 +33,9 @@ pub trait TopicClientSocketInterface: ClientSocketInterface
 
 	/// Émet au client le sujet du salon.
 	fn send_rpl_topic(&self, channel: &Self::Channel, updated: bool);
+
+	/// Émet au client l'historique des sujets du salon.
+	fn send_rpl_topichistory(&self, channel: &Self::Channel);
 }
 
 // -------------- //
@@ -69,4 +77,29 @@ impl<'s> TopicClientSocketInterface for Socket<'s>
 			}
 		};
 	}
+
+	fn send_rpl_topichistory(&self, channel: &Self::Channel)
+	{
+		let origin = Origin::from(self.client());
+
+		for (index, entry) in channel.topic().history().iter().enumerate() {
+			let rpl_topichistory = RplTopichistoryReply {
+				origin: &origin,
+				tags: RplTopichistoryReply::default_tags(),
+				channel: channel.name(),
+				index: &(index + 1),
+				updated_by: entry.updated_by(),
+				updated_at: &entry.updated_at().timestamp(),
+				topic: entry.topic(),
+			};
+			self.emit(rpl_topichistory.name(), rpl_topichistory);
+		}
+
+		let rpl_endoftopichistory = RplEndoftopichistoryReply {
+			origin: &origin,
+			tags: RplEndoftopichistoryReply::default_tags(),
+			channel: channel.name(),
+		};
+		self.emit(rpl_endoftopichistory.name(), rpl_endoftopichistory);
+	}
 }
@@ -44,6 +44,14 @@ pub trait TopicChannelsSessionInterface: ChannelsSessionInterface
 		topic: impl AsRef<str>,
 		updated_by: impl ToString,
 	) -> Option<<Self::Channel as ChannelTopicInterface>::Topic>;
+
+	/// Restaure un sujet de l'historique d'un salon.
+	fn restore_topic(
+		&self,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		index: usize,
+		updated_by: impl ToString,
+	) -> Option<<Self::Channel as ChannelTopicInterface>::Topic>;
 }
 
 // -------------- //
@@ -65,9 +73,10 @@ impl TopicChannelsSessionInterface for ChannelsSession
 		};
 
 		let topic_flag = channel.modes_settings.has_topic_flag();
+		let topic_lock_flag = channel.modes_settings.has_topic_lock_flag();
 
 		let Some(member) = channel.members().get(client.cid()) else {
-			if topic_flag {
+			if topic_flag || topic_lock_flag {
 				return Err(ChannelTopicError::ERR_CHANOPRIVSNEEDED);
 			}
 			return Ok(());
@@ -75,10 +84,22 @@ impl TopicChannelsSessionInterface for ChannelsSession
 
 		// NOTE(phisyx): une personne bannie, membre du salon, ne peut pas
 		// 				 définir un topic.
-		if channel.is_banned(client.user()) {
+		if channel.is_banned(client.user(), client.channels()) {
 			return Err(ChannelTopicError::ERR_BANNEDFROMCHAN);
 		}
 
+		let level_access =
+			member.access_level().iter().fold(0, |acc, mode| mode.flag() | acc);
+
+		// NOTE: seuls les utilisateurs avec un niveau d'accès minimal à
+		//       AdminOperator peuvent éditer le sujet d'un salon verrouillé.
+		if topic_lock_flag {
+			if level_access < ChannelAccessLevel::AdminOperator.flag() {
+				return Err(ChannelTopicError::ERR_CHANOPRIVSNEEDED);
+			}
+			return Ok(());
+		}
+
 		// NOTE(phisyx): tout le monde peut éditer le sujet du salon si le
 		//               drapeau topic n'est pas définit.
 		if !topic_flag {
@@ -88,9 +109,6 @@ impl TopicChannelsSessionInterface for ChannelsSession
 		// NOTE(phisyx): seuls les utilisateurs avec un niveau d'accès minimal à
 		// 				 HalfOperator peuvent éditer le sujet du salon.
 
-		let level_access =
-			member.access_level().iter().fold(0, |acc, mode| mode.flag() | acc);
-
 		if level_access <= ChannelAccessLevel::Vip.flag() {
 			return Err(ChannelTopicError::ERR_CHANOPRIVSNEEDED);
 		}
@@ -117,4 +135,19 @@ impl TopicChannelsSessionInterface for ChannelsSession
 		}
 		Some(channel.topic().clone())
 	}
+
+	/// Restaure un sujet de l'historique.
+	fn restore_topic(
+		&self,
+		channel_id: &<Self::Channel as ChannelInterface>::RefID<'_>,
+		index: usize,
+		updated_by: impl ToString,
+	) -> Option<<Self::Channel as ChannelTopicInterface>::Topic>
+	{
+		let mut channel = self.get_mut(channel_id)?;
+		if !channel.topic_mut().restore(index, updated_by) {
+			return None;
+		}
+		Some(channel.topic().clone())
+	}
 }
@@ -0,0 +1,260 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Socket};
+use flex_chat::mode::ApplyMode;
+use flex_chat::user::{
+	Flag,
+	ServerNoticeMask,
+	UserFlagInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+use socketioxide::extract::SocketRef;
+
+use super::{ServerNoticeCommandResponse, UserModeClientsSessionInterface};
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::mode::ServerOrigin;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Chambre regroupant les clients ayant le drapeau utilisateur `+w`.
+pub const WALLOPS_ROOM: &str = "wallops";
+
+/// Chambre regroupant les opérateurs ayant le drapeau utilisateur `+w`.
+pub const GLOBOPS_ROOM: &str = "globops";
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Chambre regroupant les opérateurs abonnés à une catégorie de notices du
+/// serveur.
+pub fn server_notice_room(mask: ServerNoticeMask) -> String
+{
+	format!("snomask:{}", mask.letter())
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait UserModeApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Émet une notice du serveur, d'origine le serveur, aux opérateurs
+	/// abonnés à la catégorie donnée. Utilisé lorsque la socket n'a pas (ou
+	/// pas encore) de client, par exemple lors du refus d'une adresse IP
+	/// bannie (ZLINE).
+	fn emit_server_notice_from_server(
+		&self,
+		socket: &SocketRef,
+		mask: ServerNoticeMask,
+		text: &str,
+	);
+
+	/// Rejoint les chambres des drapeaux `+s` et `+w` du client. Une
+	/// reconnexion fait perdre à la socket ses chambres.
+	fn join_user_modes_rooms(&self, client_socket: &Self::ClientSocket<'_>);
+
+	/// Applique les changements des catégories de notices du serveur
+	/// (drapeau `+s`) au client.
+	fn update_server_notices_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		changes: &str,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	);
+
+	/// Applique ou retire le drapeau `+i` du client.
+	fn update_invisible_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		enabled: bool,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	);
+
+	/// Applique ou retire le drapeau `+w` du client.
+	fn update_wallops_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		enabled: bool,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl UserModeApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn emit_server_notice_from_server(
+		&self,
+		socket: &SocketRef,
+		mask: ServerNoticeMask,
+		text: &str,
+	)
+	{
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>()
+		else {
+			return;
+		};
+
+		let origin = ServerOrigin {
+			name: &config.server.name,
+		};
+		let server_notice = ServerNoticeCommandResponse {
+			origin: &origin,
+			tags: ServerNoticeCommandResponse::default_tags(),
+			mask: mask.letter(),
+			text,
+		};
+		_ = socket
+			.to(server_notice_room(mask))
+			.emit(server_notice.name(), server_notice);
+	}
+
+	fn join_user_modes_rooms(&self, client_socket: &Self::ClientSocket<'_>)
+	{
+		let masks = client_socket.user().server_notice_masks();
+		for mask in ServerNoticeMask::ALL {
+			if masks.is_some_and(|masks| masks.contains(mask.letter())) {
+				_ = client_socket.socket().join(server_notice_room(mask));
+			}
+		}
+
+		if client_socket.user().has_wallops_flag() {
+			_ = client_socket.socket().join(WALLOPS_ROOM);
+			if client_socket.user().is_operator() {
+				_ = client_socket.socket().join(GLOBOPS_ROOM);
+			}
+		}
+	}
+
+	fn update_server_notices_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		changes: &str,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	)
+	{
+		let current =
+			client_socket.user().server_notice_masks().map(String::from);
+		let masks = ServerNoticeMask::apply(
+			current.as_deref().unwrap_or_default(),
+			changes,
+		);
+
+		if current.as_deref().unwrap_or_default() == masks {
+			return;
+		}
+
+		for mask in ServerNoticeMask::ALL {
+			let room = server_notice_room(mask);
+			if masks.contains(mask.letter()) {
+				_ = client_socket.socket().join(room);
+			} else {
+				_ = client_socket.socket().leave(room);
+			}
+		}
+
+		self.clients.set_server_notices_flag(client_socket.cid(), &masks);
+
+		client_socket
+			.user_mut()
+			.unset_flag(|flag| matches!(flag, Flag::ServerNotices(_)));
+
+		// NOTE: un masque vide retire le drapeau.
+		if masks.is_empty() {
+			removed.push(ApplyMode::new(Flag::ServerNotices(String::new())));
+			return;
+		}
+
+		let mode = ApplyMode::new(Flag::ServerNotices(masks))
+			.with_update_by(client_socket.user().nickname());
+		client_socket.user_mut().set_flag(mode.clone());
+		added.push(mode);
+	}
+
+	fn update_invisible_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		enabled: bool,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	)
+	{
+		if client_socket.user().has_invisible_flag() == enabled {
+			return;
+		}
+
+		self.clients.set_invisible_flag(client_socket.cid(), enabled);
+
+		if !enabled {
+			client_socket
+				.user_mut()
+				.unset_flag(|flag| matches!(flag, Flag::Invisible));
+			removed.push(ApplyMode::new(Flag::Invisible));
+			return;
+		}
+
+		let mode = ApplyMode::new(Flag::Invisible)
+			.with_update_by(client_socket.user().nickname());
+		client_socket.user_mut().set_flag(mode.clone());
+		added.push(mode);
+	}
+
+	fn update_wallops_flag(
+		&self,
+		client_socket: &mut Self::ClientSocket<'_>,
+		enabled: bool,
+		added: &mut Vec<ApplyMode<Flag>>,
+		removed: &mut Vec<ApplyMode<Flag>>,
+	)
+	{
+		if client_socket.user().has_wallops_flag() == enabled {
+			return;
+		}
+
+		self.clients.set_wallops_flag(client_socket.cid(), enabled);
+
+		if !enabled {
+			_ = client_socket.socket().leave(WALLOPS_ROOM);
+			_ = client_socket.socket().leave(GLOBOPS_ROOM);
+			client_socket
+				.user_mut()
+				.unset_flag(|flag| matches!(flag, Flag::Wallops));
+			removed.push(ApplyMode::new(Flag::Wallops));
+			return;
+		}
+
+		_ = client_socket.socket().join(WALLOPS_ROOM);
+		if client_socket.user().is_operator() {
+			_ = client_socket.socket().join(GLOBOPS_ROOM);
+		}
+
+		let mode = ApplyMode::new(Flag::Wallops)
+			.with_update_by(client_socket.user().nickname());
+		client_socket.user_mut().set_flag(mode.clone());
+		added.push(mode);
+	}
+}
@@ -0,0 +1,45 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use flex_chat::macros::command_formdata;
+
+command_formdata! {
+	struct USER_MODE
+	{
+		/// Les drapeaux utilisateurs à appliquer.
+		modes: UserModesSettings,
+	}
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UserModesSettings
+{
+	/// Les catégories de notices du serveur à appliquer/retirer, par exemple
+	/// `+ckn-o` (`*` pour toutes les catégories). Une valeur vide retire le
+	/// drapeau.
+	#[serde(rename = "s")]
+	pub server_notices: Option<Arc<str>>,
+	/// Ne pas apparaître dans les réponses de la commande WHO des clients ne
+	/// partageant aucun salon avec le client.
+	#[serde(rename = "i")]
+	pub invisible: Option<bool>,
+	/// Recevoir les messages WALLOPS (et GLOBOPS pour les opérateurs).
+	#[serde(rename = "w")]
+	pub wallops: Option<bool>,
+	/// Retire (`false`) le statut d'opérateur du client. Le statut
+	/// d'opérateur ne s'obtient qu'à l'aide de la commande /OPER.
+	#[serde(rename = "o")]
+	pub operator: Option<bool>,
+}
@@ -0,0 +1,101 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientSocketInterface;
+use flex_chat::user::UserOperatorInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::features::ChatApplication;
+use crate::features::chat::oper::{
+	OperApplicationInterface,
+	OperClientSocketErrorRepliesInterface,
+};
+use crate::features::chat::user_mode::{
+	UserModeApplicationInterface,
+	UserModeClientSocketCommandResponse,
+	UserModeCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct UserModeHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for UserModeHandler
+{
+	type App = ChatApplication;
+	type Data = UserModeCommandFormData;
+
+	const EVENT_NAME: &'static str = "USER MODE";
+
+	/// Applique ou retire des drapeaux au client courant. Seuls les drapeaux
+	/// `+i`, `+s` (réservé aux opérateurs) et `+w` peuvent être modifiés à
+	/// l'aide de cette commande ; un opérateur peut également retirer son
+	/// drapeau `+o`.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<UserModeCommandFormData>,
+	)
+	{
+		let mut client_socket = app.current_client_mut(&socket);
+
+		let mut added = vec![];
+		let mut removed = vec![];
+
+		if data.modes.operator == Some(false) {
+			app.unmarks_client_as_operator(&mut client_socket, &mut removed);
+		}
+
+		if let Some(changes) = data.modes.server_notices.as_deref() {
+			if client_socket.user().is_operator() {
+				let changes = if changes.is_empty() { "-*" } else { changes };
+				app.update_server_notices_flag(
+					&mut client_socket,
+					changes,
+					&mut added,
+					&mut removed,
+				);
+			} else {
+				client_socket.send_err_noprivileges();
+			}
+		}
+
+		if let Some(enabled) = data.modes.invisible {
+			app.update_invisible_flag(
+				&mut client_socket,
+				enabled,
+				&mut added,
+				&mut removed,
+			);
+		}
+
+		if let Some(enabled) = data.modes.wallops {
+			app.update_wallops_flag(
+				&mut client_socket,
+				enabled,
+				&mut added,
+				&mut removed,
+			);
+		}
+
+		if added.is_empty() && removed.is_empty() {
+			return;
+		}
+
+		client_socket.emit_user_modes_updated(&added, &removed);
+	}
+}
@@ -0,0 +1,75 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::mode::ApplyMode;
+use flex_chat::user::{Flag, ServerNoticeMask, UserInterface};
+
+use super::ServerNoticeCommandResponse;
+use crate::features::chat::mode::ModeCommandResponse;
+use crate::features::chat::user_mode::server_notice_room;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait UserModeClientSocketCommandResponse: ClientSocketInterface
+{
+	/// Émet une notice du serveur aux opérateurs abonnés à la catégorie
+	/// donnée.
+	fn emit_server_notice(&self, mask: ServerNoticeMask, text: &str)
+	{
+		let origin = Origin::from(self.client());
+		let server_notice = ServerNoticeCommandResponse {
+			origin: &origin,
+			tags: ServerNoticeCommandResponse::default_tags(),
+			mask: mask.letter(),
+			text,
+		};
+		self.emit_to(
+			server_notice_room(mask),
+			server_notice.name(),
+			server_notice,
+		);
+	}
+
+	/// Émet au client les drapeaux utilisateurs appliqués et retirés à
+	/// l'aide de la commande /MODE.
+	fn emit_user_modes_updated(
+		&self,
+		added: &[ApplyMode<Flag>],
+		removed: &[ApplyMode<Flag>],
+	)
+	{
+		let origin = Origin::from(self.client());
+		let mode_cmd = ModeCommandResponse {
+			origin: &origin,
+			tags: ModeCommandResponse::<()>::default_tags(),
+			added: added
+				.iter()
+				.map(|flag| (flag.letter(), flag.clone()))
+				.collect(),
+			removed: removed
+				.iter()
+				.map(|flag| (flag.letter(), flag.clone()))
+				.collect(),
+			target: self.user().nickname(),
+			updated: true,
+		};
+
+		self.emit(mode_cmd.name(), mode_cmd);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> UserModeClientSocketCommandResponse for Socket<'s> {}
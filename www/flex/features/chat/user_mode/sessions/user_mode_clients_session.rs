@@ -0,0 +1,106 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientInterface, ClientsSessionInterface};
+use flex_chat::mode::ApplyMode;
+use flex_chat::user::{Flag, UserFlagInterface};
+
+use crate::features::chat::sessions::ClientsSession;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait UserModeClientsSessionInterface: ClientsSessionInterface
+{
+	/// Définit les catégories de notices du serveur (drapeau `+s`) d'un
+	/// client. Un masque vide retire le drapeau.
+	fn set_server_notices_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		masks: &str,
+	);
+
+	/// Applique ou retire le drapeau `+i` d'un client.
+	fn set_invisible_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: bool,
+	);
+
+	/// Applique ou retire le drapeau `+w` d'un client.
+	fn set_wallops_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: bool,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl UserModeClientsSessionInterface for ClientsSession
+{
+	fn set_server_notices_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		masks: &str,
+	)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+
+		client
+			.user_mut()
+			.unset_flag(|flag| matches!(flag, Flag::ServerNotices(_)));
+
+		if !masks.is_empty() {
+			client.user_mut().set_flag(ApplyMode::new(Flag::ServerNotices(
+				masks.to_owned(),
+			)));
+		}
+	}
+
+	fn set_invisible_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: bool,
+	)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+
+		client.user_mut().unset_flag(|flag| matches!(flag, Flag::Invisible));
+
+		if enabled {
+			client.user_mut().set_flag(ApplyMode::new(Flag::Invisible));
+		}
+	}
+
+	fn set_wallops_flag(
+		&self,
+		client_id: &<Self::Client as ClientInterface>::ClientID,
+		enabled: bool,
+	)
+	{
+		let Some(mut client) = self.get_mut(client_id) else {
+			return;
+		};
+
+		client.user_mut().unset_flag(|flag| matches!(flag, Flag::Wallops));
+
+		if enabled {
+			client.user_mut().set_flag(ApplyMode::new(Flag::Wallops));
+		}
+	}
+}
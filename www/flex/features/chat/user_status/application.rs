@@ -52,8 +52,10 @@ impl UserStatusAwayApplicationInterface for ChatApplication
 		text: impl ToString,
 	)
 	{
-		self.clients.marks_client_as_away(client_socket.cid(), text);
+		let text = text.to_string();
+		self.clients.marks_client_as_away(client_socket.cid(), &text);
 		client_socket.send_rpl_nowaway();
+		client_socket.emit_away_notify(Some(&text));
 	}
 
 	fn marks_client_as_no_longer_away(
@@ -64,11 +66,13 @@ impl UserStatusAwayApplicationInterface for ChatApplication
 		if self.clients.is_client_away(client_socket.cid()) {
 			self.clients.marks_client_as_no_longer_away(client_socket.cid());
 			client_socket.send_rpl_unaway();
+			client_socket.emit_away_notify(None);
 		} else {
 			let away_message = "Je suis absent.";
 			self.clients
 				.marks_client_as_away(client_socket.cid(), away_message);
 			client_socket.send_rpl_nowaway();
+			client_socket.emit_away_notify(Some(away_message));
 		}
 	}
 }
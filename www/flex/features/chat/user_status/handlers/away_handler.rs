@@ -50,7 +50,8 @@ impl WebSocketHandler for AwayHandler
 	/// d'absence d'un client à travers les serveurs, le mode utilisateur "a"
 	/// devrait être utilisé à la place.
 	///
-	/// TODO: Notifier les clients avec les capacités serveur (away-notify).
+	/// Les membres des salons du client ayant activé la capacité serveur
+	/// `away-notify` sont notifiés du changement d'état d'absence.
 	fn handle(
 		socket: SocketRef,
 		State(app): State<ChatApplication>,
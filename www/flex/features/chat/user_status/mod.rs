@@ -25,7 +25,7 @@ flex_kernel::import! {
 
 	pub(crate) mod responses use {
 		pub(crate) mod away_command_response use *;
-		pub(super) mod rpl_away use *;
+		pub(crate) mod rpl_away use *;
 		pub(super) mod rpl_nowaway use *;
 		pub(super) mod rpl_unaway use *;
 	};
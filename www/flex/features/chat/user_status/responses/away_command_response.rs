@@ -8,17 +8,31 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::client::{Capability, ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
 use flex_chat::user::{UserAwayInterface, UserInterface};
 
 use super::{RplAwayReply, RplNowawayReply, RplUnawayReply};
 
+command_response! {
+	struct AWAY<'text>
+	{
+		/// Texte d'absence. Vaut [None] lorsque l'utilisateur n'est plus
+		/// absent.
+		text: Option<&'text str>,
+	}
+}
+
 // --------- //
 // Interface //
 // --------- //
 
 pub trait UserStatusClientSocketInterface: ClientSocketInterface
 {
+	/// Émet aux membres des salons du client ayant activé la capacité
+	/// serveur `away-notify`, le changement d'état d'absence du client.
+	fn emit_away_notify(&self, text: Option<&str>);
+
 	/// Émet au client les réponses liées à la commande /AWAY + /PRIVMSG
 	fn send_rpl_away(&self, target_client_socket: &Self)
 	{
@@ -60,4 +74,25 @@ pub trait UserStatusClientSocketInterface: ClientSocketInterface
 // Implémentation // -> Interface
 // -------------- //
 
-impl<'s> UserStatusClientSocketInterface for Socket<'s> {}
+impl<'s> UserStatusClientSocketInterface for Socket<'s>
+{
+	fn emit_away_notify(&self, text: Option<&str>)
+	{
+		let origin = Origin::from(self.client());
+		let away_command = AwayCommandResponse {
+			origin: &origin,
+			tags: AwayCommandResponse::default_tags(),
+			text,
+		};
+		let rooms = self
+			.channels_rooms_set()
+			.into_iter()
+			.map(|channel_id| format!("channel:{channel_id}"));
+		self.emit_to_capable(
+			rooms,
+			Capability::AwayNotify,
+			away_command.name(),
+			&away_command,
+		);
+	}
+}
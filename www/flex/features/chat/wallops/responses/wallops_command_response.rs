@@ -0,0 +1,69 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+use flex_chat::macros::command_response;
+
+use crate::features::chat::user_mode::{GLOBOPS_ROOM, WALLOPS_ROOM};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WallopsClientSocketCommandResponse: ClientSocketInterface
+{
+	/// Émet un message GLOBOPS aux opérateurs ayant le drapeau `+w`.
+	fn emit_globops(&self, text: &str)
+	{
+		let origin = Origin::from(self.client());
+		let globops_cmd = GlobopsCommandResponse {
+			origin: &origin,
+			tags: GlobopsCommandResponse::default_tags(),
+			text,
+		};
+		self.emit_within(GLOBOPS_ROOM, globops_cmd.name(), globops_cmd);
+	}
+
+	/// Émet un message WALLOPS aux utilisateurs ayant le drapeau `+w`.
+	fn emit_wallops(&self, text: &str)
+	{
+		let origin = Origin::from(self.client());
+		let wallops_cmd = WallopsCommandResponse {
+			origin: &origin,
+			tags: WallopsCommandResponse::default_tags(),
+			text,
+		};
+		self.emit_within(WALLOPS_ROOM, wallops_cmd.name(), wallops_cmd);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> WallopsClientSocketCommandResponse for Socket<'s> {}
+
+command_response! {
+	/// Message d'un opérateur destiné aux opérateurs ayant le drapeau `+w`.
+	struct GLOBOPS<'a>
+	{
+		/// Le message.
+		text: &'a str,
+	}
+}
+
+command_response! {
+	/// Message d'un opérateur destiné aux utilisateurs ayant le drapeau `+w`.
+	struct WALLOPS<'a>
+	{
+		/// Le message.
+		text: &'a str,
+	}
+}
@@ -0,0 +1,147 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
+	MemberInterface,
+};
+use flex_chat::client::{
+	ClientInterface,
+	ClientServerApplicationInterface,
+	ClientSocketInterface,
+	Socket,
+};
+use flex_chat::user::{
+	UserAddressInterface,
+	UserFlagInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+use flex_wildcard_matching::WildcardMatching;
+
+use crate::features::ChatApplication;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhoApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface
+	where
+		Self: 'cs;
+
+	/// Les clients, visibles par le client courant, correspondant à un masque
+	/// `nick!ident@host`.
+	///
+	/// Un utilisateur invisible n'est visible que par lui-même, par les
+	/// clients partageant un salon avec lui et par les opérateurs globaux.
+	fn who_clients_by_mask(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		mask: &str,
+	) -> Vec<<Self::ClientSocket<'_> as ClientSocketInterface>::Client>;
+
+	/// Les membres d'un salon, visibles par le client courant, avec le
+	/// symbole de leur plus haut niveau d'accès.
+	///
+	/// Les membres d'un salon secret ne sont visibles que par les membres du
+	/// salon et les opérateurs globaux. Les membres invisibles ne sont pas
+	/// visibles par les clients n'étant pas membres du salon.
+	fn who_members_of_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &str,
+	) -> Vec<(
+		<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
+		String,
+	)>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl WhoApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn who_clients_by_mask(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		mask: &str,
+	) -> Vec<<Self::ClientSocket<'_> as ClientSocketInterface>::Client>
+	{
+		let is_global_operator = client_socket.user().is_global_operator();
+		let client_channels = client_socket.client().channels();
+
+		self.clients
+			.clients
+			.iter()
+			.filter(|rm| rm.is_registered())
+			.filter(|rm| {
+				let user = rm.user();
+				if mask.contains(['!', '@']) {
+					user.full_address().iswm(mask)
+				} else {
+					user.nickname().iswm(mask)
+				}
+			})
+			.filter(|rm| {
+				is_global_operator
+					|| !rm.user().has_invisible_flag()
+					|| rm.cid() == client_socket.cid()
+					|| !rm.channels().is_disjoint(client_channels)
+			})
+			.map(|rm| rm.value().clone())
+			.collect()
+	}
+
+	fn who_members_of_channel(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		channel_name: &str,
+	) -> Vec<(
+		<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
+		String,
+	)>
+	{
+		let Some(channel) = self.channels.get(channel_name) else {
+			return Default::default();
+		};
+
+		let can_see_all_members = client_socket.user().is_global_operator()
+			|| channel.members().contains_key(client_socket.cid());
+
+		if !can_see_all_members && channel.modes_settings.has_secret_flag() {
+			return Default::default();
+		}
+
+		channel
+			.members()
+			.iter()
+			.filter_map(|(member_id, member)| {
+				let client = self.get_client_by_id(member_id)?;
+
+				if !can_see_all_members && client.user().has_invisible_flag() {
+					return None;
+				}
+
+				let prefix = member
+					.highest_access_level()
+					.map(|access_level| access_level.symbol().to_string())
+					.unwrap_or_default();
+
+				Some((client, prefix))
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,143 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::macros::command_formdata;
+
+command_formdata! {
+	struct WHO
+	{
+		/// Nom de salon ou masque `nick!ident@host`. Les caractères génériques
+		/// `*` et `?` sont autorisés. Un masque vide ou valant `0` correspond à
+		/// tous les utilisateurs.
+		#[serde(default)]
+		mask: String,
+		/// Filtre `o`: seuls les opérateurs sont retournés.
+		#[serde(default)]
+		operators_only: bool,
+		/// Champs WHOX à retourner (`%tcuhnfar`). Lorsque la liste est vide,
+		/// la réponse classique (RPL_WHOREPLY) est utilisée.
+		#[serde(default, deserialize_with = "validate_whox_fields")]
+		fields: Vec<WhoxField>,
+		/// Jeton de requête WHOX, renvoyé tel quel avec le champ `t`.
+		#[serde(default, deserialize_with = "validate_whox_token")]
+		token: Option<String>,
+	}
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Champs WHOX, dans l'ordre dans lequel ils sont retournés.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WhoxField
+{
+	/// `t`: jeton de la requête.
+	Token,
+	/// `c`: salon.
+	Channel,
+	/// `u`: ident de l'utilisateur.
+	User,
+	/// `h`: hôte (virtuel ou masqué) de l'utilisateur.
+	Host,
+	/// `n`: pseudonyme de l'utilisateur.
+	Nick,
+	/// `f`: drapeaux (absence, opérateur, niveau d'accès).
+	Flags,
+	/// `a`: compte auquel l'utilisateur est identifié.
+	Account,
+	/// `r`: nom réel de l'utilisateur.
+	Realname,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl TryFrom<char> for WhoxField
+{
+	type Error = char;
+
+	fn try_from(letter: char) -> Result<Self, Self::Error>
+	{
+		Ok(match letter {
+			| 't' => Self::Token,
+			| 'c' => Self::Channel,
+			| 'u' => Self::User,
+			| 'h' => Self::Host,
+			| 'n' => Self::Nick,
+			| 'f' => Self::Flags,
+			| 'a' => Self::Account,
+			| 'r' => Self::Realname,
+			| _ => return Err(letter),
+		})
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Valide les champs WHOX (`tcuhnfar`), triés et dédoublonnés.
+pub fn validate_whox_fields<'de, D>(
+	deserializer: D,
+) -> Result<Vec<WhoxField>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	use serde::Deserialize;
+
+	let s = String::deserialize(deserializer)?;
+
+	let mut fields = s
+		.trim_start_matches('%')
+		.chars()
+		.map(WhoxField::try_from)
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|letter| {
+			serde::de::Error::custom(format!(
+				"Le champ WHOX '{letter}' n'est pas supporté"
+			))
+		})?;
+
+	fields.sort();
+	fields.dedup();
+
+	Ok(fields)
+}
+
+/// Valide le jeton de requête WHOX: 1 à 3 chiffres.
+pub fn validate_whox_token<'de, D>(
+	deserializer: D,
+) -> Result<Option<String>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	use serde::Deserialize;
+
+	let Some(token) = Option::<String>::deserialize(deserializer)? else {
+		return Ok(None);
+	};
+
+	if token.is_empty()
+		|| token.len() > 3
+		|| !token.chars().all(|ch| ch.is_ascii_digit())
+	{
+		return Err(serde::de::Error::custom(
+			"Le jeton WHOX doit être composé de 1 à 3 chiffres",
+		));
+	}
+
+	Ok(Some(token))
+}
@@ -0,0 +1,110 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientInterface, ClientSocketInterface};
+use flex_chat::user::UserOperatorInterface;
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::FlexChatConfig;
+use crate::features::ChatApplication;
+use crate::features::chat::who::{
+	WhoApplicationInterface,
+	WhoClientSocketCommandResponseInterface,
+	WhoCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct WhoHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for WhoHandler
+{
+	type App = ChatApplication;
+	type Data = WhoCommandFormData;
+
+	const EVENT_NAME: &'static str = "WHO";
+
+	/// La commande WHO est utilisée pour obtenir la liste des utilisateurs
+	/// d'un salon ou des utilisateurs correspondant à un masque.
+	///
+	/// Avec les champs WHOX, seuls les champs demandés sont retournés, dans
+	/// une réponse RPL_WHOSPCRPL.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<WhoCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>()
+		else {
+			return;
+		};
+
+		let mask = match data.mask.as_str() {
+			| "" | "0" => "*",
+			| mask => mask,
+		};
+
+		let entries: Vec<_> = if mask.starts_with('#') {
+			app.who_members_of_channel(&client_socket, mask)
+				.into_iter()
+				.map(|(client, prefix)| (mask.to_owned(), client, prefix))
+				.collect()
+		} else {
+			app.who_clients_by_mask(&client_socket, mask)
+				.into_iter()
+				.map(|client| {
+					let channel = client
+						.channels()
+						.iter()
+						.find(|name| client_socket.client().has_channel(name))
+						.cloned()
+						.unwrap_or_else(|| String::from("*"));
+					(channel, client, String::new())
+				})
+				.collect()
+		};
+
+		for (channel, client, prefix) in entries.iter() {
+			if data.operators_only && !client.user().is_operator() {
+				continue;
+			}
+
+			if data.fields.is_empty() {
+				client_socket.send_rpl_whoreply(
+					channel,
+					client,
+					prefix,
+					&config.server.name,
+				);
+			} else {
+				client_socket.send_rpl_whospcrpl(
+					&data.fields,
+					data.token.as_deref(),
+					channel,
+					client,
+					prefix,
+				);
+			}
+		}
+
+		client_socket.send_rpl_endofwho(mask);
+	}
+}
@@ -0,0 +1,155 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{
+	Client,
+	ClientInterface,
+	ClientSocketInterface,
+	Origin,
+	Socket,
+};
+use flex_chat::user::{
+	UserAwayInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+
+use super::{RplEndofwhoReply, RplWhoreplyReply, RplWhospcrplReply};
+use crate::features::chat::who::WhoxField;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhoClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client la fin de la réponse de la commande /WHO.
+	fn send_rpl_endofwho(&self, mask: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_endofwho = RplEndofwhoReply {
+			origin: &origin,
+			tags: RplEndofwhoReply::default_tags(),
+			mask,
+		};
+		self.emit(rpl_endofwho.name(), rpl_endofwho);
+	}
+
+	/// Émet au client une réponse classique de la commande /WHO.
+	fn send_rpl_whoreply(
+		&self,
+		channel: &str,
+		target: &Self::Client,
+		prefix: &str,
+		server: &str,
+	);
+
+	/// Émet au client une réponse WHOX de la commande /WHO, ne contenant que
+	/// les champs demandés.
+	fn send_rpl_whospcrpl(
+		&self,
+		fields: &[WhoxField],
+		token: Option<&str>,
+		channel: &str,
+		target: &Self::Client,
+		prefix: &str,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> WhoClientSocketCommandResponseInterface for Socket<'s>
+{
+	fn send_rpl_whoreply(
+		&self,
+		channel: &str,
+		target: &Client,
+		prefix: &str,
+		server: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let user = target.user();
+		let host = user.host().to_string();
+		let flags = who_flags(target, prefix);
+		let rpl_whoreply = RplWhoreplyReply {
+			origin: &origin,
+			tags: RplWhoreplyReply::default_tags(),
+			channel,
+			user: user.ident(),
+			host: &host,
+			server,
+			nick: user.nickname(),
+			flags: &flags,
+			hopcount: &0,
+			realname: &user.realname,
+		};
+		self.emit(rpl_whoreply.name(), rpl_whoreply);
+	}
+
+	fn send_rpl_whospcrpl(
+		&self,
+		fields: &[WhoxField],
+		token: Option<&str>,
+		channel: &str,
+		target: &Client,
+		prefix: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let user = target.user();
+
+		let fields = fields
+			.iter()
+			.map(|field| {
+				match field {
+					| WhoxField::Token => token.unwrap_or("0").to_owned(),
+					| WhoxField::Channel => channel.to_owned(),
+					| WhoxField::User => user.ident().to_owned(),
+					| WhoxField::Host => user.host().to_string(),
+					| WhoxField::Nick => user.nickname().to_owned(),
+					| WhoxField::Flags => who_flags(target, prefix),
+					| WhoxField::Account => {
+						user.account().unwrap_or("0").to_owned()
+					}
+					| WhoxField::Realname => format!(":{}", user.realname),
+				}
+			})
+			.collect::<Vec<_>>()
+			.join(" ");
+
+		let rpl_whospcrpl = RplWhospcrplReply {
+			origin: &origin,
+			tags: RplWhospcrplReply::default_tags(),
+			fields: &fields,
+		};
+		self.emit(rpl_whospcrpl.name(), rpl_whospcrpl);
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Drapeaux d'une réponse /WHO: `H` (présent) ou `G` (absent), `*` pour les
+/// opérateurs, suivi du préfixe du niveau d'accès sur le salon.
+fn who_flags(target: &Client, prefix: &str) -> String
+{
+	let user = target.user();
+	let mut flags = String::from(if user.is_away() { "G" } else { "H" });
+	if user.is_operator() {
+		flags.push('*');
+	}
+	flags.push_str(prefix);
+	flags
+}
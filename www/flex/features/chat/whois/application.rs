@@ -0,0 +1,98 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::channel::{
+	ChannelInterface,
+	ChannelMemberInterface,
+	ChannelsSessionInterface,
+	MemberInterface,
+};
+use flex_chat::client::{ClientInterface, ClientSocketInterface, Socket};
+use flex_chat::user::UserOperatorInterface;
+
+use crate::features::ChatApplication;
+use crate::features::chat::list::ListApplicationInterface;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhoisApplicationInterface
+{
+	type ClientSocket<'cs>: ClientSocketInterface;
+
+	/// Les salons d'un client cible, visibles par le client courant. Chaque
+	/// salon est préfixé par le symbole du plus haut niveau d'accès du
+	/// client cible sur ce salon.
+	///
+	/// Les salons secrets ne sont visibles que par leurs membres et par les
+	/// opérateurs globaux, de même que les salons sur lesquels le client
+	/// cible est un membre masqué (+D) ou invisible (+u).
+	fn whois_channels_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		target: &<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
+	) -> Vec<String>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl WhoisApplicationInterface for ChatApplication
+{
+	type ClientSocket<'cs> = Socket<'cs>;
+
+	fn whois_channels_of_client(
+		&self,
+		client_socket: &Self::ClientSocket<'_>,
+		target: &<Self::ClientSocket<'_> as ClientSocketInterface>::Client,
+	) -> Vec<String>
+	{
+		let can_see_secret_channels = client_socket.user().is_global_operator()
+			|| client_socket.cid() == target.cid();
+
+		target
+			.channels()
+			.iter()
+			.filter_map(|channel_name| {
+				let channel = self.channels.get(channel_name)?;
+
+				if channel.modes_settings.has_secret_flag()
+					&& !can_see_secret_channels
+					&& !self.is_client_has_channel(
+						client_socket.cid(),
+						channel_name,
+					) {
+					return None;
+				}
+
+				// NOTE: les membres masqués d'un salon +D, et les membres
+				//       invisibles d'un salon +u, ne sont pas dévoilés.
+				let member = channel.member(target.cid())?;
+				if !can_see_secret_channels
+					&& (member.is_hidden()
+						|| (!channel.is_member_visible(target.cid())
+							&& !channel
+								.can_member_see_everyone(client_socket.cid())))
+				{
+					return None;
+				}
+
+				let symbol = member
+					.highest_access_level()
+					.map(|access_level| access_level.symbol().to_string())
+					.unwrap_or_default();
+
+				Some(format!("{symbol}{}", channel.name()))
+			})
+			.collect()
+	}
+}
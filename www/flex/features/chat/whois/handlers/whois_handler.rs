@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::ClientInterface;
+use flex_chat::client::nick::responses::NickClientSocketErrorReplies;
+use flex_chat::user::{
+	UserAwayInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::{FlexChatConfig, FlexChatConfigOperatorPrivilege};
+use crate::features::ChatApplication;
+use crate::features::chat::oper::OperApplicationInterface;
+use crate::features::chat::whois::{
+	WhoisApplicationInterface,
+	WhoisClientSocketCommandResponseInterface,
+	WhoisCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct WhoisHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for WhoisHandler
+{
+	type App = ChatApplication;
+	type Data = WhoisCommandFormData;
+
+	const EVENT_NAME: &'static str = "WHOIS";
+
+	/// La commande WHOIS est utilisée pour obtenir des informations sur des
+	/// utilisateurs connectés.
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<WhoisCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>()
+		else {
+			return;
+		};
+
+		let server_info = config
+			.network
+			.description
+			.as_deref()
+			.unwrap_or(&config.network.name);
+
+		for nickname in data.nicknames.iter() {
+			let Some(target) = app.clients.get_by_nickname(nickname) else {
+				client_socket.send_err_nosuchnick(nickname);
+				client_socket.send_rpl_endofwhois(nickname);
+				continue;
+			};
+
+			let user = target.user();
+
+			client_socket.send_rpl_whoisuser(&target);
+
+			if app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::SeeRealIp,
+			) {
+				client_socket.send_rpl_whoisactually(
+					user.nickname(),
+					&user.host().ip_addr.expose().to_string(),
+				);
+			}
+
+			let channels =
+				app.whois_channels_of_client(&client_socket, &target);
+			if !channels.is_empty() {
+				client_socket
+					.send_rpl_whoischannels(user.nickname(), &channels);
+			}
+
+			client_socket.send_rpl_whoisserver(
+				user.nickname(),
+				&config.server.name,
+				server_info,
+			);
+
+			if user.is_away() {
+				client_socket.send_rpl_whoisaway(&target);
+			}
+
+			if user.is_operator() {
+				client_socket.send_rpl_whoisoperator(&target);
+			}
+
+			if let Some(account) = user.account() {
+				client_socket.send_rpl_whoisaccount(user.nickname(), account);
+			}
+
+			client_socket.send_rpl_whoisidle(&target);
+			client_socket.send_rpl_endofwhois(user.nickname());
+		}
+	}
+}
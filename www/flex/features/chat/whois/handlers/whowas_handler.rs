@@ -0,0 +1,91 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_web_framework::WebSocketHandler;
+use socketioxide::extract::{Data, SocketRef, State};
+
+use crate::config::chat::{FlexChatConfig, FlexChatConfigOperatorPrivilege};
+use crate::features::ChatApplication;
+use crate::features::chat::oper::OperApplicationInterface;
+use crate::features::chat::whois::{
+	WhoisClientSocketCommandResponseInterface,
+	WhowasClientSocketCommandResponseInterface,
+	WhowasClientSocketErrorRepliesInterface,
+	WhowasClientsSessionInterface,
+	WhowasCommandFormData,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct WhowasHandler;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WebSocketHandler for WhowasHandler
+{
+	type App = ChatApplication;
+	type Data = WhowasCommandFormData;
+
+	const EVENT_NAME: &'static str = "WHOWAS";
+
+	/// La commande WHOWAS est utilisée pour obtenir des informations sur des
+	/// pseudonymes qui ne sont plus utilisés (changement de pseudonyme ou
+	/// déconnexion).
+	fn handle(
+		socket: SocketRef,
+		State(app): State<ChatApplication>,
+		Data(data): Data<WhowasCommandFormData>,
+	)
+	{
+		let client_socket = app.current_client(&socket);
+
+		let Some(config) =
+			socket.req_parts().extensions.get::<FlexChatConfig>()
+		else {
+			return;
+		};
+
+		let entries = app.clients.whowas(&data.nickname, data.count);
+
+		if entries.is_empty() {
+			client_socket.send_err_wasnosuchnick(&data.nickname);
+		}
+
+		for entry in entries.iter() {
+			client_socket.send_rpl_whowasuser(entry);
+
+			if app.does_client_have_operator_privilege(
+				&client_socket,
+				FlexChatConfigOperatorPrivilege::SeeRealIp,
+			) {
+				client_socket.send_rpl_whoisactually(
+					&entry.nickname,
+					&entry.ip_addr.to_string(),
+				);
+			}
+
+			if let Some(account) = entry.account.as_deref() {
+				client_socket.send_rpl_whoisaccount(&entry.nickname, account);
+			}
+
+			client_socket.send_rpl_whoisserver(
+				&entry.nickname,
+				&config.server.name,
+				&entry.logged_at.to_rfc2822(),
+			);
+		}
+
+		client_socket.send_rpl_endofwhowas(&data.nickname);
+	}
+}
@@ -0,0 +1,47 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+flex_kernel::import! {
+	pub mod application use *;
+
+	pub mod handlers use {
+		pub mod whois_handler use *;
+		pub mod whowas_handler use *;
+	};
+
+	pub mod sessions use {
+		pub mod whowas_session use *;
+	};
+
+	mod errors use {
+		pub(super) mod err_wasnosuchnick use *;
+	};
+
+	mod forms use {
+		pub(super) mod whois_form use *;
+		pub(super) mod whowas_form use *;
+	};
+
+	pub(super) mod responses use {
+		pub(super) mod whois_command_response use *;
+		pub(super) mod whowas_command_response use *;
+		pub(super) mod whowas_error_response use *;
+		pub(super) mod rpl_endofwhois use *;
+		pub(super) mod rpl_endofwhowas use *;
+		pub(super) mod rpl_whoisaccount use *;
+		pub(super) mod rpl_whoisactually use *;
+		pub(super) mod rpl_whoischannels use *;
+		pub(super) mod rpl_whoisidle use *;
+		pub(super) mod rpl_whoisoperator use *;
+		pub(super) mod rpl_whoisserver use *;
+		pub(super) mod rpl_whoisuser use *;
+		pub(super) mod rpl_whowasuser use *;
+	};
+}
@@ -0,0 +1,192 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{
+	Client,
+	ClientInterface,
+	ClientSocketInterface,
+	Origin,
+	Socket,
+};
+use flex_chat::user::{
+	UserAwayInterface,
+	UserInterface,
+	UserOperatorInterface,
+};
+
+use super::{
+	RplEndofwhoisReply,
+	RplWhoisaccountReply,
+	RplWhoisactuallyReply,
+	RplWhoischannelsReply,
+	RplWhoisidleReply,
+	RplWhoisoperatorReply,
+	RplWhoisserverReply,
+	RplWhoisuserReply,
+};
+use crate::features::chat::user_status::RplAwayReply;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhoisClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client le message d'absence d'un client cible.
+	fn send_rpl_whoisaway(&self, target: &Self::Client);
+
+	/// Émet au client le compte auquel est identifié un client cible.
+	fn send_rpl_whoisaccount(&self, nickname: &str, account: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_whoisaccount = RplWhoisaccountReply {
+			origin: &origin,
+			tags: RplWhoisaccountReply::default_tags(),
+			nick: nickname,
+			account,
+		};
+		self.emit(rpl_whoisaccount.name(), rpl_whoisaccount);
+	}
+
+	/// Émet au client l'adresse IP réelle d'un client cible.
+	fn send_rpl_whoisactually(&self, nickname: &str, ip: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_whoisactually = RplWhoisactuallyReply {
+			origin: &origin,
+			tags: RplWhoisactuallyReply::default_tags(),
+			nick: nickname,
+			ip,
+		};
+		self.emit(rpl_whoisactually.name(), rpl_whoisactually);
+	}
+
+	/// Émet au client les salons d'un client cible.
+	fn send_rpl_whoischannels(&self, nickname: &str, channels: &[String])
+	{
+		let origin = Origin::from(self.client());
+		let channels = channels.join(" ");
+		let rpl_whoischannels = RplWhoischannelsReply {
+			origin: &origin,
+			tags: RplWhoischannelsReply::default_tags(),
+			nick: nickname,
+			channels: &channels,
+		};
+		self.emit(rpl_whoischannels.name(), rpl_whoischannels);
+	}
+
+	/// Émet au client la durée d'inactivité et la date de connexion d'un
+	/// client cible.
+	fn send_rpl_whoisidle(&self, target: &Self::Client);
+
+	/// Émet au client le type d'opérateur d'un client cible.
+	fn send_rpl_whoisoperator(&self, target: &Self::Client);
+
+	/// Émet au client le serveur sur lequel est connecté un client cible.
+	fn send_rpl_whoisserver(
+		&self,
+		nickname: &str,
+		server: &str,
+		server_info: &str,
+	)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_whoisserver = RplWhoisserverReply {
+			origin: &origin,
+			tags: RplWhoisserverReply::default_tags(),
+			nick: nickname,
+			server,
+			server_info,
+		};
+		self.emit(rpl_whoisserver.name(), rpl_whoisserver);
+	}
+
+	/// Émet au client les informations utilisateur d'un client cible.
+	fn send_rpl_whoisuser(&self, target: &Self::Client);
+
+	/// Émet au client la fin de la réponse de la commande /WHOIS.
+	fn send_rpl_endofwhois(&self, nickname: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_endofwhois = RplEndofwhoisReply {
+			origin: &origin,
+			tags: RplEndofwhoisReply::default_tags(),
+			nick: nickname,
+		};
+		self.emit(rpl_endofwhois.name(), rpl_endofwhois);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> WhoisClientSocketCommandResponseInterface for Socket<'s>
+{
+	fn send_rpl_whoisaway(&self, target: &Client)
+	{
+		let origin = Origin::from(target);
+		let message = target.user().away_message();
+		let rpl_away = RplAwayReply {
+			origin: &origin,
+			tags: RplAwayReply::default_tags(),
+			message: &message,
+			nick: target.user().nickname(),
+		};
+		self.emit(rpl_away.name(), rpl_away);
+	}
+
+	fn send_rpl_whoisidle(&self, target: &Client)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_whoisidle = RplWhoisidleReply {
+			origin: &origin,
+			tags: RplWhoisidleReply::default_tags(),
+			nick: target.user().nickname(),
+			idle: &target.idle_time(),
+			signon: &target.signon_at().timestamp(),
+		};
+		self.emit(rpl_whoisidle.name(), rpl_whoisidle);
+	}
+
+	fn send_rpl_whoisoperator(&self, target: &Client)
+	{
+		let origin = Origin::from(self.client());
+		let operator_type = if target.user().is_global_operator() {
+			"global"
+		} else {
+			"local"
+		};
+		let rpl_whoisoperator = RplWhoisoperatorReply {
+			origin: &origin,
+			tags: RplWhoisoperatorReply::default_tags(),
+			nick: target.user().nickname(),
+			operator_type,
+		};
+		self.emit(rpl_whoisoperator.name(), rpl_whoisoperator);
+	}
+
+	fn send_rpl_whoisuser(&self, target: &Client)
+	{
+		let origin = Origin::from(self.client());
+		let user = target.user();
+		let host = user.host().to_string();
+		let rpl_whoisuser = RplWhoisuserReply {
+			origin: &origin,
+			tags: RplWhoisuserReply::default_tags(),
+			nick: user.nickname(),
+			user: user.ident(),
+			host: &host,
+			realname: &user.realname,
+		};
+		self.emit(rpl_whoisuser.name(), rpl_whoisuser);
+	}
+}
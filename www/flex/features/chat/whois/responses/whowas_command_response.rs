@@ -0,0 +1,55 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use super::{RplEndofwhowasReply, RplWhowasuserReply};
+use crate::features::chat::whois::WhowasEntry;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhowasClientSocketCommandResponseInterface:
+	ClientSocketInterface
+{
+	/// Émet au client une identité passée de l'historique WHOWAS.
+	fn send_rpl_whowasuser(&self, entry: &WhowasEntry)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_whowasuser = RplWhowasuserReply {
+			origin: &origin,
+			tags: RplWhowasuserReply::default_tags(),
+			nick: &entry.nickname,
+			user: &entry.ident,
+			host: &entry.host,
+			realname: &entry.realname,
+		};
+		self.emit(rpl_whowasuser.name(), rpl_whowasuser);
+	}
+
+	/// Émet au client la fin de la réponse de la commande /WHOWAS.
+	fn send_rpl_endofwhowas(&self, nickname: &str)
+	{
+		let origin = Origin::from(self.client());
+		let rpl_endofwhowas = RplEndofwhowasReply {
+			origin: &origin,
+			tags: RplEndofwhowasReply::default_tags(),
+			nick: nickname,
+		};
+		self.emit(rpl_endofwhowas.name(), rpl_endofwhowas);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> WhowasClientSocketCommandResponseInterface for Socket<'s> {}
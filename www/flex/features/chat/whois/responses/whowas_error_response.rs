@@ -0,0 +1,39 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use flex_chat::client::{ClientSocketInterface, Origin, Socket};
+
+use crate::features::chat::whois::ErrWasnosuchnickError;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhowasClientSocketErrorRepliesInterface:
+	ClientSocketInterface
+{
+	/// Émet au client l'erreur [ErrWasnosuchnickError].
+	fn send_err_wasnosuchnick(&self, nickname: &str)
+	{
+		let origin = Origin::from(self.client());
+		let err_wasnosuchnick = ErrWasnosuchnickError {
+			origin: &origin,
+			nickname,
+			tags: ErrWasnosuchnickError::default_tags(),
+		};
+		self.emit(err_wasnosuchnick.name(), err_wasnosuchnick);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'s> WhowasClientSocketErrorRepliesInterface for Socket<'s> {}
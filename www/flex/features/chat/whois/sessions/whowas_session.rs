@@ -0,0 +1,142 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::VecDeque;
+use std::net;
+use std::sync::RwLock;
+
+use flex_chat::client::{Client, ClientInterface, ClientsSessionInterface};
+use flex_chat::user::UserInterface;
+
+use crate::features::chat::sessions::ClientsSession;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Nombre maximum d'identités conservées dans l'historique WHOWAS.
+pub const WHOWAS_HISTORY_CAPACITY: usize = 1024;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Historique borné (tampon circulaire) des identités passées des clients.
+#[derive(Default)]
+pub struct WhowasSession
+{
+	entries: RwLock<VecDeque<WhowasEntry>>,
+}
+
+/// Identité passée d'un client.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct WhowasEntry
+{
+	/// Pseudonyme utilisé par le client.
+	pub nickname: String,
+	/// Identifiant de l'utilisateur.
+	pub ident: String,
+	/// Hôte (virtuel ou masqué) de l'utilisateur.
+	pub host: String,
+	/// Adresse IP réelle de l'utilisateur.
+	pub ip_addr: net::IpAddr,
+	/// Nom réel de l'utilisateur.
+	pub realname: String,
+	/// Compte auquel l'utilisateur était identifié.
+	pub account: Option<String>,
+	/// Date à laquelle l'identité a été abandonnée.
+	pub logged_at: chrono::DateTime<chrono::Utc>,
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait WhowasClientsSessionInterface: ClientsSessionInterface
+{
+	/// Enregistre l'identité courante d'un client dans l'historique WHOWAS.
+	fn record_identity_of_client(&self, client: &Self::Client);
+
+	/// Les dernières identités (des plus récentes aux plus anciennes)
+	/// correspondant à un pseudonyme.
+	fn whowas(&self, nickname: &str, count: Option<usize>) -> Vec<WhowasEntry>;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl WhowasSession
+{
+	/// Ajoute une entrée à l'historique, en supprimant la plus ancienne
+	/// lorsque la capacité maximale est atteinte.
+	pub fn push(&self, entry: WhowasEntry)
+	{
+		let mut entries = self.entries.write().unwrap();
+		if entries.len() >= WHOWAS_HISTORY_CAPACITY {
+			entries.pop_front();
+		}
+		entries.push_back(entry);
+	}
+
+	/// Cherche les entrées d'un pseudonyme, des plus récentes aux plus
+	/// anciennes.
+	pub fn find(&self, nickname: &str, count: Option<usize>)
+	-> Vec<WhowasEntry>
+	{
+		let nickname = nickname.to_lowercase();
+		let entries = self.entries.read().unwrap();
+		entries
+			.iter()
+			.rev()
+			.filter(|entry| entry.nickname.to_lowercase() == nickname)
+			.take(count.filter(|n| *n > 0).unwrap_or(usize::MAX))
+			.cloned()
+			.collect()
+	}
+}
+
+impl From<&Client> for WhowasEntry
+{
+	fn from(client: &Client) -> Self
+	{
+		let user = client.user();
+		Self {
+			nickname: user.nickname().to_owned(),
+			ident: user.ident().to_owned(),
+			host: user.host().to_string(),
+			ip_addr: *user.host().ip_addr.expose(),
+			realname: user.realname.clone(),
+			account: user.account().map(ToOwned::to_owned),
+			logged_at: chrono::Utc::now(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl WhowasClientsSessionInterface for ClientsSession
+{
+	fn record_identity_of_client(&self, client: &Self::Client)
+	{
+		if !client.is_registered() {
+			return;
+		}
+		self.whowas.push(WhowasEntry::from(client));
+	}
+
+	fn whowas(&self, nickname: &str, count: Option<usize>) -> Vec<WhowasEntry>
+	{
+		self.whowas.find(nickname, count)
+	}
+}
@@ -10,7 +10,7 @@
 
 use flex_web_framework::types::uuid;
 
-use crate::features::users::entities::UserRole;
+use crate::features::users::entities::{UserEntity, UserRole};
 
 // --------- //
 // Structure //
@@ -38,3 +38,26 @@ pub struct UserSessionDTO
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub city: Option<String>,
 }
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<UserEntity> for UserSessionDTO
+{
+	fn from(user: UserEntity) -> Self
+	{
+		Self {
+			id: user.id,
+			name: user.name,
+			email: user.email,
+			role: user.role,
+			avatar: user.avatar,
+			firstname: user.firstname,
+			lastname: user.lastname,
+			gender: user.gender,
+			country: user.country,
+			city: user.city,
+		}
+	}
+}
@@ -46,6 +46,9 @@ pub trait UserRepository
 		private: UserAccountStatus,
 	) -> Result<UserEntity, sqlx::Error>;
 
+	/// Cherche un utilisateur par son ID.
+	async fn find_by_id(&self, id: &Uuid) -> Result<UserEntity, sqlx::Error>;
+
 	/// Cherche un utilisateur par son adresse e-mail.
 	async fn find_by_email(
 		&self,
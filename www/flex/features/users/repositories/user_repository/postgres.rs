@@ -89,6 +89,19 @@ impl UserRepository for UserRepositoryPostgreSQL
 			.await
 	}
 
+	async fn find_by_id(
+		&self,
+		user_id: &Uuid,
+	) -> Result<UserEntity, sqlx::Error>
+	{
+		self.query_builder
+			.table(Self::TABLE_NAME)
+			.select_all()
+			.where_eq(("id::uuid", user_id))
+			.fetch_one()
+			.await
+	}
+
 	async fn find_by_email(
 		&self,
 		email: &email::EmailAddress,